@@ -1,10 +1,33 @@
 use std::io::{self, Write};
 
+use polymarket_hft::client::{RequestDebug, ValidatableRequest};
+
 /// Write pretty JSON to stdout using a streaming writer.
+///
+/// Non-finite floats (NaN, infinity) are written as `null` with a warning.
 pub fn write_json_output<T: serde::Serialize>(value: &T) -> anyhow::Result<()> {
+    polymarket_hft::json::warn_non_finite(value);
     let stdout = io::stdout();
     let mut handle = stdout.lock();
+    // serde_json writes NaN and infinities as `null`.
     serde_json::to_writer_pretty(&mut handle, value)?;
     writeln!(handle)?;
     Ok(())
 }
+
+/// Print the resolved URL of a request instead of sending it.
+pub fn print_request_url<R: RequestDebug>(request: &R, base_url: &str) -> anyhow::Result<()> {
+    let base = url::Url::parse(base_url)?;
+    println!("{}", request.debug_url(&base));
+    Ok(())
+}
+
+/// Check a request's parameters without sending it.
+///
+/// Prints a confirmation when the request is valid; invalid parameters are
+/// returned as the error.
+pub fn validate_request<R: ValidatableRequest>(request: &R) -> anyhow::Result<()> {
+    request.validate()?;
+    println!("Request is valid");
+    Ok(())
+}
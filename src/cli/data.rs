@@ -2,9 +2,20 @@
 //!
 //! This module provides CLI commands for interacting with the Polymarket Data API.
 
-use crate::cli::common::write_json_output;
+use std::collections::HashMap;
+use std::fs::File;
+use std::io::{BufWriter, Write};
+use std::path::{Path, PathBuf};
+
+use crate::cli::common::{print_request_url, validate_request, write_json_output};
 use clap::{Args, Subcommand};
-use polymarket_hft::client::polymarket::data::Client;
+use futures_util::StreamExt;
+use polymarket_hft::client::pagination::{DEFAULT_MAX_PAGES, collect_offset_pages};
+use polymarket_hft::client::polymarket::data::{
+    Client, DEFAULT_BASE_URL, GetTradesRequest, GetUserPositionsRequest, MAX_TRADES_OFFSET,
+};
+use polymarket_hft::engine::{ReconstructedPosition, positions_from_trades};
+use serde::Serialize;
 
 // =============================================================================
 // Commands
@@ -49,6 +60,30 @@ pub enum DataCommands {
         #[command(flatten)]
         params: GetTradesArgs,
     },
+    /// Export a user's full trade history as NDJSON (one trade per line)
+    ExportTrades {
+        /// User Profile Address (0x-prefixed, 40 hex chars)
+        #[arg(short, long, required = true)]
+        user: String,
+        /// Output file path
+        #[arg(short, long, required = true)]
+        out: PathBuf,
+        /// Trades fetched per request (1-10000, default: 500)
+        #[arg(long, default_value_t = 500, value_parser = clap::value_parser!(i32).range(1..=10000))]
+        page_size: i32,
+    },
+    /// Compare a user's reported positions with positions rebuilt from their trades
+    VerifyPositions {
+        /// User Profile Address (0x-prefixed, 40 hex chars)
+        #[arg(short, long, required = true)]
+        user: String,
+        /// Largest size or average price difference accepted as a match
+        #[arg(long, default_value_t = 0.01)]
+        tolerance: f64,
+        /// Trades fetched per request (1-10000, default: 500)
+        #[arg(long, default_value_t = 500, value_parser = clap::value_parser!(i32).range(1..=10000))]
+        page_size: i32,
+    },
     // ========== Market/System commands ==========
     /// Check API health
     Health,
@@ -92,6 +127,9 @@ pub struct GetUserPositionsArgs {
     /// Minimum position size (>= 0)
     #[arg(long)]
     pub size_threshold: Option<f64>,
+    /// Hide positions worth less than this many USD (applied client-side)
+    #[arg(long)]
+    pub min_value: Option<f64>,
     /// Filter for redeemable positions
     #[arg(long)]
     pub redeemable: Option<bool>,
@@ -113,6 +151,12 @@ pub struct GetUserPositionsArgs {
     /// Title filter (max 160 chars)
     #[arg(short, long)]
     pub title: Option<String>,
+    /// Print the resolved request URL instead of sending the request
+    #[arg(long)]
+    pub print_url: bool,
+    /// Validate the request parameters and exit without sending the request
+    #[arg(long)]
+    pub validate_only: bool,
 }
 
 #[derive(Args, Debug, Clone)]
@@ -141,6 +185,12 @@ pub struct GetUserClosedPositionsArgs {
     /// Sort direction (ASC or DESC)
     #[arg(long)]
     pub sort_direction: Option<String>,
+    /// Print the resolved request URL instead of sending the request
+    #[arg(long)]
+    pub print_url: bool,
+    /// Validate the request parameters and exit without sending the request
+    #[arg(long)]
+    pub validate_only: bool,
 }
 
 #[derive(Args, Debug, Clone)]
@@ -178,6 +228,12 @@ pub struct GetUserActivityArgs {
     /// Trade side filter (BUY or SELL)
     #[arg(long)]
     pub side: Option<String>,
+    /// Print the resolved request URL instead of sending the request
+    #[arg(long)]
+    pub print_url: bool,
+    /// Validate the request parameters and exit without sending the request
+    #[arg(long)]
+    pub validate_only: bool,
 }
 
 #[derive(Args, Debug, Clone)]
@@ -209,6 +265,12 @@ pub struct GetTradesArgs {
     /// Trade side filter (BUY or SELL)
     #[arg(short, long)]
     pub side: Option<String>,
+    /// Print the resolved request URL instead of sending the request
+    #[arg(long)]
+    pub print_url: bool,
+    /// Validate the request parameters and exit without sending the request
+    #[arg(long)]
+    pub validate_only: bool,
 }
 
 // =============================================================================
@@ -246,6 +308,23 @@ pub async fn handle(command: &DataCommands) -> anyhow::Result<()> {
         DataCommands::GetTrades { params } => {
             handle_get_trades(&client, params).await?;
         }
+        DataCommands::ExportTrades {
+            user,
+            out,
+            page_size,
+        } => {
+            let count = export_trades(&client, user, out, *page_size).await?;
+            eprintln!("Exported {} trades to {}", count, out.display());
+        }
+        DataCommands::VerifyPositions {
+            user,
+            tolerance,
+            page_size,
+        } => {
+            let discrepancies = verify_positions(&client, user, *tolerance, *page_size).await?;
+            eprintln!("{} position discrepancies", discrepancies.len());
+            write_json_output(&discrepancies)?;
+        }
         // ========== Market/System commands ==========
         DataCommands::Health => {
             let health = client.health().await?;
@@ -299,23 +378,28 @@ async fn handle_get_user_positions(
         .transpose()
         .map_err(|e| anyhow::anyhow!("invalid --sort-direction: {}", e))?;
 
-    let positions = client
-        .get_user_positions(
-            polymarket_hft::client::polymarket::data::GetUserPositionsRequest {
-                user: params.user.as_str(),
-                markets: market_refs.as_deref(),
-                event_ids: params.event_id.as_deref(),
-                size_threshold: params.size_threshold,
-                redeemable: params.redeemable,
-                mergeable: params.mergeable,
-                limit: params.limit,
-                offset: params.offset,
-                sort_by: parsed_sort_by,
-                sort_direction: parsed_sort_direction,
-                title: params.title.as_deref(),
-            },
-        )
-        .await?;
+    let request = polymarket_hft::client::polymarket::data::GetUserPositionsRequest {
+        user: params.user.as_str(),
+        markets: market_refs.as_deref(),
+        event_ids: params.event_id.as_deref(),
+        size_threshold: params.size_threshold,
+        redeemable: params.redeemable,
+        mergeable: params.mergeable,
+        limit: params.limit,
+        offset: params.offset,
+        sort_by: parsed_sort_by,
+        sort_direction: parsed_sort_direction,
+        title: params.title.as_deref(),
+        min_value: params.min_value,
+    };
+    if params.validate_only {
+        return validate_request(&request);
+    }
+    if params.print_url {
+        return print_request_url(&request, DEFAULT_BASE_URL);
+    }
+
+    let positions = client.get_user_positions(request).await?;
     write_json_output(&positions)?;
     Ok(())
 }
@@ -343,20 +427,24 @@ async fn handle_get_user_closed_positions(
         .transpose()
         .map_err(|e| anyhow::anyhow!("invalid --sort-direction: {}", e))?;
 
-    let positions = client
-        .get_user_closed_positions(
-            polymarket_hft::client::polymarket::data::GetUserClosedPositionsRequest {
-                user: params.user.as_str(),
-                markets: market_refs.as_deref(),
-                title: params.title.as_deref(),
-                event_ids: params.event_id.as_deref(),
-                limit: params.limit,
-                offset: params.offset,
-                sort_by: parsed_sort_by,
-                sort_direction: parsed_sort_direction,
-            },
-        )
-        .await?;
+    let request = polymarket_hft::client::polymarket::data::GetUserClosedPositionsRequest {
+        user: params.user.as_str(),
+        markets: market_refs.as_deref(),
+        title: params.title.as_deref(),
+        event_ids: params.event_id.as_deref(),
+        limit: params.limit,
+        offset: params.offset,
+        sort_by: parsed_sort_by,
+        sort_direction: parsed_sort_direction,
+    };
+    if params.validate_only {
+        return validate_request(&request);
+    }
+    if params.print_url {
+        return print_request_url(&request, DEFAULT_BASE_URL);
+    }
+
+    let positions = client.get_user_closed_positions(request).await?;
     write_json_output(&positions)?;
     Ok(())
 }
@@ -404,23 +492,27 @@ async fn handle_get_user_activity(
         .transpose()
         .map_err(|e| anyhow::anyhow!("invalid --side: {}", e))?;
 
-    let activity = client
-        .get_user_activity(
-            polymarket_hft::client::polymarket::data::GetUserActivityRequest {
-                user: params.user.as_str(),
-                limit: params.limit,
-                offset: params.offset,
-                markets: market_refs.as_deref(),
-                event_ids: params.event_id.as_deref(),
-                activity_types: parsed_activity_types.as_deref(),
-                start: params.start,
-                end: params.end,
-                sort_by: parsed_sort_by,
-                sort_direction: parsed_sort_direction,
-                side: parsed_side,
-            },
-        )
-        .await?;
+    let request = polymarket_hft::client::polymarket::data::GetUserActivityRequest {
+        user: params.user.as_str(),
+        limit: params.limit,
+        offset: params.offset,
+        markets: market_refs.as_deref(),
+        event_ids: params.event_id.as_deref(),
+        activity_types: parsed_activity_types.as_deref(),
+        start: params.start,
+        end: params.end,
+        sort_by: parsed_sort_by,
+        sort_direction: parsed_sort_direction,
+        side: parsed_side,
+    };
+    if params.validate_only {
+        return validate_request(&request);
+    }
+    if params.print_url {
+        return print_request_url(&request, DEFAULT_BASE_URL);
+    }
+
+    let activity = client.get_user_activity(request).await?;
     write_json_output(&activity)?;
     Ok(())
 }
@@ -445,19 +537,322 @@ async fn handle_get_trades(client: &Client, params: &GetTradesArgs) -> anyhow::R
         .transpose()
         .map_err(|e| anyhow::anyhow!("invalid --side: {}", e))?;
 
-    let trades = client
-        .get_trades(polymarket_hft::client::polymarket::data::GetTradesRequest {
-            limit: params.limit,
-            offset: params.offset,
-            taker_only: params.taker_only,
-            filter_type: parsed_filter_type,
-            filter_amount: params.filter_amount,
-            markets: market_refs.as_deref(),
-            event_ids: params.event_id.as_deref(),
-            user: params.user.as_deref(),
-            side: parsed_side,
-        })
-        .await?;
+    let request = polymarket_hft::client::polymarket::data::GetTradesRequest {
+        limit: params.limit,
+        offset: params.offset,
+        taker_only: params.taker_only,
+        filter_type: parsed_filter_type,
+        filter_amount: params.filter_amount,
+        markets: market_refs.as_deref(),
+        event_ids: params.event_id.as_deref(),
+        user: params.user.as_deref(),
+        side: parsed_side,
+    };
+    if params.validate_only {
+        return validate_request(&request);
+    }
+    if params.print_url {
+        return print_request_url(&request, DEFAULT_BASE_URL);
+    }
+
+    let trades = client.get_trades(request).await?;
     write_json_output(&trades)?;
     Ok(())
 }
+
+/// Write every trade of `user` to `out` as NDJSON, one page at a time.
+///
+/// Each page is flushed before the next is fetched, so memory use stays at
+/// one page and an interrupted export keeps the trades written so far. A
+/// warning goes to stderr when the history runs past [`MAX_TRADES_OFFSET`],
+/// since the rest of it cannot be fetched.
+async fn export_trades(
+    client: &Client,
+    user: &str,
+    out: &Path,
+    page_size: i32,
+) -> anyhow::Result<usize> {
+    let mut writer = BufWriter::new(File::create(out)?);
+    let pages = client.trades_pages(GetTradesRequest {
+        user: Some(user),
+        taker_only: Some(false),
+        limit: Some(page_size),
+        ..Default::default()
+    });
+    let mut pages = std::pin::pin!(pages);
+    let mut count = 0;
+    let mut last_page_full = false;
+
+    while let Some(page) = pages.next().await {
+        let page = page?;
+        last_page_full = page.len() >= page_size as usize;
+        for trade in page {
+            serde_json::to_writer(&mut writer, &trade)?;
+            writer.write_all(b"\n")?;
+            count += 1;
+        }
+        writer.flush()?;
+        eprintln!("{} trades exported...", count);
+    }
+    if history_truncated(count, last_page_full) {
+        eprintln!(
+            "warning: trade history of {} exceeds the trades endpoint's offset limit ({}); \
+             only the first {} trades were exported",
+            user, MAX_TRADES_OFFSET, count
+        );
+    }
+    Ok(count)
+}
+
+/// Whether a trade history paged through [`Client::trades_pages`] was cut
+/// off at [`MAX_TRADES_OFFSET`]: a full last page whose successor lies past
+/// the offset cap.
+fn history_truncated(fetched: usize, last_page_full: bool) -> bool {
+    last_page_full && fetched > MAX_TRADES_OFFSET as usize
+}
+
+/// Page size for listing positions, the endpoint's maximum limit.
+const POSITIONS_PAGE_SIZE: i32 = 500;
+
+/// A position whose reported and reconstructed values differ.
+#[derive(Debug, Serialize)]
+struct PositionDiscrepancy {
+    asset: String,
+    outcome_index: i32,
+    /// Market title, when the position endpoint reports it.
+    title: Option<String>,
+    reported_size: f64,
+    reconstructed_size: f64,
+    reported_avg_price: Option<f64>,
+    reconstructed_avg_price: Option<f64>,
+}
+
+/// Compare `user`'s open positions with positions rebuilt from their full
+/// trade history, returning those that differ by more than `tolerance`.
+///
+/// Fails if the trade history runs past [`MAX_TRADES_OFFSET`], since the
+/// rest of it cannot be fetched. Average prices are only compared when both
+/// sides hold the position.
+/// Splits, merges, redemptions and conversions are not trades, so positions
+/// touched by them show up as discrepancies too.
+async fn verify_positions(
+    client: &Client,
+    user: &str,
+    tolerance: f64,
+    page_size: i32,
+) -> anyhow::Result<Vec<PositionDiscrepancy>> {
+    let reported = collect_offset_pages(
+        POSITIONS_PAGE_SIZE as usize,
+        0,
+        DEFAULT_MAX_PAGES,
+        |offset| {
+            client.get_user_positions(GetUserPositionsRequest {
+                user,
+                size_threshold: Some(0.0),
+                limit: Some(POSITIONS_PAGE_SIZE),
+                offset: Some(offset as i32),
+                ..Default::default()
+            })
+        },
+    )
+    .await?;
+
+    let mut trades = Vec::new();
+    let mut last_page_full = false;
+    let pages = client.trades_pages(GetTradesRequest {
+        user: Some(user),
+        taker_only: Some(false),
+        limit: Some(page_size),
+        ..Default::default()
+    });
+    let mut pages = std::pin::pin!(pages);
+    while let Some(page) = pages.next().await {
+        let page = page?;
+        last_page_full = page.len() >= page_size as usize;
+        trades.extend(page);
+    }
+    // Positions rebuilt from a cut-off history would be wrong.
+    if history_truncated(trades.len(), last_page_full) {
+        anyhow::bail!(
+            "trade history of {} exceeds the trades endpoint's offset limit ({}); \
+             positions cannot be rebuilt from it",
+            user,
+            MAX_TRADES_OFFSET
+        );
+    }
+    let mut reconstructed: HashMap<(String, i32), ReconstructedPosition> =
+        positions_from_trades(&trades);
+
+    let differs = |a: f64, b: f64| (a - b).abs() > tolerance;
+    let mut discrepancies = Vec::new();
+    for position in &reported {
+        let rebuilt = reconstructed.remove(&(position.asset.clone(), position.outcome_index));
+        let rebuilt_size = rebuilt.map_or(0.0, |p| p.size);
+        let avg_price_differs =
+            rebuilt.is_some_and(|p| p.size != 0.0 && differs(position.avg_price, p.avg_price));
+        if differs(position.size, rebuilt_size) || avg_price_differs {
+            discrepancies.push(PositionDiscrepancy {
+                asset: position.asset.clone(),
+                outcome_index: position.outcome_index,
+                title: Some(position.title.clone()),
+                reported_size: position.size,
+                reconstructed_size: rebuilt_size,
+                reported_avg_price: Some(position.avg_price),
+                reconstructed_avg_price: rebuilt.map(|p| p.avg_price),
+            });
+        }
+    }
+    // Held according to trades but not reported at all.
+    let mut unreported: Vec<_> = reconstructed
+        .into_iter()
+        .filter(|(_, p)| differs(p.size, 0.0))
+        .collect();
+    unreported.sort_by(|a, b| a.0.cmp(&b.0));
+    for ((asset, outcome_index), rebuilt) in unreported {
+        discrepancies.push(PositionDiscrepancy {
+            asset,
+            outcome_index,
+            title: None,
+            reported_size: 0.0,
+            reconstructed_size: rebuilt.size,
+            reported_avg_price: None,
+            reconstructed_avg_price: Some(rebuilt.avg_price),
+        });
+    }
+    Ok(discrepancies)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testutil::sample_trade_json;
+    use wiremock::matchers::{method, path, query_param};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    const USER: &str = "0x56687bf447db6ffa42ffe2204a05edaa20f55839";
+
+    fn trade_json(timestamp: i64) -> serde_json::Value {
+        let mut trade = sample_trade_json();
+        trade["timestamp"] = serde_json::json!(timestamp);
+        trade["transactionHash"] = serde_json::json!(format!("0x{:064x}", timestamp));
+        trade
+    }
+
+    fn position_json(asset: &str, size: f64, avg_price: f64) -> serde_json::Value {
+        serde_json::json!({
+            "proxyWallet": USER,
+            "asset": asset,
+            "conditionId": "0xdd22472e552920b8438158ea7238bfadfa4f736aa4cee91a6b86c39ead110917",
+            "size": size,
+            "avgPrice": avg_price,
+            "initialValue": size * avg_price,
+            "currentValue": size * avg_price,
+            "cashPnl": 0.0,
+            "percentPnl": 0.0,
+            "totalBought": size,
+            "realizedPnl": 0.0,
+            "percentRealizedPnl": 0.0,
+            "curPrice": avg_price,
+            "redeemable": false,
+            "mergeable": false,
+            "title": "Test market",
+            "slug": "test-market",
+            "icon": "",
+            "eventSlug": "test-event",
+            "outcome": "Yes",
+            "outcomeIndex": 0,
+            "oppositeOutcome": "No",
+            "oppositeAsset": "456",
+            "endDate": "2025-12-31",
+            "negativeRisk": false
+        })
+    }
+
+    #[tokio::test]
+    async fn verify_positions_reports_size_mismatch() {
+        let mock_server = MockServer::start().await;
+        // Trades add up to 20 shares of asset 123 at 0.5.
+        Mock::given(method("GET"))
+            .and(path("/trades"))
+            .and(query_param("offset", "0"))
+            .respond_with(
+                ResponseTemplate::new(200).set_body_json(vec![trade_json(2), trade_json(1)]),
+            )
+            .mount(&mock_server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/positions"))
+            .and(query_param("user", USER))
+            .and(query_param("offset", "0"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .set_body_json(serde_json::json!([position_json("123", 15.0, 0.5)])),
+            )
+            .mount(&mock_server)
+            .await;
+
+        let client = Client::with_base_url(&mock_server.uri()).unwrap();
+        let discrepancies = verify_positions(&client, USER, 0.01, 100).await.unwrap();
+
+        assert_eq!(discrepancies.len(), 1);
+        let discrepancy = &discrepancies[0];
+        assert_eq!(discrepancy.asset, "123");
+        assert_eq!(discrepancy.reported_size, 15.0);
+        assert_eq!(discrepancy.reconstructed_size, 20.0);
+        assert_eq!(discrepancy.reconstructed_avg_price, Some(0.5));
+
+        // Within tolerance nothing is reported.
+        let discrepancies = verify_positions(&client, USER, 5.0, 100).await.unwrap();
+        assert!(discrepancies.is_empty());
+    }
+
+    #[tokio::test]
+    async fn verify_positions_fails_when_trades_exceed_offset_cap() {
+        let mock_server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/trades"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(vec![trade_json(1); 5000]))
+            .mount(&mock_server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/positions"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!([])))
+            .mount(&mock_server)
+            .await;
+
+        let client = Client::with_base_url(&mock_server.uri()).unwrap();
+        let err = verify_positions(&client, USER, 0.01, 5000)
+            .await
+            .unwrap_err();
+
+        assert!(err.to_string().contains("offset limit"), "{err}");
+    }
+
+    #[tokio::test]
+    async fn export_trades_writes_every_page_as_ndjson() {
+        let mock_server = MockServer::start().await;
+        for (offset, timestamps) in [("0", vec![5, 4]), ("2", vec![3, 2]), ("4", vec![1])] {
+            let page: Vec<_> = timestamps.into_iter().map(trade_json).collect();
+            Mock::given(method("GET"))
+                .and(path("/trades"))
+                .and(query_param("user", USER))
+                .and(query_param("offset", offset))
+                .respond_with(ResponseTemplate::new(200).set_body_json(page))
+                .expect(1)
+                .mount(&mock_server)
+                .await;
+        }
+
+        let client = Client::with_base_url(&mock_server.uri()).unwrap();
+        let out = std::env::temp_dir().join(format!("trades-{}.ndjson", uuid::Uuid::now_v7()));
+        let count = export_trades(&client, USER, &out, 2).await.unwrap();
+        let contents = std::fs::read_to_string(&out).unwrap();
+        let _ = std::fs::remove_file(&out);
+
+        assert_eq!(count, 5);
+        let lines: Vec<&str> = contents.lines().collect();
+        assert_eq!(lines.len(), 5);
+        let last: serde_json::Value = serde_json::from_str(lines[4]).unwrap();
+        assert_eq!(last["timestamp"], 1);
+    }
+}
@@ -27,6 +27,12 @@ pub struct GetTeamsArgs {
     /// Filter by abbreviation (repeatable)
     #[arg(long)]
     pub abbreviation: Option<Vec<String>>,
+    /// Print the resolved request URL instead of sending the request
+    #[arg(long)]
+    pub print_url: bool,
+    /// Validate the request parameters and exit without sending the request
+    #[arg(long)]
+    pub validate_only: bool,
 }
 
 impl<'a> From<&'a GetTeamsArgs> for GetTeamsRequest<'a> {
@@ -63,6 +69,12 @@ pub struct GetTagsArgs {
     /// Filter for carousel tags
     #[arg(long)]
     pub is_carousel: Option<bool>,
+    /// Print the resolved request URL instead of sending the request
+    #[arg(long)]
+    pub print_url: bool,
+    /// Validate the request parameters and exit without sending the request
+    #[arg(long)]
+    pub validate_only: bool,
 }
 
 impl<'a> From<&'a GetTagsArgs> for GetTagsRequest<'a> {
@@ -158,6 +170,15 @@ pub struct GetEventsArgs {
     /// Maximum end date (ISO-8601)
     #[arg(long)]
     pub end_date_max: Option<String>,
+    /// Only return these fields (comma-separated)
+    #[arg(long, value_delimiter = ',')]
+    pub fields: Option<Vec<String>>,
+    /// Print the resolved request URL instead of sending the request
+    #[arg(long)]
+    pub print_url: bool,
+    /// Validate the request parameters and exit without sending the request
+    #[arg(long)]
+    pub validate_only: bool,
 }
 
 impl<'a> From<&'a GetEventsArgs> for GetEventsRequest<'a> {
@@ -189,6 +210,7 @@ impl<'a> From<&'a GetEventsArgs> for GetEventsRequest<'a> {
             start_date_max: args.start_date_max.as_deref(),
             end_date_min: args.end_date_min.as_deref(),
             end_date_max: args.end_date_max.as_deref(),
+            fields: args.fields.clone(),
         }
     }
 }
@@ -276,6 +298,15 @@ pub struct GetMarketsArgs {
     /// Filter by closed status
     #[arg(long)]
     pub closed: Option<bool>,
+    /// Only return these fields (comma-separated)
+    #[arg(long, value_delimiter = ',')]
+    pub fields: Option<Vec<String>>,
+    /// Print the resolved request URL instead of sending the request
+    #[arg(long)]
+    pub print_url: bool,
+    /// Validate the request parameters and exit without sending the request
+    #[arg(long)]
+    pub validate_only: bool,
 }
 
 impl<'a> From<&'a GetMarketsArgs> for GetMarketsRequest<'a> {
@@ -308,6 +339,7 @@ impl<'a> From<&'a GetMarketsArgs> for GetMarketsRequest<'a> {
             question_ids: args.question_ids.clone(),
             include_tag: args.include_tag,
             closed: args.closed,
+            fields: args.fields.clone(),
         }
     }
 }
@@ -344,6 +376,12 @@ pub struct GetSeriesArgs {
     /// Filter by recurrence
     #[arg(long)]
     pub recurrence: Option<String>,
+    /// Print the resolved request URL instead of sending the request
+    #[arg(long)]
+    pub print_url: bool,
+    /// Validate the request parameters and exit without sending the request
+    #[arg(long)]
+    pub validate_only: bool,
 }
 
 impl<'a> From<&'a GetSeriesArgs> for GetSeriesRequest<'a> {
@@ -389,6 +427,12 @@ pub struct GetCommentsArgs {
     /// Restrict results to holders only
     #[arg(long)]
     pub holders_only: Option<bool>,
+    /// Print the resolved request URL instead of sending the request
+    #[arg(long)]
+    pub print_url: bool,
+    /// Validate the request parameters and exit without sending the request
+    #[arg(long)]
+    pub validate_only: bool,
 }
 
 impl<'a> From<&'a GetCommentsArgs> for GetCommentsRequest<'a> {
@@ -449,6 +493,12 @@ pub struct SearchArgs {
     /// Enable optimized search
     #[arg(long)]
     pub optimized: Option<bool>,
+    /// Print the resolved request URL instead of sending the request
+    #[arg(long)]
+    pub print_url: bool,
+    /// Validate the request parameters and exit without sending the request
+    #[arg(long)]
+    pub validate_only: bool,
 }
 
 impl<'a> From<&'a SearchArgs> for SearchRequest<'a> {
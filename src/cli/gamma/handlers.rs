@@ -3,7 +3,9 @@ use polymarket_hft::client::polymarket::gamma::{
     GetMarketsRequest, GetSeriesRequest, GetTagsRequest, GetTeamsRequest, SearchRequest,
 };
 
-use crate::cli::common::write_json_output;
+use polymarket_hft::client::polymarket::gamma::DEFAULT_BASE_URL;
+
+use crate::cli::common::{print_request_url, validate_request, write_json_output};
 
 use super::commands::GammaCommands;
 
@@ -13,8 +15,14 @@ pub async fn handle(command: &GammaCommands) -> anyhow::Result<()> {
     match command {
         GammaCommands::GetTeams { params } => {
             let request = GetTeamsRequest::from(params);
-            let teams = client.get_teams(request).await?;
-            write_json_output(&teams)?;
+            if params.validate_only {
+                validate_request(&request)?;
+            } else if params.print_url {
+                print_request_url(&request, DEFAULT_BASE_URL)?;
+            } else {
+                let teams = client.get_teams(request).await?;
+                write_json_output(&teams)?;
+            }
         }
         GammaCommands::GetSports => {
             let sports = client.get_sports().await?;
@@ -22,8 +30,14 @@ pub async fn handle(command: &GammaCommands) -> anyhow::Result<()> {
         }
         GammaCommands::GetTags { params } => {
             let request = GetTagsRequest::from(params);
-            let tags = client.get_tags(request).await?;
-            write_json_output(&tags)?;
+            if params.validate_only {
+                validate_request(&request)?;
+            } else if params.print_url {
+                print_request_url(&request, DEFAULT_BASE_URL)?;
+            } else {
+                let tags = client.get_tags(request).await?;
+                write_json_output(&tags)?;
+            }
         }
         GammaCommands::GetTagById { id } => {
             let tag = client.get_tag_by_id(id.as_str()).await?;
@@ -80,8 +94,14 @@ pub async fn handle(command: &GammaCommands) -> anyhow::Result<()> {
         }
         GammaCommands::GetEvents { params } => {
             let request = GetEventsRequest::from(params);
-            let events = client.get_events(request).await?;
-            write_json_output(&events)?;
+            if params.validate_only {
+                validate_request(&request)?;
+            } else if params.print_url {
+                print_request_url(&request, DEFAULT_BASE_URL)?;
+            } else {
+                let events = client.get_events(request).await?;
+                write_json_output(&events)?;
+            }
         }
         GammaCommands::GetEventById {
             id,
@@ -109,8 +129,14 @@ pub async fn handle(command: &GammaCommands) -> anyhow::Result<()> {
         }
         GammaCommands::GetMarkets { params } => {
             let request = GetMarketsRequest::from(params);
-            let markets = client.get_markets(request).await?;
-            write_json_output(&markets)?;
+            if params.validate_only {
+                validate_request(&request)?;
+            } else if params.print_url {
+                print_request_url(&request, DEFAULT_BASE_URL)?;
+            } else {
+                let markets = client.get_markets(request).await?;
+                write_json_output(&markets)?;
+            }
         }
         GammaCommands::GetMarketById { id, include_tag } => {
             let market = client.get_market_by_id(id.as_str(), *include_tag).await?;
@@ -128,8 +154,14 @@ pub async fn handle(command: &GammaCommands) -> anyhow::Result<()> {
         }
         GammaCommands::GetSeries { params } => {
             let request = GetSeriesRequest::from(params);
-            let series = client.get_series(request).await?;
-            write_json_output(&series)?;
+            if params.validate_only {
+                validate_request(&request)?;
+            } else if params.print_url {
+                print_request_url(&request, DEFAULT_BASE_URL)?;
+            } else {
+                let series = client.get_series(request).await?;
+                write_json_output(&series)?;
+            }
         }
         GammaCommands::GetSeriesById { id, include_chat } => {
             let series = client.get_series_by_id(id.as_str(), *include_chat).await?;
@@ -137,8 +169,14 @@ pub async fn handle(command: &GammaCommands) -> anyhow::Result<()> {
         }
         GammaCommands::GetComments { params } => {
             let request = GetCommentsRequest::from(params);
-            let comments = client.get_comments(request).await?;
-            write_json_output(&comments)?;
+            if params.validate_only {
+                validate_request(&request)?;
+            } else if params.print_url {
+                print_request_url(&request, DEFAULT_BASE_URL)?;
+            } else {
+                let comments = client.get_comments(request).await?;
+                write_json_output(&comments)?;
+            }
         }
         GammaCommands::GetCommentById { id, get_positions } => {
             let comment = client
@@ -166,8 +204,14 @@ pub async fn handle(command: &GammaCommands) -> anyhow::Result<()> {
         }
         GammaCommands::Search { params } => {
             let request = SearchRequest::from(params);
-            let results = client.search(request).await?;
-            write_json_output(&results)?;
+            if params.validate_only {
+                validate_request(&request)?;
+            } else if params.print_url {
+                print_request_url(&request, DEFAULT_BASE_URL)?;
+            } else {
+                let results = client.search(request).await?;
+                write_json_output(&results)?;
+            }
         }
     }
 
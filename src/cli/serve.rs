@@ -7,9 +7,26 @@ pub struct ServeArgs {
     /// Path to the configuration file (YAML).
     #[arg(short, long)]
     pub config: PathBuf,
+    /// Validate the configuration and exit without starting the server.
+    #[arg(long)]
+    pub check: bool,
 }
 
 /// Handle the serve command.
 pub async fn handle(args: &ServeArgs) -> anyhow::Result<()> {
+    if args.check {
+        let problems = polymarket_hft::serve::check(&args.config).await;
+        if problems.is_empty() {
+            println!("Configuration OK: {}", args.config.display());
+            return Ok(());
+        }
+        for problem in &problems {
+            eprintln!("error: {}", problem);
+        }
+        anyhow::bail!(
+            "configuration check failed with {} problem(s)",
+            problems.len()
+        );
+    }
     polymarket_hft::serve::run(args.config.clone()).await
 }
@@ -7,6 +7,8 @@
 //! - [`coingecko`]: CoinGecko API client (requires API key)
 //! - [`alternativeme`]: Alternative.me free Crypto API client
 //! - [`http`]: Shared HTTP client with retry middleware
+//! - [`pagination`]: Offset and cursor paginators with a `max_pages` safeguard
+//! - [`coalesce`]: Single-flight sharing of identical in-flight requests
 
 use std::future::Future;
 use std::pin::Pin;
@@ -14,9 +16,11 @@ use std::pin::Pin;
 use crate::Metric;
 
 pub mod alternativeme;
+pub mod coalesce;
 pub mod coingecko;
 pub mod coinmarketcap;
 pub mod http;
+pub mod pagination;
 pub mod polymarket;
 
 // =============================================================================
@@ -87,3 +91,48 @@ pub trait DataSourceClient: Send + Sync {
         params: Option<serde_json::Value>,
     ) -> BoxFuture<'a, anyhow::Result<Vec<Metric>>>;
 }
+
+// =============================================================================
+// Request Validation
+// =============================================================================
+
+/// Client-side validation of request parameters.
+///
+/// Implemented by every Polymarket request struct. Client methods call
+/// [`validate`](Self::validate) before sending, so invalid parameters fail
+/// with [`PolymarketError::BadRequest`](crate::error::PolymarketError::BadRequest)
+/// without a network round trip; callers can also run it on its own to
+/// check input up front.
+pub trait ValidatableRequest {
+    /// Checks the request parameters without sending anything.
+    fn validate(&self) -> crate::error::Result<()>;
+}
+
+// =============================================================================
+// Request Debugging
+// =============================================================================
+
+/// Exposes the fully resolved URL of a request for debugging.
+///
+/// Implemented for request structs that build their own query strings, so
+/// parameter encoding (e.g. comma-joined market IDs) can be inspected without
+/// sending the request.
+pub trait RequestDebug {
+    /// Returns the URL this request would be sent to, relative to `base`.
+    fn debug_url(&self, base: &url::Url) -> String;
+}
+
+/// Implements [`RequestDebug`] for types with an inherent `build_url(&self, &Url) -> Url`.
+macro_rules! impl_request_debug {
+    ($($ty:ty),+ $(,)?) => {
+        $(
+            impl $crate::client::RequestDebug for $ty {
+                fn debug_url(&self, base: &::url::Url) -> String {
+                    self.build_url(base).to_string()
+                }
+            }
+        )+
+    };
+}
+
+pub(crate) use impl_request_debug;
@@ -6,10 +6,12 @@ use reqwest_middleware::ClientWithMiddleware;
 use serde_json::Value;
 
 use super::model::*;
-use crate::Metric;
 use crate::add_query_params;
-use crate::client::http::HttpClientConfig;
+#[cfg(feature = "metrics")]
+use crate::client::http::{ClientStats, ClientStatsSnapshot};
+use crate::client::http::{HttpClientConfig, deserialize_checked};
 use crate::client::{DataSourceClient, MethodMetadata, MethodParam, MetricInfo};
+use crate::{Metric, ToMetrics};
 
 const BASE_URL: &str = "https://api.alternative.me";
 
@@ -23,6 +25,8 @@ const BASE_URL: &str = "https://api.alternative.me";
 pub struct Client {
     http_client: ClientWithMiddleware,
     base_url: String,
+    #[cfg(feature = "metrics")]
+    stats: ClientStats,
 }
 
 impl Client {
@@ -33,12 +37,16 @@ impl Client {
 
     /// Creates a new Alternative.me API client with custom configuration.
     pub fn with_config(config: HttpClientConfig) -> Self {
+        #[cfg(feature = "metrics")]
+        let stats = config.stats.clone();
         let http_client = config
             .build()
             .expect("Failed to build HTTP client for Alternative.me");
         Self {
             http_client,
             base_url: BASE_URL.to_string(),
+            #[cfg(feature = "metrics")]
+            stats,
         }
     }
 
@@ -48,6 +56,12 @@ impl Client {
         self
     }
 
+    /// Request, retry and latency counters (`metrics` feature).
+    #[cfg(feature = "metrics")]
+    pub fn stats(&self) -> ClientStatsSnapshot {
+        self.stats.snapshot()
+    }
+
     /// Helper to create a request builder.
     fn request(&self, method: Method, path: &str) -> reqwest_middleware::RequestBuilder {
         let url = format!("{}{}", self.base_url, path);
@@ -76,7 +90,7 @@ impl Client {
         // Force array structure for consistent response type
         let req = req.query(&[("structure", "array")]);
 
-        let response: TickerArrayResponse = req.send().await?.json().await?;
+        let response: TickerArrayResponse = deserialize_checked(req.send().await?).await?;
         Self::check_metadata_error(&response.metadata.error)?;
         Ok(response)
     }
@@ -92,7 +106,7 @@ impl Client {
         let req = self.request(Method::GET, &path);
         let req = req.query(&[("structure", "array")]);
 
-        let response: TickerArrayResponse = req.send().await?.json().await?;
+        let response: TickerArrayResponse = deserialize_checked(req.send().await?).await?;
         Self::check_metadata_error(&response.metadata.error)?;
         Ok(response)
     }
@@ -103,7 +117,7 @@ impl Client {
     pub async fn get_global(&self) -> Result<GlobalResponse, AlternativeMeError> {
         let req = self.request(Method::GET, "/v2/global/");
 
-        let response: GlobalResponse = req.send().await?.json().await?;
+        let response: GlobalResponse = deserialize_checked(req.send().await?).await?;
         Self::check_metadata_error(&response.metadata.error)?;
         Ok(response)
     }
@@ -119,7 +133,7 @@ impl Client {
         let req = self.request(Method::GET, "/fng/");
         let req = add_query_params!(req, ("limit", limit),);
 
-        let response: FearAndGreedResponse = req.send().await?.json().await?;
+        let response: FearAndGreedResponse = deserialize_checked(req.send().await?).await?;
         Self::check_metadata_error(&response.metadata.error)?;
         Ok(response)
     }
@@ -217,11 +231,11 @@ impl DataSourceClient for Client {
             match method {
                 "get_fear_and_greed" => {
                     let resp = self.get_fear_and_greed(Some(1)).await?;
-                    Ok(resp.to_metric())
+                    Ok(resp.to_metrics())
                 }
                 "get_global" => {
                     let resp = self.get_global().await?;
-                    Ok(resp.to_metrics())
+                    Ok(ToMetrics::to_metrics(&resp))
                 }
                 "get_ticker" => {
                     let target = params
@@ -230,7 +244,7 @@ impl DataSourceClient for Client {
                         .ok_or_else(|| anyhow!("get_ticker requires 'target' parameter"))?
                         .to_string();
                     let resp = self.get_ticker(target).await?;
-                    Ok(resp.to_metrics())
+                    Ok(ToMetrics::to_metrics(&resp))
                 }
                 _ => Err(anyhow!("Unknown method: {}", method)),
             }
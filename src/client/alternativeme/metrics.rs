@@ -1,20 +1,28 @@
 //! Metric conversion for Alternative.me API responses.
 
-use crate::{DataSource, Metric, MetricUnit};
+use crate::engine::{parse_timestamp_i64, parse_timestamp_str};
+use crate::{DataSource, Metric, MetricUnit, ToMetrics};
 
 use super::model::{FearAndGreedResponse, GlobalResponse, TickerArrayResponse};
 
-impl FearAndGreedResponse {
+impl ToMetrics for FearAndGreedResponse {
     /// Converts the Fear and Greed response to metrics.
     ///
     /// Returns a vector of metrics, one for each data point in the response.
-    /// Skips data points that cannot be parsed.
-    pub fn to_metric(&self) -> Vec<Metric> {
+    /// Timestamps may be stringified Unix times or RFC 3339 (see
+    /// [`parse_timestamp_str`]); data points that cannot be parsed are skipped.
+    fn to_metrics(&self) -> Vec<Metric> {
         self.data
             .iter()
             .filter_map(|data| {
                 let value: f64 = data.value.parse().ok()?;
-                let data_timestamp: i64 = data.timestamp.parse().ok()?;
+                let Some(data_timestamp) = parse_timestamp_str(&data.timestamp) else {
+                    tracing::warn!(
+                        timestamp = %data.timestamp,
+                        "Skipping Fear and Greed point with unparsable timestamp"
+                    );
+                    return None;
+                };
 
                 Some(
                     Metric::new(
@@ -32,7 +40,7 @@ impl FearAndGreedResponse {
     }
 }
 
-impl GlobalResponse {
+impl ToMetrics for GlobalResponse {
     /// Converts the Global response to metrics.
     ///
     /// Returns a vector of metrics containing:
@@ -41,46 +49,48 @@ impl GlobalResponse {
     /// - `bitcoin_dominance`: Bitcoin percentage of market cap
     /// - `total_market_cap`: Total market cap (per currency)
     /// - `total_volume_24h`: Total 24h volume (per currency)
-    pub fn to_metrics(&self) -> Vec<Metric> {
-        let data_timestamp = self.data.last_updated.to_string();
+    ///
+    /// `last_updated` is normalised to Unix seconds (see
+    /// [`parse_timestamp_i64`]) for the `data_timestamp` label, which is left
+    /// out if it is negative.
+    fn to_metrics(&self) -> Vec<Metric> {
+        let data_timestamp = parse_timestamp_i64(self.data.last_updated);
+        let stamped = |metric: Metric| with_data_timestamp(metric, data_timestamp);
         let mut metrics = Vec::new();
 
-        metrics.push(
+        metrics.push(stamped(
             Metric::new(
                 DataSource::AlternativeMe,
                 "active_cryptocurrencies",
                 self.data.active_cryptocurrencies.into(),
                 MetricUnit::Count,
             )
-            .with_label("endpoint", "get_global")
-            .with_label("data_timestamp", &data_timestamp),
-        );
+            .with_label("endpoint", "get_global"),
+        ));
 
-        metrics.push(
+        metrics.push(stamped(
             Metric::new(
                 DataSource::AlternativeMe,
                 "active_markets",
                 self.data.active_markets.into(),
                 MetricUnit::Count,
             )
-            .with_label("endpoint", "get_global")
-            .with_label("data_timestamp", &data_timestamp),
-        );
+            .with_label("endpoint", "get_global"),
+        ));
 
-        metrics.push(
+        metrics.push(stamped(
             Metric::new(
                 DataSource::AlternativeMe,
                 "bitcoin_dominance",
                 self.data.bitcoin_percentage_of_market_cap,
                 MetricUnit::Percent,
             )
-            .with_label("endpoint", "get_global")
-            .with_label("data_timestamp", &data_timestamp),
-        );
+            .with_label("endpoint", "get_global"),
+        ));
 
         // USD metrics only
         if let Some(quote) = self.data.quotes.get("USD") {
-            metrics.push(
+            metrics.push(stamped(
                 Metric::new(
                     DataSource::AlternativeMe,
                     "total_market_cap",
@@ -88,11 +98,10 @@ impl GlobalResponse {
                     MetricUnit::USD,
                 )
                 .with_label("endpoint", "get_global")
-                .with_label("currency", "USD")
-                .with_label("data_timestamp", &data_timestamp),
-            );
+                .with_label("currency", "USD"),
+            ));
 
-            metrics.push(
+            metrics.push(stamped(
                 Metric::new(
                     DataSource::AlternativeMe,
                     "total_volume_24h",
@@ -100,16 +109,15 @@ impl GlobalResponse {
                     MetricUnit::USD,
                 )
                 .with_label("endpoint", "get_global")
-                .with_label("currency", "USD")
-                .with_label("data_timestamp", &data_timestamp),
-            );
+                .with_label("currency", "USD"),
+            ));
         }
 
         metrics
     }
 }
 
-impl TickerArrayResponse {
+impl ToMetrics for TickerArrayResponse {
     /// Converts the Ticker array response to metrics.
     ///
     /// Returns a vector of metrics for each ticker containing:
@@ -120,8 +128,9 @@ impl TickerArrayResponse {
     /// - `<symbol>_percent_change_24h`: 24h percent change (per currency, if available)
     /// - `<symbol>_percent_change_7d`: 7d percent change (per currency, if available)
     ///
-    /// Each metric includes `symbol`, `name`, and `currency` labels.
-    pub fn to_metrics(&self) -> Vec<Metric> {
+    /// Each metric includes `symbol`, `name`, and `currency` labels, plus
+    /// `data_timestamp` as for [`GlobalResponse`].
+    fn to_metrics(&self) -> Vec<Metric> {
         self.data
             .iter()
             .filter_map(|ticker| ticker.quotes.get("USD").map(|quote| (ticker, quote)))
@@ -163,13 +172,37 @@ impl TickerArrayResponse {
     }
 }
 
+impl FearAndGreedResponse {
+    /// Converts the Fear and Greed response to metrics.
+    #[deprecated(note = "use the `ToMetrics` impl instead")]
+    pub fn to_metric(&self) -> Vec<Metric> {
+        ToMetrics::to_metrics(self)
+    }
+}
+
+impl GlobalResponse {
+    /// Converts the Global response to metrics.
+    #[deprecated(note = "use the `ToMetrics` impl instead")]
+    pub fn to_metrics(&self) -> Vec<Metric> {
+        ToMetrics::to_metrics(self)
+    }
+}
+
+impl TickerArrayResponse {
+    /// Converts the Ticker array response to metrics.
+    #[deprecated(note = "use the `ToMetrics` impl instead")]
+    pub fn to_metrics(&self) -> Vec<Metric> {
+        ToMetrics::to_metrics(self)
+    }
+}
+
 fn create_ticker_metric(
     ticker: &super::model::Ticker,
     suffix: &str,
     value: f64,
     unit: MetricUnit,
 ) -> Metric {
-    Metric::new(
+    let metric = Metric::new(
         DataSource::AlternativeMe,
         format!("{}_{}", ticker.symbol.to_lowercase(), suffix),
         value,
@@ -178,8 +211,16 @@ fn create_ticker_metric(
     .with_label("endpoint", "get_ticker")
     .with_label("symbol", &ticker.symbol)
     .with_label("name", &ticker.name)
-    .with_label("currency", "USD")
-    .with_label("data_timestamp", ticker.last_updated.to_string())
+    .with_label("currency", "USD");
+    with_data_timestamp(metric, parse_timestamp_i64(ticker.last_updated))
+}
+
+/// Adds the `data_timestamp` label when the upstream time could be parsed.
+fn with_data_timestamp(metric: Metric, data_timestamp: Option<i64>) -> Metric {
+    match data_timestamp {
+        Some(ts) => metric.with_label("data_timestamp", ts.to_string()),
+        None => metric,
+    }
 }
 
 #[cfg(test)]
@@ -217,7 +258,7 @@ mod tests {
         let response =
             create_test_fng_response(vec![create_test_fng_data("50", "1703001600", "Neutral")]);
 
-        let metrics = response.to_metric();
+        let metrics = response.to_metrics();
 
         assert_eq!(metrics.len(), 1);
         let metric = &metrics[0];
@@ -246,7 +287,7 @@ mod tests {
             create_test_fng_data("75", "1702828800", "Greed"),
         ]);
 
-        let metrics = response.to_metric();
+        let metrics = response.to_metrics();
 
         assert_eq!(metrics.len(), 3);
         assert_eq!(metrics[0].value, 25.0);
@@ -266,11 +307,65 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_global_and_ticker_normalise_data_timestamp() {
+        let global: GlobalResponse = serde_json::from_value(serde_json::json!({
+            "data": {
+                "active_cryptocurrencies": 100,
+                "active_markets": 200,
+                "bitcoin_percentage_of_market_cap": 50.0,
+                "quotes": {},
+                "last_updated": 1_703_001_600_000_i64
+            },
+            "metadata": {"timestamp": null}
+        }))
+        .unwrap();
+        let metrics = ToMetrics::to_metrics(&global);
+        assert!(
+            metrics.iter().all(|m| {
+                m.labels.get("data_timestamp").map(String::as_str) == Some("1703001600")
+            })
+        );
+
+        let ticker: TickerArrayResponse = serde_json::from_value(serde_json::json!({
+            "data": [{
+                "id": 1,
+                "name": "Bitcoin",
+                "symbol": "BTC",
+                "website_slug": "bitcoin",
+                "rank": 1,
+                "circulating_supply": null,
+                "total_supply": null,
+                "max_supply": null,
+                "quotes": {"USD": {"price": 1.0, "volume_24h": 1.0, "market_cap": 1.0}},
+                "last_updated": -1
+            }],
+            "metadata": {"timestamp": null}
+        }))
+        .unwrap();
+        let metrics = ToMetrics::to_metrics(&ticker);
+        assert!(!metrics.is_empty());
+        assert!(
+            metrics
+                .iter()
+                .all(|m| !m.labels.contains_key("data_timestamp"))
+        );
+    }
+
+    #[test]
+    #[allow(deprecated)]
+    fn test_deprecated_to_metric_matches_to_metrics() {
+        let response =
+            create_test_fng_response(vec![create_test_fng_data("50", "1703001600", "Neutral")]);
+
+        assert_eq!(response.to_metric(), ToMetrics::to_metrics(&response));
+    }
+
     #[test]
     fn test_to_metric_empty_data() {
         let response = create_test_fng_response(vec![]);
 
-        let metrics = response.to_metric();
+        let metrics = response.to_metrics();
 
         assert!(metrics.is_empty());
     }
@@ -283,13 +378,29 @@ mod tests {
             create_test_fng_data("75", "1702828800", "Greed"),
         ]);
 
-        let metrics = response.to_metric();
+        let metrics = response.to_metrics();
 
         assert_eq!(metrics.len(), 2);
         assert_eq!(metrics[0].value, 50.0);
         assert_eq!(metrics[1].value, 75.0);
     }
 
+    #[test]
+    fn test_to_metric_accepts_rfc3339_timestamp() {
+        let response = create_test_fng_response(vec![create_test_fng_data(
+            "50",
+            "2024-01-01T00:00:00Z",
+            "Neutral",
+        )]);
+
+        let metrics = response.to_metrics();
+
+        assert_eq!(
+            metrics[0].labels.get("data_timestamp"),
+            Some(&"1704067200".to_string())
+        );
+    }
+
     #[test]
     fn test_to_metric_skips_invalid_timestamp() {
         let response = create_test_fng_response(vec![
@@ -298,7 +409,7 @@ mod tests {
             create_test_fng_data("75", "1702828800", "Greed"),
         ]);
 
-        let metrics = response.to_metric();
+        let metrics = response.to_metrics();
 
         assert_eq!(metrics.len(), 2);
         assert_eq!(
@@ -321,7 +432,7 @@ mod tests {
             create_test_fng_data("90", "1703001604", "Extreme Greed"),
         ]);
 
-        let metrics = response.to_metric();
+        let metrics = response.to_metrics();
 
         assert_eq!(metrics.len(), 5);
         let classifications: Vec<_> = metrics
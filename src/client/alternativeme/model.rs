@@ -18,6 +18,10 @@ pub enum AlternativeMeError {
     /// API returned an error response.
     #[error("API error: {0}")]
     Api(String),
+
+    /// Response body could not be decoded (e.g. an HTML error page).
+    #[error(transparent)]
+    Response(#[from] crate::client::http::ResponseError),
 }
 
 // =============================================================================
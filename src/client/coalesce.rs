@@ -0,0 +1,167 @@
+//! Single-flight coalescing of identical in-flight requests.
+//!
+//! A [`Coalescer`] lets concurrent callers asking for the same key share one
+//! upstream call: the first caller starts it, later callers await the same
+//! result, and the entry is dropped once it completes.
+
+use std::collections::HashMap;
+use std::future::Future;
+use std::hash::Hash;
+use std::sync::{Arc, Mutex};
+
+use futures_util::FutureExt;
+use futures_util::future::Shared;
+
+use super::BoxFuture;
+use crate::error::{PolymarketError, Result};
+
+type Flight<V> = Shared<BoxFuture<'static, std::result::Result<V, Arc<PolymarketError>>>>;
+
+/// Shares one in-flight request between concurrent callers with the same key.
+///
+/// Results are not cached: once a call completes, the next caller with that
+/// key starts a new one.
+pub struct Coalescer<V, K = String> {
+    in_flight: Mutex<HashMap<K, Flight<V>>>,
+}
+
+impl<V: Clone + Send + Sync + 'static> Coalescer<V> {
+    /// Creates a coalescer keyed by strings with no requests in flight.
+    ///
+    /// Use [`Default`] for other key types.
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl<V, K> Coalescer<V, K>
+where
+    V: Clone + Send + Sync + 'static,
+    K: Clone + Eq + Hash,
+{
+    /// Runs `fetch` unless a request for `key` is already in flight, in which
+    /// case its result is awaited instead.
+    ///
+    /// A caller whose request was not shared gets its error as returned by
+    /// `fetch`. Callers that shared one get [`PolymarketError::Shared`]
+    /// pointing at that error, so they can still match on its variant with
+    /// [`PolymarketError::unshared`].
+    pub async fn run<F>(&self, key: impl Into<K>, fetch: F) -> Result<V>
+    where
+        F: Future<Output = Result<V>> + Send + 'static,
+    {
+        let key = key.into();
+        let flight = {
+            let mut in_flight = self.lock();
+            in_flight
+                .entry(key.clone())
+                .or_insert_with(|| {
+                    let fetch: BoxFuture<'static, _> =
+                        Box::pin(fetch.map(|result| result.map_err(Arc::new)));
+                    fetch.shared()
+                })
+                .clone()
+        };
+
+        let result = flight.clone().await;
+
+        // A newer flight may already use this key; only remove our own.
+        let mut in_flight = self.lock();
+        if in_flight.get(&key).is_some_and(|f| f.ptr_eq(&flight)) {
+            in_flight.remove(&key);
+        }
+        drop(in_flight);
+        // Release this caller's hold on the shared result, so the error can
+        // be unwrapped if no other caller still holds it.
+        drop(flight);
+
+        result.map_err(|e| Arc::try_unwrap(e).unwrap_or_else(PolymarketError::Shared))
+    }
+
+    /// Number of distinct requests currently in flight.
+    pub fn in_flight(&self) -> usize {
+        self.lock().len()
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, HashMap<K, Flight<V>>> {
+        self.in_flight.lock().unwrap_or_else(|e| e.into_inner())
+    }
+}
+
+impl<V, K> Default for Coalescer<V, K>
+where
+    V: Clone + Send + Sync + 'static,
+    K: Clone + Eq + Hash,
+{
+    fn default() -> Self {
+        Self {
+            in_flight: Mutex::new(HashMap::new()),
+        }
+    }
+}
+
+impl<V, K> std::fmt::Debug for Coalescer<V, K> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Coalescer").finish_non_exhaustive()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    #[tokio::test]
+    async fn concurrent_calls_share_one_fetch() {
+        let coalescer = Coalescer::new();
+        let calls = Arc::new(AtomicUsize::new(0));
+
+        let results = futures_util::future::join_all((0..5).map(|_| {
+            let calls = Arc::clone(&calls);
+            coalescer.run("key", async move {
+                calls.fetch_add(1, Ordering::SeqCst);
+                tokio::time::sleep(std::time::Duration::from_millis(20)).await;
+                Ok(7)
+            })
+        }))
+        .await;
+
+        assert_eq!(calls.load(Ordering::SeqCst), 1);
+        assert!(results.iter().all(|r| matches!(r, Ok(7))));
+        assert_eq!(coalescer.in_flight(), 0);
+
+        // Completed flights are not cached.
+        assert_eq!(coalescer.run("key", async { Ok(8) }).await.unwrap(), 8);
+    }
+
+    #[tokio::test]
+    async fn errors_keep_their_variant() {
+        let coalescer: Coalescer<u32> = Coalescer::new();
+        let err = coalescer
+            .run("key", async {
+                Err(PolymarketError::Serde(
+                    serde_json::from_str::<u32>("x").unwrap_err(),
+                ))
+            })
+            .await
+            .unwrap_err();
+        assert!(matches!(err, PolymarketError::Serde(_)), "got {err:?}");
+
+        let results = futures_util::future::join_all((0..3).map(|_| {
+            coalescer.run("key", async {
+                tokio::time::sleep(std::time::Duration::from_millis(20)).await;
+                Err(PolymarketError::Serde(
+                    serde_json::from_str::<u32>("x").unwrap_err(),
+                ))
+            })
+        }))
+        .await;
+        let errors: Vec<_> = results.into_iter().map(Result::unwrap_err).collect();
+        assert!(
+            errors
+                .iter()
+                .all(|e| matches!(e.unshared(), PolymarketError::Serde(_))),
+            "got {errors:?}"
+        );
+    }
+}
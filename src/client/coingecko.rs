@@ -38,6 +38,8 @@
 
 mod client;
 mod model;
+mod resolver;
 
 pub use client::Client;
 pub use model::*;
+pub use resolver::{DEFAULT_SYMBOL_CACHE_TTL, SymbolResolver};
@@ -1,13 +1,27 @@
 //! CoinGecko API client.
 
+use std::time::Duration;
+
+use futures_util::{Stream, StreamExt, stream};
 use reqwest::Method;
 use reqwest_middleware::ClientWithMiddleware;
 
 use super::model::*;
-use crate::client::http::{self, HttpClientConfig};
+use crate::client::http::{
+    self, HttpClientConfig, RateLimitInfo, RateLimitTracker, deserialize_checked,
+};
+#[cfg(feature = "metrics")]
+use crate::client::http::{ClientStats, ClientStatsSnapshot};
+use crate::client::pagination::{DEFAULT_MAX_PAGES, offset_pages};
 
 const BASE_URL: &str = "https://api.coingecko.com/api/v3";
 
+/// Maximum `per_page` accepted by `/coins/markets`.
+const COINS_MARKETS_MAX_PER_PAGE: usize = 250;
+
+/// Default pause between paginated requests (the Demo plan allows ~30 calls/min).
+const DEFAULT_PAGE_DELAY: Duration = Duration::from_secs(2);
+
 /// Helper macro to add optional query parameters to a request.
 macro_rules! add_optional_query {
     ($req:expr, $($key:literal => $value:expr),* $(,)?) => {{
@@ -34,35 +48,60 @@ macro_rules! add_optional_bool_query {
     }};
 }
 
+/// Body of a CoinGecko error response.
+#[derive(serde::Deserialize)]
+struct ErrorBody {
+    status: Option<ErrorStatus>,
+    error: Option<String>,
+}
+
+#[derive(serde::Deserialize)]
+struct ErrorStatus {
+    error_code: Option<i64>,
+    error_message: Option<String>,
+}
+
 /// CoinGecko API client.
 #[derive(Clone)]
 pub struct Client {
     inner: ClientWithMiddleware,
     api_key: String,
     base_url: String,
+    rate_limit: RateLimitTracker,
+    page_delay: Duration,
+    default_convert: Option<String>,
+    #[cfg(feature = "metrics")]
+    stats: ClientStats,
 }
 
 impl Client {
     /// Creates a new CoinGecko API client.
     pub fn new(api_key: impl Into<String>) -> Self {
-        Self {
-            inner: http::build_default_client().expect("Failed to build default HTTP client"),
-            api_key: api_key.into(),
-            base_url: BASE_URL.to_string(),
-        }
+        Self::with_config(api_key, HttpClientConfig::default())
     }
 
     /// Creates a new CoinGecko API client with custom configuration.
     pub fn with_config(api_key: impl Into<String>, config: HttpClientConfig) -> Self {
         Self {
+            #[cfg(feature = "metrics")]
+            stats: config.stats.clone(),
             inner: config
                 .build()
                 .expect("Failed to build HTTP client with config"),
             api_key: api_key.into(),
             base_url: BASE_URL.to_string(),
+            rate_limit: RateLimitTracker::default(),
+            page_delay: DEFAULT_PAGE_DELAY,
+            default_convert: None,
         }
     }
 
+    /// Request, retry and latency counters (`metrics` feature).
+    #[cfg(feature = "metrics")]
+    pub fn stats(&self) -> ClientStatsSnapshot {
+        self.stats.snapshot()
+    }
+
     /// Sets the base URL (internal use or testing).
     #[allow(dead_code)]
     pub(crate) fn with_base_url(mut self, base_url: String) -> Self {
@@ -70,6 +109,42 @@ impl Client {
         self
     }
 
+    /// Base URL requests are sent to.
+    pub(crate) fn base_url(&self) -> &str {
+        &self.base_url
+    }
+
+    /// Sets the pause between pages in auto-paginating methods.
+    ///
+    /// Defaults to 2 seconds, which keeps a full crawl under the Demo plan's
+    /// rate limit.
+    pub fn with_page_delay(mut self, delay: Duration) -> Self {
+        self.page_delay = delay;
+        self
+    }
+
+    /// Sets the target currency used when a request leaves `vs_currency` or
+    /// `vs_currencies` empty (e.g. `"eur"`).
+    pub fn with_default_convert(mut self, convert: impl Into<String>) -> Self {
+        self.default_convert = Some(convert.into());
+        self
+    }
+
+    /// The request's target currency, or the default if it is empty.
+    fn vs_currency_or_default<'a>(&'a self, vs_currency: &'a str) -> &'a str {
+        match &self.default_convert {
+            Some(default) if vs_currency.is_empty() => default,
+            _ => vs_currency,
+        }
+    }
+
+    /// Rate-limit budget reported by the most recent response, if any.
+    ///
+    /// Updated from `x-ratelimit-*` headers after every request.
+    pub fn last_rate_limit(&self) -> Option<RateLimitInfo> {
+        self.rate_limit.last()
+    }
+
     /// Helper to create a request builder with the API key header.
     fn request(&self, method: Method, path: &str) -> reqwest_middleware::RequestBuilder {
         let url = format!("{}{}", self.base_url, path);
@@ -79,6 +154,42 @@ impl Client {
             .header("Accept", "application/json")
     }
 
+    /// Send a request, recording any rate-limit headers on the response.
+    ///
+    /// Non-2xx responses become errors here: the upstream message from the
+    /// body (`status.error_message`, or a bare `error` string) is returned as
+    /// [`CgError::Api`], and anything else as the HTTP status error.
+    async fn send(
+        &self,
+        req: reqwest_middleware::RequestBuilder,
+    ) -> Result<reqwest::Response, CgError> {
+        let response = req.send().await?;
+        self.rate_limit.record(response.headers());
+
+        http::check_status(response, |body| {
+            let message = match serde_json::from_slice::<ErrorBody>(body).ok()? {
+                ErrorBody {
+                    status:
+                        Some(ErrorStatus {
+                            error_code,
+                            error_message: Some(message),
+                        }),
+                    ..
+                } => match error_code {
+                    Some(code) => format!("{} (error_code {})", message, code),
+                    None => message,
+                },
+                ErrorBody {
+                    error: Some(message),
+                    ..
+                } => message,
+                _ => return None,
+            };
+            Some(CgError::Api(message))
+        })
+        .await
+    }
+
     /// Get simple price for one or more coins.
     ///
     /// Returns simple price data for the specified coin IDs in the target currencies.
@@ -88,9 +199,10 @@ impl Client {
     ) -> Result<SimplePriceResponse, CgError> {
         let req = self.request(Method::GET, "/simple/price");
 
-        let req = req
-            .query(&[("ids", &request.ids)])
-            .query(&[("vs_currencies", &request.vs_currencies)]);
+        let req = req.query(&[("ids", &request.ids)]).query(&[(
+            "vs_currencies",
+            self.vs_currency_or_default(&request.vs_currencies),
+        )]);
 
         let req = add_optional_bool_query!(req,
             "include_market_cap" => request.include_market_cap,
@@ -99,8 +211,8 @@ impl Client {
             "include_last_updated_at" => request.include_last_updated_at,
         );
 
-        let response = req.send().await?;
-        let data = response.json::<SimplePriceResponse>().await?;
+        let response = self.send(req).await?;
+        let data = deserialize_checked::<SimplePriceResponse>(response).await?;
         Ok(data)
     }
 
@@ -117,8 +229,8 @@ impl Client {
             "include_platform" => request.include_platform,
         );
 
-        let response = req.send().await?;
-        let data = response.json::<CoinsListResponse>().await?;
+        let response = self.send(req).await?;
+        let data = deserialize_checked::<CoinsListResponse>(response).await?;
         Ok(data)
     }
 
@@ -131,7 +243,10 @@ impl Client {
     ) -> Result<CoinsMarketsResponse, CgError> {
         let req = self.request(Method::GET, "/coins/markets");
 
-        let req = req.query(&[("vs_currency", &request.vs_currency)]);
+        let req = req.query(&[(
+            "vs_currency",
+            self.vs_currency_or_default(&request.vs_currency),
+        )]);
 
         let per_page_str = request.per_page.map(|v| v.to_string());
         let page_str = request.page.map(|v| v.to_string());
@@ -151,18 +266,54 @@ impl Client {
             "sparkline" => request.sparkline,
         );
 
-        let response = req.send().await?;
-        let data = response.json::<CoinsMarketsResponse>().await?;
+        let response = self.send(req).await?;
+        let data = deserialize_checked::<CoinsMarketsResponse>(response).await?;
         Ok(data)
     }
 
+    /// Stream market data for every coin, fetching `/coins/markets` page by page.
+    ///
+    /// Pages of 250 coins are requested until a short page is returned, with
+    /// a pause (see [`Client::with_page_delay`]) between requests to respect
+    /// rate limits. Dropping the stream stops paging.
+    pub fn get_all_coins_markets<'a>(
+        &'a self,
+        vs_currency: &'a str,
+        order: Option<&'a str>,
+    ) -> impl Stream<Item = Result<CoinMarket, CgError>> + 'a {
+        let per_page = COINS_MARKETS_MAX_PER_PAGE;
+        let pages = offset_pages(per_page, 0, DEFAULT_MAX_PAGES, move |offset| {
+            let request = CoinsMarketsRequest {
+                vs_currency: vs_currency.to_string(),
+                order: order.map(str::to_string),
+                per_page: Some(per_page as u32),
+                page: Some((offset / per_page + 1) as u32),
+                ..Default::default()
+            };
+            async move {
+                if offset > 0 {
+                    tokio::time::sleep(self.page_delay).await;
+                }
+                self.get_coins_markets(request).await
+            }
+        });
+
+        pages.flat_map(|page| {
+            let items: Vec<Result<CoinMarket, CgError>> = match page {
+                Ok(coins) => coins.into_iter().map(Ok).collect(),
+                Err(e) => vec![Err(e)],
+            };
+            stream::iter(items)
+        })
+    }
+
     /// Get trending search coins, NFTs, and categories.
     ///
     /// Returns the top trending coins, NFTs, and categories based on user searches.
     pub async fn get_trending(&self) -> Result<TrendingResponse, CgError> {
         let req = self.request(Method::GET, "/search/trending");
-        let response = req.send().await?;
-        let data = response.json::<TrendingResponse>().await?;
+        let response = self.send(req).await?;
+        let data = deserialize_checked::<TrendingResponse>(response).await?;
         Ok(data)
     }
 
@@ -171,8 +322,8 @@ impl Client {
     /// Returns global crypto statistics including total market cap, volume, and dominance.
     pub async fn get_global(&self) -> Result<GlobalResponse, CgError> {
         let req = self.request(Method::GET, "/global");
-        let response = req.send().await?;
-        let data = response.json::<GlobalResponse>().await?;
+        let response = self.send(req).await?;
+        let data = deserialize_checked::<GlobalResponse>(response).await?;
         Ok(data)
     }
 
@@ -183,8 +334,8 @@ impl Client {
         &self,
     ) -> Result<SupportedVsCurrenciesResponse, CgError> {
         let req = self.request(Method::GET, "/simple/supported_vs_currencies");
-        let response = req.send().await?;
-        let data = response.json::<SupportedVsCurrenciesResponse>().await?;
+        let response = self.send(req).await?;
+        let data = deserialize_checked::<SupportedVsCurrenciesResponse>(response).await?;
         Ok(data)
     }
 
@@ -205,8 +356,8 @@ impl Client {
             "page" => page_str,
         );
 
-        let response = req.send().await?;
-        let data = response.json::<ExchangesResponse>().await?;
+        let response = self.send(req).await?;
+        let data = deserialize_checked::<ExchangesResponse>(response).await?;
         Ok(data)
     }
 
@@ -229,8 +380,8 @@ impl Client {
             "sparkline" => request.sparkline,
         );
 
-        let response = req.send().await?;
-        let data = response.json::<CoinDetailResponse>().await?;
+        let response = self.send(req).await?;
+        let data = deserialize_checked::<CoinDetailResponse>(response).await?;
         Ok(data)
     }
 
@@ -245,15 +396,18 @@ impl Client {
         let req = self.request(Method::GET, &path);
 
         let req = req
-            .query(&[("vs_currency", &request.vs_currency)])
+            .query(&[(
+                "vs_currency",
+                self.vs_currency_or_default(&request.vs_currency),
+            )])
             .query(&[("days", &request.days)]);
 
         let req = add_optional_query!(req,
             "interval" => request.interval,
         );
 
-        let response = req.send().await?;
-        let data = response.json::<MarketChartResponse>().await?;
+        let response = self.send(req).await?;
+        let data = deserialize_checked::<MarketChartResponse>(response).await?;
         Ok(data)
     }
 
@@ -273,8 +427,8 @@ impl Client {
             "localization" => request.localization,
         );
 
-        let response = req.send().await?;
-        let data = response.json::<CoinHistoryResponse>().await?;
+        let response = self.send(req).await?;
+        let data = deserialize_checked::<CoinHistoryResponse>(response).await?;
         Ok(data)
     }
 
@@ -286,21 +440,175 @@ impl Client {
         let req = self.request(Method::GET, &path);
 
         let req = req
-            .query(&[("vs_currency", &request.vs_currency)])
+            .query(&[(
+                "vs_currency",
+                self.vs_currency_or_default(&request.vs_currency),
+            )])
             .query(&[("days", &request.days)]);
 
-        let response = req.send().await?;
-        let data = response.json::<OhlcResponse>().await?;
+        let response = self.send(req).await?;
+        let data = deserialize_checked::<OhlcResponse>(response).await?;
         Ok(data)
     }
+
+    /// Get OHLC candlestick data for a coin as typed [`Candle`]s.
+    ///
+    /// Same request as [`get_coin_ohlc`](Self::get_coin_ohlc). Fails with
+    /// [`CgError::Api`] if any candle does not have exactly 5 values.
+    pub async fn get_coin_ohlc_typed(&self, request: OhlcRequest) -> Result<Vec<Candle>, CgError> {
+        self.get_coin_ohlc(request)
+            .await?
+            .iter()
+            .map(|raw| Candle::try_from(raw.as_slice()))
+            .collect()
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use wiremock::matchers::{method, path};
+    use wiremock::matchers::{method, path, query_param};
     use wiremock::{Mock, MockServer, ResponseTemplate};
 
+    #[tokio::test]
+    async fn test_get_coin_ohlc_typed_maps_columns() {
+        let mock_server = MockServer::start().await;
+        let client = Client::new("test-key").with_base_url(mock_server.uri());
+
+        Mock::given(method("GET"))
+            .and(path("/coins/bitcoin/ohlc"))
+            .and(query_param("days", "1"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!([
+                [1709395200000_i64, 61942.0, 62211.0, 61721.0, 61845.0],
+                [1709396800000_i64, 61845.0, 62015.5, 61800.0, 62001.25]
+            ])))
+            .mount(&mock_server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/coins/ethereum/ohlc"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!([
+                [1709395200000_i64, 3400.0, 3450.0, 3390.0, 3420.0],
+                [1709396800000_i64, 3420.0, 3440.0, 3410.0]
+            ])))
+            .mount(&mock_server)
+            .await;
+
+        let request = |id: &str| OhlcRequest {
+            id: id.to_string(),
+            vs_currency: "usd".to_string(),
+            days: "1".to_string(),
+        };
+        let candles = client
+            .get_coin_ohlc_typed(request("bitcoin"))
+            .await
+            .unwrap();
+        assert_eq!(
+            candles[1],
+            Candle {
+                timestamp: 1709396800000,
+                open: 61845.0,
+                high: 62015.5,
+                low: 61800.0,
+                close: 62001.25,
+            }
+        );
+        assert_eq!(candles[0].low, 61721.0);
+
+        let err = client
+            .get_coin_ohlc_typed(request("ethereum"))
+            .await
+            .unwrap_err();
+        assert!(
+            matches!(err, CgError::Api(ref m) if m.contains("got 4")),
+            "{err}"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_default_convert_applies_when_request_omits_it() {
+        let mock_server = MockServer::start().await;
+        let client = Client::new("test-key")
+            .with_base_url(mock_server.uri())
+            .with_default_convert("eur");
+
+        Mock::given(method("GET"))
+            .and(path("/simple/price"))
+            .and(query_param("ids", "bitcoin"))
+            .and(query_param("vs_currencies", "eur"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .set_body_json(serde_json::json!({"bitcoin": {"eur": 45000.0}})),
+            )
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        let request = SimplePriceRequest {
+            ids: "bitcoin".to_string(),
+            ..Default::default()
+        };
+        let response = client.get_simple_price(request).await.unwrap();
+        assert!(response.contains_key("bitcoin"));
+
+        // An explicit currency takes precedence.
+        assert_eq!(client.vs_currency_or_default("usd"), "usd");
+    }
+
+    #[tokio::test]
+    async fn test_error_status_preserves_upstream_message() {
+        let mock_server = MockServer::start().await;
+        let client =
+            Client::with_config("test-key", HttpClientConfig::default().with_max_retries(0))
+                .with_base_url(mock_server.uri());
+
+        Mock::given(method("GET"))
+            .and(path("/global"))
+            .respond_with(ResponseTemplate::new(429).set_body_json(serde_json::json!({
+                "status": {
+                    "error_code": 429,
+                    "error_message": "You've exceeded the Rate Limit."
+                }
+            })))
+            .mount(&mock_server)
+            .await;
+
+        let err = client.get_global().await.unwrap_err();
+        match err {
+            CgError::Api(message) => {
+                assert_eq!(message, "You've exceeded the Rate Limit. (error_code 429)")
+            }
+            other => panic!("expected API error, got {other:?}"),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_error_status_falls_back_to_error_field_or_http_error() {
+        let mock_server = MockServer::start().await;
+        let client = Client::new("test-key").with_base_url(mock_server.uri());
+
+        Mock::given(method("GET"))
+            .respond_with(ResponseTemplate::new(404).set_body_json(serde_json::json!({
+                "error": "coin not found"
+            })))
+            .mount(&mock_server)
+            .await;
+        let err = client.get_global().await.unwrap_err();
+        assert!(matches!(err, CgError::Api(ref m) if m == "coin not found"));
+
+        mock_server.reset().await;
+        Mock::given(method("GET"))
+            .respond_with(
+                ResponseTemplate::new(403)
+                    .set_body_json(serde_json::json!({"message": "Forbidden"})),
+            )
+            .mount(&mock_server)
+            .await;
+        let err = client.get_global().await.unwrap_err();
+        assert!(
+            matches!(err, CgError::Request(ref e) if e.status().map(|s| s.as_u16()) == Some(403))
+        );
+    }
+
     #[tokio::test]
     async fn test_get_simple_price() {
         let mock_server = MockServer::start().await;
@@ -338,6 +646,38 @@ mod tests {
         assert_eq!(bitcoin.get("usd").unwrap(), &Some(50000.0));
     }
 
+    #[tokio::test]
+    async fn test_last_rate_limit_parsed_from_headers() {
+        let mock_server = MockServer::start().await;
+        let client = Client::new("test-key").with_base_url(mock_server.uri());
+        assert_eq!(client.last_rate_limit(), None);
+
+        Mock::given(method("GET"))
+            .and(path("/coins/list"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .insert_header("x-ratelimit-limit", "30")
+                    .insert_header("x-ratelimit-remaining", "29")
+                    .insert_header("x-ratelimit-reset", "1700000060")
+                    .set_body_string("[]"),
+            )
+            .mount(&mock_server)
+            .await;
+
+        client
+            .get_coins_list(CoinsListRequest::default())
+            .await
+            .unwrap();
+
+        let info = client.last_rate_limit().unwrap();
+        assert_eq!(info.limit, Some(30));
+        assert_eq!(info.remaining, Some(29));
+        assert_eq!(info.reset_at.map(|t| t.timestamp()), Some(1_700_000_060));
+
+        // Clones share the recorded budget.
+        assert_eq!(client.clone().last_rate_limit(), Some(info));
+    }
+
     #[tokio::test]
     async fn test_get_coins_list() {
         let mock_server = MockServer::start().await;
@@ -420,6 +760,73 @@ mod tests {
         assert_eq!(response[0].current_price, Some(50000.0));
     }
 
+    fn coin_market_json(index: usize) -> serde_json::Value {
+        serde_json::json!({
+            "id": format!("coin-{index}"),
+            "symbol": format!("c{index}"),
+            "name": format!("Coin {index}"),
+            "current_price": 1.0,
+        })
+    }
+
+    #[tokio::test]
+    async fn test_get_all_coins_markets_streams_every_page() {
+        let mock_server = MockServer::start().await;
+        let client = Client::new("test-key")
+            .with_base_url(mock_server.uri())
+            .with_page_delay(Duration::ZERO);
+
+        for (page, range) in [("1", 0..250), ("2", 250..300)] {
+            let body: Vec<_> = range.map(coin_market_json).collect();
+            Mock::given(method("GET"))
+                .and(path("/coins/markets"))
+                .and(query_param("vs_currency", "usd"))
+                .and(query_param("order", "market_cap_desc"))
+                .and(query_param("per_page", "250"))
+                .and(query_param("page", page))
+                .respond_with(ResponseTemplate::new(200).set_body_json(body))
+                .expect(1)
+                .mount(&mock_server)
+                .await;
+        }
+
+        let coins: Vec<CoinMarket> = client
+            .get_all_coins_markets("usd", Some("market_cap_desc"))
+            .map(|coin| coin.unwrap())
+            .collect()
+            .await;
+
+        assert_eq!(coins.len(), 300);
+        assert_eq!(coins[0].id, "coin-0");
+        assert_eq!(coins[299].id, "coin-299");
+    }
+
+    #[tokio::test]
+    async fn test_html_error_page_is_reported_clearly() {
+        let mock_server = MockServer::start().await;
+        let client =
+            Client::with_config("test-key", HttpClientConfig::default().with_max_retries(0))
+                .with_base_url(mock_server.uri());
+
+        Mock::given(method("GET"))
+            .and(path("/global"))
+            .respond_with(
+                ResponseTemplate::new(502)
+                    .set_body_raw("<html><h1>502 Bad Gateway</h1></html>", "text/html"),
+            )
+            .mount(&mock_server)
+            .await;
+
+        let err = client.get_global().await.unwrap_err();
+        match err {
+            CgError::Response(http::ResponseError::UnexpectedContentType(e)) => {
+                assert_eq!(e.status, 502);
+                assert!(e.snippet.contains("502 Bad Gateway"));
+            }
+            other => panic!("unexpected error: {other:?}"),
+        }
+    }
+
     #[tokio::test]
     async fn test_get_trending() {
         let mock_server = MockServer::start().await;
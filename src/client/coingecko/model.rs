@@ -18,6 +18,14 @@ pub enum CgError {
     /// API returned an error response.
     #[error("API error: {0}")]
     Api(String),
+
+    /// A paginator hit its page budget before reaching the last page.
+    #[error(transparent)]
+    MaxPagesExceeded(#[from] crate::client::pagination::MaxPagesExceeded),
+
+    /// Response body could not be decoded (e.g. an HTML error page).
+    #[error(transparent)]
+    Response(#[from] crate::client::http::ResponseError),
 }
 
 /// Helper to deserialize a value that may be either a string or integer.
@@ -533,3 +541,38 @@ pub type OhlcCandle = Vec<f64>;
 /// Response from /coins/{id}/ohlc endpoint.
 /// Array of OHLC candles.
 pub type OhlcResponse = Vec<OhlcCandle>;
+
+/// OHLC candle with named fields, see
+/// [`get_coin_ohlc_typed`](super::Client::get_coin_ohlc_typed).
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct Candle {
+    /// Candle close time, Unix milliseconds.
+    pub timestamp: i64,
+    pub open: f64,
+    pub high: f64,
+    pub low: f64,
+    pub close: f64,
+}
+
+impl TryFrom<&[f64]> for Candle {
+    type Error = CgError;
+
+    /// Map a raw `[timestamp, open, high, low, close]` array.
+    ///
+    /// Fails with [`CgError::Api`] unless the array has exactly 5 values.
+    fn try_from(raw: &[f64]) -> Result<Self, Self::Error> {
+        match *raw {
+            [timestamp, open, high, low, close] => Ok(Self {
+                timestamp: timestamp as i64,
+                open,
+                high,
+                low,
+                close,
+            }),
+            _ => Err(CgError::Api(format!(
+                "malformed OHLC candle: expected 5 values, got {}",
+                raw.len()
+            ))),
+        }
+    }
+}
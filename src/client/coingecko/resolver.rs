@@ -0,0 +1,219 @@
+//! Symbol to coin id resolution.
+
+use std::collections::HashMap;
+use std::time::{Duration, Instant};
+
+use tokio::sync::RwLock;
+
+use super::client::Client;
+use super::model::{CgError, CoinsListRequest, CoinsMarketsRequest};
+
+/// Default time a fetched coin list is reused before it is refetched.
+pub const DEFAULT_SYMBOL_CACHE_TTL: Duration = Duration::from_secs(24 * 60 * 60);
+
+/// Number of top coins by market cap fetched to rank ambiguous symbols.
+const RANKED_COINS: u32 = 250;
+
+/// Maps ticker symbols ("btc") to CoinGecko coin ids ("bitcoin").
+///
+/// Many coins share a symbol, so [`resolve`](Self::resolve) can pick the
+/// candidate with the highest market cap. The coin list is fetched from
+/// `/coins/list` on first use, ranks from the first page of
+/// `/coins/markets`, and both are cached for the configured TTL. If a
+/// refresh fails the previous list keeps being served.
+pub struct SymbolResolver {
+    client: Client,
+    ttl: Duration,
+    cache: RwLock<Option<SymbolTable>>,
+}
+
+struct SymbolTable {
+    /// Lowercase symbol to candidate ids, in `/coins/list` order.
+    ids_by_symbol: HashMap<String, Vec<String>>,
+    /// Market cap rank of the top coins (lower is larger).
+    rank_by_id: HashMap<String, u32>,
+    fetched_at: Instant,
+}
+
+impl SymbolResolver {
+    /// Creates a resolver caching the coin list for [`DEFAULT_SYMBOL_CACHE_TTL`].
+    pub fn new(client: Client) -> Self {
+        Self::with_ttl(client, DEFAULT_SYMBOL_CACHE_TTL)
+    }
+
+    /// Creates a resolver caching the coin list for `ttl`.
+    pub fn with_ttl(client: Client, ttl: Duration) -> Self {
+        Self {
+            client,
+            ttl,
+            cache: RwLock::new(None),
+        }
+    }
+
+    /// Resolve `symbol` (case-insensitive) to a coin id.
+    ///
+    /// With `prefer_rank`, an ambiguous symbol resolves to the candidate with
+    /// the best market cap rank (unranked candidates lose to ranked ones, ties
+    /// keep list order). Without it, only unambiguous symbols resolve.
+    /// Returns `None` for unknown symbols or if the list cannot be fetched.
+    pub async fn resolve(&self, symbol: &str, prefer_rank: bool) -> Option<String> {
+        match self.try_resolve(symbol, prefer_rank).await {
+            Ok(id) => id,
+            Err(e) => {
+                tracing::warn!(error = %e, "failed to fetch CoinGecko coin list");
+                None
+            }
+        }
+    }
+
+    /// Like [`resolve`](Self::resolve), but fails with the fetch error when
+    /// no coin list has been loaded yet. `Ok(None)` means the symbol is
+    /// unknown or ambiguous.
+    pub async fn try_resolve(
+        &self,
+        symbol: &str,
+        prefer_rank: bool,
+    ) -> Result<Option<String>, CgError> {
+        if let Err(e) = self.ensure_fresh().await {
+            if self.cache.read().await.is_none() {
+                return Err(e);
+            }
+            tracing::warn!(error = %e, "failed to refresh CoinGecko coin list");
+        }
+        let cache = self.cache.read().await;
+        let Some(table) = cache.as_ref() else {
+            return Ok(None);
+        };
+        let Some(candidates) = table.ids_by_symbol.get(&symbol.to_lowercase()) else {
+            return Ok(None);
+        };
+        Ok(match candidates.as_slice() {
+            [id] => Some(id.clone()),
+            _ if !prefer_rank => None,
+            _ => candidates
+                .iter()
+                .min_by_key(|id| table.rank_by_id.get(*id).copied().unwrap_or(u32::MAX))
+                .cloned(),
+        })
+    }
+
+    /// All coin ids sharing `symbol`, in `/coins/list` order.
+    pub async fn candidates(&self, symbol: &str) -> Result<Vec<String>, CgError> {
+        self.ensure_fresh().await?;
+        let cache = self.cache.read().await;
+        Ok(cache
+            .as_ref()
+            .and_then(|table| table.ids_by_symbol.get(&symbol.to_lowercase()))
+            .cloned()
+            .unwrap_or_default())
+    }
+
+    /// Drop the cached list so the next lookup refetches it.
+    pub async fn invalidate(&self) {
+        *self.cache.write().await = None;
+    }
+
+    async fn ensure_fresh(&self) -> Result<(), CgError> {
+        let stale = match self.cache.read().await.as_ref() {
+            Some(table) => table.fetched_at.elapsed() >= self.ttl,
+            None => true,
+        };
+        if !stale {
+            return Ok(());
+        }
+
+        let mut cache = self.cache.write().await;
+        // Another caller may have refreshed while we waited for the lock.
+        if let Some(table) = cache.as_ref()
+            && table.fetched_at.elapsed() < self.ttl
+        {
+            return Ok(());
+        }
+        *cache = Some(self.fetch().await?);
+        Ok(())
+    }
+
+    async fn fetch(&self) -> Result<SymbolTable, CgError> {
+        let coins = self
+            .client
+            .get_coins_list(CoinsListRequest::default())
+            .await?;
+        let top = self
+            .client
+            .get_coins_markets(CoinsMarketsRequest {
+                vs_currency: "usd".to_string(),
+                order: Some("market_cap_desc".to_string()),
+                per_page: Some(RANKED_COINS),
+                page: Some(1),
+                ..Default::default()
+            })
+            .await?;
+
+        let mut ids_by_symbol: HashMap<String, Vec<String>> = HashMap::new();
+        for coin in coins {
+            ids_by_symbol
+                .entry(coin.symbol.to_lowercase())
+                .or_default()
+                .push(coin.id);
+        }
+        let rank_by_id = top
+            .into_iter()
+            .filter_map(|coin| Some((coin.id, coin.market_cap_rank?)))
+            .collect();
+
+        Ok(SymbolTable {
+            ids_by_symbol,
+            rank_by_id,
+            fetched_at: Instant::now(),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use wiremock::matchers::{method, path};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    #[tokio::test]
+    async fn ambiguous_symbol_resolves_to_highest_market_cap() {
+        let mock_server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/coins/list"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!([
+                {"id": "wrapped-usdt", "symbol": "usdt", "name": "Wrapped USDT"},
+                {"id": "tether", "symbol": "USDT", "name": "Tether"},
+                {"id": "bitcoin", "symbol": "btc", "name": "Bitcoin"},
+            ])))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/coins/markets"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!([
+                {"id": "bitcoin", "symbol": "btc", "name": "Bitcoin", "market_cap_rank": 1},
+                {"id": "tether", "symbol": "usdt", "name": "Tether", "market_cap_rank": 3},
+            ])))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        let client = Client::new("test-key").with_base_url(mock_server.uri());
+        let resolver = SymbolResolver::new(client);
+
+        assert_eq!(
+            resolver.resolve("usdt", true).await.as_deref(),
+            Some("tether")
+        );
+        assert_eq!(resolver.resolve("usdt", false).await, None);
+        assert_eq!(
+            resolver.resolve("BTC", false).await.as_deref(),
+            Some("bitcoin")
+        );
+        assert_eq!(resolver.resolve("nope", true).await, None);
+        assert_eq!(
+            resolver.candidates("usdt").await.unwrap(),
+            vec!["wrapped-usdt", "tether"]
+        );
+    }
+}
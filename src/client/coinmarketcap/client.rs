@@ -2,7 +2,9 @@ use reqwest::Method;
 use reqwest_middleware::ClientWithMiddleware;
 
 use super::model::*;
-use crate::client::http::{self, HttpClientConfig};
+#[cfg(feature = "metrics")]
+use crate::client::http::{ClientStats, ClientStatsSnapshot};
+use crate::client::http::{HttpClientConfig, RateLimitInfo, RateLimitTracker, deserialize_checked};
 
 const BASE_URL: &str = "https://pro-api.coinmarketcap.com";
 
@@ -19,35 +21,51 @@ macro_rules! add_optional_query {
     }};
 }
 
+/// Body of a CMC error response; only the `status` object is reliable.
+#[derive(serde::Deserialize)]
+struct ErrorBody {
+    status: Status,
+}
+
 /// CoinMarketCap API client.
 #[derive(Clone)]
 pub struct Client {
     inner: ClientWithMiddleware,
     api_key: String,
     base_url: String,
+    rate_limit: RateLimitTracker,
+    default_convert: Option<String>,
+    #[cfg(feature = "metrics")]
+    stats: ClientStats,
 }
 
 impl Client {
     /// Creates a new CoinMarketCap API client.
     pub fn new(api_key: impl Into<String>) -> Self {
-        Self {
-            inner: http::build_default_client().expect("Failed to build default HTTP client"),
-            api_key: api_key.into(),
-            base_url: BASE_URL.to_string(),
-        }
+        Self::with_config(api_key, HttpClientConfig::default())
     }
 
     /// Creates a new CoinMarketCap API client with custom configuration.
     pub fn with_config(api_key: impl Into<String>, config: HttpClientConfig) -> Self {
         Self {
+            #[cfg(feature = "metrics")]
+            stats: config.stats.clone(),
             inner: config
                 .build()
                 .expect("Failed to build HTTP client with config"),
             api_key: api_key.into(),
             base_url: BASE_URL.to_string(),
+            rate_limit: RateLimitTracker::default(),
+            default_convert: None,
         }
     }
 
+    /// Request, retry and latency counters (`metrics` feature).
+    #[cfg(feature = "metrics")]
+    pub fn stats(&self) -> ClientStatsSnapshot {
+        self.stats.snapshot()
+    }
+
     /// Sets the base URL (internal use or testing).
     #[allow(dead_code)]
     pub(crate) fn with_base_url(mut self, base_url: String) -> Self {
@@ -55,6 +73,32 @@ impl Client {
         self
     }
 
+    /// Sets the `convert` currency used when a request specifies neither
+    /// `convert` nor `convert_id` (e.g. `"EUR"`).
+    pub fn with_default_convert(mut self, convert: impl Into<String>) -> Self {
+        self.default_convert = Some(convert.into());
+        self
+    }
+
+    /// The request's `convert`, or the default if no conversion was requested.
+    fn convert_or_default(
+        &self,
+        convert: Option<String>,
+        convert_id: &Option<String>,
+    ) -> Option<String> {
+        match (convert, convert_id) {
+            (None, None) => self.default_convert.clone(),
+            (convert, _) => convert,
+        }
+    }
+
+    /// Rate-limit budget reported by the most recent response, if any.
+    ///
+    /// Updated from `x-ratelimit-*` headers after every request.
+    pub fn last_rate_limit(&self) -> Option<RateLimitInfo> {
+        self.rate_limit.last()
+    }
+
     /// Helper to create a request builder with the API key header.
     fn request(&self, method: Method, path: &str) -> reqwest_middleware::RequestBuilder {
         let url = format!("{}{}", self.base_url, path);
@@ -64,6 +108,32 @@ impl Client {
             .header("Accept", "application/json")
     }
 
+    /// Send a request, recording any rate-limit headers on the response.
+    ///
+    /// Non-2xx responses are turned into errors here. CMC reports the reason
+    /// in the body's `status` object, so that is surfaced as
+    /// [`CmcError::Api`] when present; otherwise the HTTP status error is
+    /// returned, whatever the body's content type.
+    async fn send(
+        &self,
+        req: reqwest_middleware::RequestBuilder,
+    ) -> Result<reqwest::Response, CmcError> {
+        let response = req.send().await?;
+        self.rate_limit.record(response.headers());
+
+        let Err(http_error) = response.error_for_status_ref() else {
+            return Ok(response);
+        };
+        let body = response.bytes().await?;
+        match serde_json::from_slice::<ErrorBody>(&body) {
+            Ok(ErrorBody { status }) if status.error_code != 0 => Err(CmcError::Api {
+                code: status.error_code,
+                message: status.error_message.unwrap_or_default(),
+            }),
+            _ => Err(http_error.into()),
+        }
+    }
+
     /// Check response status and return error if API returned an error.
     fn check_status(status: &Status) -> Result<(), CmcError> {
         if status.error_code != 0 {
@@ -95,7 +165,7 @@ impl Client {
             "circulating_supply_max" => request.circulating_supply_max,
             "percent_change_24h_min" => request.percent_change_24h_min,
             "percent_change_24h_max" => request.percent_change_24h_max,
-            "convert" => request.convert,
+            "convert" => self.convert_or_default(request.convert, &request.convert_id),
             "convert_id" => request.convert_id,
             "sort" => request.sort,
             "sort_dir" => request.sort_dir,
@@ -104,8 +174,8 @@ impl Client {
             "aux" => request.aux,
         );
 
-        let response = req.send().await?;
-        let data = response.json::<ListingsLatestResponse>().await?;
+        let response = self.send(req).await?;
+        let data = deserialize_checked::<ListingsLatestResponse>(response).await?;
         Self::check_status(&data.status)?;
         Ok(data)
     }
@@ -118,12 +188,12 @@ impl Client {
         let req = self.request(Method::GET, "/v1/global-metrics/quotes/latest");
 
         let req = add_optional_query!(req,
-            "convert" => request.convert,
+            "convert" => self.convert_or_default(request.convert, &request.convert_id),
             "convert_id" => request.convert_id,
         );
 
-        let response = req.send().await?;
-        let data = response.json::<GlobalMetricsQuotesLatestResponse>().await?;
+        let response = self.send(req).await?;
+        let data = deserialize_checked::<GlobalMetricsQuotesLatestResponse>(response).await?;
         Self::check_status(&data.status)?;
         Ok(data)
     }
@@ -134,8 +204,8 @@ impl Client {
         _request: GetFearAndGreedLatestRequest,
     ) -> Result<FearAndGreedResponse, CmcError> {
         let req = self.request(Method::GET, "/v3/fear-and-greed/latest");
-        let response = req.send().await?;
-        let data = response.json::<FearAndGreedResponse>().await?;
+        let response = self.send(req).await?;
+        let data = deserialize_checked::<FearAndGreedResponse>(response).await?;
         Self::check_status(&data.status)?;
         Ok(data)
     }
@@ -146,8 +216,8 @@ impl Client {
     /// including daily and monthly credit limits and consumption.
     pub async fn get_key_info(&self) -> Result<KeyInfoResponse, CmcError> {
         let req = self.request(Method::GET, "/v1/key/info");
-        let response = req.send().await?;
-        let data = response.json::<KeyInfoResponse>().await?;
+        let response = self.send(req).await?;
+        let data = deserialize_checked::<KeyInfoResponse>(response).await?;
         Self::check_status(&data.status)?;
         Ok(data)
     }
@@ -171,8 +241,8 @@ impl Client {
             "aux" => request.aux,
         );
 
-        let response = req.send().await?;
-        let data = response.json::<CryptocurrencyMapResponse>().await?;
+        let response = self.send(req).await?;
+        let data = deserialize_checked::<CryptocurrencyMapResponse>(response).await?;
         Self::check_status(&data.status)?;
         Ok(data)
     }
@@ -197,8 +267,8 @@ impl Client {
             "skip_invalid" => skip_invalid_str,
         );
 
-        let response = req.send().await?;
-        let data = response.json::<CryptocurrencyInfoResponse>().await?;
+        let response = self.send(req).await?;
+        let data = deserialize_checked::<CryptocurrencyInfoResponse>(response).await?;
         Self::check_status(&data.status)?;
         Ok(data)
     }
@@ -218,14 +288,14 @@ impl Client {
             "id" => request.id,
             "slug" => request.slug,
             "symbol" => request.symbol,
-            "convert" => request.convert,
+            "convert" => self.convert_or_default(request.convert, &request.convert_id),
             "convert_id" => request.convert_id,
             "aux" => request.aux,
             "skip_invalid" => skip_invalid_str,
         );
 
-        let response = req.send().await?;
-        let data = response.json::<QuotesLatestResponse>().await?;
+        let response = self.send(req).await?;
+        let data = deserialize_checked::<QuotesLatestResponse>(response).await?;
         Self::check_status(&data.status)?;
         Ok(data)
     }
@@ -247,8 +317,8 @@ impl Client {
             "include_metals" => include_metals_str,
         );
 
-        let response = req.send().await?;
-        let data = response.json::<FiatMapResponse>().await?;
+        let response = self.send(req).await?;
+        let data = deserialize_checked::<FiatMapResponse>(response).await?;
         Self::check_status(&data.status)?;
         Ok(data)
     }
@@ -269,13 +339,13 @@ impl Client {
             "amount" => amount_str,
             "id" => id_str,
             "symbol" => request.symbol,
-            "convert" => request.convert,
+            "convert" => self.convert_or_default(request.convert, &request.convert_id),
             "convert_id" => request.convert_id,
             "time" => request.time,
         );
 
-        let response = req.send().await?;
-        let data = response.json::<PriceConversionResponse>().await?;
+        let response = self.send(req).await?;
+        let data = deserialize_checked::<PriceConversionResponse>(response).await?;
         Self::check_status(&data.status)?;
         Ok(data)
     }
@@ -284,9 +354,96 @@ impl Client {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use wiremock::matchers::{method, path};
+    use wiremock::matchers::{method, path, query_param};
     use wiremock::{Mock, MockServer, ResponseTemplate};
 
+    #[tokio::test]
+    async fn test_error_status_surfaces_api_message() {
+        let mock_server = MockServer::start().await;
+        let client = Client::new("bad-key").with_base_url(mock_server.uri());
+
+        Mock::given(method("GET"))
+            .and(path("/v1/key/info"))
+            .respond_with(ResponseTemplate::new(400).set_body_string(
+                r#"{
+                    "status": {
+                        "timestamp": "2024-01-01T00:00:00.000Z",
+                        "error_code": 1002,
+                        "error_message": "API key missing.",
+                        "elapsed": 0,
+                        "credit_count": 0
+                    }
+                }"#,
+            ))
+            .mount(&mock_server)
+            .await;
+
+        let err = client.get_key_info().await.unwrap_err();
+        match err {
+            CmcError::Api { code, message } => {
+                assert_eq!(code, 1002);
+                assert_eq!(message, "API key missing.");
+            }
+            other => panic!("expected API error, got {other:?}"),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_error_status_without_cmc_body_is_http_error() {
+        let mock_server = MockServer::start().await;
+        let client = Client::new("test-key").with_base_url(mock_server.uri());
+
+        Mock::given(method("GET"))
+            .respond_with(ResponseTemplate::new(401).set_body_string("Unauthorized"))
+            .mount(&mock_server)
+            .await;
+
+        let err = client.get_key_info().await.unwrap_err();
+        assert!(
+            matches!(err, CmcError::Request(ref e) if e.status().map(|s| s.as_u16()) == Some(401))
+        );
+    }
+
+    #[tokio::test]
+    async fn test_default_convert_applies_when_request_omits_it() {
+        let mock_server = MockServer::start().await;
+        let client = Client::new("test-key")
+            .with_base_url(mock_server.uri())
+            .with_default_convert("EUR");
+
+        Mock::given(method("GET"))
+            .and(path("/v1/cryptocurrency/listings/latest"))
+            .and(query_param("convert", "EUR"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "status": {
+                    "timestamp": "2024-01-01T00:00:00.000Z",
+                    "error_code": 0,
+                    "error_message": null,
+                    "elapsed": 0,
+                    "credit_count": 1
+                },
+                "data": []
+            })))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        let result = client
+            .get_listings_latest(GetListingsLatestRequest::default())
+            .await;
+        assert!(result.is_ok());
+
+        // An explicit convert or convert_id takes precedence.
+        assert_eq!(
+            client.convert_or_default(Some("USD".to_string()), &None),
+            Some("USD".to_string())
+        );
+        assert_eq!(
+            client.convert_or_default(None, &Some("2781".to_string())),
+            None
+        );
+    }
+
     #[tokio::test]
     async fn test_get_listings_latest() {
         let mock_server = MockServer::start().await;
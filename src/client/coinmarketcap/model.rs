@@ -16,6 +16,10 @@ pub enum CmcError {
     /// API returned an error response (error_code != 0).
     #[error("API error {code}: {message}")]
     Api { code: i32, message: String },
+
+    /// Response body could not be decoded (e.g. an HTML error page).
+    #[error(transparent)]
+    Response(#[from] crate::client::http::ResponseError),
 }
 
 /// Helper to deserialize error_code that may be either string or integer.
@@ -0,0 +1,229 @@
+//! On-disk HTTP response cache middleware.
+
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+use base64::Engine;
+use base64::engine::general_purpose::STANDARD as BASE64;
+use reqwest::{Method, Request, Response};
+use reqwest_middleware::{Middleware, Next};
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+
+/// Middleware that caches successful GET responses on disk, keyed by URL.
+///
+/// Intended for backtesting against historical data: identical requests made
+/// within `ttl` are answered from disk without touching the network. Only
+/// 2xx responses are stored. Cache read/write failures are logged and fall
+/// through to a normal request.
+#[derive(Debug, Clone)]
+pub struct DiskCache {
+    dir: PathBuf,
+    ttl: Duration,
+}
+
+/// Serialized form of a cached response.
+#[derive(Serialize, Deserialize)]
+struct CachedResponse {
+    url: String,
+    status: u16,
+    headers: Vec<(String, String)>,
+    /// Base64-encoded response body.
+    body: String,
+    /// Unix timestamp (ms) when the entry was written.
+    stored_at: i64,
+}
+
+impl DiskCache {
+    /// Creates a cache that stores entries under `dir` for `ttl`.
+    pub fn new(dir: impl Into<PathBuf>, ttl: Duration) -> Self {
+        Self {
+            dir: dir.into(),
+            ttl,
+        }
+    }
+
+    /// Directory holding cache entries.
+    pub fn dir(&self) -> &Path {
+        &self.dir
+    }
+
+    /// How long an entry is served before it is refetched.
+    pub fn ttl(&self) -> Duration {
+        self.ttl
+    }
+
+    fn entry_path(&self, url: &url::Url) -> PathBuf {
+        let digest = Sha256::digest(url.as_str().as_bytes());
+        self.dir.join(format!("{}.json", hex::encode(digest)))
+    }
+
+    async fn read_fresh(&self, path: &Path, url: &url::Url) -> Option<Response> {
+        let raw = tokio::fs::read(path).await.ok()?;
+        let entry: CachedResponse = match serde_json::from_slice(&raw) {
+            Ok(entry) => entry,
+            Err(e) => {
+                tracing::warn!(path = %path.display(), error = %e, "ignoring corrupt HTTP cache entry");
+                return None;
+            }
+        };
+        let age_ms = chrono::Utc::now().timestamp_millis() - entry.stored_at;
+        if entry.url != url.as_str() || age_ms < 0 || age_ms as u128 > self.ttl.as_millis() {
+            return None;
+        }
+
+        let mut builder = http::Response::builder().status(entry.status);
+        for (name, value) in &entry.headers {
+            builder = builder.header(name, value);
+        }
+        let body = BASE64.decode(entry.body).ok()?;
+        let response = builder.body(body).ok()?;
+        tracing::trace!(url = %url, "serving response from HTTP disk cache");
+        Some(Response::from(response))
+    }
+
+    async fn write(&self, path: &Path, entry: &CachedResponse) -> std::io::Result<()> {
+        tokio::fs::create_dir_all(&self.dir).await?;
+        let json = serde_json::to_vec(entry)?;
+        // Write to a temp file first so readers never see a partial entry.
+        let tmp = path.with_extension("json.tmp");
+        tokio::fs::write(&tmp, json).await?;
+        tokio::fs::rename(&tmp, path).await
+    }
+}
+
+#[async_trait::async_trait]
+impl Middleware for DiskCache {
+    async fn handle(
+        &self,
+        req: Request,
+        extensions: &mut http::Extensions,
+        next: Next<'_>,
+    ) -> reqwest_middleware::Result<Response> {
+        if req.method() != Method::GET {
+            return next.run(req, extensions).await;
+        }
+
+        let url = req.url().clone();
+        let path = self.entry_path(&url);
+        if let Some(response) = self.read_fresh(&path, &url).await {
+            return Ok(response);
+        }
+
+        let response = next.run(req, extensions).await?;
+        if !response.status().is_success() {
+            return Ok(response);
+        }
+
+        let status = response.status();
+        let headers = response.headers().clone();
+        let body = response.bytes().await?;
+
+        let entry = CachedResponse {
+            url: url.to_string(),
+            status: status.as_u16(),
+            headers: headers
+                .iter()
+                .filter_map(|(k, v)| Some((k.to_string(), v.to_str().ok()?.to_string())))
+                .collect(),
+            body: BASE64.encode(&body),
+            stored_at: chrono::Utc::now().timestamp_millis(),
+        };
+        if let Err(e) = self.write(&path, &entry).await {
+            tracing::warn!(path = %path.display(), error = %e, "failed to write HTTP cache entry");
+        }
+
+        let mut rebuilt = http::Response::builder().status(status);
+        if let Some(h) = rebuilt.headers_mut() {
+            *h = headers;
+        }
+        let rebuilt = rebuilt
+            .body(body.to_vec())
+            .map_err(reqwest_middleware::Error::middleware)?;
+        Ok(Response::from(rebuilt))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::client::http::HttpClientConfig;
+    use wiremock::matchers::{method, path};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    fn temp_cache_dir() -> PathBuf {
+        std::env::temp_dir().join(format!("polymarket-hft-cache-{}", uuid::Uuid::now_v7()))
+    }
+
+    #[tokio::test]
+    async fn second_request_within_ttl_is_served_from_disk() {
+        let mock_server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/markets"))
+            .respond_with(
+                ResponseTemplate::new(200).set_body_json(serde_json::json!([{"id": "1"}])),
+            )
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        let dir = temp_cache_dir();
+        let client = HttpClientConfig::default()
+            .with_max_retries(0)
+            .with_disk_cache(&dir, Duration::from_secs(60))
+            .build()
+            .unwrap();
+        let url = format!("{}/markets", mock_server.uri());
+
+        for _ in 0..2 {
+            let response = client.get(&url).send().await.unwrap();
+            assert_eq!(response.status(), 200);
+            let body: serde_json::Value = response.json().await.unwrap();
+            assert_eq!(body, serde_json::json!([{"id": "1"}]));
+        }
+
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+
+    #[tokio::test]
+    async fn expired_and_failed_responses_are_refetched() {
+        let mock_server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/ok"))
+            .respond_with(ResponseTemplate::new(200).set_body_string("fresh"))
+            .expect(2)
+            .mount(&mock_server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/bad"))
+            .respond_with(ResponseTemplate::new(400))
+            .expect(2)
+            .mount(&mock_server)
+            .await;
+
+        let dir = temp_cache_dir();
+        let client = HttpClientConfig::default()
+            .with_max_retries(0)
+            .with_disk_cache(&dir, Duration::ZERO)
+            .build()
+            .unwrap();
+
+        for _ in 0..2 {
+            tokio::time::sleep(Duration::from_millis(5)).await;
+            let ok = client
+                .get(format!("{}/ok", mock_server.uri()))
+                .send()
+                .await
+                .unwrap();
+            assert_eq!(ok.text().await.unwrap(), "fresh");
+            let bad = client
+                .get(format!("{}/bad", mock_server.uri()))
+                .send()
+                .await
+                .unwrap();
+            assert_eq!(bad.status(), 400);
+        }
+
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+}
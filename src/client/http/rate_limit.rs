@@ -0,0 +1,127 @@
+//! Rate-limit information parsed from response headers.
+
+use std::sync::{Arc, RwLock};
+
+use chrono::{DateTime, Utc};
+use reqwest::header::HeaderMap;
+
+/// Reset values above this are unix timestamps; smaller ones are seconds from now.
+const EPOCH_THRESHOLD_SECS: i64 = 1_000_000_000;
+
+/// Remaining request budget reported by a provider.
+///
+/// Parsed from `x-ratelimit-limit`, `x-ratelimit-remaining` and
+/// `x-ratelimit-reset` (or their unprefixed `ratelimit-*` forms). `reset_at`
+/// accepts either a unix timestamp or a number of seconds until the reset.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct RateLimitInfo {
+    /// Requests allowed in the current window.
+    pub limit: Option<u64>,
+    /// Requests left in the current window.
+    pub remaining: Option<u64>,
+    /// When the window resets.
+    pub reset_at: Option<DateTime<Utc>>,
+}
+
+impl RateLimitInfo {
+    /// Parse rate-limit headers, returning `None` if none are present.
+    pub fn from_headers(headers: &HeaderMap) -> Option<Self> {
+        Self::from_headers_at(headers, Utc::now())
+    }
+
+    fn from_headers_at(headers: &HeaderMap, now: DateTime<Utc>) -> Option<Self> {
+        let limit = header_number(headers, "limit");
+        let remaining = header_number(headers, "remaining");
+        let reset_at = header_number(headers, "reset").and_then(|reset| {
+            let reset = i64::try_from(reset).ok()?;
+            if reset >= EPOCH_THRESHOLD_SECS {
+                DateTime::from_timestamp(reset, 0)
+            } else {
+                Some(now + chrono::Duration::seconds(reset))
+            }
+        });
+
+        if limit.is_none() && remaining.is_none() && reset_at.is_none() {
+            return None;
+        }
+        Some(Self {
+            limit,
+            remaining,
+            reset_at,
+        })
+    }
+}
+
+/// Reads `x-ratelimit-{suffix}`, falling back to `ratelimit-{suffix}`.
+fn header_number(headers: &HeaderMap, suffix: &str) -> Option<u64> {
+    [
+        format!("x-ratelimit-{}", suffix),
+        format!("ratelimit-{}", suffix),
+    ]
+    .iter()
+    .find_map(|name| {
+        headers
+            .get(name.as_str())?
+            .to_str()
+            .ok()?
+            .trim()
+            .parse()
+            .ok()
+    })
+}
+
+/// Shared slot holding the most recent [`RateLimitInfo`] seen by a client.
+///
+/// Clones share the same slot, so every clone of a client reports the same
+/// budget.
+#[derive(Debug, Clone, Default)]
+pub(crate) struct RateLimitTracker {
+    last: Arc<RwLock<Option<RateLimitInfo>>>,
+}
+
+impl RateLimitTracker {
+    /// Record rate-limit headers from a response, if it has any.
+    pub(crate) fn record(&self, headers: &HeaderMap) {
+        if let Some(info) = RateLimitInfo::from_headers(headers)
+            && let Ok(mut last) = self.last.write()
+        {
+            *last = Some(info);
+        }
+    }
+
+    /// The most recently recorded rate-limit info.
+    pub(crate) fn last(&self) -> Option<RateLimitInfo> {
+        self.last.read().ok().and_then(|last| *last)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use reqwest::header::HeaderValue;
+
+    #[test]
+    fn parses_relative_and_absolute_reset() {
+        let now = DateTime::from_timestamp(1_700_000_000, 0).unwrap();
+
+        let mut headers = HeaderMap::new();
+        headers.insert("x-ratelimit-limit", HeaderValue::from_static("30"));
+        headers.insert("x-ratelimit-remaining", HeaderValue::from_static("12"));
+        headers.insert("x-ratelimit-reset", HeaderValue::from_static("45"));
+        let info = RateLimitInfo::from_headers_at(&headers, now).unwrap();
+        assert_eq!(info.limit, Some(30));
+        assert_eq!(info.remaining, Some(12));
+        assert_eq!(info.reset_at.unwrap().timestamp(), 1_700_000_045);
+
+        let mut headers = HeaderMap::new();
+        headers.insert("ratelimit-reset", HeaderValue::from_static("1700000600"));
+        let info = RateLimitInfo::from_headers_at(&headers, now).unwrap();
+        assert_eq!(info.limit, None);
+        assert_eq!(info.reset_at.unwrap().timestamp(), 1_700_000_600);
+    }
+
+    #[test]
+    fn missing_headers_yield_none() {
+        assert_eq!(RateLimitInfo::from_headers(&HeaderMap::new()), None);
+    }
+}
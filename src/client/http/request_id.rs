@@ -0,0 +1,115 @@
+//! `X-Request-Id` middleware for correlating logs with upstream services.
+
+use reqwest::header::HeaderValue;
+use reqwest::{Request, Response};
+use reqwest_middleware::{Middleware, Next};
+use tracing::{Instrument, debug, debug_span};
+use uuid::Uuid;
+
+/// Header carrying the generated request ID.
+pub const REQUEST_ID_HEADER: &str = "x-request-id";
+
+/// Middleware that tags each call with a fresh UUID.
+///
+/// The ID is sent as [`REQUEST_ID_HEADER`] and recorded as `request_id` on an
+/// `http_request` span around the call. It sits outside the retry layer, so
+/// retries of a call reuse its ID.
+#[derive(Debug, Clone, Copy, Default)]
+pub(super) struct RequestIdLayer;
+
+#[async_trait::async_trait]
+impl Middleware for RequestIdLayer {
+    async fn handle(
+        &self,
+        mut req: Request,
+        extensions: &mut http::Extensions,
+        next: Next<'_>,
+    ) -> reqwest_middleware::Result<Response> {
+        let request_id = Uuid::now_v7().to_string();
+        if let Ok(value) = HeaderValue::from_str(&request_id) {
+            req.headers_mut().insert(REQUEST_ID_HEADER, value);
+        }
+        let span = debug_span!(
+            "http_request",
+            request_id = %request_id,
+            method = %req.method(),
+            url = %req.url(),
+        );
+        async move {
+            debug!("sending request");
+            next.run(req, extensions).await
+        }
+        .instrument(span)
+        .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashSet;
+    use std::io::Write;
+    use std::sync::{Arc, Mutex};
+
+    use wiremock::matchers::method;
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    use super::*;
+    use crate::client::http::HttpClientConfig;
+
+    /// Log sink shared with the test subscriber.
+    #[derive(Clone, Default)]
+    struct Captured(Arc<Mutex<Vec<u8>>>);
+
+    impl Write for Captured {
+        fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+            self.0.lock().unwrap().extend_from_slice(buf);
+            Ok(buf.len())
+        }
+
+        fn flush(&mut self) -> std::io::Result<()> {
+            Ok(())
+        }
+    }
+
+    #[tokio::test]
+    async fn each_request_carries_a_unique_logged_id() {
+        let logs = Captured::default();
+        let writer = logs.clone();
+        let subscriber = tracing_subscriber::fmt()
+            .with_max_level(tracing::Level::DEBUG)
+            .with_ansi(false)
+            .with_writer(move || writer.clone())
+            .finish();
+        let _guard = tracing::subscriber::set_default(subscriber);
+
+        let mock_server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .respond_with(ResponseTemplate::new(200))
+            .expect(3)
+            .mount(&mock_server)
+            .await;
+
+        let client = HttpClientConfig::default()
+            .with_request_ids(true)
+            .build()
+            .unwrap();
+        for _ in 0..3 {
+            client.get(mock_server.uri()).send().await.unwrap();
+        }
+
+        let ids: Vec<String> = mock_server
+            .received_requests()
+            .await
+            .unwrap()
+            .iter()
+            .map(|req| req.headers[REQUEST_ID_HEADER].to_str().unwrap().to_string())
+            .collect();
+        assert_eq!(ids.len(), 3);
+        assert_eq!(ids.iter().collect::<HashSet<_>>().len(), 3);
+
+        let output = String::from_utf8(logs.0.lock().unwrap().clone()).unwrap();
+        for id in &ids {
+            assert!(output.contains(id.as_str()), "{id} not logged in {output}");
+        }
+    }
+}
@@ -0,0 +1,152 @@
+//! Request, retry and latency counters (`metrics` feature).
+
+use std::sync::Arc;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::time::{Duration, Instant};
+
+use reqwest::{Request, Response};
+use reqwest_middleware::{Middleware, Next};
+
+/// Upper bounds of the latency histogram buckets, in milliseconds.
+///
+/// A final bucket counts requests slower than the last bound.
+pub const LATENCY_BUCKETS_MS: [u64; 10] = [5, 10, 25, 50, 100, 250, 500, 1000, 2500, 10_000];
+
+#[derive(Debug, Default)]
+struct Counters {
+    /// Calls made by the client, each possibly retried.
+    calls: AtomicU64,
+    /// HTTP requests sent, including retries.
+    requests: AtomicU64,
+    latency: [AtomicU64; LATENCY_BUCKETS_MS.len() + 1],
+}
+
+/// Counters shared by every clone of a client.
+///
+/// Installed by [`HttpClientConfig::build`](super::HttpClientConfig::build);
+/// clones share the same counters. Clients wrapping a caller-supplied HTTP
+/// client never record anything, so their stats stay at zero.
+#[derive(Debug, Clone, Default)]
+pub struct ClientStats {
+    counters: Arc<Counters>,
+}
+
+/// Point-in-time copy of [`ClientStats`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ClientStatsSnapshot {
+    /// HTTP requests sent, including retries.
+    pub requests: u64,
+    /// Requests that were retries of an earlier failed attempt.
+    pub retries: u64,
+    /// Request counts per latency bucket, aligned with [`LATENCY_BUCKETS_MS`]
+    /// plus a final overflow bucket.
+    pub latency_buckets: Vec<u64>,
+}
+
+impl ClientStats {
+    /// Current counter values.
+    pub fn snapshot(&self) -> ClientStatsSnapshot {
+        let calls = self.counters.calls.load(Ordering::Relaxed);
+        let requests = self.counters.requests.load(Ordering::Relaxed);
+        ClientStatsSnapshot {
+            requests,
+            retries: requests.saturating_sub(calls),
+            latency_buckets: self
+                .counters
+                .latency
+                .iter()
+                .map(|bucket| bucket.load(Ordering::Relaxed))
+                .collect(),
+        }
+    }
+
+    /// Middleware counting calls; goes outside the retry middleware.
+    pub(super) fn call_layer(&self) -> CallLayer {
+        CallLayer(self.clone())
+    }
+
+    /// Middleware counting and timing each attempt; goes inside the retry
+    /// middleware.
+    pub(super) fn attempt_layer(&self) -> AttemptLayer {
+        AttemptLayer(self.clone())
+    }
+
+    fn record_latency(&self, elapsed: Duration) {
+        let ms = elapsed.as_millis();
+        let bucket = LATENCY_BUCKETS_MS
+            .iter()
+            .position(|&bound| ms <= u128::from(bound))
+            .unwrap_or(LATENCY_BUCKETS_MS.len());
+        self.counters.latency[bucket].fetch_add(1, Ordering::Relaxed);
+    }
+}
+
+pub(super) struct CallLayer(ClientStats);
+
+#[async_trait::async_trait]
+impl Middleware for CallLayer {
+    async fn handle(
+        &self,
+        req: Request,
+        extensions: &mut http::Extensions,
+        next: Next<'_>,
+    ) -> reqwest_middleware::Result<Response> {
+        self.0.counters.calls.fetch_add(1, Ordering::Relaxed);
+        next.run(req, extensions).await
+    }
+}
+
+pub(super) struct AttemptLayer(ClientStats);
+
+#[async_trait::async_trait]
+impl Middleware for AttemptLayer {
+    async fn handle(
+        &self,
+        req: Request,
+        extensions: &mut http::Extensions,
+        next: Next<'_>,
+    ) -> reqwest_middleware::Result<Response> {
+        self.0.counters.requests.fetch_add(1, Ordering::Relaxed);
+        let started = Instant::now();
+        let result = next.run(req, extensions).await;
+        self.0.record_latency(started.elapsed());
+        result
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::client::alternativeme::Client;
+    use crate::client::http::HttpClientConfig;
+    use wiremock::matchers::method;
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    #[tokio::test]
+    async fn retried_request_is_counted() {
+        let mock_server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .respond_with(ResponseTemplate::new(503))
+            .up_to_n_times(1)
+            .mount(&mock_server)
+            .await;
+        Mock::given(method("GET"))
+            .respond_with(ResponseTemplate::new(200).set_body_string(
+                r#"{"name": "Fear and Greed Index", "data": [], "metadata": {"error": null}}"#,
+            ))
+            .mount(&mock_server)
+            .await;
+
+        let config = HttpClientConfig::default()
+            .with_min_retry_interval(Duration::from_millis(1))
+            .with_max_retry_interval(Duration::from_millis(1));
+        let client = Client::with_config(config).with_base_url(mock_server.uri());
+
+        client.get_fear_and_greed(None).await.unwrap();
+
+        let stats = client.stats();
+        assert_eq!(stats.requests, 2);
+        assert_eq!(stats.retries, 1);
+        assert_eq!(stats.latency_buckets.iter().sum::<u64>(), 2);
+    }
+}
@@ -0,0 +1,245 @@
+//! Pagination helpers shared by API clients.
+//!
+//! Paginators repeatedly call a page-fetching closure and stop on a short
+//! page ([`offset_pages`]) or a missing next cursor ([`cursor_pages`]). Every
+//! paginator carries a `max_pages` safeguard so a misbehaving API
+//! that keeps returning full pages cannot make it loop forever.
+
+use std::future::Future;
+
+use futures_util::{Stream, StreamExt, stream};
+
+/// Default upper bound on the number of pages a paginator will fetch.
+pub const DEFAULT_MAX_PAGES: usize = 1000;
+
+/// Error returned when a paginator hits its `max_pages` safeguard.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, thiserror::Error)]
+#[error("pagination exceeded max_pages ({max_pages}) without reaching the last page")]
+pub struct MaxPagesExceeded {
+    /// The page budget that was exhausted.
+    pub max_pages: usize,
+}
+
+/// Stream offset-based pages until a page shorter than `page_size` is returned.
+///
+/// `fetch` is called with the offset of each page, starting at `start_offset`
+/// and advancing by `page_size`. If `max_pages` full pages have been fetched
+/// and the API would still have more, the stream yields a
+/// [`MaxPagesExceeded`] error and ends. Dropping the stream stops paging.
+pub fn offset_pages<T, E, F, Fut>(
+    page_size: usize,
+    start_offset: usize,
+    max_pages: usize,
+    mut fetch: F,
+) -> impl Stream<Item = Result<Vec<T>, E>>
+where
+    E: From<MaxPagesExceeded>,
+    F: FnMut(usize) -> Fut,
+    Fut: Future<Output = Result<Vec<T>, E>>,
+{
+    // State: (next offset, pages fetched); `None` once paging is finished.
+    stream::unfold(Some((start_offset, 0usize)), move |state| {
+        let next = state.map(|(offset, pages)| {
+            if pages >= max_pages {
+                return Err(MaxPagesExceeded { max_pages });
+            }
+            Ok((offset, pages, fetch(offset)))
+        });
+        async move {
+            match next? {
+                Err(e) => Some((Err(e.into()), None)),
+                Ok((offset, pages, fut)) => match fut.await {
+                    Ok(page) => {
+                        let next_state = (page.len() >= page_size && page_size > 0)
+                            .then_some((offset + page.len(), pages + 1));
+                        if page.is_empty() {
+                            None
+                        } else {
+                            Some((Ok(page), next_state))
+                        }
+                    }
+                    Err(e) => Some((Err(e), None)),
+                },
+            }
+        }
+    })
+}
+
+/// Collect every item from [`offset_pages`] into a single vector.
+pub async fn collect_offset_pages<T, E, F, Fut>(
+    page_size: usize,
+    start_offset: usize,
+    max_pages: usize,
+    fetch: F,
+) -> Result<Vec<T>, E>
+where
+    E: From<MaxPagesExceeded>,
+    F: FnMut(usize) -> Fut,
+    Fut: Future<Output = Result<Vec<T>, E>>,
+{
+    let mut pages = std::pin::pin!(offset_pages(page_size, start_offset, max_pages, fetch));
+    let mut items = Vec::new();
+    while let Some(page) = pages.next().await {
+        items.extend(page?);
+    }
+    Ok(items)
+}
+
+/// One page of a cursor-paginated listing.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CursorPaginated<T> {
+    /// Items on this page.
+    pub items: Vec<T>,
+    /// Cursor for the next page, `None` on the last page.
+    pub next_cursor: Option<String>,
+}
+
+/// Stream cursor-based pages until a page without `next_cursor` is returned.
+///
+/// `fetch` is called with `None` for the first page and with the previous
+/// page's `next_cursor` afterwards. If `max_pages` pages have been fetched
+/// and the last one still has a cursor, the stream yields a
+/// [`MaxPagesExceeded`] error and ends. Dropping the stream stops paging.
+pub fn cursor_pages<T, E, F, Fut>(
+    max_pages: usize,
+    mut fetch: F,
+) -> impl Stream<Item = Result<Vec<T>, E>>
+where
+    E: From<MaxPagesExceeded>,
+    F: FnMut(Option<String>) -> Fut,
+    Fut: Future<Output = Result<CursorPaginated<T>, E>>,
+{
+    // State: (cursor, pages fetched); `None` once paging is finished.
+    stream::unfold(Some((None, 0usize)), move |state| {
+        let next = state.map(|(cursor, pages)| {
+            if pages >= max_pages {
+                return Err(MaxPagesExceeded { max_pages });
+            }
+            Ok((pages, fetch(cursor)))
+        });
+        async move {
+            match next? {
+                Err(e) => Some((Err(e.into()), None)),
+                Ok((pages, fut)) => match fut.await {
+                    Ok(page) => {
+                        let next_state = page.next_cursor.map(|cursor| (Some(cursor), pages + 1));
+                        Some((Ok(page.items), next_state))
+                    }
+                    Err(e) => Some((Err(e), None)),
+                },
+            }
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Debug, PartialEq)]
+    enum TestError {
+        MaxPages(MaxPagesExceeded),
+        Fetch,
+    }
+
+    impl From<MaxPagesExceeded> for TestError {
+        fn from(e: MaxPagesExceeded) -> Self {
+            TestError::MaxPages(e)
+        }
+    }
+
+    #[tokio::test]
+    async fn collects_until_short_page() {
+        let items = collect_offset_pages(3, 0, DEFAULT_MAX_PAGES, |offset| async move {
+            let end = (offset + 3).min(7);
+            Ok::<_, TestError>((offset..end).collect::<Vec<_>>())
+        })
+        .await
+        .unwrap();
+        assert_eq!(items, (0..7).collect::<Vec<_>>());
+    }
+
+    #[tokio::test]
+    async fn stops_with_error_when_pages_are_always_full() {
+        let mut calls = 0;
+        let result = collect_offset_pages(2, 0, 5, |_offset| {
+            calls += 1;
+            async { Ok::<_, TestError>(vec![1, 2]) }
+        })
+        .await;
+        assert_eq!(
+            result,
+            Err(TestError::MaxPages(MaxPagesExceeded { max_pages: 5 }))
+        );
+        assert_eq!(calls, 5);
+    }
+
+    #[tokio::test]
+    async fn short_last_page_within_budget_is_ok() {
+        let items = collect_offset_pages(2, 0, 2, |offset| async move {
+            Ok::<_, TestError>(if offset == 0 { vec![1, 2] } else { vec![3] })
+        })
+        .await
+        .unwrap();
+        assert_eq!(items, vec![1, 2, 3]);
+    }
+
+    #[tokio::test]
+    async fn propagates_fetch_errors() {
+        let result = collect_offset_pages(2, 0, 10, |offset| async move {
+            if offset == 0 {
+                Ok(vec![1, 2])
+            } else {
+                Err(TestError::Fetch)
+            }
+        })
+        .await;
+        assert_eq!(result, Err(TestError::Fetch));
+    }
+
+    #[tokio::test]
+    async fn follows_cursor_until_none() {
+        let mut cursors = Vec::new();
+        let pages: Vec<_> = cursor_pages(DEFAULT_MAX_PAGES, |cursor: Option<String>| {
+            cursors.push(cursor.clone());
+            async move {
+                Ok::<_, TestError>(match cursor.as_deref() {
+                    None => CursorPaginated {
+                        items: vec![1, 2],
+                        next_cursor: Some("page-2".to_string()),
+                    },
+                    Some(_) => CursorPaginated {
+                        items: vec![3],
+                        next_cursor: None,
+                    },
+                })
+            }
+        })
+        .collect()
+        .await;
+
+        assert_eq!(pages, vec![Ok(vec![1, 2]), Ok(vec![3])]);
+        assert_eq!(cursors, vec![None, Some("page-2".to_string())]);
+    }
+
+    #[tokio::test]
+    async fn cursor_pages_stop_at_max_pages() {
+        let pages: Vec<_> = cursor_pages(2, |_cursor| async {
+            Ok::<_, TestError>(CursorPaginated {
+                items: vec![1],
+                next_cursor: Some("again".to_string()),
+            })
+        })
+        .collect()
+        .await;
+
+        assert_eq!(
+            pages,
+            vec![
+                Ok(vec![1]),
+                Ok(vec![1]),
+                Err(TestError::MaxPages(MaxPagesExceeded { max_pages: 2 }))
+            ]
+        );
+    }
+}
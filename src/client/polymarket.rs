@@ -4,9 +4,90 @@
 //! - [`data`]: User data, positions, trades, and portfolio information
 //! - [`clob`]: Central Limit Order Book (REST + WebSocket)
 //! - [`gamma`]: Market discovery and metadata
+//! - [`market`]: Outcome classification helpers shared across APIs
 //! - [`rtds`]: Real-time data streaming
 
 pub mod clob;
 pub mod data;
 pub mod gamma;
+pub mod market;
 pub mod rtds;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::client::ValidatableRequest;
+    use crate::error::PolymarketError;
+
+    fn assert_rejected(request: &dyn ValidatableRequest) {
+        let err = request.validate().unwrap_err();
+        assert!(matches!(err, PolymarketError::BadRequest(_)), "got {err:?}");
+    }
+
+    #[test]
+    fn data_requests_reject_invalid_input() {
+        assert_rejected(&data::GetUserPositionsRequest {
+            user: "not-an-address",
+            ..Default::default()
+        });
+        assert_rejected(&data::GetUserClosedPositionsRequest {
+            user: "not-an-address",
+            ..Default::default()
+        });
+        assert_rejected(&data::GetUserActivityRequest {
+            user: "not-an-address",
+            ..Default::default()
+        });
+        assert_rejected(&data::GetTradesRequest {
+            limit: Some(-1),
+            ..Default::default()
+        });
+    }
+
+    #[test]
+    fn gamma_requests_reject_invalid_input() {
+        assert_rejected(&gamma::GetMarketsRequest {
+            limit: Some(0),
+            ..Default::default()
+        });
+        assert_rejected(&gamma::GetEventsRequest {
+            limit: Some(0),
+            ..Default::default()
+        });
+        assert_rejected(&gamma::GetSeriesRequest {
+            slug: Some(" "),
+            ..Default::default()
+        });
+        assert_rejected(&gamma::GetTeamsRequest {
+            limit: Some(0),
+            ..Default::default()
+        });
+        assert_rejected(&gamma::GetTagsRequest {
+            limit: Some(0),
+            ..Default::default()
+        });
+        assert_rejected(&gamma::GetCommentsRequest::default());
+        assert_rejected(&gamma::GetCommentsByUserAddressRequest::default());
+        assert_rejected(&gamma::SearchRequest {
+            q: " ",
+            cache: None,
+            events_status: None,
+            limit_per_type: None,
+            page: None,
+            events_tag: None,
+            keep_closed_markets: None,
+            sort: None,
+            ascending: None,
+            search_tags: None,
+            search_profiles: None,
+            recurrence: None,
+            exclude_tag_id: None,
+            optimized: None,
+        });
+    }
+
+    #[test]
+    fn clob_requests_reject_invalid_input() {
+        assert_rejected(&clob::GetPriceHistoryRequest::default());
+    }
+}
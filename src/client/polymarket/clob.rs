@@ -22,10 +22,13 @@
 
 mod auth;
 mod client;
+mod idempotency;
 
+mod markets;
 pub mod order_utils;
 pub mod orderbook;
 mod pricing;
+mod quotes;
 mod spreads;
 mod token_info;
 mod trading;
@@ -33,13 +36,17 @@ mod types;
 pub mod ws;
 
 pub use client::{Client, DEFAULT_BASE_URL};
+pub use idempotency::DEFAULT_IDEMPOTENCY_WINDOW;
+
+pub use markets::{MarketRewards, MarketToken, RewardRate, SamplingMarket, SamplingMarketsPage};
 
 pub use order_utils::{ExchangeOrderBuilder, OrderData, OrderSide, SignatureType, SignedOrder};
-pub use orderbook::{GetOrderBooksRequestItem, OrderBookSummary, PriceLevel};
+pub use orderbook::{GetOrderBooksRequestItem, Level, OrderBook, OrderBookSummary, PriceLevel};
 pub use pricing::{
     GetPriceHistoryRequest, MarketPrice, MarketPriceRequest, MidpointPrice, PriceHistory,
     PriceHistoryInterval, PriceHistoryPoint, Side,
 };
+pub use quotes::{Quote, QuoteCache};
 pub use spreads::SpreadRequest;
 pub use trading::TradingClient;
 pub use types::{
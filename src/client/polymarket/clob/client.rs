@@ -1,12 +1,20 @@
 //! CLOB API client implementation.
 
+use anyhow::anyhow;
 use reqwest::{Client as HttpClient, Response};
 use reqwest_middleware::ClientWithMiddleware;
+use serde_json::Value;
 use tracing::trace;
 use url::Url;
 
-use crate::client::http::{DEFAULT_MAX_RETRIES, HttpClientConfig, wrap_with_retry};
+#[cfg(feature = "metrics")]
+use crate::client::http::{ClientStats, ClientStatsSnapshot};
+use crate::client::http::{
+    DEFAULT_MAX_RETRIES, HttpClientConfig, deserialize_checked, wrap_with_retry,
+};
+use crate::client::{DataSourceClient, MethodMetadata, MethodParam, MetricInfo};
 use crate::error::{PolymarketError, Result};
+use crate::{DataSource, Metric, MetricUnit, ToMetrics};
 
 /// Default base URL for the Polymarket CLOB API.
 pub const DEFAULT_BASE_URL: &str = "https://clob.polymarket.com";
@@ -21,6 +29,9 @@ pub struct Client {
     pub(super) http_client: ClientWithMiddleware,
     /// Base URL for the API (validated URL).
     pub(super) base_url: Url,
+    /// Request counters (`metrics` feature).
+    #[cfg(feature = "metrics")]
+    pub(super) stats: ClientStats,
 }
 
 impl Client {
@@ -56,12 +67,17 @@ impl Client {
     /// ```
     pub fn with_base_url(base_url: &str) -> Result<Self> {
         let url = Url::parse(base_url)?;
-        let http_client = HttpClientConfig::default()
+        let config = HttpClientConfig::default();
+        #[cfg(feature = "metrics")]
+        let stats = config.stats.clone();
+        let http_client = config
             .build()
             .map_err(|e| PolymarketError::other(format!("failed to create HTTP client: {}", e)))?;
         Ok(Self {
             http_client,
             base_url: url,
+            #[cfg(feature = "metrics")]
+            stats,
         })
     }
 
@@ -77,13 +93,17 @@ impl Client {
     /// Returns `Ok(Client)` if the URL is valid, or an error if parsing fails.
     pub fn with_retries(base_url: &str, max_retries: u32) -> Result<Self> {
         let url = Url::parse(base_url)?;
-        let http_client = HttpClientConfig::default()
-            .with_max_retries(max_retries)
+        let config = HttpClientConfig::default().with_max_retries(max_retries);
+        #[cfg(feature = "metrics")]
+        let stats = config.stats.clone();
+        let http_client = config
             .build()
             .map_err(|e| PolymarketError::other(format!("failed to create HTTP client: {}", e)))?;
         Ok(Self {
             http_client,
             base_url: url,
+            #[cfg(feature = "metrics")]
+            stats,
         })
     }
 
@@ -112,6 +132,8 @@ impl Client {
         Self {
             http_client: wrap_with_retry(http_client, DEFAULT_MAX_RETRIES),
             base_url: Url::parse(DEFAULT_BASE_URL).expect("default CLOB base URL is valid"),
+            #[cfg(feature = "metrics")]
+            stats: ClientStats::default(),
         }
     }
 
@@ -124,9 +146,18 @@ impl Client {
         Self {
             http_client,
             base_url: Url::parse(DEFAULT_BASE_URL).expect("default CLOB base URL is valid"),
+            #[cfg(feature = "metrics")]
+            stats: ClientStats::default(),
         }
     }
 
+    /// Request, retry and latency counters (`metrics` feature); see
+    /// [`ClientStats`] for which clients record them.
+    #[cfg(feature = "metrics")]
+    pub fn stats(&self) -> ClientStatsSnapshot {
+        self.stats.snapshot()
+    }
+
     /// Checks if the response is successful and returns an appropriate error if not.
     pub(super) async fn check_response(&self, response: Response) -> Result<Response> {
         let status = response.status();
@@ -242,7 +273,7 @@ impl Client {
             time: u64,
         }
 
-        let result: TimeResponse = response.json().await?;
+        let result: TimeResponse = deserialize_checked(response).await?;
         trace!(time = result.time, "received server time");
         Ok(result.time)
     }
@@ -254,6 +285,73 @@ impl Default for Client {
     }
 }
 
+impl DataSourceClient for Client {
+    fn supported_methods(&self) -> Vec<MethodMetadata> {
+        vec![MethodMetadata {
+            method: "get_midpoints",
+            description: "Midpoint prices of several tokens",
+            params: vec![MethodParam {
+                name: "token_ids",
+                description: "Array of CLOB token IDs",
+                required: true,
+            }],
+            metrics: vec![MetricInfo {
+                name: "midpoint",
+                description: "Midpoint price (per token)",
+            }],
+        }]
+    }
+
+    fn fetch<'a>(
+        &'a self,
+        method: &'a str,
+        params: Option<Value>,
+    ) -> crate::client::BoxFuture<'a, anyhow::Result<Vec<Metric>>> {
+        Box::pin(async move {
+            match method {
+                "get_midpoints" => {
+                    let token_ids: Vec<&str> = params
+                        .as_ref()
+                        .and_then(|p| p.get("token_ids")?.as_array())
+                        .ok_or_else(|| anyhow!("get_midpoints requires 'token_ids' parameter"))?
+                        .iter()
+                        .map(|id| {
+                            id.as_str()
+                                .ok_or_else(|| anyhow!("'token_ids' must be an array of strings"))
+                        })
+                        .collect::<anyhow::Result<_>>()?;
+                    let midpoints = self.get_midpoints(&token_ids).await?;
+                    Ok(MidpointPrices(&midpoints).to_metrics())
+                }
+                _ => Err(anyhow!("Unknown method: {}", method)),
+            }
+        })
+    }
+}
+
+/// Midpoint prices keyed by token ID, as returned by `get_midpoints`.
+struct MidpointPrices<'a>(&'a std::collections::HashMap<String, String>);
+
+impl ToMetrics for MidpointPrices<'_> {
+    /// One `midpoint` metric per token, skipping prices that do not parse.
+    fn to_metrics(&self) -> Vec<Metric> {
+        let mut metrics: Vec<Metric> = self
+            .0
+            .iter()
+            .filter_map(|(token_id, mid)| {
+                let value: f64 = mid.parse().ok()?;
+                Some(
+                    Metric::new(DataSource::Polymarket, "midpoint", value, MetricUnit::Ratio)
+                        .with_label("endpoint", "get_midpoints")
+                        .with_label("token", token_id),
+                )
+            })
+            .collect();
+        metrics.sort_by(|a, b| a.labels.get("token").cmp(&b.labels.get("token")));
+        metrics
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -315,4 +413,42 @@ mod tests {
         let client = Client::default();
         assert!(client.base_url.as_str().starts_with(DEFAULT_BASE_URL));
     }
+
+    #[tokio::test]
+    async fn test_fetch_get_midpoints_emits_metric_per_token() {
+        use wiremock::matchers::{body_json, method, path};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let mock_server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(path("/midpoints"))
+            .and(body_json(serde_json::json!([
+                {"token_id": "111"},
+                {"token_id": "222"}
+            ])))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .set_body_json(serde_json::json!({"111": "0.45", "222": "0.615"})),
+            )
+            .mount(&mock_server)
+            .await;
+
+        let client = Client::with_base_url(&mock_server.uri()).unwrap();
+        let metrics = client
+            .fetch(
+                "get_midpoints",
+                Some(serde_json::json!({"token_ids": ["111", "222"]})),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(metrics.len(), 2);
+        assert!(metrics.iter().all(|m| m.name == "midpoint"));
+        assert_eq!(metrics[0].labels["token"], "111");
+        assert_eq!(metrics[0].value, 0.45);
+        assert_eq!(metrics[1].labels["token"], "222");
+        assert_eq!(metrics[1].value, 0.615);
+
+        assert!(client.fetch("get_midpoints", None).await.is_err());
+    }
 }
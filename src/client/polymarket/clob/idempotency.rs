@@ -0,0 +1,125 @@
+//! Client-side deduplication of order submissions.
+//!
+//! The CLOB API has no idempotency field, so retrying a `POST /order` whose
+//! response was lost can place the order twice. [`SubmittedOrders`] remembers
+//! the response for each caller-chosen key and replays it instead.
+
+use std::collections::HashMap;
+use std::future::Future;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+use crate::client::coalesce::Coalescer;
+use crate::error::Result;
+
+/// Default time a submitted key is remembered.
+pub const DEFAULT_IDEMPOTENCY_WINDOW: Duration = Duration::from_secs(600);
+
+type Completed = HashMap<String, (Instant, serde_json::Value)>;
+
+/// Responses of successful submissions keyed by idempotency key.
+///
+/// Concurrent submissions with the same key share one request. Failed
+/// submissions are not remembered, so they can be retried with the same key.
+pub(super) struct SubmittedOrders {
+    window: Duration,
+    completed: Arc<Mutex<Completed>>,
+    in_flight: Coalescer<serde_json::Value>,
+}
+
+impl SubmittedOrders {
+    pub(super) fn new(window: Duration) -> Self {
+        Self {
+            window,
+            completed: Arc::new(Mutex::new(HashMap::new())),
+            in_flight: Coalescer::new(),
+        }
+    }
+
+    /// Runs `submit` unless `key` was submitted within the window, in which
+    /// case the earlier response is returned.
+    pub(super) async fn submit<F>(&self, key: &str, submit: F) -> Result<serde_json::Value>
+    where
+        F: Future<Output = Result<serde_json::Value>> + Send + 'static,
+    {
+        if let Some(response) = completed(&self.completed, key, self.window) {
+            return Ok(response);
+        }
+        // A flight that finished after the check above has already recorded
+        // its response, so look again once this flight starts. The response
+        // is recorded inside the shared future, before the flight is
+        // released, so a caller arriving in between finds it.
+        let completed_orders = Arc::clone(&self.completed);
+        let window = self.window;
+        let owned_key = key.to_string();
+        let submit = async move {
+            if let Some(response) = completed(&completed_orders, &owned_key, window) {
+                return Ok(response);
+            }
+            let response = submit.await?;
+            lock(&completed_orders).insert(owned_key, (Instant::now(), response.clone()));
+            Ok(response)
+        };
+        self.in_flight.run(key, submit).await
+    }
+}
+
+/// Earlier response for `key`, dropping entries older than `window`.
+fn completed(
+    completed: &Mutex<Completed>,
+    key: &str,
+    window: Duration,
+) -> Option<serde_json::Value> {
+    let mut completed = lock(completed);
+    completed.retain(|_, (at, _)| at.elapsed() < window);
+    completed.get(key).map(|(_, response)| response.clone())
+}
+
+fn lock(completed: &Mutex<Completed>) -> std::sync::MutexGuard<'_, Completed> {
+    completed.lock().unwrap_or_else(|e| e.into_inner())
+}
+
+impl std::fmt::Debug for SubmittedOrders {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("SubmittedOrders")
+            .field("window", &self.window)
+            .finish_non_exhaustive()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    #[tokio::test(flavor = "multi_thread", worker_threads = 4)]
+    async fn concurrent_submissions_with_one_key_submit_once() {
+        let submitted = Arc::new(SubmittedOrders::new(DEFAULT_IDEMPOTENCY_WINDOW));
+        let calls = Arc::new(AtomicUsize::new(0));
+
+        let tasks: Vec<_> = (0..64)
+            .map(|i| {
+                let submitted = Arc::clone(&submitted);
+                let calls = Arc::clone(&calls);
+                tokio::spawn(async move {
+                    for _ in 0..i % 8 {
+                        tokio::task::yield_now().await;
+                    }
+                    submitted
+                        .submit("order-1", async move {
+                            calls.fetch_add(1, Ordering::SeqCst);
+                            tokio::task::yield_now().await;
+                            Ok(serde_json::json!({ "orderID": "0xabc" }))
+                        })
+                        .await
+                })
+            })
+            .collect();
+
+        for task in tasks {
+            let response = task.await.unwrap().unwrap();
+            assert_eq!(response["orderID"], "0xabc");
+        }
+        assert_eq!(calls.load(Ordering::SeqCst), 1);
+    }
+}
@@ -0,0 +1,272 @@
+//! Market discovery endpoints for CLOB API.
+//!
+//! `/sampling-markets` lists the markets currently eligible for liquidity
+//! rewards and `/simplified-markets` lists every CLOB market; both are paged
+//! with opaque cursors.
+
+use futures_util::{Stream, StreamExt, stream};
+use serde::{Deserialize, Serialize};
+use tracing::{instrument, trace};
+
+use super::Client;
+use super::types::{END_CURSOR, INITIAL_CURSOR};
+use crate::client::http::deserialize_checked;
+use crate::client::pagination::{CursorPaginated, DEFAULT_MAX_PAGES, cursor_pages};
+use crate::error::Result;
+
+/// Outcome token of a CLOB market.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct MarketToken {
+    /// ERC1155 token ID.
+    pub token_id: String,
+    /// Outcome label (e.g. "Yes").
+    pub outcome: String,
+    /// Last price, if the API reports one.
+    #[serde(default)]
+    pub price: Option<f64>,
+}
+
+/// Daily reward rate paid in one asset.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RewardRate {
+    /// Address of the reward asset.
+    pub asset_address: String,
+    /// Amount paid per day across the market.
+    pub rewards_daily_rate: f64,
+}
+
+/// Liquidity reward parameters of a market.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct MarketRewards {
+    /// Reward rates; `null` upstream when the market pays none.
+    #[serde(
+        default,
+        deserialize_with = "crate::json::deserialize_null_as_empty_vec"
+    )]
+    pub rates: Vec<RewardRate>,
+    /// Minimum order size that qualifies for rewards.
+    #[serde(default)]
+    pub min_size: f64,
+    /// Maximum spread from the midpoint, in cents, that qualifies for rewards.
+    #[serde(default)]
+    pub max_spread: f64,
+}
+
+/// Market entry of the sampling and simplified market listings.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SamplingMarket {
+    /// Condition ID of the market.
+    pub condition_id: String,
+    /// Outcome tokens.
+    #[serde(
+        default,
+        deserialize_with = "crate::json::deserialize_null_as_empty_vec"
+    )]
+    pub tokens: Vec<MarketToken>,
+    /// Liquidity reward parameters.
+    #[serde(default)]
+    pub rewards: MarketRewards,
+}
+
+/// One page of a market listing.
+pub type SamplingMarketsPage = CursorPaginated<SamplingMarket>;
+
+/// Raw page as returned by the server.
+#[derive(Debug, Deserialize)]
+struct MarketsPageResponse {
+    data: Vec<SamplingMarket>,
+    #[serde(default)]
+    next_cursor: Option<String>,
+}
+
+impl From<MarketsPageResponse> for SamplingMarketsPage {
+    fn from(raw: MarketsPageResponse) -> Self {
+        let next_cursor = raw
+            .next_cursor
+            .filter(|cursor| !cursor.is_empty() && cursor != END_CURSOR);
+        Self {
+            items: raw.data,
+            next_cursor,
+        }
+    }
+}
+
+// =============================================================================
+// Client Implementation
+// =============================================================================
+
+impl Client {
+    /// Gets a page of markets eligible for liquidity rewards.
+    ///
+    /// # Arguments
+    ///
+    /// * `cursor` - `next_cursor` of the previous page, or `None` for the first.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use polymarket_hft::client::polymarket::clob::Client;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let client = Client::new();
+    ///     let page = client.get_sampling_markets(None).await?;
+    ///     println!("{} markets, next page: {:?}", page.items.len(), page.next_cursor);
+    ///     Ok(())
+    /// }
+    /// ```
+    #[instrument(skip(self), level = "trace")]
+    pub async fn get_sampling_markets(&self, cursor: Option<&str>) -> Result<SamplingMarketsPage> {
+        self.get_markets_page("sampling-markets", cursor).await
+    }
+
+    /// Stream every market eligible for liquidity rewards, following
+    /// `next_cursor` page by page.
+    ///
+    /// Paging stops after [`DEFAULT_MAX_PAGES`] pages with a
+    /// [`PolymarketError::MaxPagesExceeded`](crate::error::PolymarketError::MaxPagesExceeded)
+    /// error. Dropping the stream stops paging.
+    pub fn get_all_sampling_markets(&self) -> impl Stream<Item = Result<SamplingMarket>> + '_ {
+        cursor_pages(DEFAULT_MAX_PAGES, move |cursor| async move {
+            self.get_sampling_markets(cursor.as_deref()).await
+        })
+        .flat_map(|page| {
+            let items: Vec<Result<SamplingMarket>> = match page {
+                Ok(markets) => markets.into_iter().map(Ok).collect(),
+                Err(e) => vec![Err(e)],
+            };
+            stream::iter(items)
+        })
+    }
+
+    /// Gets a page of all CLOB markets, in the same shape as
+    /// [`get_sampling_markets`](Self::get_sampling_markets).
+    #[instrument(skip(self), level = "trace")]
+    pub async fn get_simplified_markets(
+        &self,
+        cursor: Option<&str>,
+    ) -> Result<SamplingMarketsPage> {
+        self.get_markets_page("simplified-markets", cursor).await
+    }
+
+    async fn get_markets_page(
+        &self,
+        path: &str,
+        cursor: Option<&str>,
+    ) -> Result<SamplingMarketsPage> {
+        let mut url = self.build_url(path);
+        url.query_pairs_mut()
+            .append_pair("next_cursor", cursor.unwrap_or(INITIAL_CURSOR));
+
+        trace!(url = %url, method = "GET", "sending HTTP request");
+        let response = self.http_client.get(url).send().await?;
+        let response = self.check_response(response).await?;
+        let page: MarketsPageResponse = deserialize_checked(response).await?;
+
+        trace!(count = page.data.len(), "received markets page");
+        Ok(page.into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use wiremock::matchers::{method, path, query_param};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    #[tokio::test]
+    async fn sampling_markets_cursor_is_followed() {
+        let mock_server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/sampling-markets"))
+            .and(query_param("next_cursor", INITIAL_CURSOR))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "limit": 1,
+                "count": 1,
+                "next_cursor": "MTAw",
+                "data": [{
+                    "condition_id": "0xabc",
+                    "active": true,
+                    "tokens": [
+                        {"token_id": "1", "outcome": "Yes", "price": 0.62, "winner": false},
+                        {"token_id": "2", "outcome": "No", "price": 0.38, "winner": false}
+                    ],
+                    "rewards": {
+                        "rates": [{"asset_address": "0xusdc", "rewards_daily_rate": 25}],
+                        "min_size": 50,
+                        "max_spread": 3.5
+                    }
+                }]
+            })))
+            .mount(&mock_server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/sampling-markets"))
+            .and(query_param("next_cursor", "MTAw"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "limit": 1,
+                "count": 1,
+                "next_cursor": END_CURSOR,
+                "data": [{
+                    "condition_id": "0xdef",
+                    "tokens": [],
+                    "rewards": {"rates": null, "min_size": 0, "max_spread": 0}
+                }]
+            })))
+            .mount(&mock_server)
+            .await;
+
+        let client = Client::with_base_url(&mock_server.uri()).unwrap();
+
+        let first = client.get_sampling_markets(None).await.unwrap();
+        assert_eq!(first.next_cursor.as_deref(), Some("MTAw"));
+        let market = &first.items[0];
+        assert_eq!(market.condition_id, "0xabc");
+        assert_eq!(market.tokens[0].token_id, "1");
+        assert_eq!(market.tokens[1].price, Some(0.38));
+        assert_eq!(market.rewards.rates[0].rewards_daily_rate, 25.0);
+        assert_eq!(market.rewards.max_spread, 3.5);
+
+        let second = client
+            .get_sampling_markets(first.next_cursor.as_deref())
+            .await
+            .unwrap();
+        assert_eq!(second.items[0].condition_id, "0xdef");
+        assert!(second.items[0].rewards.rates.is_empty());
+        assert_eq!(second.next_cursor, None);
+    }
+
+    #[tokio::test]
+    async fn all_sampling_markets_pages_to_exhaustion() {
+        let mock_server = MockServer::start().await;
+        let page = |condition_id: &str, next_cursor: &str| {
+            ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "next_cursor": next_cursor,
+                "data": [{"condition_id": condition_id, "tokens": [], "rewards": {}}]
+            }))
+        };
+        Mock::given(method("GET"))
+            .and(path("/sampling-markets"))
+            .and(query_param("next_cursor", INITIAL_CURSOR))
+            .respond_with(page("0x1", "MQ=="))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/sampling-markets"))
+            .and(query_param("next_cursor", "MQ=="))
+            .respond_with(page("0x2", END_CURSOR))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        let client = Client::with_base_url(&mock_server.uri()).unwrap();
+        let markets: Vec<String> = client
+            .get_all_sampling_markets()
+            .map(|market| market.unwrap().condition_id)
+            .collect()
+            .await;
+
+        assert_eq!(markets, vec!["0x1", "0x2"]);
+    }
+}
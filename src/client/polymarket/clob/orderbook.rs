@@ -1,12 +1,15 @@
 //! OrderBook types and endpoints for CLOB API.
 
+use std::collections::HashMap;
+
 use serde::{Deserialize, Serialize};
 use tracing::{instrument, trace};
 
-use crate::error::Result;
+use crate::error::{PolymarketError, Result};
 
 use super::Client;
 use super::pricing::Side;
+use crate::client::http::deserialize_checked;
 
 /// Price level in an order book.
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -29,8 +32,10 @@ pub struct OrderBookSummary {
     /// Hash of the order book state.
     pub hash: String,
     /// Array of bid levels.
+    #[serde(deserialize_with = "crate::json::deserialize_null_as_empty_vec")]
     pub bids: Vec<PriceLevel>,
     /// Array of ask levels.
+    #[serde(deserialize_with = "crate::json::deserialize_null_as_empty_vec")]
     pub asks: Vec<PriceLevel>,
     /// Minimum order size for this market.
     pub min_order_size: String,
@@ -40,6 +45,133 @@ pub struct OrderBookSummary {
     pub neg_risk: bool,
 }
 
+impl PriceLevel {
+    /// Parses the price, returning `None` if it is not a valid number.
+    pub fn price_f64(&self) -> Option<f64> {
+        self.price.parse().ok()
+    }
+}
+
+impl OrderBookSummary {
+    /// Highest-priced bid level, if any.
+    ///
+    /// Levels are compared by parsed price, so the upstream sort order does
+    /// not matter; levels with unparseable prices are ignored.
+    pub fn best_bid(&self) -> Option<&PriceLevel> {
+        best_level(&self.bids, |a, b| a > b)
+    }
+
+    /// Lowest-priced ask level, if any.
+    pub fn best_ask(&self) -> Option<&PriceLevel> {
+        best_level(&self.asks, |a, b| a < b)
+    }
+
+    /// Whether the best bid is at or above the best ask.
+    ///
+    /// Locked books (equal prices) count as crossed. Returns `false` if
+    /// either side is empty.
+    pub fn is_crossed(&self) -> bool {
+        self.top_prices().is_some_and(|(bid, ask)| bid >= ask)
+    }
+
+    /// Whether the best bid equals the best ask.
+    pub fn is_locked(&self) -> bool {
+        self.top_prices().is_some_and(|(bid, ask)| bid == ask)
+    }
+
+    fn top_prices(&self) -> Option<(f64, f64)> {
+        let bid = self.best_bid()?.price_f64()?;
+        let ask = self.best_ask()?.price_f64()?;
+        Some((bid, ask))
+    }
+}
+
+/// Returns the level whose price wins under `better(candidate, current)`.
+fn best_level(levels: &[PriceLevel], better: fn(f64, f64) -> bool) -> Option<&PriceLevel> {
+    levels
+        .iter()
+        .filter_map(|level| Some((level, level.price_f64()?)))
+        .fold(
+            None,
+            |best: Option<(&PriceLevel, f64)>, (level, price)| match best {
+                Some((_, best_price)) if !better(price, best_price) => best,
+                _ => Some((level, price)),
+            },
+        )
+        .map(|(level, _)| level)
+}
+
+/// Price level with numeric price and size.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct Level {
+    pub price: f64,
+    pub size: f64,
+}
+
+/// Order book snapshot with levels parsed into numbers.
+///
+/// Built from an [`OrderBookSummary`] by [`Client::get_order_book_typed`].
+/// Levels keep the upstream order.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct OrderBook {
+    /// Asset (token) identifier.
+    pub asset_id: String,
+    pub bids: Vec<Level>,
+    pub asks: Vec<Level>,
+    /// Hash of the order book state.
+    pub hash: String,
+    /// Snapshot time as a unix timestamp in milliseconds.
+    pub timestamp: i64,
+}
+
+impl TryFrom<OrderBookSummary> for OrderBook {
+    type Error = PolymarketError;
+
+    fn try_from(summary: OrderBookSummary) -> Result<Self> {
+        let timestamp = summary.timestamp.parse().map_err(|_| {
+            PolymarketError::other(format!(
+                "invalid order book timestamp '{}'",
+                summary.timestamp
+            ))
+        })?;
+        Ok(Self {
+            bids: parse_levels(&summary.bids)?,
+            asks: parse_levels(&summary.asks)?,
+            asset_id: summary.asset_id,
+            hash: summary.hash,
+            timestamp,
+        })
+    }
+}
+
+fn parse_levels(levels: &[PriceLevel]) -> Result<Vec<Level>> {
+    levels
+        .iter()
+        .map(|level| {
+            let parse = |value: &str| {
+                value.parse::<f64>().map_err(|_| {
+                    PolymarketError::other(format!("invalid order book level value '{}'", value))
+                })
+            };
+            Ok(Level {
+                price: parse(&level.price)?,
+                size: parse(&level.size)?,
+            })
+        })
+        .collect()
+}
+
+/// Token ids are non-empty decimal strings.
+fn validate_token_id(token_id: &str) -> Result<()> {
+    if token_id.is_empty() || !token_id.chars().all(|c| c.is_ascii_digit()) {
+        return Err(PolymarketError::bad_request(format!(
+            "invalid token_id '{}': expected a decimal token id",
+            token_id
+        )));
+    }
+    Ok(())
+}
+
 /// Request item for getting multiple order books.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct GetOrderBooksRequestItem {
@@ -88,7 +220,7 @@ impl Client {
         trace!(url = %url, method = "GET", "sending HTTP request");
         let response = self.http_client.get(url).send().await?;
         let response = self.check_response(response).await?;
-        let order_book: OrderBookSummary = response.json().await?;
+        let order_book: OrderBookSummary = deserialize_checked(response).await?;
         trace!(
             market = %order_book.market,
             bids_count = order_book.bids.len(),
@@ -98,6 +230,17 @@ impl Client {
         Ok(order_book)
     }
 
+    /// Gets the order book for a token with numeric price levels.
+    ///
+    /// Same endpoint as [`get_order_book`](Self::get_order_book), but the
+    /// token id is validated before sending and the response is parsed into
+    /// an [`OrderBook`].
+    #[instrument(skip(self), fields(token_id = %token_id), level = "trace")]
+    pub async fn get_order_book_typed(&self, token_id: &str) -> Result<OrderBook> {
+        validate_token_id(token_id)?;
+        self.get_order_book(token_id).await?.try_into()
+    }
+
     /// Gets order book summaries for multiple tokens.
     ///
     /// # Arguments
@@ -137,8 +280,270 @@ impl Client {
         trace!(url = %url, method = "POST", count = request.len(), "sending HTTP request");
         let response = self.http_client.post(url).json(request).send().await?;
         let response = self.check_response(response).await?;
-        let order_books: Vec<OrderBookSummary> = response.json().await?;
+        let order_books: Vec<OrderBookSummary> = deserialize_checked(response).await?;
         trace!(count = order_books.len(), "received order books");
         Ok(order_books)
     }
+
+    /// Gets typed order books for several tokens in one request, keyed by token id.
+    ///
+    /// Every token id is validated before anything is sent. Tokens the API
+    /// returns no book for are absent from the map.
+    #[instrument(skip(self, token_ids), fields(count = token_ids.len()), level = "trace")]
+    pub async fn get_order_books_typed(
+        &self,
+        token_ids: &[&str],
+    ) -> Result<HashMap<String, OrderBook>> {
+        for token_id in token_ids {
+            validate_token_id(token_id)?;
+        }
+        let request: Vec<GetOrderBooksRequestItem> = token_ids
+            .iter()
+            .map(|token_id| GetOrderBooksRequestItem {
+                token_id: token_id.to_string(),
+                side: None,
+            })
+            .collect();
+
+        self.get_order_books(&request)
+            .await?
+            .into_iter()
+            .map(|summary| {
+                let book = OrderBook::try_from(summary)?;
+                Ok((book.asset_id.clone(), book))
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use wiremock::matchers::{body_json, method, path, query_param};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    fn book(bids: &[&str], asks: &[&str]) -> OrderBookSummary {
+        let levels = |prices: &[&str]| {
+            prices
+                .iter()
+                .map(|p| PriceLevel {
+                    price: p.to_string(),
+                    size: "100".to_string(),
+                })
+                .collect()
+        };
+        OrderBookSummary {
+            market: "0xmarket".to_string(),
+            asset_id: "123".to_string(),
+            timestamp: "1700000000000".to_string(),
+            hash: "hash".to_string(),
+            bids: levels(bids),
+            asks: levels(asks),
+            min_order_size: "5".to_string(),
+            tick_size: "0.01".to_string(),
+            neg_risk: false,
+        }
+    }
+
+    #[test]
+    fn normal_book_has_top_of_book_and_is_not_crossed() {
+        // Upstream lists bids ascending and asks descending.
+        let book = book(&["0.40", "0.44", "0.45"], &["0.60", "0.55", "0.50"]);
+
+        assert_eq!(book.best_bid().unwrap().price, "0.45");
+        assert_eq!(book.best_ask().unwrap().price, "0.50");
+        assert!(!book.is_crossed());
+        assert!(!book.is_locked());
+    }
+
+    #[test]
+    fn null_levels_deserialize_as_empty() {
+        let summary: OrderBookSummary = serde_json::from_value(serde_json::json!({
+            "market": "0xmarket",
+            "asset_id": "123",
+            "timestamp": "1700000000000",
+            "hash": "hash",
+            "bids": null,
+            "asks": [{"price": "0.50", "size": "10"}],
+            "min_order_size": "5",
+            "tick_size": "0.01",
+            "neg_risk": false
+        }))
+        .unwrap();
+        assert!(summary.bids.is_empty());
+        assert_eq!(summary.asks.len(), 1);
+        assert!(summary.best_bid().is_none());
+
+        // Null is tolerated, a missing side is still a malformed book.
+        let missing = serde_json::from_value::<OrderBookSummary>(serde_json::json!({
+            "market": "0xmarket",
+            "asset_id": "123",
+            "timestamp": "1700000000000",
+            "hash": "hash",
+            "asks": [],
+            "min_order_size": "5",
+            "tick_size": "0.01",
+            "neg_risk": false
+        }));
+        assert!(missing.is_err());
+    }
+
+    #[test]
+    fn crossed_and_locked_books_are_detected() {
+        let crossed = book(&["0.52"], &["0.50"]);
+        assert!(crossed.is_crossed());
+        assert!(!crossed.is_locked());
+
+        let locked = book(&["0.50"], &["0.50"]);
+        assert!(locked.is_crossed());
+        assert!(locked.is_locked());
+    }
+
+    #[test]
+    fn summary_converts_to_typed_book() {
+        let typed = OrderBook::try_from(book(&["0.45"], &["0.50", "0.55"])).unwrap();
+        assert_eq!(typed.timestamp, 1_700_000_000_000);
+        assert_eq!(
+            typed.bids,
+            vec![Level {
+                price: 0.45,
+                size: 100.0
+            }]
+        );
+        assert_eq!(typed.asks.len(), 2);
+
+        let mut bad = book(&["abc"], &[]);
+        assert!(OrderBook::try_from(bad.clone()).is_err());
+        bad.bids.clear();
+        bad.timestamp = "soon".to_string();
+        assert!(OrderBook::try_from(bad).is_err());
+    }
+
+    #[tokio::test]
+    async fn get_order_book_typed_parses_payload() {
+        let mock_server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/book"))
+            .and(query_param("token_id", "123"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "market": "0xmarket",
+                "asset_id": "123",
+                "timestamp": "1700000000123",
+                "hash": "0xabc",
+                "bids": [{"price": "0.48", "size": "250"}, {"price": "0.49", "size": "10.5"}],
+                "asks": [{"price": "0.52", "size": "75"}],
+                "min_order_size": "5",
+                "tick_size": "0.01",
+                "neg_risk": false
+            })))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        let client = Client::with_base_url(&mock_server.uri()).unwrap();
+        let book = client.get_order_book_typed("123").await.unwrap();
+
+        assert_eq!(book.asset_id, "123");
+        assert_eq!(book.hash, "0xabc");
+        assert_eq!(book.timestamp, 1_700_000_000_123);
+        assert_eq!(
+            book.bids,
+            vec![
+                Level {
+                    price: 0.48,
+                    size: 250.0
+                },
+                Level {
+                    price: 0.49,
+                    size: 10.5
+                },
+            ]
+        );
+        assert_eq!(
+            book.asks,
+            vec![Level {
+                price: 0.52,
+                size: 75.0
+            }]
+        );
+    }
+
+    #[tokio::test]
+    async fn get_order_book_typed_rejects_invalid_token_id() {
+        let client = Client::new();
+        for token_id in ["", "0xabc", "12 3"] {
+            let err = client.get_order_book_typed(token_id).await.unwrap_err();
+            assert!(
+                matches!(err, PolymarketError::BadRequest(_)),
+                "{}",
+                token_id
+            );
+        }
+    }
+
+    #[tokio::test]
+    async fn get_order_books_typed_keys_books_by_token_id() {
+        let mock_server = MockServer::start().await;
+        let summary = |asset_id: &str, bid: &str| {
+            serde_json::json!({
+                "market": "0xmarket",
+                "asset_id": asset_id,
+                "timestamp": "1700000000000",
+                "hash": format!("hash-{}", asset_id),
+                "bids": [{"price": bid, "size": "10"}],
+                "asks": [],
+                "min_order_size": "5",
+                "tick_size": "0.01",
+                "neg_risk": false
+            })
+        };
+        Mock::given(method("POST"))
+            .and(path("/books"))
+            .and(body_json(serde_json::json!([
+                {"token_id": "1"},
+                {"token_id": "2"},
+                {"token_id": "3"}
+            ])))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!([
+                summary("1", "0.10"),
+                summary("2", "0.20"),
+                summary("3", "0.30")
+            ])))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        let client = Client::with_base_url(&mock_server.uri()).unwrap();
+        let books = client
+            .get_order_books_typed(&["1", "2", "3"])
+            .await
+            .unwrap();
+
+        assert_eq!(books.len(), 3);
+        assert_eq!(books["2"].hash, "hash-2");
+        assert_eq!(books["3"].bids[0].price, 0.30);
+    }
+
+    #[tokio::test]
+    async fn get_order_books_typed_validates_all_ids_first() {
+        let client = Client::new();
+        let err = client
+            .get_order_books_typed(&["1", "bad", "3"])
+            .await
+            .unwrap_err();
+        assert!(matches!(err, PolymarketError::BadRequest(_)));
+    }
+
+    #[test]
+    fn empty_book_has_no_top_of_book() {
+        let empty = book(&[], &[]);
+        assert!(empty.best_bid().is_none());
+        assert!(empty.best_ask().is_none());
+        assert!(!empty.is_crossed());
+        assert!(!empty.is_locked());
+
+        let one_sided = book(&["0.40"], &[]);
+        assert!(one_sided.best_bid().is_some());
+        assert!(!one_sided.is_crossed());
+    }
 }
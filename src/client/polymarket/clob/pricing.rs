@@ -8,6 +8,8 @@ use tracing::{instrument, trace};
 use crate::error::Result;
 
 use super::Client;
+use crate::client::ValidatableRequest;
+use crate::client::http::deserialize_checked;
 
 /// Market side for pricing operations.
 #[derive(Debug, Clone, Copy, Serialize, Deserialize, Default)]
@@ -69,6 +71,7 @@ pub struct PriceHistoryPoint {
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct PriceHistory {
     /// List of timestamp/price pairs.
+    #[serde(deserialize_with = "crate::json::deserialize_null_as_empty_vec")]
     pub history: Vec<PriceHistoryPoint>,
 }
 
@@ -144,13 +147,8 @@ pub struct GetPriceHistoryRequest<'a> {
     pub fidelity: Option<i32>,
 }
 
-impl GetPriceHistoryRequest<'_> {
-    /// Validates the request parameters.
-    ///
-    /// # Returns
-    ///
-    /// Returns `Ok(())` if parameters are valid, or an error describing the issue.
-    pub fn validate(&self) -> Result<()> {
+impl ValidatableRequest for GetPriceHistoryRequest<'_> {
+    fn validate(&self) -> Result<()> {
         // Market is required and cannot be empty
         if self.market.is_empty() {
             return Err(crate::error::PolymarketError::bad_request(
@@ -187,6 +185,17 @@ impl GetPriceHistoryRequest<'_> {
     }
 }
 
+impl GetPriceHistoryRequest<'_> {
+    /// Validates the request parameters.
+    ///
+    /// # Returns
+    ///
+    /// Returns `Ok(())` if parameters are valid, or an error describing the issue.
+    pub fn validate(&self) -> Result<()> {
+        ValidatableRequest::validate(self)
+    }
+}
+
 /// Request item for getting multiple market prices.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct MarketPriceRequest {
@@ -235,7 +244,7 @@ impl Client {
         trace!(url = %url, method = "GET", "sending HTTP request");
         let response = self.http_client.get(url).send().await?;
         let response = self.check_response(response).await?;
-        let price: MarketPrice = response.json().await?;
+        let price: MarketPrice = deserialize_checked(response).await?;
         trace!(price = %price.price, "received market price");
         Ok(price)
     }
@@ -271,7 +280,8 @@ impl Client {
         trace!(url = %url, method = "GET", "sending HTTP request");
         let response = self.http_client.get(url).send().await?;
         let response = self.check_response(response).await?;
-        let prices: HashMap<String, HashMap<String, String>> = response.json().await?;
+        let prices: HashMap<String, HashMap<String, String>> =
+            deserialize_checked(response).await?;
         trace!(count = prices.len(), "received market prices");
         Ok(prices)
     }
@@ -312,7 +322,8 @@ impl Client {
         trace!(url = %url, method = "POST", count = request.len(), "sending HTTP request");
         let response = self.http_client.post(url).json(request).send().await?;
         let response = self.check_response(response).await?;
-        let prices: HashMap<String, HashMap<String, String>> = response.json().await?;
+        let prices: HashMap<String, HashMap<String, String>> =
+            deserialize_checked(response).await?;
         trace!(count = prices.len(), "received market prices");
         Ok(prices)
     }
@@ -348,11 +359,41 @@ impl Client {
         trace!(url = %url, method = "GET", "sending HTTP request");
         let response = self.http_client.get(url).send().await?;
         let response = self.check_response(response).await?;
-        let midpoint: MidpointPrice = response.json().await?;
+        let midpoint: MidpointPrice = deserialize_checked(response).await?;
         trace!(mid = %midpoint.mid, "received midpoint price");
         Ok(midpoint)
     }
 
+    /// Gets midpoint prices for multiple tokens in one request.
+    ///
+    /// # Arguments
+    ///
+    /// * `token_ids` - The unique identifiers of the tokens.
+    ///
+    /// # Returns
+    ///
+    /// Returns a map of token_id to midpoint price.
+    #[instrument(skip(self, token_ids), fields(count = token_ids.len()), level = "trace")]
+    pub async fn get_midpoints(&self, token_ids: &[&str]) -> Result<HashMap<String, String>> {
+        #[derive(Serialize)]
+        struct MidpointRequest<'a> {
+            token_id: &'a str,
+        }
+
+        let url = self.build_url("midpoints");
+        let body: Vec<MidpointRequest<'_>> = token_ids
+            .iter()
+            .map(|token_id| MidpointRequest { token_id })
+            .collect();
+
+        trace!(url = %url, method = "POST", count = body.len(), "sending HTTP request");
+        let response = self.http_client.post(url).json(&body).send().await?;
+        let response = self.check_response(response).await?;
+        let midpoints: HashMap<String, String> = deserialize_checked(response).await?;
+        trace!(count = midpoints.len(), "received midpoints");
+        Ok(midpoints)
+    }
+
     /// Gets price history for a traded token.
     ///
     /// # Arguments
@@ -387,6 +428,7 @@ impl Client {
         &self,
         request: GetPriceHistoryRequest<'_>,
     ) -> Result<PriceHistory> {
+        request.validate()?;
         let mut url = self.build_url("prices-history");
         url.query_pairs_mut().append_pair("market", request.market);
 
@@ -410,7 +452,7 @@ impl Client {
         trace!(url = %url, method = "GET", "sending HTTP request");
         let response = self.http_client.get(url).send().await?;
         let response = self.check_response(response).await?;
-        let history: PriceHistory = response.json().await?;
+        let history: PriceHistory = deserialize_checked(response).await?;
         trace!(count = history.history.len(), "received price history");
         Ok(history)
     }
@@ -452,7 +494,7 @@ impl Client {
             price: String,
         }
 
-        let result: LastTradePriceResponse = response.json().await?;
+        let result: LastTradePriceResponse = deserialize_checked(response).await?;
         trace!(price = %result.price, "received last trade price");
         Ok(result.price)
     }
@@ -485,7 +527,7 @@ impl Client {
         trace!(url = %url, method = "POST", count = token_ids.len(), "sending HTTP request");
         let response = self.http_client.post(url).json(&request).send().await?;
         let response = self.check_response(response).await?;
-        let prices: HashMap<String, String> = response.json().await?;
+        let prices: HashMap<String, String> = deserialize_checked(response).await?;
         trace!(count = prices.len(), "received last trades prices");
         Ok(prices)
     }
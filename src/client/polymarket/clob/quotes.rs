@@ -0,0 +1,213 @@
+//! Midpoint and spread cache kept fresh by the market websocket.
+//!
+//! [`QuoteCache`] is seeded over REST and then updated from
+//! [`BookUpdate`]s, so strategy loops can read the latest quote per token
+//! without awaiting anything.
+
+use std::collections::HashMap;
+use std::sync::RwLock;
+use std::time::{Duration, Instant};
+
+use futures_util::{Stream, StreamExt};
+
+use super::Client;
+use super::spreads::SpreadRequest;
+use super::ws::{BookUpdate, WsPriceLevel};
+use crate::error::Result;
+
+/// Latest midpoint and spread of one token.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Quote {
+    /// Midpoint between best bid and best ask.
+    pub mid: f64,
+    /// Best ask minus best bid, if known.
+    pub spread: Option<f64>,
+    /// When this quote was last written.
+    pub updated_at: Instant,
+}
+
+/// Per-token midpoint/spread cache with synchronous reads.
+///
+/// Share it behind an `Arc`: one task runs [`follow`](Self::follow) on a
+/// market stream while any number of readers call [`mid`](Self::mid),
+/// [`spread`](Self::spread) and [`staleness`](Self::staleness).
+#[derive(Debug, Default)]
+pub struct QuoteCache {
+    quotes: RwLock<HashMap<String, Quote>>,
+}
+
+impl QuoteCache {
+    /// Creates an empty cache.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Loads the current midpoint and spread of `token_ids` over REST.
+    ///
+    /// Midpoints and spreads are each fetched in one batch request. Tokens
+    /// the batch has no parseable midpoint for are logged and left out.
+    pub async fn seed(&self, client: &Client, token_ids: &[&str]) -> Result<()> {
+        let requests: Vec<SpreadRequest> = token_ids
+            .iter()
+            .map(|id| SpreadRequest {
+                token_id: id.to_string(),
+                side: None,
+            })
+            .collect();
+        let spreads = client.get_spreads(&requests).await?;
+        let midpoints = client.get_midpoints(token_ids).await?;
+
+        for token_id in token_ids {
+            let Some(mid) = midpoints.get(*token_id).and_then(|m| m.parse().ok()) else {
+                tracing::warn!(token_id = %token_id, "No midpoint to seed quote, skipping token");
+                continue;
+            };
+            let spread = spreads.get(*token_id).and_then(|s| s.parse().ok());
+            self.set(token_id, mid, spread);
+        }
+        Ok(())
+    }
+
+    /// Applies one market channel update for `token_id`.
+    ///
+    /// Book snapshots and price changes update the quote; other updates and
+    /// books missing a side are ignored.
+    pub fn apply(&self, token_id: &str, update: &BookUpdate) {
+        let top = match update {
+            BookUpdate::Snapshot(book) => {
+                best_price(&book.bids, f64::max).zip(best_price(&book.asks, f64::min))
+            }
+            BookUpdate::PriceChange { change, .. } => change
+                .best_bid
+                .parse::<f64>()
+                .ok()
+                .zip(change.best_ask.parse::<f64>().ok()),
+            _ => None,
+        };
+        if let Some((bid, ask)) = top {
+            self.set(token_id, (bid + ask) / 2.0, Some(ask - bid));
+        }
+    }
+
+    /// Applies updates from a merged market stream until it ends.
+    ///
+    /// Returns the first stream error.
+    pub async fn follow<S>(&self, updates: S) -> Result<()>
+    where
+        S: Stream<Item = Result<(String, BookUpdate)>>,
+    {
+        let mut updates = std::pin::pin!(updates);
+        while let Some(item) = updates.next().await {
+            let (token_id, update) = item?;
+            self.apply(&token_id, &update);
+        }
+        Ok(())
+    }
+
+    /// Cached quote of `token_id`.
+    pub fn quote(&self, token_id: &str) -> Option<Quote> {
+        self.read().get(token_id).copied()
+    }
+
+    /// Cached midpoint of `token_id`.
+    pub fn mid(&self, token_id: &str) -> Option<f64> {
+        self.quote(token_id).map(|q| q.mid)
+    }
+
+    /// Cached spread of `token_id`.
+    pub fn spread(&self, token_id: &str) -> Option<f64> {
+        self.quote(token_id).and_then(|q| q.spread)
+    }
+
+    /// Time since the quote of `token_id` was last updated.
+    pub fn staleness(&self, token_id: &str) -> Option<Duration> {
+        self.quote(token_id).map(|q| q.updated_at.elapsed())
+    }
+
+    fn set(&self, token_id: &str, mid: f64, spread: Option<f64>) {
+        let quote = Quote {
+            mid,
+            spread,
+            updated_at: Instant::now(),
+        };
+        self.quotes
+            .write()
+            .unwrap_or_else(|e| e.into_inner())
+            .insert(token_id.to_string(), quote);
+    }
+
+    fn read(&self) -> std::sync::RwLockReadGuard<'_, HashMap<String, Quote>> {
+        self.quotes.read().unwrap_or_else(|e| e.into_inner())
+    }
+}
+
+/// Best parseable price among `levels`, picked with `better`.
+fn best_price(levels: &[WsPriceLevel], better: fn(f64, f64) -> f64) -> Option<f64> {
+    levels
+        .iter()
+        .filter_map(|level| level.price.parse::<f64>().ok())
+        .reduce(better)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::client::polymarket::clob::ws::PriceChange;
+    use wiremock::matchers::{method, path};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    #[tokio::test]
+    async fn seeded_quote_follows_book_updates() {
+        let mock_server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(path("/midpoints"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "111": "0.45"
+            })))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+        Mock::given(method("POST"))
+            .and(path("/spreads"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "111": "0.02"
+            })))
+            .mount(&mock_server)
+            .await;
+
+        let client = Client::with_base_url(&mock_server.uri()).unwrap();
+        let cache = QuoteCache::new();
+        cache.seed(&client, &["111", "222"]).await.unwrap();
+        assert_eq!(cache.mid("111"), Some(0.45));
+        assert_eq!(cache.spread("111"), Some(0.02));
+        assert!(cache.staleness("111").is_some());
+        assert_eq!(cache.mid("222"), None);
+
+        let change: PriceChange = serde_json::from_value(serde_json::json!({
+            "asset_id": "111",
+            "price": "0.5",
+            "size": "5",
+            "side": "BUY",
+            "hash": "h",
+            "best_bid": "0.5",
+            "best_ask": "0.54"
+        }))
+        .unwrap();
+        let update = BookUpdate::PriceChange {
+            change,
+            timestamp: "1000".to_string(),
+        };
+        cache
+            .follow(futures_util::stream::iter([Ok((
+                "111".to_string(),
+                update,
+            ))]))
+            .await
+            .unwrap();
+
+        let mid = cache.mid("111").unwrap();
+        assert!((mid - 0.52).abs() < 1e-9, "got {mid}");
+        let spread = cache.spread("111").unwrap();
+        assert!((spread - 0.04).abs() < 1e-9, "got {spread}");
+    }
+}
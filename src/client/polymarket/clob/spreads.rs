@@ -9,6 +9,7 @@ use crate::error::Result;
 
 use super::Client;
 use super::pricing::Side;
+use crate::client::http::deserialize_checked;
 
 /// Request item for getting bid-ask spreads.
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -61,7 +62,7 @@ impl Client {
         trace!(url = %url, method = "POST", count = request.len(), "sending HTTP request");
         let response = self.http_client.post(url).json(request).send().await?;
         let response = self.check_response(response).await?;
-        let spreads: HashMap<String, String> = response.json().await?;
+        let spreads: HashMap<String, String> = deserialize_checked(response).await?;
         trace!(count = spreads.len(), "received spreads");
         Ok(spreads)
     }
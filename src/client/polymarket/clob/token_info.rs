@@ -5,6 +5,7 @@ use tracing::{instrument, trace};
 
 use super::Client;
 use super::types::TickSize;
+use crate::client::http::deserialize_checked;
 use crate::error::Result;
 
 /// Token info response from the server (tick-size endpoint).
@@ -55,7 +56,7 @@ impl Client {
         trace!(url = %url, method = "GET", "sending HTTP request");
         let response = self.http_client.get(url).send().await?;
         let response = self.check_response(response).await?;
-        let info: TickSizeResponse = response.json().await?;
+        let info: TickSizeResponse = deserialize_checked(response).await?;
 
         // Parse tick size float to enum
         let tick_size = if (info.minimum_tick_size - 0.1).abs() < f64::EPSILON {
@@ -105,7 +106,7 @@ impl Client {
         trace!(url = %url, method = "GET", "sending HTTP request");
         let response = self.http_client.get(url).send().await?;
         let response = self.check_response(response).await?;
-        let info: NegRiskResponse = response.json().await?;
+        let info: NegRiskResponse = deserialize_checked(response).await?;
 
         trace!(neg_risk = %info.neg_risk, "received neg_risk");
         Ok(info.neg_risk)
@@ -148,7 +149,7 @@ impl Client {
             fee_rate_bps: u32,
         }
 
-        let info: FeeRateResponse = response.json().await?;
+        let info: FeeRateResponse = deserialize_checked(response).await?;
         trace!(fee_rate_bps = info.fee_rate_bps, "received fee rate");
         Ok(info.fee_rate_bps)
     }
@@ -2,17 +2,24 @@
 //!
 //! Provides order creation, submission, querying, and cancellation.
 
+use std::sync::Arc;
+use std::time::Duration;
+
 use alloy_signer_local::PrivateKeySigner;
+use futures_util::StreamExt;
 use serde_json::json;
 use tracing::{instrument, trace};
 
 use super::Client;
 use super::auth::{create_l2_headers, get_current_timestamp};
+use super::idempotency::{DEFAULT_IDEMPOTENCY_WINDOW, SubmittedOrders};
 use super::types::{
     ApiKeyCreds, Chain, END_CURSOR, INITIAL_CURSOR, OpenOrder, OpenOrderParams, OpenOrdersResponse,
     OrderType, Trade, TradeParams, TradesPaginatedResponse,
 };
-use crate::error::Result;
+use crate::client::http::deserialize_checked;
+use crate::client::pagination::{CursorPaginated, DEFAULT_MAX_PAGES, cursor_pages};
+use crate::error::{PolymarketError, Result};
 
 // =============================================================================
 // Order Submission Endpoints
@@ -49,6 +56,8 @@ pub struct TradingClient {
     creds: ApiKeyCreds,
     /// Whether to use server time for signatures.
     use_server_time: bool,
+    /// Responses of orders posted with an idempotency key.
+    submitted: Arc<SubmittedOrders>,
 }
 
 impl TradingClient {
@@ -66,6 +75,7 @@ impl TradingClient {
             wallet,
             creds,
             use_server_time: false,
+            submitted: Arc::new(SubmittedOrders::new(DEFAULT_IDEMPOTENCY_WINDOW)),
         }
     }
 
@@ -82,6 +92,7 @@ impl TradingClient {
             wallet,
             creds,
             use_server_time: false,
+            submitted: Arc::new(SubmittedOrders::new(DEFAULT_IDEMPOTENCY_WINDOW)),
         })
     }
 
@@ -91,6 +102,15 @@ impl TradingClient {
         self
     }
 
+    /// Sets how long idempotency keys passed to
+    /// [`post_order_with_key`](Self::post_order_with_key) are remembered.
+    ///
+    /// Forgets keys already submitted.
+    pub fn with_idempotency_window(mut self, window: Duration) -> Self {
+        self.submitted = Arc::new(SubmittedOrders::new(window));
+        self
+    }
+
     /// Returns a reference to the inner client for public API access.
     pub fn client(&self) -> &Client {
         &self.inner
@@ -144,7 +164,7 @@ impl TradingClient {
 
         let response = request.send().await?;
         let response = self.inner.check_response(response).await?;
-        let raw: super::types::ApiKeyRaw = response.json().await?;
+        let raw: super::types::ApiKeyRaw = deserialize_checked(response).await?;
         trace!("API key created successfully");
         Ok(raw.into())
     }
@@ -184,7 +204,7 @@ impl TradingClient {
 
         let response = request.send().await?;
         let response = self.inner.check_response(response).await?;
-        let raw: super::types::ApiKeyRaw = response.json().await?;
+        let raw: super::types::ApiKeyRaw = deserialize_checked(response).await?;
         trace!("API key derived successfully");
         Ok(raw.into())
     }
@@ -242,7 +262,7 @@ impl TradingClient {
 
         let response = request.send().await?;
         let response = self.inner.check_response(response).await?;
-        let result: super::types::ApiKeysResponse = response.json().await?;
+        let result: super::types::ApiKeysResponse = deserialize_checked(response).await?;
         trace!(count = result.api_keys.len(), "received API keys");
         Ok(result.api_keys)
     }
@@ -305,7 +325,7 @@ impl TradingClient {
 
         let response = request.send().await?;
         let response = self.inner.check_response(response).await?;
-        let result: super::types::BanStatus = response.json().await?;
+        let result: super::types::BanStatus = deserialize_checked(response).await?;
         trace!(
             closed_only_mode = result.closed_only_mode,
             "received ban status"
@@ -365,11 +385,44 @@ impl TradingClient {
             .send()
             .await?;
         let response = self.inner.check_response(response).await?;
-        let result: serde_json::Value = response.json().await?;
+        let result: serde_json::Value = deserialize_checked(response).await?;
         trace!("order posted successfully");
         Ok(result)
     }
 
+    /// Posts a signed order at most once per idempotency key.
+    ///
+    /// With `Some(key)`, a key that was posted successfully within the
+    /// idempotency window returns the earlier response without another HTTP
+    /// call, and concurrent calls with the same key share one request. This
+    /// makes it safe to retry after a lost response. The key is only tracked
+    /// client-side; the CLOB API has no idempotency field. Callers that
+    /// shared a failed request get [`PolymarketError::Shared`]. With `None`
+    /// this is [`post_order`](Self::post_order).
+    ///
+    /// # Arguments
+    ///
+    /// * `order` - Signed order JSON.
+    /// * `order_type` - Order type (GTC, FOK, GTD, FAK).
+    /// * `idempotency_key` - Client-generated key, unique per intended order.
+    pub async fn post_order_with_key(
+        &self,
+        order: serde_json::Value,
+        order_type: OrderType,
+        idempotency_key: Option<&str>,
+    ) -> Result<serde_json::Value> {
+        let Some(key) = idempotency_key else {
+            return self.post_order(order, order_type).await;
+        };
+        let client = self.clone();
+        self.submitted
+            .submit(
+                key,
+                async move { client.post_order(order, order_type).await },
+            )
+            .await
+    }
+
     /// Posts multiple signed orders to the exchange.
     #[instrument(skip(self, orders), level = "trace")]
     pub async fn post_orders(
@@ -414,7 +467,7 @@ impl TradingClient {
             .send()
             .await?;
         let response = self.inner.check_response(response).await?;
-        let result: serde_json::Value = response.json().await?;
+        let result: serde_json::Value = deserialize_checked(response).await?;
         trace!("orders posted successfully");
         Ok(result)
     }
@@ -448,7 +501,7 @@ impl TradingClient {
 
         let response = request.send().await?;
         let response = self.inner.check_response(response).await?;
-        let order: OpenOrder = response.json().await?;
+        let order: OpenOrder = deserialize_checked(response).await?;
         trace!(order_id = %order.id, "received open order");
         Ok(order)
     }
@@ -493,25 +546,35 @@ impl TradingClient {
 
         let response = request.send().await?;
         let response = self.inner.check_response(response).await?;
-        let orders: OpenOrdersResponse = response.json().await?;
+        let orders: OpenOrdersResponse = deserialize_checked(response).await?;
         trace!(count = orders.len(), "received open orders");
         Ok(orders)
     }
 
     /// Gets all trade history with automatic pagination.
+    ///
+    /// Paging stops after [`DEFAULT_MAX_PAGES`] pages with a
+    /// [`PolymarketError::MaxPagesExceeded`](crate::error::PolymarketError::MaxPagesExceeded)
+    /// error.
     #[instrument(skip(self), level = "trace")]
     pub async fn get_trades(&self, params: Option<TradeParams>) -> Result<Vec<Trade>> {
-        let mut results = Vec::new();
-        let mut next_cursor = INITIAL_CURSOR.to_string();
+        let mut pages = std::pin::pin!(cursor_pages(DEFAULT_MAX_PAGES, |cursor| {
+            let params = params.clone();
+            async move {
+                let response = self.get_trades_paginated(params, cursor.as_deref()).await?;
+                let next_cursor = Some(response.next_cursor)
+                    .filter(|cursor| !cursor.is_empty() && cursor != END_CURSOR);
+                Ok::<_, PolymarketError>(CursorPaginated {
+                    items: response.data,
+                    next_cursor,
+                })
+            }
+        }));
 
-        while next_cursor != END_CURSOR {
-            let response = self
-                .get_trades_paginated(params.clone(), Some(&next_cursor))
-                .await?;
-            next_cursor = response.next_cursor;
-            results.extend(response.data);
+        let mut results = Vec::new();
+        while let Some(page) = pages.next().await {
+            results.extend(page?);
         }
-
         Ok(results)
     }
 
@@ -569,7 +632,7 @@ impl TradingClient {
 
         let response = request.send().await?;
         let response = self.inner.check_response(response).await?;
-        let trades: TradesPaginatedResponse = response.json().await?;
+        let trades: TradesPaginatedResponse = deserialize_checked(response).await?;
         trace!(count = trades.data.len(), "received trades");
         Ok(trades)
     }
@@ -609,7 +672,7 @@ impl TradingClient {
             .send()
             .await?;
         let response = self.inner.check_response(response).await?;
-        let result: serde_json::Value = response.json().await?;
+        let result: serde_json::Value = deserialize_checked(response).await?;
         trace!("order cancelled");
         Ok(result)
     }
@@ -645,7 +708,7 @@ impl TradingClient {
             .send()
             .await?;
         let response = self.inner.check_response(response).await?;
-        let result: serde_json::Value = response.json().await?;
+        let result: serde_json::Value = deserialize_checked(response).await?;
         trace!("orders cancelled");
         Ok(result)
     }
@@ -674,7 +737,7 @@ impl TradingClient {
 
         let response = request.send().await?;
         let response = self.inner.check_response(response).await?;
-        let result: serde_json::Value = response.json().await?;
+        let result: serde_json::Value = deserialize_checked(response).await?;
         trace!("all orders cancelled");
         Ok(result)
     }
@@ -717,7 +780,7 @@ impl TradingClient {
             .send()
             .await?;
         let response = self.inner.check_response(response).await?;
-        let result: serde_json::Value = response.json().await?;
+        let result: serde_json::Value = deserialize_checked(response).await?;
         trace!("market orders cancelled");
         Ok(result)
     }
@@ -886,7 +949,7 @@ impl TradingClient {
 
         let response = request.send().await?;
         let response = self.inner.check_response(response).await?;
-        let result: super::types::BalanceAllowance = response.json().await?;
+        let result: super::types::BalanceAllowance = deserialize_checked(response).await?;
         trace!(balance = %result.balance, allowance = %result.allowance, "received balance allowance");
         Ok(result)
     }
@@ -980,7 +1043,7 @@ impl TradingClient {
             scoring: bool,
         }
 
-        let result: OrderScoringResponse = response.json().await?;
+        let result: OrderScoringResponse = deserialize_checked(response).await?;
         trace!(scoring = result.scoring, "received order scoring status");
         Ok(result.scoring)
     }
@@ -1026,7 +1089,7 @@ impl TradingClient {
             .send()
             .await?;
         let response = self.inner.check_response(response).await?;
-        let result: std::collections::HashMap<String, bool> = response.json().await?;
+        let result: std::collections::HashMap<String, bool> = deserialize_checked(response).await?;
         trace!(count = result.len(), "received orders scoring status");
         Ok(result)
     }
@@ -1064,7 +1127,7 @@ impl TradingClient {
 
         let response = request.send().await?;
         let response = self.inner.check_response(response).await?;
-        let result: Vec<serde_json::Value> = response.json().await?;
+        let result: Vec<serde_json::Value> = deserialize_checked(response).await?;
         trace!(count = result.len(), "received notifications");
         Ok(result)
     }
@@ -1210,3 +1273,48 @@ impl TradingClient {
         Ok(avg_price)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use wiremock::matchers::{method, path};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    fn trading_client(base_url: &str) -> TradingClient {
+        let creds = ApiKeyCreds {
+            key: "key".to_string(),
+            secret: "c2VjcmV0".to_string(),
+            passphrase: "passphrase".to_string(),
+        };
+        TradingClient::with_base_url(base_url, PrivateKeySigner::random(), creds, Chain::Polygon)
+            .unwrap()
+    }
+
+    #[tokio::test]
+    async fn post_order_with_same_key_is_sent_once() {
+        let mock_server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(path("/order"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "success": true,
+                "orderID": "0xabc"
+            })))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        let client = trading_client(&mock_server.uri());
+        let order = json!({ "salt": 1 });
+        let first = client
+            .post_order_with_key(order.clone(), OrderType::Gtc, Some("order-1"))
+            .await
+            .unwrap();
+        let second = client
+            .post_order_with_key(order, OrderType::Gtc, Some("order-1"))
+            .await
+            .unwrap();
+
+        assert_eq!(first, second);
+        assert_eq!(second["orderID"], "0xabc");
+    }
+}
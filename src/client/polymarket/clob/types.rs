@@ -143,6 +143,7 @@ impl From<ApiKeyRaw> for ApiKeyCreds {
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct ApiKeysResponse {
+    #[serde(deserialize_with = "crate::json::deserialize_null_as_empty_vec")]
     pub api_keys: Vec<String>,
 }
 
@@ -317,6 +318,7 @@ pub struct OpenOrder {
     pub original_size: String,
     pub size_matched: String,
     pub price: String,
+    #[serde(deserialize_with = "crate::json::deserialize_null_as_empty_vec")]
     pub associate_trades: Vec<String>,
     pub outcome: String,
     pub created_at: u64,
@@ -361,6 +363,7 @@ pub struct Trade {
     pub owner: String,
     pub transaction_hash: Option<String>,
     pub bucket_index: Option<String>,
+    #[serde(deserialize_with = "crate::json::deserialize_null_as_empty_vec")]
     pub maker_orders: Vec<MakerOrder>,
     #[serde(rename = "type")]
     pub trade_type: Option<String>,
@@ -400,6 +403,7 @@ pub struct TradeParams {
 /// Paginated trades response.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct TradesPaginatedResponse {
+    #[serde(deserialize_with = "crate::json::deserialize_null_as_empty_vec")]
     pub data: Vec<Trade>,
     pub next_cursor: String,
 }
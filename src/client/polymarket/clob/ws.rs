@@ -1,14 +1,23 @@
 //! CLOB WebSocket client module.
 //!
 //! Provides real-time streaming of order book data, price changes, and user events
-//! from the Polymarket CLOB WebSocket API.
+//! from the Polymarket CLOB WebSocket API, and a supervisor that restarts
+//! streams which terminate and buffers them for slow consumers.
 
 mod client;
+mod merged;
+mod supervisor;
 mod types;
 
 pub use client::{
     ClobWsClient, ClobWsClientBuilder, ConnectionStatus, DEFAULT_PING_INTERVAL, DEFAULT_WS_URL,
 };
+pub use merged::{BookUpdate, merged_market_stream};
+pub use supervisor::{
+    DEFAULT_BUFFER_CAPACITY, DEFAULT_MAX_RESTART_BACKOFF, DEFAULT_RESTART_BACKOFF, OverflowPolicy,
+    SupervisedStream, SupervisorConfig, SupervisorHealth, supervised_stream,
+    supervised_stream_with_backoff, supervised_stream_with_config,
+};
 pub use types::{
     BookMessage, Channel, LastTradePriceMessage, MakerOrder, MarketSubscription, OrderEventType,
     OrderMessage, Outcome, PriceChange, PriceChangeMessage, Side, TickSizeChangeMessage,
@@ -7,7 +7,7 @@ use futures_util::stream::{SplitSink, SplitStream};
 use futures_util::{SinkExt, StreamExt};
 use tokio::net::TcpStream;
 use tokio::sync::Mutex;
-use tokio::time::interval;
+use tokio::time::{Instant, interval, timeout_at};
 use tokio_tungstenite::tungstenite::protocol::Message as TungsteniteMessage;
 use tokio_tungstenite::{MaybeTlsStream, WebSocketStream, connect_async};
 use tracing::{debug, error, info, trace, warn};
@@ -41,6 +41,7 @@ pub struct ClobWsClientBuilder {
     base_url: String,
     ping_interval: Duration,
     auto_reconnect: bool,
+    subscription_ack_timeout: Option<Duration>,
 }
 
 impl Default for ClobWsClientBuilder {
@@ -49,6 +50,7 @@ impl Default for ClobWsClientBuilder {
             base_url: DEFAULT_WS_URL.to_string(),
             ping_interval: DEFAULT_PING_INTERVAL,
             auto_reconnect: true,
+            subscription_ack_timeout: None,
         }
     }
 }
@@ -77,12 +79,25 @@ impl ClobWsClientBuilder {
         self
     }
 
+    /// Fails the market stream if nothing arrives this long after subscribing.
+    ///
+    /// The server answers a market subscription with a book snapshot. If no
+    /// message arrives within `timeout`, [`ClobWsClient::try_next_message`]
+    /// returns an error so the caller can reconnect instead of waiting on a
+    /// silent socket. Disabled by default.
+    pub fn subscription_ack_timeout(mut self, timeout: Duration) -> Self {
+        self.subscription_ack_timeout = Some(timeout);
+        self
+    }
+
     /// Builds the `ClobWsClient`.
     pub fn build(self) -> ClobWsClient {
         ClobWsClient {
             base_url: self.base_url,
             ping_interval: self.ping_interval,
             auto_reconnect: self.auto_reconnect,
+            subscription_ack_timeout: self.subscription_ack_timeout,
+            ack_deadline: None,
             channel: None,
             status: Arc::new(Mutex::new(ConnectionStatus::Disconnected)),
             writer: Arc::new(Mutex::new(None)),
@@ -101,6 +116,9 @@ pub struct ClobWsClient {
     base_url: String,
     ping_interval: Duration,
     auto_reconnect: bool,
+    subscription_ack_timeout: Option<Duration>,
+    /// When the pending market subscription must have been answered by.
+    ack_deadline: Option<Instant>,
     channel: Option<Channel>,
     status: Arc<Mutex<ConnectionStatus>>,
     writer: Arc<Mutex<Option<WsWriter>>>,
@@ -115,6 +133,8 @@ impl Clone for ClobWsClient {
             base_url: self.base_url.clone(),
             ping_interval: self.ping_interval,
             auto_reconnect: self.auto_reconnect,
+            subscription_ack_timeout: self.subscription_ack_timeout,
+            ack_deadline: self.ack_deadline,
             channel: self.channel,
             status: Arc::clone(&self.status),
             writer: Arc::clone(&self.writer),
@@ -161,7 +181,16 @@ impl ClobWsClient {
         *self.market_subscription.lock().await = Some(subscription.clone());
 
         self.connect_to_channel(Channel::Market).await?;
-        self.send_subscription(&subscription).await
+        self.send_subscription(&subscription).await?;
+        self.arm_ack_deadline();
+        Ok(())
+    }
+
+    /// Starts waiting for the market subscription to be answered, if configured.
+    fn arm_ack_deadline(&mut self) {
+        self.ack_deadline = self
+            .subscription_ack_timeout
+            .map(|timeout| Instant::now() + timeout);
     }
 
     /// Connects and subscribes to the user channel.
@@ -227,6 +256,7 @@ impl ClobWsClient {
         }
         *self.reader.lock().await = None;
         self.channel = None;
+        self.ack_deadline = None;
 
         self.set_status(ConnectionStatus::Disconnected).await;
         info!("Disconnected from CLOB WebSocket");
@@ -234,13 +264,57 @@ impl ClobWsClient {
 
     /// Receives the next message from the WebSocket.
     ///
-    /// Returns `None` if the connection is closed.
+    /// Returns `None` if the connection is closed. A missed subscription
+    /// acknowledgement (see [`ClobWsClientBuilder::subscription_ack_timeout`])
+    /// triggers a reconnect when auto-reconnect is enabled and ends the
+    /// stream otherwise.
     pub async fn next_message(&mut self) -> Option<WsMessage> {
         loop {
+            match self.try_next_message().await {
+                Ok(msg) => return msg,
+                Err(e) => {
+                    warn!("{e}");
+                    if !self.auto_reconnect {
+                        return None;
+                    }
+                    info!("Attempting to reconnect...");
+                    if let Err(e) = self.reconnect().await {
+                        error!("Reconnect failed: {e}");
+                        return None;
+                    }
+                }
+            }
+        }
+    }
+
+    /// Receives the next message, reporting a missed subscription acknowledgement.
+    ///
+    /// Behaves like [`next_message`](Self::next_message), except that when a
+    /// subscription acknowledgement timeout is configured and the server has
+    /// sent nothing in time, a [`PolymarketError::WebSocket`] is returned
+    /// instead of waiting indefinitely.
+    pub async fn try_next_message(&mut self) -> Result<Option<WsMessage>> {
+        loop {
+            let deadline = self.ack_deadline;
             let msg = {
                 let mut reader_guard = self.reader.lock().await;
-                let reader = reader_guard.as_mut()?;
-                reader.next().await
+                let Some(reader) = reader_guard.as_mut() else {
+                    return Ok(None);
+                };
+                match deadline {
+                    Some(deadline) => match timeout_at(deadline, reader.next()).await {
+                        Ok(msg) => msg,
+                        Err(_) => {
+                            drop(reader_guard);
+                            self.ack_deadline = None;
+                            return Err(PolymarketError::websocket(format!(
+                                "No response to market subscription within {:?}",
+                                self.subscription_ack_timeout.unwrap_or_default()
+                            )));
+                        }
+                    },
+                    None => reader.next().await,
+                }
             };
 
             match msg {
@@ -256,7 +330,8 @@ impl ClobWsClient {
                         Ok(value) => {
                             let ws_msg = WsMessage::from_json(value);
                             trace!("Received message: type={}", ws_msg.event_type());
-                            return Some(ws_msg);
+                            self.ack_deadline = None;
+                            return Ok(Some(ws_msg));
                         }
                         Err(e) => {
                             warn!("Failed to parse message: {e}");
@@ -281,10 +356,10 @@ impl ClobWsClient {
                         info!("Attempting to reconnect...");
                         if let Err(e) = self.reconnect().await {
                             error!("Reconnect failed: {e}");
-                            return None;
+                            return Ok(None);
                         }
                     } else {
-                        return None;
+                        return Ok(None);
                     }
                 }
                 Some(Err(e)) => {
@@ -295,10 +370,10 @@ impl ClobWsClient {
                         info!("Attempting to reconnect...");
                         if let Err(e) = self.reconnect().await {
                             error!("Reconnect failed: {e}");
-                            return None;
+                            return Ok(None);
                         }
                     } else {
-                        return None;
+                        return Ok(None);
                     }
                 }
                 None => {
@@ -309,10 +384,10 @@ impl ClobWsClient {
                         info!("Attempting to reconnect...");
                         if let Err(e) = self.reconnect().await {
                             error!("Reconnect failed: {e}");
-                            return None;
+                            return Ok(None);
                         }
                     } else {
-                        return None;
+                        return Ok(None);
                     }
                 }
                 _ => {
@@ -410,11 +485,13 @@ impl ClobWsClient {
             // Resend subscription
             match channel {
                 Channel::Market => {
-                    if let Some(sub) = self.market_subscription.lock().await.clone()
-                        && let Err(e) = self.send_subscription(&sub).await
-                    {
-                        warn!("Resubscribe failed: {e}");
-                        continue;
+                    let subscription = self.market_subscription.lock().await.clone();
+                    if let Some(sub) = subscription {
+                        if let Err(e) = self.send_subscription(&sub).await {
+                            warn!("Resubscribe failed: {e}");
+                            continue;
+                        }
+                        self.arm_ack_deadline();
                     }
                 }
                 Channel::User => {
@@ -439,6 +516,7 @@ impl ClobWsClient {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::testutil::mock_socket;
 
     #[test]
     fn test_builder_defaults() {
@@ -446,6 +524,7 @@ mod tests {
         assert_eq!(client.base_url, DEFAULT_WS_URL);
         assert_eq!(client.ping_interval, DEFAULT_PING_INTERVAL);
         assert!(client.auto_reconnect);
+        assert!(client.subscription_ack_timeout.is_none());
     }
 
     #[test]
@@ -461,6 +540,51 @@ mod tests {
         assert!(!client.auto_reconnect);
     }
 
+    #[tokio::test]
+    async fn test_unacknowledged_subscription_times_out() {
+        let url = mock_socket(vec![]).await;
+        let mut client = ClobWsClient::builder()
+            .base_url(url)
+            .auto_reconnect(false)
+            .subscription_ack_timeout(Duration::from_millis(200))
+            .build();
+        client
+            .subscribe_market(vec!["123".to_string()])
+            .await
+            .unwrap();
+
+        let result = tokio::time::timeout(Duration::from_secs(5), client.try_next_message())
+            .await
+            .expect("ack timeout should fire before the outer timeout");
+        match result {
+            Err(PolymarketError::WebSocket(msg)) => assert!(msg.contains("subscription")),
+            other => panic!("expected WebSocket error, got {other:?}"),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_acknowledged_subscription_clears_deadline() {
+        let url = mock_socket(vec![
+            r#"{"event_type":"book","asset_id":"123"}"#.to_string(),
+        ])
+        .await;
+        let mut client = ClobWsClient::builder()
+            .base_url(url)
+            .auto_reconnect(false)
+            .subscription_ack_timeout(Duration::from_millis(200))
+            .build();
+        client
+            .subscribe_market(vec!["123".to_string()])
+            .await
+            .unwrap();
+
+        assert!(client.try_next_message().await.unwrap().is_some());
+        // Quiet after the snapshot is normal and must not be reported.
+        let quiet =
+            tokio::time::timeout(Duration::from_millis(500), client.try_next_message()).await;
+        assert!(quiet.is_err());
+    }
+
     #[tokio::test]
     async fn test_initial_status() {
         let client = ClobWsClient::new();
@@ -0,0 +1,227 @@
+//! Single merged stream of market updates for several tokens.
+
+use std::collections::{HashSet, VecDeque};
+
+use futures_util::{Stream, stream};
+
+use super::client::ClobWsClient;
+use super::types::{
+    BookMessage, LastTradePriceMessage, PriceChange, TickSizeChangeMessage, WsMessage,
+};
+use crate::error::Result;
+
+/// A market channel update for one token.
+#[derive(Debug, Clone)]
+pub enum BookUpdate {
+    /// Full order book snapshot.
+    Snapshot(BookMessage),
+    /// A single price level change, with the timestamp of its message.
+    PriceChange {
+        change: PriceChange,
+        timestamp: String,
+    },
+    /// Tick size change.
+    TickSizeChange(TickSizeChangeMessage),
+    /// Last trade price.
+    LastTradePrice(LastTradePriceMessage),
+}
+
+impl BookUpdate {
+    /// Timestamp reported by the server.
+    pub fn timestamp(&self) -> &str {
+        match self {
+            BookUpdate::Snapshot(msg) => &msg.timestamp,
+            BookUpdate::PriceChange { timestamp, .. } => timestamp,
+            BookUpdate::TickSizeChange(msg) => &msg.timestamp,
+            BookUpdate::LastTradePrice(msg) => &msg.timestamp,
+        }
+    }
+}
+
+/// Stream market updates for `token_ids` over one connection, tagged with
+/// the token each update belongs to.
+///
+/// Uses a default [`ClobWsClient`]; see
+/// [`ClobWsClient::into_merged_market_stream`] to configure the connection.
+pub fn merged_market_stream(
+    token_ids: &[&str],
+) -> impl Stream<Item = Result<(String, BookUpdate)>> + Send + 'static {
+    ClobWsClient::new().into_merged_market_stream(token_ids)
+}
+
+enum State {
+    Connect(ClobWsClient, Vec<String>),
+    Open(
+        ClobWsClient,
+        HashSet<String>,
+        VecDeque<(String, BookUpdate)>,
+    ),
+    Done,
+}
+
+impl ClobWsClient {
+    /// Subscribe to `token_ids` on the market channel and stream their
+    /// updates as one sequence of `(token_id, update)` pairs.
+    ///
+    /// All tokens share this client's single connection, so updates arrive
+    /// in the server's timestamp order. Price change messages covering
+    /// several tokens are split into one update per token, and updates for
+    /// tokens that were not requested are dropped. A failed subscription or
+    /// missed acknowledgement is yielded as an error and ends the stream;
+    /// so does the connection closing without auto-reconnect.
+    pub fn into_merged_market_stream(
+        self,
+        token_ids: &[&str],
+    ) -> impl Stream<Item = Result<(String, BookUpdate)>> + Send + 'static {
+        let token_ids = token_ids.iter().map(|id| id.to_string()).collect();
+        stream::unfold(State::Connect(self, token_ids), |state| async move {
+            let (mut client, wanted, mut pending) = match state {
+                State::Connect(mut client, token_ids) => {
+                    if let Err(e) = client.subscribe_market(token_ids.clone()).await {
+                        return Some((Err(e), State::Done));
+                    }
+                    (client, token_ids.into_iter().collect(), VecDeque::new())
+                }
+                State::Open(client, wanted, pending) => (client, wanted, pending),
+                State::Done => return None,
+            };
+
+            loop {
+                if let Some(update) = pending.pop_front() {
+                    return Some((Ok(update), State::Open(client, wanted, pending)));
+                }
+                match client.try_next_message().await {
+                    Ok(Some(msg)) => pending.extend(
+                        split_updates(msg)
+                            .into_iter()
+                            .filter(|(token, _)| wanted.contains(token)),
+                    ),
+                    Ok(None) => return None,
+                    Err(e) => {
+                        client.disconnect().await;
+                        return Some((Err(e), State::Done));
+                    }
+                }
+            }
+        })
+    }
+}
+
+/// Breaks a market channel message into per-token updates.
+fn split_updates(msg: WsMessage) -> Vec<(String, BookUpdate)> {
+    match msg {
+        WsMessage::Book(book) => vec![(book.asset_id.clone(), BookUpdate::Snapshot(book))],
+        WsMessage::PriceChange(msg) => msg
+            .price_changes
+            .into_iter()
+            .map(|change| {
+                (
+                    change.asset_id.clone(),
+                    BookUpdate::PriceChange {
+                        change,
+                        timestamp: msg.timestamp.clone(),
+                    },
+                )
+            })
+            .collect(),
+        WsMessage::TickSizeChange(msg) => {
+            vec![(msg.asset_id.clone(), BookUpdate::TickSizeChange(msg))]
+        }
+        WsMessage::LastTradePrice(msg) => {
+            vec![(msg.asset_id.clone(), BookUpdate::LastTradePrice(msg))]
+        }
+        // The initial snapshots may arrive batched as a JSON array.
+        WsMessage::Unknown(serde_json::Value::Array(items)) => items
+            .into_iter()
+            .flat_map(|item| split_updates(WsMessage::from_json(item)))
+            .collect(),
+        _ => Vec::new(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testutil::mock_socket;
+    use futures_util::StreamExt;
+    use std::time::Duration;
+
+    fn book(asset_id: &str, timestamp: &str) -> serde_json::Value {
+        serde_json::json!({
+            "event_type": "book",
+            "asset_id": asset_id,
+            "market": "0xabc",
+            "bids": [{"price": "0.48", "size": "10"}],
+            "asks": [{"price": "0.52", "size": "10"}],
+            "timestamp": timestamp,
+            "hash": "h"
+        })
+    }
+
+    fn change(asset_id: &str) -> serde_json::Value {
+        serde_json::json!({
+            "asset_id": asset_id,
+            "price": "0.5",
+            "size": "5",
+            "side": "BUY",
+            "hash": "h",
+            "best_bid": "0.5",
+            "best_ask": "0.52"
+        })
+    }
+
+    #[tokio::test]
+    async fn updates_for_two_tokens_are_tagged() {
+        let replies = vec![
+            serde_json::Value::Array(vec![book("111", "1000"), book("222", "1000")]).to_string(),
+            serde_json::json!({
+                "event_type": "price_change",
+                "market": "0xabc",
+                "timestamp": "1001",
+                "price_changes": [change("222"), change("999"), change("111")]
+            })
+            .to_string(),
+        ];
+        let url = mock_socket(replies).await;
+        let client = ClobWsClient::builder()
+            .base_url(url)
+            .auto_reconnect(false)
+            .build();
+
+        let updates: Vec<(String, BookUpdate)> = tokio::time::timeout(
+            Duration::from_secs(5),
+            client
+                .into_merged_market_stream(&["111", "222"])
+                .take(4)
+                .map(|item| item.unwrap())
+                .collect(),
+        )
+        .await
+        .unwrap();
+
+        let tags: Vec<(&str, &str)> = updates
+            .iter()
+            .map(|(token, update)| {
+                let kind = match update {
+                    BookUpdate::Snapshot(_) => "book",
+                    BookUpdate::PriceChange { change, .. } => {
+                        assert_eq!(&change.asset_id, token);
+                        "price_change"
+                    }
+                    _ => "other",
+                };
+                (token.as_str(), kind)
+            })
+            .collect();
+        assert_eq!(
+            tags,
+            vec![
+                ("111", "book"),
+                ("222", "book"),
+                ("222", "price_change"),
+                ("111", "price_change"),
+            ]
+        );
+        assert_eq!(updates[3].1.timestamp(), "1001");
+    }
+}
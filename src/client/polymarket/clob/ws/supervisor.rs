@@ -0,0 +1,521 @@
+//! Supervisor that keeps a WebSocket-backed stream alive.
+//!
+//! A stream that ends, or a task that panics while polling it, otherwise
+//! stops data flow without anyone noticing. [`supervised_stream`] polls the
+//! stream on its own task and builds a fresh one with backoff whenever it
+//! terminates.
+//!
+//! Items wait for the consumer in a bounded buffer. What happens when a slow
+//! consumer lets it fill up is set by [`OverflowPolicy`].
+
+use std::collections::VecDeque;
+use std::pin::Pin;
+use std::sync::atomic::{AtomicBool, AtomicI64, AtomicU64, AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex};
+use std::task::{Context, Poll};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use futures_util::task::AtomicWaker;
+use futures_util::{Stream, StreamExt};
+use tokio::sync::Notify;
+use tokio::task::JoinHandle;
+use tracing::warn;
+
+/// Default delay before the first restart.
+pub const DEFAULT_RESTART_BACKOFF: Duration = Duration::from_secs(1);
+
+/// Default cap on the restart delay.
+pub const DEFAULT_MAX_RESTART_BACKOFF: Duration = Duration::from_secs(30);
+
+/// Default number of items buffered between the supervisor task and the
+/// consumer.
+pub const DEFAULT_BUFFER_CAPACITY: usize = 256;
+
+/// What to do with a new item when the consumer has fallen a full buffer
+/// behind.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum OverflowPolicy {
+    /// Wait for the consumer. Nothing is lost, but reading from the socket
+    /// stalls and the backlog moves to the server.
+    Block,
+    /// Discard the oldest buffered item, so the consumer always sees the
+    /// latest data. Suits HFT, where freshness beats completeness, but not
+    /// delta streams that need every item.
+    #[default]
+    DropOldest,
+    /// Discard the new item, keeping what is already buffered.
+    DropNewest,
+}
+
+/// Tuning for [`supervised_stream_with_config`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SupervisorConfig {
+    /// Delay before the first restart.
+    pub min_backoff: Duration,
+    /// Cap on the restart delay.
+    pub max_backoff: Duration,
+    /// Items buffered for the consumer (at least one).
+    pub buffer_capacity: usize,
+    /// Handling of items arriving while the buffer is full.
+    pub overflow: OverflowPolicy,
+}
+
+impl Default for SupervisorConfig {
+    fn default() -> Self {
+        Self {
+            min_backoff: DEFAULT_RESTART_BACKOFF,
+            max_backoff: DEFAULT_MAX_RESTART_BACKOFF,
+            buffer_capacity: DEFAULT_BUFFER_CAPACITY,
+            overflow: OverflowPolicy::default(),
+        }
+    }
+}
+
+/// Shared view of a supervised stream's state.
+#[derive(Debug, Clone, Default)]
+pub struct SupervisorHealth {
+    running: Arc<AtomicBool>,
+    last_message_ms: Arc<AtomicI64>,
+    restarts: Arc<AtomicU64>,
+    dropped: Arc<AtomicU64>,
+}
+
+impl SupervisorHealth {
+    /// Whether an underlying stream is currently being polled.
+    pub fn is_healthy(&self) -> bool {
+        self.running.load(Ordering::Acquire)
+    }
+
+    /// Unix timestamp in milliseconds of the last item, if any arrived.
+    pub fn last_message_at(&self) -> Option<i64> {
+        match self.last_message_ms.load(Ordering::Acquire) {
+            0 => None,
+            ms => Some(ms),
+        }
+    }
+
+    /// Number of times the underlying stream has been recreated.
+    pub fn restarts(&self) -> u64 {
+        self.restarts.load(Ordering::Acquire)
+    }
+
+    /// Number of items discarded because the buffer was full.
+    ///
+    /// Always zero with [`OverflowPolicy::Block`].
+    pub fn dropped(&self) -> u64 {
+        self.dropped.load(Ordering::Acquire)
+    }
+}
+
+/// State shared by the ends of a [`buffer`].
+struct Buffer<T> {
+    items: Mutex<VecDeque<T>>,
+    capacity: usize,
+    policy: OverflowPolicy,
+    dropped: Arc<AtomicU64>,
+    senders: AtomicUsize,
+    receiver_closed: AtomicBool,
+    /// Wakes the consumer when an item arrives or the last sender goes.
+    item_ready: AtomicWaker,
+    /// Wakes a blocked sender when space frees up or the consumer goes.
+    space_ready: Notify,
+}
+
+impl<T> Buffer<T> {
+    fn lock(&self) -> std::sync::MutexGuard<'_, VecDeque<T>> {
+        self.items.lock().unwrap_or_else(|e| e.into_inner())
+    }
+}
+
+/// Bounded single-consumer queue applying `policy` when full.
+fn buffer<T>(
+    capacity: usize,
+    policy: OverflowPolicy,
+    dropped: Arc<AtomicU64>,
+) -> (BufferSender<T>, BufferReceiver<T>) {
+    let shared = Arc::new(Buffer {
+        items: Mutex::new(VecDeque::new()),
+        capacity: capacity.max(1),
+        policy,
+        dropped,
+        senders: AtomicUsize::new(1),
+        receiver_closed: AtomicBool::new(false),
+        item_ready: AtomicWaker::new(),
+        space_ready: Notify::new(),
+    });
+    (BufferSender(shared.clone()), BufferReceiver(shared))
+}
+
+struct BufferSender<T>(Arc<Buffer<T>>);
+
+impl<T> BufferSender<T> {
+    /// Queue `item`, returning it back if the consumer is gone.
+    async fn send(&self, item: T) -> Result<(), T> {
+        let buffer = &self.0;
+        loop {
+            let space = buffer.space_ready.notified();
+            if self.is_closed() {
+                return Err(item);
+            }
+            if buffer.policy != OverflowPolicy::Block || buffer.lock().len() < buffer.capacity {
+                break;
+            }
+            space.await;
+        }
+        {
+            let mut items = buffer.lock();
+            // Only the consumer takes items out, so under `Block` the space
+            // found above is still there.
+            if items.len() >= buffer.capacity {
+                match buffer.policy {
+                    OverflowPolicy::Block => {}
+                    OverflowPolicy::DropOldest => {
+                        items.pop_front();
+                        buffer.dropped.fetch_add(1, Ordering::AcqRel);
+                    }
+                    OverflowPolicy::DropNewest => {
+                        buffer.dropped.fetch_add(1, Ordering::AcqRel);
+                        return Ok(());
+                    }
+                }
+            }
+            items.push_back(item);
+        }
+        buffer.item_ready.wake();
+        Ok(())
+    }
+
+    fn is_closed(&self) -> bool {
+        self.0.receiver_closed.load(Ordering::Acquire)
+    }
+
+    /// Resolves once the consumer is gone.
+    async fn closed(&self) {
+        loop {
+            let space = self.0.space_ready.notified();
+            if self.is_closed() {
+                return;
+            }
+            space.await;
+        }
+    }
+}
+
+impl<T> Clone for BufferSender<T> {
+    fn clone(&self) -> Self {
+        self.0.senders.fetch_add(1, Ordering::AcqRel);
+        Self(self.0.clone())
+    }
+}
+
+impl<T> Drop for BufferSender<T> {
+    fn drop(&mut self) {
+        if self.0.senders.fetch_sub(1, Ordering::AcqRel) == 1 {
+            self.0.item_ready.wake();
+        }
+    }
+}
+
+struct BufferReceiver<T>(Arc<Buffer<T>>);
+
+impl<T> BufferReceiver<T> {
+    fn poll_recv(&self, cx: &mut Context<'_>) -> Poll<Option<T>> {
+        let buffer = &self.0;
+        // Register before checking so an item queued in between wakes us.
+        buffer.item_ready.register(cx.waker());
+        if let Some(item) = buffer.lock().pop_front() {
+            buffer.space_ready.notify_waiters();
+            return Poll::Ready(Some(item));
+        }
+        if buffer.senders.load(Ordering::Acquire) == 0 {
+            return Poll::Ready(None);
+        }
+        Poll::Pending
+    }
+}
+
+impl<T> Drop for BufferReceiver<T> {
+    fn drop(&mut self) {
+        self.0.receiver_closed.store(true, Ordering::Release);
+        self.0.space_ready.notify_waiters();
+    }
+}
+
+/// Items from a stream that is recreated whenever it terminates.
+///
+/// Dropping it stops the supervisor and the current underlying stream.
+pub struct SupervisedStream<T> {
+    rx: BufferReceiver<T>,
+    health: SupervisorHealth,
+    task: JoinHandle<()>,
+}
+
+impl<T> SupervisedStream<T> {
+    /// Handle to the health flag and last-message timestamp.
+    pub fn health(&self) -> SupervisorHealth {
+        self.health.clone()
+    }
+}
+
+impl<T> Stream for SupervisedStream<T> {
+    type Item = T;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<T>> {
+        self.rx.poll_recv(cx)
+    }
+}
+
+impl<T> Drop for SupervisedStream<T> {
+    fn drop(&mut self) {
+        self.task.abort();
+    }
+}
+
+/// Poll the stream built by `factory`, recreating it whenever it ends or
+/// panics, with the default [`SupervisorConfig`].
+///
+/// See [`supervised_stream_with_config`].
+///
+/// # Warning
+///
+/// The default [`OverflowPolicy::DropOldest`] discards items when the
+/// consumer falls behind. Delta streams such as `book` and `price_change`
+/// updates cannot recover from a lost item, so supervise them with
+/// [`supervised_stream_with_config`] and [`OverflowPolicy::Block`].
+pub fn supervised_stream<F, S>(factory: F) -> SupervisedStream<S::Item>
+where
+    F: FnMut() -> S + Send + 'static,
+    S: Stream + Send + 'static,
+    S::Item: Send + 'static,
+{
+    supervised_stream_with_config(factory, SupervisorConfig::default())
+}
+
+/// Poll the stream built by `factory`, recreating it whenever it ends or
+/// panics, with the given restart backoff.
+///
+/// See [`supervised_stream_with_config`].
+pub fn supervised_stream_with_backoff<F, S>(
+    factory: F,
+    min_backoff: Duration,
+    max_backoff: Duration,
+) -> SupervisedStream<S::Item>
+where
+    F: FnMut() -> S + Send + 'static,
+    S: Stream + Send + 'static,
+    S::Item: Send + 'static,
+{
+    supervised_stream_with_config(
+        factory,
+        SupervisorConfig {
+            min_backoff,
+            max_backoff,
+            ..Default::default()
+        },
+    )
+}
+
+/// Poll the stream built by `factory`, recreating it whenever it ends or
+/// panics.
+///
+/// Restarts wait `min_backoff`, doubling up to `max_backoff` while streams
+/// keep terminating without yielding anything; a stream that delivered at
+/// least one item resets the delay. Errors carried as items (e.g.
+/// `Result::Err`) are passed through untouched, so wrap streams that end on
+/// error to have them restarted.
+///
+/// Up to `buffer_capacity` items wait for the consumer; beyond that the
+/// `overflow` policy applies and discarded items are counted in
+/// [`SupervisorHealth::dropped`].
+pub fn supervised_stream_with_config<F, S>(
+    mut factory: F,
+    config: SupervisorConfig,
+) -> SupervisedStream<S::Item>
+where
+    F: FnMut() -> S + Send + 'static,
+    S: Stream + Send + 'static,
+    S::Item: Send + 'static,
+{
+    let SupervisorConfig {
+        min_backoff,
+        max_backoff,
+        buffer_capacity,
+        overflow,
+    } = config;
+    let health = SupervisorHealth::default();
+    let (tx, rx) = buffer(buffer_capacity, overflow, health.dropped.clone());
+    let state = health.clone();
+
+    let task = tokio::spawn(async move {
+        let mut backoff = min_backoff;
+        loop {
+            let stream = factory();
+            let sender = tx.clone();
+            let last_message_ms = state.last_message_ms.clone();
+            state.running.store(true, Ordering::Release);
+
+            // Poll on a separate task so a panic ends the stream, not the
+            // supervisor. It also stops once the consumer is dropped.
+            let forward = tokio::spawn(async move {
+                let mut stream = std::pin::pin!(stream);
+                let mut delivered = false;
+                loop {
+                    let item = tokio::select! {
+                        item = stream.next() => item,
+                        _ = sender.closed() => break,
+                    };
+                    let Some(item) = item else { break };
+                    last_message_ms.store(now_millis(), Ordering::Release);
+                    delivered = true;
+                    if sender.send(item).await.is_err() {
+                        break;
+                    }
+                }
+                delivered
+            });
+            let delivered = match forward.await {
+                Ok(delivered) => delivered,
+                Err(e) => {
+                    warn!("Supervised stream task failed: {e}");
+                    false
+                }
+            };
+            state.running.store(false, Ordering::Release);
+
+            if tx.is_closed() {
+                return;
+            }
+            if delivered {
+                backoff = min_backoff;
+            }
+            warn!("Supervised stream terminated, restarting in {:?}", backoff);
+            tokio::time::sleep(backoff).await;
+            backoff = (backoff * 2).min(max_backoff);
+            state.restarts.fetch_add(1, Ordering::AcqRel);
+        }
+    });
+
+    SupervisedStream { rx, health, task }
+}
+
+fn now_millis() -> i64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_millis() as i64)
+        .unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use futures_util::stream;
+    use std::sync::atomic::AtomicUsize;
+
+    #[tokio::test]
+    async fn ended_stream_is_recreated() {
+        let created = Arc::new(AtomicUsize::new(0));
+        let counter = created.clone();
+        let supervised = supervised_stream_with_backoff(
+            move || {
+                let generation = counter.fetch_add(1, Ordering::SeqCst);
+                stream::iter([generation * 10, generation * 10 + 1])
+            },
+            Duration::from_millis(1),
+            Duration::from_millis(5),
+        );
+        let health = supervised.health();
+
+        let items: Vec<usize> =
+            tokio::time::timeout(Duration::from_secs(5), supervised.take(6).collect())
+                .await
+                .unwrap();
+
+        assert_eq!(items, vec![0, 1, 10, 11, 20, 21]);
+        assert!(created.load(Ordering::SeqCst) >= 3);
+        assert!(health.restarts() >= 2);
+        assert!(health.last_message_at().is_some());
+    }
+
+    #[tokio::test]
+    async fn panicking_stream_is_recreated() {
+        let created = Arc::new(AtomicUsize::new(0));
+        let counter = created.clone();
+        let supervised = supervised_stream_with_backoff(
+            move || {
+                let first = counter.fetch_add(1, Ordering::SeqCst) == 0;
+                stream::iter([1]).map(move |item| {
+                    assert!(!first, "first stream dies");
+                    item
+                })
+            },
+            Duration::from_millis(1),
+            Duration::from_millis(5),
+        );
+
+        let first = tokio::time::timeout(Duration::from_secs(5), supervised.take(1).next())
+            .await
+            .unwrap();
+        assert_eq!(first, Some(1));
+        assert!(created.load(Ordering::SeqCst) >= 2);
+    }
+
+    /// Supervise `0..count` followed by silence, so the stream never restarts.
+    fn burst(count: usize, overflow: OverflowPolicy) -> SupervisedStream<usize> {
+        supervised_stream_with_config(
+            move || stream::iter(0..count).chain(stream::pending()),
+            SupervisorConfig {
+                buffer_capacity: 4,
+                overflow,
+                ..Default::default()
+            },
+        )
+    }
+
+    /// Wait until the supervisor has stopped adding to `health.dropped()`.
+    async fn settle(health: &SupervisorHealth, expected: u64) {
+        tokio::time::timeout(Duration::from_secs(5), async {
+            while health.dropped() < expected {
+                tokio::time::sleep(Duration::from_millis(1)).await;
+            }
+        })
+        .await
+        .unwrap();
+    }
+
+    #[tokio::test]
+    async fn drop_oldest_keeps_latest_items_for_slow_consumer() {
+        let supervised = burst(100, OverflowPolicy::DropOldest);
+        let health = supervised.health();
+
+        // Nothing is read until the whole burst has been pushed.
+        settle(&health, 96).await;
+        let items: Vec<usize> =
+            tokio::time::timeout(Duration::from_secs(5), supervised.take(4).collect())
+                .await
+                .unwrap();
+
+        assert_eq!(items, vec![96, 97, 98, 99]);
+        assert_eq!(health.dropped(), 96);
+    }
+
+    #[tokio::test]
+    async fn drop_newest_and_block_policies() {
+        let supervised = burst(10, OverflowPolicy::DropNewest);
+        let health = supervised.health();
+        settle(&health, 6).await;
+        let items: Vec<usize> =
+            tokio::time::timeout(Duration::from_secs(5), supervised.take(4).collect())
+                .await
+                .unwrap();
+        assert_eq!(items, vec![0, 1, 2, 3]);
+
+        let supervised = burst(10, OverflowPolicy::Block);
+        let health = supervised.health();
+        let items: Vec<usize> =
+            tokio::time::timeout(Duration::from_secs(5), supervised.take(10).collect())
+                .await
+                .unwrap();
+        assert_eq!(items, (0..10).collect::<Vec<_>>());
+        assert_eq!(health.dropped(), 0);
+    }
+}
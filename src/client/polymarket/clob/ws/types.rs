@@ -146,8 +146,10 @@ pub struct BookMessage {
     /// Market ID (condition ID).
     pub market: String,
     /// Bid price levels.
+    #[serde(deserialize_with = "crate::json::deserialize_null_as_empty_vec")]
     pub bids: Vec<WsPriceLevel>,
     /// Ask price levels.
+    #[serde(deserialize_with = "crate::json::deserialize_null_as_empty_vec")]
     pub asks: Vec<WsPriceLevel>,
     /// Timestamp.
     pub timestamp: String,
@@ -182,6 +184,7 @@ pub struct PriceChangeMessage {
     /// Market ID (condition ID).
     pub market: String,
     /// List of price changes.
+    #[serde(deserialize_with = "crate::json::deserialize_null_as_empty_vec")]
     pub price_changes: Vec<PriceChange>,
     /// Timestamp.
     pub timestamp: String,
@@ -284,6 +287,7 @@ pub struct TradeMessage {
     /// Trade price.
     pub price: String,
     /// Maker orders involved.
+    #[serde(deserialize_with = "crate::json::deserialize_null_as_empty_vec")]
     pub maker_orders: Vec<MakerOrder>,
     /// Taker order ID.
     pub taker_order_id: String,
@@ -24,21 +24,33 @@ mod client;
 mod holders;
 mod market;
 mod positions;
+mod profile;
 mod trades;
 mod validation;
 
-pub use activity::{Activity, ActivitySortBy, ActivityType, GetUserActivityRequest};
-pub use client::{Client, DEFAULT_BASE_URL};
-pub use holders::{Holder, MarketTopHolders};
-pub use market::{EventLiveVolume, MarketLiveVolume, MarketOpenInterest};
+pub use activity::{Activity, ActivitySortBy, ActivityType, GetUserActivityRequest, total_rewards};
+pub use client::{Client, DEFAULT_BASE_URL, DEFAULT_PROFILE_BASE_URL};
+pub use holders::{AddressBook, AnnotatedHolder, Holder, MarketTopHolders, annotate_holders};
+pub use market::{EventLiveVolume, MarketLiveVolume, MarketOpenInterest, TokenInfo};
 pub use positions::{
     ClosedPosition, ClosedPositionSortBy, GetUserClosedPositionsRequest, GetUserPositionsRequest,
-    Position, PositionSortBy, UserPositionValue,
+    Position, PositionSortBy, UserPositionValue, stable_sort_positions,
+};
+pub use profile::PublicProfile;
+pub use trades::{
+    GetTradesRequest, MAX_TRADES_OFFSET, Trade, TradeFilterType, TradesSince,
+    UserTradedMarketsCount,
 };
-pub use trades::{GetTradesRequest, Trade, TradeFilterType, UserTradedMarketsCount};
 
 use serde::{Deserialize, Serialize};
 
+crate::client::impl_request_debug!(
+    GetTradesRequest<'_>,
+    GetUserActivityRequest<'_>,
+    GetUserClosedPositionsRequest<'_>,
+    GetUserPositionsRequest<'_>,
+);
+
 // Re-export validation functions for internal use
 pub(crate) use validation::{
     validate_event_id, validate_limit, validate_market_id, validate_min_balance, validate_user,
@@ -113,6 +125,7 @@ pub struct HealthStatus {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::client::RequestDebug;
 
     #[test]
     fn test_sort_direction_display() {
@@ -155,4 +168,26 @@ mod tests {
         assert!(matches!("sell".parse::<TradeSide>(), Ok(TradeSide::Sell)));
         assert!("invalid".parse::<TradeSide>().is_err());
     }
+
+    #[test]
+    fn test_debug_url_joins_markets_with_commas() {
+        let markets = [
+            "0xdd22472e552920b8438158ea7238bfadfa4f736aa4cee91a6b86c39ead110917",
+            "0x2c95a7a4bc2b5a6e4b8b2f1d6c2e0f1a3b4c5d6e7f8091a2b3c4d5e6f7081920",
+        ];
+        let request = GetTradesRequest {
+            limit: Some(10),
+            markets: Some(&markets),
+            ..Default::default()
+        };
+        let base = url::Url::parse(DEFAULT_BASE_URL).unwrap();
+
+        assert_eq!(
+            request.debug_url(&base),
+            format!(
+                "{}/trades?limit=10&takerOnly=true&market={}%2C{}",
+                DEFAULT_BASE_URL, markets[0], markets[1]
+            )
+        );
+    }
 }
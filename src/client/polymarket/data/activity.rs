@@ -10,7 +10,10 @@ use super::{
     Client, SortDirection, TradeSide, validate_event_id, validate_limit, validate_market_id,
     validate_user,
 };
+use crate::client::ValidatableRequest;
+use crate::client::http::deserialize_checked;
 use crate::error::{PolymarketError, Result};
+use crate::{DataSource, Metric, MetricUnit, ToMetrics};
 
 // ============================================================================
 // Types
@@ -36,7 +39,7 @@ where
 }
 
 /// Activity type enum for user activity.
-#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 pub enum ActivityType {
     /// Trade activity.
     #[serde(rename = "TRADE")]
@@ -58,6 +61,18 @@ pub enum ActivityType {
     Conversion,
 }
 
+impl ActivityType {
+    /// Every activity type, in API documentation order.
+    pub const ALL: [ActivityType; 6] = [
+        ActivityType::Trade,
+        ActivityType::Split,
+        ActivityType::Merge,
+        ActivityType::Redeem,
+        ActivityType::Reward,
+        ActivityType::Conversion,
+    ];
+}
+
 impl std::fmt::Display for ActivityType {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
@@ -186,6 +201,84 @@ pub struct Activity {
     /// Optimized profile image URL.
     #[serde(rename = "profileImageOptimized")]
     pub profile_image_optimized: String,
+    /// Reward paid in USDC, for REWARD records.
+    ///
+    /// Taken from `rewardAmount` when present, otherwise from `usdcSize`.
+    #[serde(default, rename = "rewardAmount")]
+    pub reward_amount: Option<f64>,
+    /// Liquidity rewards epoch the payout belongs to, for REWARD records.
+    #[serde(default, rename = "rewardEpoch")]
+    pub reward_epoch: Option<i64>,
+}
+
+impl Activity {
+    /// Fills or clears the reward fields depending on the activity type.
+    fn normalize_reward_fields(&mut self) {
+        if matches!(self.activity_type, ActivityType::Reward) {
+            self.reward_amount.get_or_insert(self.usdc_size);
+        } else {
+            self.reward_amount = None;
+            self.reward_epoch = None;
+        }
+    }
+}
+
+/// Total USDC paid out by the REWARD records in `activities`.
+pub fn total_rewards(activities: &[Activity]) -> f64 {
+    activities
+        .iter()
+        .filter(|a| matches!(a.activity_type, ActivityType::Reward))
+        .map(|a| a.reward_amount.unwrap_or(a.usdc_size))
+        .sum()
+}
+
+impl ToMetrics for [Activity] {
+    /// Converts a user's activity records to metrics.
+    ///
+    /// Returns:
+    /// - `user_activity_count`: Number of records, one metric per activity type
+    ///   (labelled `activity_type`, zero when absent)
+    /// - `user_activity_cash_volume`: Total USDC size across all records
+    ///
+    /// Metrics carry a `user` label with the proxy wallet of the first record.
+    fn to_metrics(&self) -> Vec<Metric> {
+        let user = self.first().map(|a| a.proxy_wallet.as_str());
+        let labelled = |metric: Metric| {
+            let metric = metric.with_label("endpoint", "get_user_activity");
+            match user {
+                Some(user) => metric.with_label("user", user),
+                None => metric,
+            }
+        };
+
+        let mut metrics: Vec<Metric> = ActivityType::ALL
+            .iter()
+            .map(|activity_type| {
+                let count = self
+                    .iter()
+                    .filter(|a| a.activity_type == *activity_type)
+                    .count();
+                labelled(
+                    Metric::new(
+                        DataSource::Polymarket,
+                        "user_activity_count",
+                        count as f64,
+                        MetricUnit::Count,
+                    )
+                    .with_label("activity_type", activity_type.to_string()),
+                )
+            })
+            .collect();
+
+        metrics.push(labelled(Metric::new(
+            DataSource::Polymarket,
+            "user_activity_cash_volume",
+            self.iter().map(|a| a.usdc_size).sum(),
+            MetricUnit::USD,
+        )));
+
+        metrics
+    }
 }
 
 /// Request parameters for [`Client::get_user_activity`].
@@ -234,9 +327,8 @@ pub struct GetUserActivityRequest<'a> {
     pub side: Option<TradeSide>,
 }
 
-impl GetUserActivityRequest<'_> {
-    /// Validates the request parameters.
-    pub fn validate(&self) -> Result<()> {
+impl ValidatableRequest for GetUserActivityRequest<'_> {
+    fn validate(&self) -> Result<()> {
         validate_user(self.user)?;
         validate_limit(self.limit)?;
 
@@ -293,6 +385,13 @@ impl GetUserActivityRequest<'_> {
 
         Ok(())
     }
+}
+
+impl GetUserActivityRequest<'_> {
+    /// Validates the request parameters.
+    pub fn validate(&self) -> Result<()> {
+        ValidatableRequest::validate(self)
+    }
 
     /// Builds the URL with query parameters for this request.
     pub fn build_url(&self, base_url: &Url) -> Url {
@@ -427,10 +526,52 @@ impl Client {
         trace!(url = %url, method = "GET", "sending HTTP request");
         let response = self.http_client.get(url).send().await?;
         let response = self.check_response(response).await?;
-        let activity: Vec<Activity> = response.json().await?;
+        let mut activity: Vec<Activity> = deserialize_checked(response).await?;
+        activity
+            .iter_mut()
+            .for_each(Activity::normalize_reward_fields);
         trace!(count = activity.len(), "received activity records");
         Ok(activity)
     }
+
+    /// Gets the most recent activity across all users, newest first.
+    ///
+    /// Same endpoint as [`Client::get_user_activity`] without a `user`
+    /// filter, useful for spotting which markets are busy right now.
+    ///
+    /// # Arguments
+    ///
+    /// * `limit` - Maximum number of records (0-500, default: 100).
+    /// * `activity_types` - Only return these activity types.
+    #[instrument(skip(self), level = "trace")]
+    pub async fn get_recent_activity(
+        &self,
+        limit: Option<i32>,
+        activity_types: Option<&[ActivityType]>,
+    ) -> Result<Vec<Activity>> {
+        validate_limit(limit)?;
+        let mut url = self.build_url("activity");
+        if let Some(l) = limit {
+            url.query_pairs_mut().append_pair("limit", &l.to_string());
+        }
+        if let Some(types) = activity_types.filter(|t| !t.is_empty()) {
+            let type_value = types
+                .iter()
+                .map(|t| t.to_string())
+                .collect::<Vec<_>>()
+                .join(",");
+            url.query_pairs_mut().append_pair("type", &type_value);
+        }
+        trace!(url = %url, method = "GET", "sending HTTP request");
+        let response = self.http_client.get(url).send().await?;
+        let response = self.check_response(response).await?;
+        let mut activity: Vec<Activity> = deserialize_checked(response).await?;
+        activity
+            .iter_mut()
+            .for_each(Activity::normalize_reward_fields);
+        trace!(count = activity.len(), "received recent activity records");
+        Ok(activity)
+    }
 }
 
 // ============================================================================
@@ -440,6 +581,7 @@ impl Client {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::testutil::{SAMPLE_USER, mock_json_server, sample_activity_json};
 
     const VALID_USER: &str = "0x0123456789012345678901234567890123456789";
     const VALID_MARKET: &str = "0xdd22472e552920b8438158ea7238bfadfa4f736aa4cee91a6b86c39ead110917";
@@ -546,6 +688,151 @@ mod tests {
         }
     }
 
+    fn activity_json(activity_type: &str, usdc_size: f64) -> serde_json::Value {
+        let mut json = sample_activity_json();
+        json["type"] = serde_json::json!(activity_type);
+        json["usdcSize"] = serde_json::json!(usdc_size);
+        json
+    }
+
+    #[tokio::test]
+    async fn get_user_activity_fills_reward_amount() {
+        let mut reward = activity_json("REWARD", 4.0);
+        reward["rewardEpoch"] = serde_json::json!(7);
+        let server = mock_json_server([(
+            "/activity",
+            serde_json::json!([reward, activity_json("TRADE", 9.0)]),
+        )])
+        .await;
+
+        let client = Client::with_base_url(&server.uri()).unwrap();
+        let activity = client
+            .get_user_activity(GetUserActivityRequest {
+                user: SAMPLE_USER,
+                ..Default::default()
+            })
+            .await
+            .unwrap();
+
+        assert_eq!(activity.len(), 2);
+        assert_eq!(activity[0].reward_amount, Some(4.0));
+        assert_eq!(activity[0].reward_epoch, Some(7));
+        assert_eq!(activity[1].reward_amount, None);
+        assert_eq!(total_rewards(&activity), 4.0);
+    }
+
+    #[tokio::test]
+    async fn get_recent_activity_parses_global_feed() {
+        use wiremock::matchers::{method, path, query_param, query_param_is_missing};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let mut split = activity_json("SPLIT", 20.0);
+        split["proxyWallet"] = serde_json::json!(VALID_USER);
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/activity"))
+            .and(query_param_is_missing("user"))
+            .and(query_param("limit", "3"))
+            .and(query_param("type", "TRADE,SPLIT"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!([
+                activity_json("TRADE", 9.0),
+                split,
+                activity_json("TRADE", 1.5),
+            ])))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let client = Client::with_base_url(&server.uri()).unwrap();
+        let activity = client
+            .get_recent_activity(Some(3), Some(&[ActivityType::Trade, ActivityType::Split]))
+            .await
+            .unwrap();
+
+        assert_eq!(activity.len(), 3);
+        assert_eq!(activity[1].activity_type, ActivityType::Split);
+        assert_eq!(activity[1].proxy_wallet, VALID_USER);
+        assert_eq!(activity[2].usdc_size, 1.5);
+
+        let err = client
+            .get_recent_activity(Some(501), None)
+            .await
+            .unwrap_err();
+        assert!(matches!(err, PolymarketError::BadRequest(_)), "got {err:?}");
+    }
+
+    #[test]
+    fn reward_activity_deserializes_typed_fields() {
+        let mut json = activity_json("REWARD", 12.5);
+        json["rewardEpoch"] = serde_json::json!(42);
+        let mut reward: Activity = serde_json::from_value(json).unwrap();
+        reward.normalize_reward_fields();
+        assert!(matches!(reward.activity_type, ActivityType::Reward));
+        assert_eq!(reward.reward_amount, Some(12.5));
+        assert_eq!(reward.reward_epoch, Some(42));
+
+        let mut json = activity_json("TRADE", 3.0);
+        json["rewardAmount"] = serde_json::json!(1.0);
+        let mut trade: Activity = serde_json::from_value(json).unwrap();
+        trade.normalize_reward_fields();
+        assert_eq!(trade.reward_amount, None);
+    }
+
+    #[test]
+    fn total_rewards_sums_only_reward_records() {
+        let mut explicit = activity_json("REWARD", 0.0);
+        explicit["rewardAmount"] = serde_json::json!(2.25);
+        let activities: Vec<Activity> = serde_json::from_value(serde_json::json!([
+            activity_json("REWARD", 1.5),
+            explicit,
+            activity_json("TRADE", 100.0),
+        ]))
+        .unwrap();
+
+        assert_eq!(total_rewards(&activities), 3.75);
+        assert_eq!(total_rewards(&[]), 0.0);
+    }
+
+    #[test]
+    fn activity_to_metrics_counts_types_and_volume() {
+        let activities: Vec<Activity> = serde_json::from_value(serde_json::json!([
+            activity_json("TRADE", 10.0),
+            activity_json("TRADE", 5.5),
+            activity_json("REDEEM", 20.0),
+            activity_json("REWARD", 1.0),
+        ]))
+        .unwrap();
+
+        let metrics = activities.to_metrics();
+        assert_eq!(metrics.len(), ActivityType::ALL.len() + 1);
+        for metric in &metrics {
+            assert_eq!(metric.source, DataSource::Polymarket);
+            assert_eq!(metric.labels["user"], activities[0].proxy_wallet);
+        }
+
+        let count = |activity_type: &str| {
+            metrics
+                .iter()
+                .find(|m| {
+                    m.name == "user_activity_count"
+                        && m.labels.get("activity_type").map(String::as_str) == Some(activity_type)
+                })
+                .map(|m| m.value)
+                .unwrap()
+        };
+        assert_eq!(count("TRADE"), 2.0);
+        assert_eq!(count("REDEEM"), 1.0);
+        assert_eq!(count("REWARD"), 1.0);
+        assert_eq!(count("SPLIT"), 0.0);
+
+        let volume = metrics
+            .iter()
+            .find(|m| m.name == "user_activity_cash_volume")
+            .unwrap();
+        assert_eq!(volume.value, 36.5);
+        assert_eq!(volume.unit, MetricUnit::USD);
+    }
+
     #[test]
     fn test_activity_type_display() {
         assert_eq!(ActivityType::Trade.to_string(), "TRADE");
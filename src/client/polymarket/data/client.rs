@@ -5,7 +5,11 @@ use reqwest_middleware::ClientWithMiddleware;
 use tracing::{instrument, trace};
 use url::Url;
 
-use crate::client::http::{DEFAULT_MAX_RETRIES, HttpClientConfig, wrap_with_retry};
+#[cfg(feature = "metrics")]
+use crate::client::http::{ClientStats, ClientStatsSnapshot};
+use crate::client::http::{
+    DEFAULT_MAX_RETRIES, HttpClientConfig, deserialize_checked, wrap_with_retry,
+};
 use crate::error::{PolymarketError, Result};
 
 use super::HealthStatus;
@@ -13,6 +17,10 @@ use super::HealthStatus;
 /// Default base URL for the Polymarket Data API.
 pub const DEFAULT_BASE_URL: &str = "https://data-api.polymarket.com";
 
+/// Default base URL for public profile and market metadata lookups (served by
+/// the Gamma API).
+pub const DEFAULT_PROFILE_BASE_URL: &str = "https://gamma-api.polymarket.com";
+
 /// Maximum error message length to prevent sensitive data leakage.
 const MAX_ERROR_MESSAGE_LEN: usize = 500;
 
@@ -23,6 +31,12 @@ pub struct Client {
     pub(super) http_client: ClientWithMiddleware,
     /// Base URL for the API (validated URL).
     pub(super) base_url: Url,
+    /// Base URL for public profile and market metadata lookups (see
+    /// [`Client::resolve_proxy_wallet`] and [`Client::get_tokens_for_condition`]).
+    pub(super) profile_base_url: Url,
+    /// Request counters (`metrics` feature).
+    #[cfg(feature = "metrics")]
+    pub(super) stats: ClientStats,
 }
 
 impl Client {
@@ -59,12 +73,18 @@ impl Client {
     /// ```
     pub fn with_base_url(base_url: &str) -> Result<Self> {
         let url = Url::parse(base_url)?;
-        let http_client = HttpClientConfig::default()
+        let config = HttpClientConfig::default();
+        #[cfg(feature = "metrics")]
+        let stats = config.stats.clone();
+        let http_client = config
             .build()
             .map_err(|e| PolymarketError::other(format!("failed to create HTTP client: {}", e)))?;
         Ok(Self {
             http_client,
             base_url: url,
+            profile_base_url: default_profile_base_url(),
+            #[cfg(feature = "metrics")]
+            stats,
         })
     }
 
@@ -80,13 +100,18 @@ impl Client {
     /// Returns `Ok(Client)` if the URL is valid, or an error if parsing fails.
     pub fn with_retries(base_url: &str, max_retries: u32) -> Result<Self> {
         let url = Url::parse(base_url)?;
-        let http_client = HttpClientConfig::default()
-            .with_max_retries(max_retries)
+        let config = HttpClientConfig::default().with_max_retries(max_retries);
+        #[cfg(feature = "metrics")]
+        let stats = config.stats.clone();
+        let http_client = config
             .build()
             .map_err(|e| PolymarketError::other(format!("failed to create HTTP client: {}", e)))?;
         Ok(Self {
             http_client,
             base_url: url,
+            profile_base_url: default_profile_base_url(),
+            #[cfg(feature = "metrics")]
+            stats,
         })
     }
 
@@ -115,6 +140,9 @@ impl Client {
         Self {
             http_client: wrap_with_retry(http_client, DEFAULT_MAX_RETRIES),
             base_url: Url::parse(DEFAULT_BASE_URL).expect("default base URL is valid"),
+            profile_base_url: default_profile_base_url(),
+            #[cfg(feature = "metrics")]
+            stats: ClientStats::default(),
         }
     }
 
@@ -127,9 +155,25 @@ impl Client {
         Self {
             http_client,
             base_url: Url::parse(DEFAULT_BASE_URL).expect("default base URL is valid"),
+            profile_base_url: default_profile_base_url(),
+            #[cfg(feature = "metrics")]
+            stats: ClientStats::default(),
         }
     }
 
+    /// Uses a custom base URL for public profile and market metadata lookups.
+    pub fn with_profile_base_url(mut self, base_url: &str) -> Result<Self> {
+        self.profile_base_url = Url::parse(base_url)?;
+        Ok(self)
+    }
+
+    /// Request, retry and latency counters (`metrics` feature); see
+    /// [`ClientStats`] for which clients record them.
+    #[cfg(feature = "metrics")]
+    pub fn stats(&self) -> ClientStatsSnapshot {
+        self.stats.snapshot()
+    }
+
     /// Checks if the response is successful and returns an appropriate error if not.
     ///
     /// This helper method centralizes error handling and sanitizes error messages
@@ -173,19 +217,12 @@ impl Client {
     /// This avoids dropping path components when users provide a base URL like
     /// `https://example.com/api/v1`, where we still need `/api/v1/<path>`.
     pub(super) fn build_url(&self, path: &str) -> Url {
-        let mut url = self.base_url.clone();
-
-        let base_path = url.path().trim_end_matches('/');
-        let suffix = path.trim_start_matches('/');
-
-        let merged = if base_path.is_empty() {
-            format!("/{}", suffix)
-        } else {
-            format!("{}/{}", base_path, suffix)
-        };
+        join_path(&self.base_url, path)
+    }
 
-        url.set_path(&merged);
-        url
+    /// Like [`build_url`](Self::build_url), relative to the profile base URL.
+    pub(super) fn build_profile_url(&self, path: &str) -> Url {
+        join_path(&self.profile_base_url, path)
     }
 
     /// Performs a health check on the Data API.
@@ -216,12 +253,33 @@ impl Client {
         trace!(url = %url, method = "GET", "sending HTTP request");
         let response = self.http_client.get(url).send().await?;
         let response = self.check_response(response).await?;
-        let health_response: HealthStatus = response.json().await?;
+        let health_response: HealthStatus = deserialize_checked(response).await?;
         trace!(data = %health_response.data, "health check completed");
         Ok(health_response)
     }
 }
 
+/// Appends `path` to `base`, keeping any path prefix of `base`.
+fn join_path(base: &Url, path: &str) -> Url {
+    let mut url = base.clone();
+
+    let base_path = url.path().trim_end_matches('/');
+    let suffix = path.trim_start_matches('/');
+
+    let merged = if base_path.is_empty() {
+        format!("/{}", suffix)
+    } else {
+        format!("{}/{}", base_path, suffix)
+    };
+
+    url.set_path(&merged);
+    url
+}
+
+fn default_profile_base_url() -> Url {
+    Url::parse(DEFAULT_PROFILE_BASE_URL).expect("default profile base URL is valid")
+}
+
 impl Default for Client {
     fn default() -> Self {
         Self::new()
@@ -2,11 +2,14 @@
 //!
 //! This module provides types and methods for querying market top holders.
 
+use std::collections::HashMap;
+
 use serde::{Deserialize, Serialize};
 use tokio::time::{Duration, sleep};
 use tracing::{instrument, trace};
 
 use super::{Client, validate_limit, validate_market_id, validate_min_balance};
+use crate::client::http::deserialize_checked;
 use crate::error::Result;
 
 // ============================================================================
@@ -51,9 +54,92 @@ pub struct MarketTopHolders {
     /// Token identifier.
     pub token: String,
     /// List of holders for this token.
+    #[serde(deserialize_with = "crate::json::deserialize_null_as_empty_vec")]
     pub holders: Vec<Holder>,
 }
 
+// ============================================================================
+// Address Labeling
+// ============================================================================
+
+/// Known-entity labels keyed by wallet address.
+///
+/// Addresses are matched case-insensitively, so checksummed and lowercase
+/// forms resolve to the same label.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct AddressBook {
+    labels: HashMap<String, String>,
+}
+
+impl AddressBook {
+    /// Creates an empty address book.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds or replaces the label for an address.
+    pub fn insert(&mut self, address: impl AsRef<str>, label: impl Into<String>) {
+        self.labels
+            .insert(address.as_ref().to_ascii_lowercase(), label.into());
+    }
+
+    /// Returns the label for an address, if known.
+    pub fn label(&self, address: &str) -> Option<&str> {
+        self.labels
+            .get(&address.to_ascii_lowercase())
+            .map(String::as_str)
+    }
+
+    /// Returns the number of labeled addresses.
+    pub fn len(&self) -> usize {
+        self.labels.len()
+    }
+
+    /// Returns true if no addresses are labeled.
+    pub fn is_empty(&self) -> bool {
+        self.labels.is_empty()
+    }
+}
+
+impl<K: AsRef<str>, V: Into<String>> FromIterator<(K, V)> for AddressBook {
+    fn from_iter<I: IntoIterator<Item = (K, V)>>(iter: I) -> Self {
+        let mut book = Self::new();
+        for (address, label) in iter {
+            book.insert(address, label);
+        }
+        book
+    }
+}
+
+impl From<HashMap<String, String>> for AddressBook {
+    fn from(map: HashMap<String, String>) -> Self {
+        map.into_iter().collect()
+    }
+}
+
+/// A holder with an optional known-entity label.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AnnotatedHolder {
+    /// The original holder record.
+    #[serde(flatten)]
+    pub holder: Holder,
+    /// Label from the address book, if the proxy wallet is known.
+    pub label: Option<String>,
+}
+
+/// Attaches address-book labels to holders by proxy wallet.
+///
+/// Holders without a known address keep `label: None`. Order is preserved.
+pub fn annotate_holders(holders: &[Holder], book: &AddressBook) -> Vec<AnnotatedHolder> {
+    holders
+        .iter()
+        .map(|holder| AnnotatedHolder {
+            label: book.label(&holder.proxy_wallet).map(str::to_string),
+            holder: holder.clone(),
+        })
+        .collect()
+}
+
 // ============================================================================
 // Client Implementation
 // ============================================================================
@@ -140,7 +226,8 @@ impl Client {
                     trace!(status = %status, attempt = attempt, "received HTTP response");
 
                     if status.is_success() {
-                        let holders_response: Vec<MarketTopHolders> = resp.json().await?;
+                        let holders_response: Vec<MarketTopHolders> =
+                            deserialize_checked(resp).await?;
                         trace!(count = holders_response.len(), "received holders data");
                         return Ok(holders_response);
                     }
@@ -195,4 +282,63 @@ mod tests {
         assert!(!is_retryable_status(499)); // Client error (outside retryable range)
         assert!(!is_retryable_status(505)); // HTTP Version Not Supported (outside 500-504)
     }
+
+    #[test]
+    fn test_null_holders_are_empty_but_missing_holders_are_rejected() {
+        let top: MarketTopHolders =
+            serde_json::from_value(serde_json::json!({"token": "123", "holders": null})).unwrap();
+        assert!(top.holders.is_empty());
+
+        let missing =
+            serde_json::from_value::<MarketTopHolders>(serde_json::json!({"token": "123"}));
+        assert!(missing.is_err());
+    }
+
+    fn holder(proxy_wallet: &str, amount: f64) -> Holder {
+        Holder {
+            proxy_wallet: proxy_wallet.to_string(),
+            bio: String::new(),
+            asset: "123".to_string(),
+            pseudonym: String::new(),
+            amount,
+            display_username_public: false,
+            outcome_index: 0,
+            name: String::new(),
+            profile_image: String::new(),
+            profile_image_optimized: String::new(),
+        }
+    }
+
+    #[test]
+    fn test_annotate_holders_labels_known_addresses() {
+        let book: AddressBook = [
+            ("0xAAAAaaaaAAAAaaaaAAAAaaaaAAAAaaaaAAAAaaaa", "Market Maker"),
+            ("0xcccccccccccccccccccccccccccccccccccccccc", "Treasury"),
+        ]
+        .into_iter()
+        .collect();
+        let holders = vec![
+            holder("0xaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa", 100.0),
+            holder("0xbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbb", 50.0),
+            holder("0xCCCCCCCCCCCCCCCCCCCCCCCCCCCCCCCCCCCCCCCC", 25.0),
+        ];
+
+        let annotated = annotate_holders(&holders, &book);
+
+        assert_eq!(annotated.len(), 3);
+        assert_eq!(annotated[0].label.as_deref(), Some("Market Maker"));
+        assert_eq!(annotated[1].label, None);
+        assert_eq!(annotated[2].label.as_deref(), Some("Treasury"));
+        assert_eq!(annotated[1].holder.amount, 50.0);
+    }
+
+    #[test]
+    fn test_annotate_holders_with_empty_book() {
+        let book = AddressBook::from(HashMap::new());
+        assert!(book.is_empty());
+
+        let annotated = annotate_holders(&[holder("0xabc", 1.0)], &book);
+        assert_eq!(annotated.len(), 1);
+        assert!(annotated[0].label.is_none());
+    }
 }
@@ -1,12 +1,21 @@
 //! Market data types and API methods.
 //!
-//! This module provides types and methods for querying market open interest and live volume.
+//! This module provides types and methods for querying market open interest,
+//! live volume and the CLOB tokens of a market.
 
+use std::collections::HashMap;
+
+use futures_util::future::join_all;
 use serde::{Deserialize, Serialize};
+use tokio::sync::Semaphore;
 use tracing::{instrument, trace};
 
 use super::{Client, validate_event_id, validate_market_id};
-use crate::error::Result;
+use crate::client::http::deserialize_checked;
+use crate::error::{PolymarketError, Result};
+
+/// Maximum number of concurrent requests issued by [`Client::get_event_live_volumes`].
+const MAX_CONCURRENT_LIVE_VOLUME_REQUESTS: usize = 8;
 
 // ============================================================================
 // Types
@@ -43,6 +52,60 @@ pub struct EventLiveVolume {
     pub markets: Option<Vec<MarketLiveVolume>>,
 }
 
+/// CLOB token of one outcome of a market.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TokenInfo {
+    /// CLOB token ID used for order books, quotes and subscriptions.
+    pub token_id: String,
+    /// Outcome label (e.g. "Yes").
+    pub outcome: String,
+    /// Position of the outcome in the market's outcome list.
+    pub outcome_index: i32,
+}
+
+/// Token fields of a Gamma market; both are JSON-encoded string arrays.
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct MarketTokens {
+    #[serde(default)]
+    outcomes: Option<String>,
+    #[serde(default)]
+    clob_token_ids: Option<String>,
+}
+
+impl MarketTokens {
+    fn into_tokens(self, condition_id: &str) -> Result<Vec<TokenInfo>> {
+        let parse = |field: &str, value: Option<String>| -> Result<Vec<String>> {
+            serde_json::from_str(value.as_deref().unwrap_or("[]")).map_err(|e| {
+                PolymarketError::other(format!(
+                    "invalid {} for market '{}': {}",
+                    field, condition_id, e
+                ))
+            })
+        };
+        let outcomes = parse("outcomes", self.outcomes)?;
+        let token_ids = parse("clobTokenIds", self.clob_token_ids)?;
+        if outcomes.len() != token_ids.len() {
+            return Err(PolymarketError::other(format!(
+                "market '{}' has {} outcomes but {} token IDs",
+                condition_id,
+                outcomes.len(),
+                token_ids.len()
+            )));
+        }
+        Ok(token_ids
+            .into_iter()
+            .zip(outcomes)
+            .enumerate()
+            .map(|(index, (token_id, outcome))| TokenInfo {
+                token_id,
+                outcome,
+                outcome_index: index as i32,
+            })
+            .collect())
+    }
+}
+
 // ============================================================================
 // Client Implementation
 // ============================================================================
@@ -94,7 +157,7 @@ impl Client {
         trace!(url = %url, method = "GET", market_count = markets.len(), "sending HTTP request");
         let response = self.http_client.get(url).send().await?;
         let response = self.check_response(response).await?;
-        let open_interest_response: Vec<MarketOpenInterest> = response.json().await?;
+        let open_interest_response: Vec<MarketOpenInterest> = deserialize_checked(response).await?;
         trace!(
             count = open_interest_response.len(),
             "received open interest data"
@@ -142,11 +205,200 @@ impl Client {
         let response = self.http_client.get(url).send().await?;
         let response = self.check_response(response).await?;
         // API returns an array, we take the first element
-        let volume_responses: Vec<EventLiveVolume> = response.json().await?;
+        let volume_responses: Vec<EventLiveVolume> = deserialize_checked(response).await?;
         let result = volume_responses.into_iter().next().ok_or_else(|| {
             crate::error::PolymarketError::api("empty live volume response".to_string())
         })?;
         trace!(total = result.total, "received live volume");
         Ok(result)
     }
+
+    /// Gets the live volume for several events concurrently.
+    ///
+    /// Requests are issued in parallel, bounded to a small number in flight at
+    /// once. Each event keeps its own result, so one failing id does not affect
+    /// the others. Duplicate ids are fetched once.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use polymarket_hft::client::polymarket::data::Client;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let client = Client::new();
+    ///     let volumes = client.get_event_live_volumes(&[123, 456]).await;
+    ///     for (id, volume) in &volumes {
+    ///         match volume {
+    ///             Ok(v) => println!("Event {} total volume: {}", id, v.total),
+    ///             Err(e) => println!("Event {} failed: {}", id, e),
+    ///         }
+    ///     }
+    ///     Ok(())
+    /// }
+    /// ```
+    #[instrument(skip(self, event_ids), fields(count = event_ids.len()), level = "trace")]
+    pub async fn get_event_live_volumes(
+        &self,
+        event_ids: &[i64],
+    ) -> HashMap<i64, Result<EventLiveVolume>> {
+        let mut unique_ids = event_ids.to_vec();
+        unique_ids.sort_unstable();
+        unique_ids.dedup();
+
+        let semaphore = Semaphore::new(MAX_CONCURRENT_LIVE_VOLUME_REQUESTS);
+        let requests = unique_ids.into_iter().map(|event_id| {
+            let semaphore = &semaphore;
+            async move {
+                let _permit = semaphore
+                    .acquire()
+                    .await
+                    .expect("semaphore is never closed");
+                (event_id, self.get_event_live_volume(event_id).await)
+            }
+        });
+
+        join_all(requests).await.into_iter().collect()
+    }
+
+    /// Gets the CLOB token IDs of a market, one per outcome, in outcome order.
+    ///
+    /// Looks the market up by condition ID in the Gamma market metadata
+    /// (see [`Client::with_profile_base_url`]). Returns
+    /// [`PolymarketError::NotFound`] if no market has that condition ID.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use polymarket_hft::client::polymarket::data::Client;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let client = Client::new();
+    ///     let tokens = client
+    ///         .get_tokens_for_condition(
+    ///             "0xdd22472e552920b8438158ea7238bfadfa4f736aa4cee91a6b86c39ead110917",
+    ///         )
+    ///         .await?;
+    ///     for token in tokens {
+    ///         println!("{}: {}", token.outcome, token.token_id);
+    ///     }
+    ///     Ok(())
+    /// }
+    /// ```
+    #[instrument(skip(self), level = "trace")]
+    pub async fn get_tokens_for_condition(&self, condition_id: &str) -> Result<Vec<TokenInfo>> {
+        validate_market_id(condition_id)?;
+
+        let mut url = self.build_profile_url("markets");
+        url.query_pairs_mut()
+            .append_pair("condition_ids", condition_id);
+
+        trace!(url = %url, method = "GET", "sending HTTP request");
+        let response = self.http_client.get(url).send().await?;
+        let response = self.check_response(response).await?;
+        let markets: Vec<MarketTokens> = deserialize_checked(response).await?;
+        let market = markets.into_iter().next().ok_or_else(|| {
+            PolymarketError::not_found(format!("no market with condition ID '{}'", condition_id))
+        })?;
+        let tokens = market.into_tokens(condition_id)?;
+        trace!(count = tokens.len(), "received market tokens");
+        Ok(tokens)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use wiremock::matchers::{method, path, query_param};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    #[tokio::test]
+    async fn test_get_event_live_volumes_preserves_per_id_errors() {
+        let mock_server = MockServer::start().await;
+
+        for (id, total) in [("1", 100.0), ("2", 250.5)] {
+            Mock::given(method("GET"))
+                .and(path("/live-volume"))
+                .and(query_param("id", id))
+                .respond_with(
+                    ResponseTemplate::new(200)
+                        .set_body_json(serde_json::json!([{"total": total, "markets": null}])),
+                )
+                .expect(1)
+                .mount(&mock_server)
+                .await;
+        }
+        Mock::given(method("GET"))
+            .and(path("/live-volume"))
+            .and(query_param("id", "3"))
+            .respond_with(ResponseTemplate::new(400).set_body_string("unknown event"))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        let client = Client::with_base_url(&mock_server.uri()).unwrap();
+        let volumes = client.get_event_live_volumes(&[1, 2, 3, 1]).await;
+
+        assert_eq!(volumes.len(), 3);
+        assert_eq!(volumes[&1].as_ref().unwrap().total, 100.0);
+        assert_eq!(volumes[&2].as_ref().unwrap().total, 250.5);
+        let err = volumes[&3].as_ref().unwrap_err();
+        assert!(err.to_string().contains("400"));
+    }
+
+    #[tokio::test]
+    async fn test_get_event_live_volumes_rejects_invalid_id_without_request() {
+        let client = Client::with_base_url("http://127.0.0.1:9").unwrap();
+        let volumes = client.get_event_live_volumes(&[0]).await;
+        assert!(matches!(
+            volumes[&0],
+            Err(crate::error::PolymarketError::BadRequest(_))
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_get_tokens_for_condition_maps_binary_market() {
+        const CONDITION: &str =
+            "0xdd22472e552920b8438158ea7238bfadfa4f736aa4cee91a6b86c39ead110917";
+        let mock_server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/markets"))
+            .and(query_param("condition_ids", CONDITION))
+            .respond_with(
+                ResponseTemplate::new(200).set_body_json(serde_json::json!([{
+                    "conditionId": CONDITION,
+                    "outcomes": "[\"Yes\", \"No\"]",
+                    "clobTokenIds": "[\"1111\", \"2222\"]"
+                }])),
+            )
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        let client = Client::new()
+            .with_profile_base_url(&mock_server.uri())
+            .unwrap();
+        let tokens = client.get_tokens_for_condition(CONDITION).await.unwrap();
+
+        assert_eq!(
+            tokens,
+            vec![
+                TokenInfo {
+                    token_id: "1111".to_string(),
+                    outcome: "Yes".to_string(),
+                    outcome_index: 0,
+                },
+                TokenInfo {
+                    token_id: "2222".to_string(),
+                    outcome: "No".to_string(),
+                    outcome_index: 1,
+                },
+            ]
+        );
+        assert!(matches!(
+            client.get_tokens_for_condition("0x1234").await,
+            Err(PolymarketError::BadRequest(_))
+        ));
+    }
 }
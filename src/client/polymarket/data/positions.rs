@@ -2,6 +2,9 @@
 //!
 //! This module provides types and methods for querying user positions.
 
+use std::cmp::Ordering;
+
+use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 use tracing::{instrument, trace};
 use url::Url;
@@ -9,6 +12,8 @@ use url::Url;
 use super::{
     Client, SortDirection, validate_event_id, validate_limit, validate_market_id, validate_user,
 };
+use crate::client::ValidatableRequest;
+use crate::client::http::deserialize_checked;
 use crate::error::{PolymarketError, Result};
 
 // ============================================================================
@@ -73,6 +78,26 @@ impl std::str::FromStr for PositionSortBy {
     }
 }
 
+impl PositionSortBy {
+    /// Compare two positions by this field in ascending order.
+    ///
+    /// Floats use a total order, so NaN values sort deterministically.
+    /// `Resolving` compares end dates, which are ISO-8601 strings.
+    pub fn compare(&self, a: &Position, b: &Position) -> Ordering {
+        match self {
+            PositionSortBy::Current => a.current_value.total_cmp(&b.current_value),
+            PositionSortBy::Initial => a.initial_value.total_cmp(&b.initial_value),
+            PositionSortBy::Tokens => a.size.total_cmp(&b.size),
+            PositionSortBy::CashPnl => a.cash_pnl.total_cmp(&b.cash_pnl),
+            PositionSortBy::PercentPnl => a.percent_pnl.total_cmp(&b.percent_pnl),
+            PositionSortBy::Title => a.title.cmp(&b.title),
+            PositionSortBy::Resolving => a.end_date.cmp(&b.end_date),
+            PositionSortBy::Price => a.cur_price.total_cmp(&b.cur_price),
+            PositionSortBy::AvgPrice => a.avg_price.total_cmp(&b.avg_price),
+        }
+    }
+}
+
 /// A user's position in a market.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Position {
@@ -195,6 +220,14 @@ pub struct ClosedPosition {
     pub end_date: String,
 }
 
+impl ClosedPosition {
+    /// Returns `timestamp` (seconds) as a UTC datetime, via
+    /// [`datetime_from_secs`](crate::engine::datetime_from_secs).
+    pub fn as_datetime(&self) -> DateTime<Utc> {
+        crate::engine::datetime_from_secs(self.timestamp)
+    }
+}
+
 /// Response from the value endpoint.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct UserPositionValue {
@@ -216,10 +249,14 @@ pub struct GetUserPositionsRequest<'a> {
     pub sort_by: Option<PositionSortBy>,
     pub sort_direction: Option<SortDirection>,
     pub title: Option<&'a str>,
+    /// Drop positions whose `current_value` is below this, in USD.
+    ///
+    /// Applied client-side after the response arrives; it is not sent to the API.
+    pub min_value: Option<f64>,
 }
 
-impl GetUserPositionsRequest<'_> {
-    pub fn validate(&self) -> Result<()> {
+impl ValidatableRequest for GetUserPositionsRequest<'_> {
+    fn validate(&self) -> Result<()> {
         validate_user(self.user)?;
         if let Some(market_ids) = self.markets {
             for market_id in market_ids {
@@ -238,6 +275,13 @@ impl GetUserPositionsRequest<'_> {
                 "sizeThreshold must be >= 0".to_string(),
             ));
         }
+        if let Some(min_value) = self.min_value
+            && (min_value < 0.0 || min_value.is_nan())
+        {
+            return Err(PolymarketError::bad_request(
+                "min_value must be >= 0".to_string(),
+            ));
+        }
         validate_limit(self.limit)?;
         if let Some(o) = self.offset
             && !(0..=10000).contains(&o)
@@ -255,6 +299,12 @@ impl GetUserPositionsRequest<'_> {
         }
         Ok(())
     }
+}
+
+impl GetUserPositionsRequest<'_> {
+    pub fn validate(&self) -> Result<()> {
+        ValidatableRequest::validate(self)
+    }
 
     pub fn build_url(&self, base_url: &Url) -> Url {
         let mut url = base_url.clone();
@@ -318,8 +368,8 @@ pub struct GetUserClosedPositionsRequest<'a> {
     pub sort_direction: Option<SortDirection>,
 }
 
-impl GetUserClosedPositionsRequest<'_> {
-    pub fn validate(&self) -> Result<()> {
+impl ValidatableRequest for GetUserClosedPositionsRequest<'_> {
+    fn validate(&self) -> Result<()> {
         validate_user(self.user)?;
         if let Some(market_ids) = self.markets {
             for market_id in market_ids {
@@ -354,6 +404,12 @@ impl GetUserClosedPositionsRequest<'_> {
         }
         Ok(())
     }
+}
+
+impl GetUserClosedPositionsRequest<'_> {
+    pub fn validate(&self) -> Result<()> {
+        ValidatableRequest::validate(self)
+    }
 
     pub fn build_url(&self, base_url: &Url) -> Url {
         let mut url = base_url.clone();
@@ -392,6 +448,28 @@ impl GetUserClosedPositionsRequest<'_> {
     }
 }
 
+// ============================================================================
+// Sorting
+// ============================================================================
+
+/// Sort positions client-side with a deterministic order.
+///
+/// Positions are sorted ascending by `primary`, then by `secondary`, and
+/// finally by asset ID so that ties the API returns in arbitrary order always
+/// come out the same way. Call `reverse()` afterwards for descending order.
+pub fn stable_sort_positions(
+    positions: &mut [Position],
+    primary: PositionSortBy,
+    secondary: PositionSortBy,
+) {
+    positions.sort_by(|a, b| {
+        primary
+            .compare(a, b)
+            .then_with(|| secondary.compare(a, b))
+            .then_with(|| a.asset.cmp(&b.asset))
+    });
+}
+
 // ============================================================================
 // Client Implementation
 // ============================================================================
@@ -407,8 +485,11 @@ impl Client {
         trace!(url = %url, method = "GET", "sending HTTP request");
         let response = self.http_client.get(url).send().await?;
         let response = self.check_response(response).await?;
-        let positions: Vec<Position> = response.json().await?;
+        let mut positions: Vec<Position> = deserialize_checked(response).await?;
         trace!(count = positions.len(), "received positions");
+        if let Some(min_value) = request.min_value {
+            positions.retain(|p| p.current_value >= min_value);
+        }
         Ok(positions)
     }
 
@@ -422,7 +503,7 @@ impl Client {
         trace!(url = %url, method = "GET", "sending HTTP request");
         let response = self.http_client.get(url).send().await?;
         let response = self.check_response(response).await?;
-        let positions: Vec<ClosedPosition> = response.json().await?;
+        let positions: Vec<ClosedPosition> = deserialize_checked(response).await?;
         trace!(count = positions.len(), "received closed positions");
         Ok(positions)
     }
@@ -448,7 +529,7 @@ impl Client {
         trace!(url = %url, method = "GET", "sending HTTP request");
         let response = self.http_client.get(url).send().await?;
         let response = self.check_response(response).await?;
-        let value_response: Vec<UserPositionValue> = response.json().await?;
+        let value_response: Vec<UserPositionValue> = deserialize_checked(response).await?;
         trace!(count = value_response.len(), "received portfolio values");
         Ok(value_response)
     }
@@ -457,6 +538,113 @@ impl Client {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::testutil::{SAMPLE_USER, sample_closed_position_json, sample_position_json};
+
+    #[test]
+    fn closed_position_as_datetime() {
+        let closed: ClosedPosition = serde_json::from_value(sample_closed_position_json()).unwrap();
+        assert_eq!(
+            closed.as_datetime().to_rfc3339(),
+            "2023-11-14T22:13:20+00:00"
+        );
+    }
+
+    fn position(asset: &str, title: &str, cash_pnl: f64, size: f64) -> Position {
+        let mut json = sample_position_json();
+        json["asset"] = serde_json::json!(asset);
+        json["title"] = serde_json::json!(title);
+        json["cashPnl"] = serde_json::json!(cash_pnl);
+        json["size"] = serde_json::json!(size);
+        serde_json::from_value(json).unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_get_user_positions_min_value_drops_dust() {
+        use wiremock::matchers::{method, path, query_param_is_missing};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let body: Vec<serde_json::Value> = [("a", 0.4), ("b", 12.0), ("c", 5.0), ("d", 4.99)]
+            .into_iter()
+            .map(|(asset, value)| {
+                let mut p = position(asset, "t", 0.0, 1.0);
+                p.current_value = value;
+                serde_json::to_value(p).unwrap()
+            })
+            .collect();
+
+        let mock_server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/positions"))
+            .and(query_param_is_missing("minValue"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(body))
+            .mount(&mock_server)
+            .await;
+
+        let client = Client::with_base_url(&mock_server.uri()).unwrap();
+        let positions = client
+            .get_user_positions(GetUserPositionsRequest {
+                user: SAMPLE_USER,
+                min_value: Some(5.0),
+                ..Default::default()
+            })
+            .await
+            .unwrap();
+
+        let assets: Vec<&str> = positions.iter().map(|p| p.asset.as_str()).collect();
+        assert_eq!(assets, ["b", "c"]);
+    }
+
+    #[test]
+    fn test_min_value_must_be_non_negative() {
+        let request = GetUserPositionsRequest {
+            user: SAMPLE_USER,
+            min_value: Some(-1.0),
+            ..Default::default()
+        };
+        assert!(request.validate().is_err());
+    }
+
+    #[test]
+    fn test_stable_sort_positions_breaks_ties_with_secondary_key() {
+        let mut positions = vec![
+            position("a1", "Zeta", 5.0, 1.0),
+            position("a2", "Alpha", 5.0, 2.0),
+            position("a3", "Mid", -1.0, 3.0),
+            position("a4", "Beta", 5.0, 4.0),
+        ];
+
+        stable_sort_positions(
+            &mut positions,
+            PositionSortBy::CashPnl,
+            PositionSortBy::Title,
+        );
+        let titles: Vec<&str> = positions.iter().map(|p| p.title.as_str()).collect();
+        assert_eq!(titles, vec!["Mid", "Alpha", "Beta", "Zeta"]);
+
+        // Same input in a different order yields the same result.
+        positions.reverse();
+        stable_sort_positions(
+            &mut positions,
+            PositionSortBy::CashPnl,
+            PositionSortBy::Title,
+        );
+        let assets: Vec<&str> = positions.iter().map(|p| p.asset.as_str()).collect();
+        assert_eq!(assets, vec!["a3", "a2", "a4", "a1"]);
+    }
+
+    #[test]
+    fn test_stable_sort_positions_falls_back_to_asset() {
+        let mut positions = vec![
+            position("b", "Same", 1.0, 1.0),
+            position("a", "Same", 1.0, 1.0),
+        ];
+        stable_sort_positions(
+            &mut positions,
+            PositionSortBy::CashPnl,
+            PositionSortBy::Tokens,
+        );
+        assert_eq!(positions[0].asset, "a");
+    }
 
     #[test]
     fn test_position_sort_by_display() {
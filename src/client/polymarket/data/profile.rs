@@ -0,0 +1,164 @@
+//! Profile lookups for mapping wallet addresses.
+//!
+//! Polymarket users sign with an EOA (externally owned account), but their
+//! positions, trades and activity are held by a proxy wallet deployed for
+//! them. The Data API endpoints expect the proxy wallet (the "profile
+//! address" shown on polymarket.com); querying them with the EOA returns
+//! empty results instead of an error.
+
+use serde::{Deserialize, Serialize};
+use tracing::{instrument, trace};
+
+use super::{Client, validate_user};
+use crate::client::http::deserialize_checked;
+use crate::error::{PolymarketError, Result};
+
+// ============================================================================
+// Types
+// ============================================================================
+
+/// Public profile of a Polymarket user.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PublicProfile {
+    /// Proxy wallet holding the user's positions.
+    pub proxy_wallet: String,
+    /// Display name.
+    #[serde(default)]
+    pub name: Option<String>,
+    /// Generated pseudonym.
+    #[serde(default)]
+    pub pseudonym: Option<String>,
+}
+
+// ============================================================================
+// Client Implementation
+// ============================================================================
+
+impl Client {
+    /// Gets the public profile for an EOA or proxy wallet address.
+    ///
+    /// Returns [`PolymarketError::NotFound`] if the address has no profile.
+    #[instrument(skip(self), fields(address = %address), level = "trace")]
+    pub async fn get_public_profile(&self, address: &str) -> Result<PublicProfile> {
+        validate_user(address)?;
+        let mut url = self.build_profile_url("public-profile");
+        url.query_pairs_mut().append_pair("address", address);
+        trace!(url = %url, method = "GET", "sending HTTP request");
+        let response = self.http_client.get(url).send().await?;
+        if response.status() == reqwest::StatusCode::NOT_FOUND {
+            return Err(PolymarketError::not_found(format!(
+                "no Polymarket profile for address '{}'",
+                address
+            )));
+        }
+        let response = self.check_response(response).await?;
+        let profile: PublicProfile = deserialize_checked(response).await?;
+        trace!(proxy_wallet = %profile.proxy_wallet, "received public profile");
+        Ok(profile)
+    }
+
+    /// Resolves an EOA to the proxy wallet that Data API endpoints expect.
+    ///
+    /// Use it when an address comes from a signer or wallet connection rather
+    /// than from a Polymarket profile page. Passing a proxy wallet returns it
+    /// unchanged.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use polymarket_hft::client::polymarket::data::{Client, GetUserActivityRequest};
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let client = Client::new();
+    ///     let user = client
+    ///         .resolve_proxy_wallet("0x56687bf447db6ffa42ffe2204a05edaa20f55839")
+    ///         .await?;
+    ///     let activity = client.get_user_activity(GetUserActivityRequest {
+    ///         user: &user,
+    ///         ..Default::default()
+    ///     }).await?;
+    ///     println!("{} records", activity.len());
+    ///     Ok(())
+    /// }
+    /// ```
+    pub async fn resolve_proxy_wallet(&self, eoa: &str) -> Result<String> {
+        Ok(self.get_public_profile(eoa).await?.proxy_wallet)
+    }
+
+    /// Checks that `address` is a proxy wallet rather than an EOA.
+    ///
+    /// Returns [`PolymarketError::BadRequest`] naming the proxy wallet to use
+    /// when `address` is the EOA behind a different proxy. Addresses without
+    /// a profile pass, since they may simply have no activity yet.
+    pub async fn ensure_proxy_wallet(&self, address: &str) -> Result<()> {
+        let proxy_wallet = match self.resolve_proxy_wallet(address).await {
+            Ok(proxy_wallet) => proxy_wallet,
+            Err(PolymarketError::NotFound(_)) => return Ok(()),
+            Err(e) => return Err(e),
+        };
+        if proxy_wallet.eq_ignore_ascii_case(address) {
+            return Ok(());
+        }
+        Err(PolymarketError::bad_request(format!(
+            "address '{}' is an EOA; Data API endpoints expect its proxy wallet '{}'",
+            address, proxy_wallet
+        )))
+    }
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use wiremock::matchers::{method, path, query_param};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    const EOA: &str = "0x1111111111111111111111111111111111111111";
+    const PROXY: &str = "0x2222222222222222222222222222222222222222";
+
+    async fn profile_server() -> MockServer {
+        let mock_server = MockServer::start().await;
+        for address in [EOA, PROXY] {
+            Mock::given(method("GET"))
+                .and(path("/public-profile"))
+                .and(query_param("address", address))
+                .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                    "proxyWallet": PROXY,
+                    "name": "trader",
+                    "pseudonym": "Quiet-Fox"
+                })))
+                .mount(&mock_server)
+                .await;
+        }
+        mock_server
+    }
+
+    #[tokio::test]
+    async fn resolve_proxy_wallet_maps_eoa_to_proxy() {
+        let mock_server = profile_server().await;
+        let client = Client::new()
+            .with_profile_base_url(&mock_server.uri())
+            .unwrap();
+
+        assert_eq!(client.resolve_proxy_wallet(EOA).await.unwrap(), PROXY);
+        assert_eq!(client.resolve_proxy_wallet(PROXY).await.unwrap(), PROXY);
+    }
+
+    #[tokio::test]
+    async fn ensure_proxy_wallet_rejects_eoa_with_hint() {
+        let mock_server = profile_server().await;
+        let client = Client::new()
+            .with_profile_base_url(&mock_server.uri())
+            .unwrap();
+
+        client.ensure_proxy_wallet(PROXY).await.unwrap();
+        let err = client.ensure_proxy_wallet(EOA).await.unwrap_err();
+        assert!(matches!(err, PolymarketError::BadRequest(_)), "got {err:?}");
+        assert!(err.to_string().contains(PROXY), "got {err}");
+    }
+}
@@ -2,13 +2,31 @@
 //!
 //! This module provides types and methods for querying trades.
 
+use chrono::{DateTime, Utc};
+use futures_util::{Stream, StreamExt};
 use serde::{Deserialize, Serialize};
-use tracing::{instrument, trace};
+use tracing::{instrument, trace, warn};
 use url::Url;
 
 use super::{Client, TradeSide, validate_event_id, validate_market_id, validate_user};
+use crate::client::ValidatableRequest;
+use crate::client::http::{Parsed, deserialize_checked, deserialize_parsed};
+use crate::client::pagination::{DEFAULT_MAX_PAGES, offset_pages};
 use crate::error::{PolymarketError, Result};
 
+/// Page size used by [`Client::trades_pages`] and [`Client::get_trades_since`]
+/// when the request has no limit.
+const DEFAULT_TRADES_PAGE_SIZE: i32 = 100;
+
+/// Maximum offset accepted by the trades endpoint.
+///
+/// Trades past this offset cannot be paged to, so [`Client::trades_pages`]
+/// stops there even if the history goes on.
+pub const MAX_TRADES_OFFSET: i32 = 10000;
+
+/// Page size used by [`Client::get_user_traded_markets_list`].
+const TRADED_MARKETS_PAGE_SIZE: i32 = 500;
+
 // ============================================================================
 // Types
 // ============================================================================
@@ -74,6 +92,24 @@ pub struct Trade {
     pub transaction_hash: String,
 }
 
+impl Trade {
+    /// Returns `timestamp` (seconds) as a UTC datetime, via
+    /// [`datetime_from_secs`](crate::engine::datetime_from_secs).
+    pub fn as_datetime(&self) -> DateTime<Utc> {
+        crate::engine::datetime_from_secs(self.timestamp)
+    }
+}
+
+/// Trades returned by [`Client::get_trades_since`].
+#[derive(Debug, Clone)]
+pub struct TradesSince {
+    /// Trades newer than the cutoff, oldest first.
+    pub trades: Vec<Trade>,
+    /// Paging stopped at [`MAX_TRADES_OFFSET`] before reaching the cutoff, so
+    /// trades between the cutoff and the oldest returned trade are missing.
+    pub truncated: bool,
+}
+
 /// Response from the traded endpoint.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct UserTradedMarketsCount {
@@ -111,8 +147,8 @@ impl Default for GetTradesRequest<'_> {
     }
 }
 
-impl GetTradesRequest<'_> {
-    pub fn validate(&self) -> Result<()> {
+impl ValidatableRequest for GetTradesRequest<'_> {
+    fn validate(&self) -> Result<()> {
         if let Some(l) = self.limit
             && !(0..=10000).contains(&l)
         {
@@ -161,6 +197,12 @@ impl GetTradesRequest<'_> {
         }
         Ok(())
     }
+}
+
+impl GetTradesRequest<'_> {
+    pub fn validate(&self) -> Result<()> {
+        ValidatableRequest::validate(self)
+    }
 
     pub fn build_url(&self, base_url: &Url) -> Url {
         let mut url = base_url.clone();
@@ -217,11 +259,189 @@ impl Client {
         trace!(url = %url, method = "GET", "sending HTTP request");
         let response = self.http_client.get(url).send().await?;
         let response = self.check_response(response).await?;
-        let trades: Vec<Trade> = response.json().await?;
+        let trades: Vec<Trade> = deserialize_checked(response).await?;
         trace!(count = trades.len(), "received trades");
         Ok(trades)
     }
 
+    /// Like [`get_trades`](Self::get_trades), but also returns the raw JSON
+    /// body, including fields [`Trade`] does not model.
+    #[instrument(skip(self, request), level = "trace")]
+    pub async fn get_trades_raw(
+        &self,
+        request: GetTradesRequest<'_>,
+    ) -> Result<Parsed<Vec<Trade>>> {
+        request.validate()?;
+        let url = request.build_url(&self.base_url);
+        trace!(url = %url, method = "GET", "sending HTTP request");
+        let response = self.http_client.get(url).send().await?;
+        let response = self.check_response(response).await?;
+        let trades: Parsed<Vec<Trade>> = deserialize_parsed(response).await?;
+        trace!(count = trades.value.len(), "received trades");
+        Ok(trades)
+    }
+
+    /// Streams pages of trades matching `request`, newest first.
+    ///
+    /// The request's `limit` is used as the page size (default 100) and its
+    /// `offset` as the starting offset. Paging stops after the last page or
+    /// once the endpoint's maximum offset is reached, so only one page is held
+    /// in memory at a time.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use futures_util::StreamExt;
+    /// use polymarket_hft::client::polymarket::data::{Client, GetTradesRequest};
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let client = Client::new();
+    ///     let mut pages = std::pin::pin!(client.trades_pages(GetTradesRequest {
+    ///         user: Some("0x56687bf447db6ffa42ffe2204a05edaa20f55839"),
+    ///         ..Default::default()
+    ///     }));
+    ///     while let Some(page) = pages.next().await {
+    ///         println!("{} trades", page?.len());
+    ///     }
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn trades_pages<'a>(
+        &'a self,
+        request: GetTradesRequest<'a>,
+    ) -> impl Stream<Item = Result<Vec<Trade>>> + 'a {
+        let page_size = request
+            .limit
+            .filter(|l| *l > 0)
+            .unwrap_or(DEFAULT_TRADES_PAGE_SIZE);
+        let start_offset = request.offset.unwrap_or(0).max(0);
+        let reachable_pages = ((MAX_TRADES_OFFSET - start_offset).max(0) / page_size) as usize + 1;
+
+        offset_pages(
+            page_size as usize,
+            start_offset as usize,
+            DEFAULT_MAX_PAGES,
+            move |offset| {
+                self.get_trades(GetTradesRequest {
+                    limit: Some(page_size),
+                    offset: Some(offset as i32),
+                    ..request.clone()
+                })
+            },
+        )
+        .take(reachable_pages)
+    }
+
+    /// Gets trades newer than `last_timestamp`, oldest first.
+    ///
+    /// Pages through the trades endpoint (newest first) using the request's
+    /// filters, stopping at the first page that reaches a trade at or before
+    /// `last_timestamp`. The request's `limit` is used as the page size and its
+    /// `offset` as the starting offset. At most [`DEFAULT_MAX_PAGES`] pages are
+    /// fetched; see [`Client::get_trades_since_with_max_pages`].
+    ///
+    /// If the cutoff lies past [`MAX_TRADES_OFFSET`], only the newest trades
+    /// can be fetched and the result is marked
+    /// [`truncated`](TradesSince::truncated).
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use polymarket_hft::client::polymarket::data::{Client, GetTradesRequest};
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let client = Client::new();
+    ///     let mut last_seen = 0;
+    ///     let since = client
+    ///         .get_trades_since(GetTradesRequest::default(), last_seen)
+    ///         .await?;
+    ///     if since.truncated {
+    ///         eprintln!("some trades since {} could not be fetched", last_seen);
+    ///     }
+    ///     if let Some(trade) = since.trades.last() {
+    ///         last_seen = trade.timestamp;
+    ///     }
+    ///     println!("{} new trades, last seen {}", since.trades.len(), last_seen);
+    ///     Ok(())
+    /// }
+    /// ```
+    pub async fn get_trades_since(
+        &self,
+        request: GetTradesRequest<'_>,
+        last_timestamp: i64,
+    ) -> Result<TradesSince> {
+        self.get_trades_since_with_max_pages(request, last_timestamp, DEFAULT_MAX_PAGES)
+            .await
+    }
+
+    /// Same as [`Client::get_trades_since`] with a custom page budget.
+    ///
+    /// Returns [`PolymarketError::MaxPagesExceeded`] if `max_pages` full pages
+    /// were fetched without reaching the cutoff.
+    #[instrument(skip(self, request), fields(last_timestamp = %last_timestamp), level = "trace")]
+    pub async fn get_trades_since_with_max_pages(
+        &self,
+        request: GetTradesRequest<'_>,
+        last_timestamp: i64,
+        max_pages: usize,
+    ) -> Result<TradesSince> {
+        let page_size = request
+            .limit
+            .filter(|l| *l > 0)
+            .unwrap_or(DEFAULT_TRADES_PAGE_SIZE);
+        let start_offset = request.offset.unwrap_or(0).max(0) as usize;
+        let mut newer = Vec::new();
+
+        let pages = offset_pages(page_size as usize, start_offset, max_pages, |offset| {
+            self.get_trades(GetTradesRequest {
+                limit: Some(page_size),
+                offset: Some(offset as i32),
+                ..request.clone()
+            })
+        });
+        let mut pages = std::pin::pin!(pages);
+        let mut offset = start_offset as i32;
+        let mut truncated = false;
+
+        while let Some(page) = pages.next().await {
+            let page = page?;
+            let full = page.len() >= page_size as usize;
+            let mut reached_cutoff = false;
+            for trade in page {
+                if trade.timestamp > last_timestamp {
+                    newer.push(trade);
+                } else {
+                    reached_cutoff = true;
+                }
+            }
+
+            offset += page_size;
+            if reached_cutoff {
+                break;
+            }
+            if offset > MAX_TRADES_OFFSET {
+                truncated = full;
+                break;
+            }
+        }
+
+        if truncated {
+            warn!(
+                last_timestamp,
+                count = newer.len(),
+                "trades since cutoff exceed the trades endpoint's offset limit; older ones are missing"
+            );
+        }
+        newer.sort_by_key(|t| t.timestamp);
+        trace!(count = newer.len(), "received trades since cutoff");
+        Ok(TradesSince {
+            trades: newer,
+            truncated,
+        })
+    }
+
     #[instrument(skip(self), fields(user = %user), level = "trace")]
     pub async fn get_user_traded_markets(&self, user: &str) -> Result<UserTradedMarketsCount> {
         validate_user(user)?;
@@ -230,18 +450,227 @@ impl Client {
         trace!(url = %url, method = "GET", "sending HTTP request");
         let response = self.http_client.get(url).send().await?;
         let response = self.check_response(response).await?;
-        let traded_response: UserTradedMarketsCount = response.json().await?;
+        let traded_response: UserTradedMarketsCount = deserialize_checked(response).await?;
         trace!(
             traded = traded_response.traded,
             "received traded markets count"
         );
         Ok(traded_response)
     }
+
+    /// Gets the condition IDs of the markets a user has traded, most recently
+    /// traded first.
+    ///
+    /// There is no endpoint listing them, so this pages through the user's
+    /// trades (maker and taker) and collects the distinct `condition_id`s.
+    /// Only the trades reachable within the endpoint's maximum offset are
+    /// scanned, so very active users may have older markets missing; compare
+    /// with [`Client::get_user_traded_markets`] for the full count.
+    #[instrument(skip(self), fields(user = %user), level = "trace")]
+    pub async fn get_user_traded_markets_list(&self, user: &str) -> Result<Vec<String>> {
+        validate_user(user)?;
+        let pages = self.trades_pages(GetTradesRequest {
+            user: Some(user),
+            taker_only: Some(false),
+            limit: Some(TRADED_MARKETS_PAGE_SIZE),
+            ..Default::default()
+        });
+        let mut pages = std::pin::pin!(pages);
+        let mut seen = std::collections::HashSet::new();
+        let mut markets = Vec::new();
+
+        while let Some(page) = pages.next().await {
+            for trade in page? {
+                if seen.insert(trade.condition_id.clone()) {
+                    markets.push(trade.condition_id);
+                }
+            }
+        }
+
+        trace!(count = markets.len(), "collected traded markets");
+        Ok(markets)
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::testutil::sample_trade_json;
+    use wiremock::matchers::{method, path, query_param};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    fn trade_json(timestamp: i64) -> serde_json::Value {
+        let mut trade = sample_trade_json();
+        trade["timestamp"] = serde_json::json!(timestamp);
+        trade["transactionHash"] = serde_json::json!(format!("0x{:064x}", timestamp));
+        trade
+    }
+
+    #[test]
+    fn test_trade_as_datetime() {
+        let trade: Trade = serde_json::from_value(trade_json(1_700_000_000)).unwrap();
+        assert_eq!(
+            trade.as_datetime().to_rfc3339(),
+            "2023-11-14T22:13:20+00:00"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_get_trades_raw_keeps_unmodelled_fields() {
+        let mock_server = MockServer::start().await;
+        let mut trade = trade_json(1000);
+        trade["newUpstreamField"] = serde_json::json!({"nested": true});
+        Mock::given(method("GET"))
+            .and(path("/trades"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(vec![trade]))
+            .mount(&mock_server)
+            .await;
+
+        let client = Client::with_base_url(&mock_server.uri()).unwrap();
+        let parsed = client
+            .get_trades_raw(GetTradesRequest::default())
+            .await
+            .unwrap();
+
+        assert_eq!(parsed.value.len(), 1);
+        assert_eq!(parsed.value[0].timestamp, 1000);
+        assert_eq!(
+            parsed.raw[0]["newUpstreamField"],
+            serde_json::json!({"nested": true})
+        );
+    }
+
+    #[tokio::test]
+    async fn test_get_trades_since_stops_at_cutoff() {
+        let mock_server = MockServer::start().await;
+
+        // Descending trade list split into pages of 3.
+        let page0: Vec<_> = [1009, 1008, 1007].into_iter().map(trade_json).collect();
+        let page1: Vec<_> = [1006, 1005, 1004].into_iter().map(trade_json).collect();
+        Mock::given(method("GET"))
+            .and(path("/trades"))
+            .and(query_param("offset", "0"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(page0))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/trades"))
+            .and(query_param("offset", "3"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(page1))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+        // Pages beyond the cutoff must not be requested.
+        Mock::given(method("GET"))
+            .and(path("/trades"))
+            .and(query_param("offset", "6"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(Vec::<serde_json::Value>::new()))
+            .expect(0)
+            .mount(&mock_server)
+            .await;
+
+        let client = Client::with_base_url(&mock_server.uri()).unwrap();
+        let trades = client
+            .get_trades_since(
+                GetTradesRequest {
+                    limit: Some(3),
+                    ..Default::default()
+                },
+                1005,
+            )
+            .await
+            .unwrap();
+
+        assert!(!trades.truncated);
+        let timestamps: Vec<i64> = trades.trades.iter().map(|t| t.timestamp).collect();
+        assert_eq!(timestamps, vec![1006, 1007, 1008, 1009]);
+    }
+
+    #[tokio::test]
+    async fn test_get_trades_since_stops_on_short_page() {
+        let mock_server = MockServer::start().await;
+
+        let page0: Vec<_> = [2002, 2001].into_iter().map(trade_json).collect();
+        Mock::given(method("GET"))
+            .and(path("/trades"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(page0))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        let client = Client::with_base_url(&mock_server.uri()).unwrap();
+        let trades = client
+            .get_trades_since(GetTradesRequest::default(), 0)
+            .await
+            .unwrap();
+
+        assert!(!trades.truncated);
+        let timestamps: Vec<i64> = trades.trades.iter().map(|t| t.timestamp).collect();
+        assert_eq!(timestamps, vec![2001, 2002]);
+    }
+
+    #[tokio::test]
+    async fn test_get_trades_since_reports_truncation_at_offset_limit() {
+        let mock_server = MockServer::start().await;
+
+        // Full pages newer than the cutoff, all the way to the offset limit.
+        let page_size = MAX_TRADES_OFFSET / 2;
+        let page: Vec<_> = (0..page_size)
+            .map(|i| trade_json(10_000 + i as i64))
+            .collect();
+        Mock::given(method("GET"))
+            .and(path("/trades"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(page))
+            .expect(3)
+            .mount(&mock_server)
+            .await;
+
+        let client = Client::with_base_url(&mock_server.uri()).unwrap();
+        let since = client
+            .get_trades_since(
+                GetTradesRequest {
+                    limit: Some(page_size),
+                    ..Default::default()
+                },
+                0,
+            )
+            .await
+            .unwrap();
+
+        assert!(since.truncated);
+        assert_eq!(since.trades.len(), 3 * page_size as usize);
+    }
+
+    #[tokio::test]
+    async fn test_get_user_traded_markets_list_returns_distinct_markets() {
+        let mock_server = MockServer::start().await;
+        let user = "0x56687bf447db6ffa42ffe2204a05edaa20f55839";
+        let market_a = format!("0x{:064x}", 0xa);
+        let market_b = format!("0x{:064x}", 0xb);
+        let trades: Vec<_> = [(5, &market_b), (4, &market_a), (3, &market_b)]
+            .into_iter()
+            .map(|(ts, market)| {
+                let mut trade = trade_json(ts);
+                trade["conditionId"] = serde_json::json!(market);
+                trade
+            })
+            .collect();
+        Mock::given(method("GET"))
+            .and(path("/trades"))
+            .and(query_param("user", user))
+            .and(query_param("takerOnly", "false"))
+            .and(query_param("offset", "0"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(trades))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        let client = Client::with_base_url(&mock_server.uri()).unwrap();
+        let markets = client.get_user_traded_markets_list(user).await.unwrap();
+
+        assert_eq!(markets, vec![market_b, market_a]);
+    }
 
     #[test]
     fn test_trade_filter_type_display() {
@@ -271,4 +700,35 @@ mod tests {
         // Invalid
         assert!("invalid".parse::<TradeFilterType>().is_err());
     }
+
+    #[tokio::test]
+    async fn test_get_trades_since_max_pages_safeguard() {
+        let mock_server = MockServer::start().await;
+
+        // Always-full pages of trades newer than the cutoff.
+        let page: Vec<_> = [5002, 5001].into_iter().map(trade_json).collect();
+        Mock::given(method("GET"))
+            .and(path("/trades"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(page))
+            .expect(3)
+            .mount(&mock_server)
+            .await;
+
+        let client = Client::with_base_url(&mock_server.uri()).unwrap();
+        let result = client
+            .get_trades_since_with_max_pages(
+                GetTradesRequest {
+                    limit: Some(2),
+                    ..Default::default()
+                },
+                0,
+                3,
+            )
+            .await;
+
+        assert!(matches!(
+            result,
+            Err(PolymarketError::MaxPagesExceeded(e)) if e.max_pages == 3
+        ));
+    }
 }
@@ -27,6 +27,7 @@
 
 mod client;
 mod comments;
+mod diff;
 mod events;
 pub(crate) mod helpers;
 mod markets;
@@ -37,9 +38,21 @@ mod tags;
 
 pub use client::{Client, DEFAULT_BASE_URL};
 pub use comments::{Comment, CommentProfile, GetCommentsByUserAddressRequest, GetCommentsRequest};
+pub use diff::{Change, MarketDelta, MarketStatus, NumericChange, diff_markets};
 pub use events::{Category, Collection, Event, EventChat, EventSummary, GetEventsRequest};
-pub use markets::{GetMarketsRequest, Market};
+pub use markets::{GetMarketsRequest, Market, SportsMarketType, UmaResolutionStatus, sports_only};
 pub use search::{SearchRequest, SearchResults};
-pub use series::{GetSeriesRequest, Series, SeriesSummary};
+pub use series::{GetSeriesRequest, Series, SeriesDetail, SeriesSummary};
 pub use sports::{GetTeamsRequest, SportMetadata, Team};
 pub use tags::{GetTagsRequest, Tag, TagRelationship, TagRelationshipStatus};
+
+crate::client::impl_request_debug!(
+    GetCommentsByUserAddressRequest<'_>,
+    GetCommentsRequest<'_>,
+    GetEventsRequest<'_>,
+    GetMarketsRequest<'_>,
+    GetSeriesRequest<'_>,
+    GetTagsRequest<'_>,
+    GetTeamsRequest<'_>,
+    SearchRequest<'_>,
+);
@@ -1,10 +1,16 @@
 //! Gamma API client implementation.
 
+use std::sync::Arc;
+
 use reqwest::{Client as HttpClient, Response};
 use reqwest_middleware::ClientWithMiddleware;
 use tracing::trace;
 use url::Url;
 
+use super::Market;
+use crate::client::coalesce::Coalescer;
+#[cfg(feature = "metrics")]
+use crate::client::http::{ClientStats, ClientStatsSnapshot};
 use crate::client::http::{DEFAULT_MAX_RETRIES, HttpClientConfig, wrap_with_retry};
 use crate::error::{PolymarketError, Result};
 
@@ -21,6 +27,12 @@ pub struct Client {
     pub(super) http_client: ClientWithMiddleware,
     /// Base URL for the API (validated URL).
     pub(super) base_url: Url,
+    /// Single-market lookups in flight, keyed by URL and 404 mapping and
+    /// shared by clones.
+    pub(super) market_flights: Arc<Coalescer<Market, (Url, Option<String>)>>,
+    /// Request counters (`metrics` feature).
+    #[cfg(feature = "metrics")]
+    pub(super) stats: ClientStats,
 }
 
 impl Client {
@@ -32,25 +44,36 @@ impl Client {
     /// Creates a new Gamma API client with a custom base URL.
     pub fn with_base_url(base_url: &str) -> Result<Self> {
         let url = Url::parse(base_url)?;
-        let http_client = HttpClientConfig::default()
+        let config = HttpClientConfig::default();
+        #[cfg(feature = "metrics")]
+        let stats = config.stats.clone();
+        let http_client = config
             .build()
             .map_err(|e| PolymarketError::other(format!("failed to create HTTP client: {}", e)))?;
         Ok(Self {
             http_client,
             base_url: url,
+            market_flights: Arc::default(),
+            #[cfg(feature = "metrics")]
+            stats,
         })
     }
 
     /// Creates a new Gamma API client with a custom base URL and retry configuration.
     pub fn with_retries(base_url: &str, max_retries: u32) -> Result<Self> {
         let url = Url::parse(base_url)?;
-        let http_client = HttpClientConfig::default()
-            .with_max_retries(max_retries)
+        let config = HttpClientConfig::default().with_max_retries(max_retries);
+        #[cfg(feature = "metrics")]
+        let stats = config.stats.clone();
+        let http_client = config
             .build()
             .map_err(|e| PolymarketError::other(format!("failed to create HTTP client: {}", e)))?;
         Ok(Self {
             http_client,
             base_url: url,
+            market_flights: Arc::default(),
+            #[cfg(feature = "metrics")]
+            stats,
         })
     }
 
@@ -61,6 +84,9 @@ impl Client {
         Self {
             http_client: wrap_with_retry(http_client, DEFAULT_MAX_RETRIES),
             base_url: Url::parse(DEFAULT_BASE_URL).expect("default gamma base URL is valid"),
+            market_flights: Arc::default(),
+            #[cfg(feature = "metrics")]
+            stats: ClientStats::default(),
         }
     }
 
@@ -69,9 +95,19 @@ impl Client {
         Self {
             http_client,
             base_url: Url::parse(DEFAULT_BASE_URL).expect("default gamma base URL is valid"),
+            market_flights: Arc::default(),
+            #[cfg(feature = "metrics")]
+            stats: ClientStats::default(),
         }
     }
 
+    /// Request, retry and latency counters (`metrics` feature); see
+    /// [`ClientStats`] for which clients record them.
+    #[cfg(feature = "metrics")]
+    pub fn stats(&self) -> ClientStatsSnapshot {
+        self.stats.snapshot()
+    }
+
     /// Checks if the response is successful and returns an appropriate error if not.
     pub(super) async fn check_response(&self, response: Response) -> Result<Response> {
         let status = response.status();
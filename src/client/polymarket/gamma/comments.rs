@@ -9,6 +9,8 @@ use crate::error::Result;
 use super::Client;
 use super::events::OptimizedImage;
 use super::helpers::{deserialize_option_u64, validate_comment_parent};
+use crate::client::ValidatableRequest;
+use crate::client::http::deserialize_checked;
 
 /// Comment reaction.
 #[derive(Debug, Clone, Deserialize, Serialize)]
@@ -102,11 +104,17 @@ pub struct GetCommentsRequest<'a> {
     pub holders_only: Option<bool>,
 }
 
+impl ValidatableRequest for GetCommentsRequest<'_> {
+    fn validate(&self) -> Result<()> {
+        validate_comment_parent(self.parent_entity_type, self.parent_entity_id)?;
+        Ok(())
+    }
+}
+
 impl<'a> GetCommentsRequest<'a> {
     /// Validates request parameters.
     pub fn validate(&self) -> Result<()> {
-        validate_comment_parent(self.parent_entity_type, self.parent_entity_id)?;
-        Ok(())
+        ValidatableRequest::validate(self)
     }
 
     pub(crate) fn build_url(&self, base_url: &Url) -> Url {
@@ -156,8 +164,8 @@ pub struct GetCommentsByUserAddressRequest<'a> {
     pub ascending: Option<bool>,
 }
 
-impl<'a> GetCommentsByUserAddressRequest<'a> {
-    pub fn validate(&self) -> Result<()> {
+impl ValidatableRequest for GetCommentsByUserAddressRequest<'_> {
+    fn validate(&self) -> Result<()> {
         if self.user_address.trim().is_empty() {
             return Err(crate::error::PolymarketError::bad_request(
                 "user_address cannot be empty",
@@ -165,6 +173,12 @@ impl<'a> GetCommentsByUserAddressRequest<'a> {
         }
         Ok(())
     }
+}
+
+impl<'a> GetCommentsByUserAddressRequest<'a> {
+    pub fn validate(&self) -> Result<()> {
+        ValidatableRequest::validate(self)
+    }
 
     pub(crate) fn build_url(&self, base_url: &Url) -> Url {
         let mut url = base_url.clone();
@@ -201,7 +215,7 @@ impl Client {
         trace!(url = %url, method = "GET", "sending HTTP request");
         let response = self.http_client.get(url).send().await?;
         let response = self.check_response(response).await?;
-        let comments: Vec<Comment> = response.json().await?;
+        let comments: Vec<Comment> = deserialize_checked(response).await?;
         trace!(count = comments.len(), "received comments");
         Ok(comments)
     }
@@ -223,7 +237,7 @@ impl Client {
         trace!(url = %url, method = "GET", "sending HTTP request");
         let response = self.http_client.get(url).send().await?;
         let response = self.check_response(response).await?;
-        let comment: Comment = response.json().await?;
+        let comment: Comment = deserialize_checked(response).await?;
         trace!(comment_id = %comment.id, "received comment");
         Ok(comment)
     }
@@ -239,7 +253,7 @@ impl Client {
         trace!(url = %url, method = "GET", "sending HTTP request");
         let response = self.http_client.get(url).send().await?;
         let response = self.check_response(response).await?;
-        let comments: Vec<Comment> = response.json().await?;
+        let comments: Vec<Comment> = deserialize_checked(response).await?;
         trace!(count = comments.len(), "received comments");
         Ok(comments)
     }
@@ -0,0 +1,204 @@
+//! Structured diffs between two Gamma market snapshots.
+//!
+//! [`diff_markets`] compares snapshots taken at different times and reports,
+//! per market id, which numeric fields moved and whether the market changed
+//! status. It does no I/O, so alerting tools can run it on cached data.
+
+use std::collections::HashMap;
+
+use serde::Serialize;
+
+use super::markets::Market;
+
+/// Lifecycle status derived from a market's `active`/`closed`/`archived` flags.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum MarketStatus {
+    Active,
+    Inactive,
+    Closed,
+    Archived,
+}
+
+impl MarketStatus {
+    /// Derive the status of a market. `archived` wins over `closed`, which wins over `active`.
+    pub fn of(market: &Market) -> Self {
+        if market.archived == Some(true) {
+            MarketStatus::Archived
+        } else if market.closed == Some(true) {
+            MarketStatus::Closed
+        } else if market.active == Some(true) {
+            MarketStatus::Active
+        } else {
+            MarketStatus::Inactive
+        }
+    }
+}
+
+/// A before/after pair for a changed value.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct Change<T> {
+    pub before: T,
+    pub after: T,
+}
+
+/// A changed numeric field.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct NumericChange {
+    /// Field name as it appears on [`Market`] (e.g. `liquidity_num`).
+    pub field: &'static str,
+    pub before: Option<f64>,
+    pub after: Option<f64>,
+}
+
+impl NumericChange {
+    /// Difference `after - before`, if both values are present.
+    pub fn delta(&self) -> Option<f64> {
+        Some(self.after? - self.before?)
+    }
+}
+
+/// Everything that changed for one market between two snapshots.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct MarketDelta {
+    pub market_id: String,
+    /// Numeric fields whose value changed, in a fixed field order.
+    pub numeric: Vec<NumericChange>,
+    /// Status transition (e.g. active -> closed), if any.
+    pub status: Option<Change<MarketStatus>>,
+    /// End date change, if any.
+    pub end_date: Option<Change<Option<String>>>,
+}
+
+/// Reads one numeric field from a market.
+type FieldGetter = fn(&Market) -> Option<f64>;
+
+/// Numeric fields compared by [`diff_markets`].
+const NUMERIC_FIELDS: &[(&str, FieldGetter)] = &[
+    ("liquidity_num", |m| m.liquidity_num),
+    ("volume_num", |m| m.volume_num),
+    ("volume24hr", |m| m.volume24hr),
+    ("last_trade_price", |m| m.last_trade_price),
+    ("best_bid", |m| m.best_bid),
+    ("best_ask", |m| m.best_ask),
+    ("spread", |m| m.spread),
+];
+
+/// Compare two market snapshots keyed by market id.
+///
+/// Only markets present in both snapshots are compared, and only markets with
+/// at least one change are returned, in the order they appear in `after`.
+pub fn diff_markets(before: &[Market], after: &[Market]) -> Vec<MarketDelta> {
+    let previous: HashMap<&str, &Market> = before.iter().map(|m| (m.id.as_str(), m)).collect();
+
+    after
+        .iter()
+        .filter_map(|current| {
+            let old = previous.get(current.id.as_str())?;
+            diff_market(old, current)
+        })
+        .collect()
+}
+
+fn diff_market(before: &Market, after: &Market) -> Option<MarketDelta> {
+    let numeric: Vec<NumericChange> = NUMERIC_FIELDS
+        .iter()
+        .filter_map(|(field, get)| {
+            let (old, new) = (get(before), get(after));
+            (old != new).then_some(NumericChange {
+                field,
+                before: old,
+                after: new,
+            })
+        })
+        .collect();
+
+    let (old_status, new_status) = (MarketStatus::of(before), MarketStatus::of(after));
+    let status = (old_status != new_status).then_some(Change {
+        before: old_status,
+        after: new_status,
+    });
+
+    let end_date = (before.end_date != after.end_date).then(|| Change {
+        before: before.end_date.clone(),
+        after: after.end_date.clone(),
+    });
+
+    if numeric.is_empty() && status.is_none() && end_date.is_none() {
+        return None;
+    }
+    Some(MarketDelta {
+        market_id: after.id.clone(),
+        numeric,
+        status,
+        end_date,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn market(value: serde_json::Value) -> Market {
+        serde_json::from_value(value).unwrap()
+    }
+
+    #[test]
+    fn reports_liquidity_change() {
+        let before = [market(serde_json::json!({
+            "id": "1", "active": true, "liquidityNum": 1000.0, "volumeNum": 50.0
+        }))];
+        let after = [market(serde_json::json!({
+            "id": "1", "active": true, "liquidityNum": 1500.0, "volumeNum": 50.0
+        }))];
+
+        let deltas = diff_markets(&before, &after);
+        assert_eq!(deltas.len(), 1);
+        assert_eq!(deltas[0].market_id, "1");
+        assert_eq!(
+            deltas[0].numeric,
+            vec![NumericChange {
+                field: "liquidity_num",
+                before: Some(1000.0),
+                after: Some(1500.0),
+            }]
+        );
+        assert_eq!(deltas[0].numeric[0].delta(), Some(500.0));
+        assert!(deltas[0].status.is_none());
+    }
+
+    #[test]
+    fn reports_status_transition() {
+        let before = [market(
+            serde_json::json!({"id": "7", "active": true, "closed": false}),
+        )];
+        let after = [market(
+            serde_json::json!({"id": "7", "active": true, "closed": true}),
+        )];
+
+        let deltas = diff_markets(&before, &after);
+        assert_eq!(deltas.len(), 1);
+        assert_eq!(
+            deltas[0].status,
+            Some(Change {
+                before: MarketStatus::Active,
+                after: MarketStatus::Closed,
+            })
+        );
+        assert!(deltas[0].numeric.is_empty());
+    }
+
+    #[test]
+    fn skips_unchanged_and_unmatched_markets() {
+        let before = [
+            market(serde_json::json!({"id": "1", "liquidityNum": 10.0})),
+            market(serde_json::json!({"id": "2"})),
+        ];
+        let after = [
+            market(serde_json::json!({"id": "1", "liquidityNum": 10.0})),
+            market(serde_json::json!({"id": "3", "liquidityNum": 5.0})),
+        ];
+
+        assert!(diff_markets(&before, &after).is_empty());
+    }
+}
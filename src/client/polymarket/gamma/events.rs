@@ -4,10 +4,13 @@ use serde::{Deserialize, Serialize};
 use tracing::{instrument, trace};
 use url::Url;
 
+use crate::client::ValidatableRequest;
 use crate::error::Result;
 
 use super::Client;
-use super::helpers::{deserialize_option_f64, deserialize_option_u64, validate_tag_id};
+use super::helpers::{
+    deserialize_option_f64, deserialize_option_u64, validate_page_limit, validate_tag_id,
+};
 use super::tags::Tag;
 
 /// Optimized image metadata.
@@ -194,6 +197,7 @@ pub struct EventSummary {
 // Forward declarations for circular dependencies
 use super::markets::Market;
 use super::series::SeriesSummary;
+use crate::client::http::deserialize_checked;
 
 /// Event representation from the Gamma API.
 #[derive(Debug, Clone, Deserialize, Serialize)]
@@ -409,11 +413,14 @@ pub struct GetEventsRequest<'a> {
     pub start_date_max: Option<&'a str>,
     pub end_date_min: Option<&'a str>,
     pub end_date_max: Option<&'a str>,
+    /// Only return these fields (sent as a comma-separated `fields` parameter)
+    /// to reduce payload size. Fields left out deserialize as `None`.
+    pub fields: Option<Vec<String>>,
 }
 
-impl<'a> GetEventsRequest<'a> {
-    /// Validates request parameters before sending.
-    pub fn validate(&self) -> Result<()> {
+impl ValidatableRequest for GetEventsRequest<'_> {
+    fn validate(&self) -> Result<()> {
+        validate_page_limit(self.limit)?;
         if let Some(ids) = &self.id {
             for id in ids {
                 validate_tag_id(Some(id.as_str()))?;
@@ -427,6 +434,13 @@ impl<'a> GetEventsRequest<'a> {
         }
         Ok(())
     }
+}
+
+impl<'a> GetEventsRequest<'a> {
+    /// Validates request parameters before sending.
+    pub fn validate(&self) -> Result<()> {
+        ValidatableRequest::validate(self)
+    }
 
     /// Builds the request URL using the provided base URL.
     pub(crate) fn build_url(&self, base_url: &Url) -> Url {
@@ -518,6 +532,11 @@ impl<'a> GetEventsRequest<'a> {
             if let Some(end_date_max) = self.end_date_max {
                 pairs.append_pair("end_date_max", end_date_max);
             }
+            if let Some(fields) = &self.fields
+                && !fields.is_empty()
+            {
+                pairs.append_pair("fields", &fields.join(","));
+            }
         }
         url
     }
@@ -536,7 +555,7 @@ impl Client {
         trace!(url = %url, method = "GET", "sending HTTP request");
         let response = self.http_client.get(url).send().await?;
         let response = self.check_response(response).await?;
-        let events: Vec<Event> = response.json().await?;
+        let events: Vec<Event> = deserialize_checked(response).await?;
         trace!(count = events.len(), "received events");
         Ok(events)
     }
@@ -562,7 +581,7 @@ impl Client {
         trace!(url = %url, method = "GET", "sending HTTP request");
         let response = self.http_client.get(url).send().await?;
         let response = self.check_response(response).await?;
-        let event: Event = response.json().await?;
+        let event: Event = deserialize_checked(response).await?;
         trace!(event_id = %event.id, "received event");
         Ok(event)
     }
@@ -574,7 +593,7 @@ impl Client {
         trace!(url = %url, method = "GET", "sending HTTP request");
         let response = self.http_client.get(url).send().await?;
         let response = self.check_response(response).await?;
-        let tags: Vec<Tag> = response.json().await?;
+        let tags: Vec<Tag> = deserialize_checked(response).await?;
         trace!(count = tags.len(), "received tags");
         Ok(tags)
     }
@@ -600,7 +619,7 @@ impl Client {
         trace!(url = %url, method = "GET", "sending HTTP request");
         let response = self.http_client.get(url).send().await?;
         let response = self.check_response(response).await?;
-        let event: Event = response.json().await?;
+        let event: Event = deserialize_checked(response).await?;
         trace!(event_id = %event.id, "received event");
         Ok(event)
     }
@@ -632,6 +651,33 @@ mod tests {
         assert!(err.to_string().contains("digits"));
     }
 
+    #[test]
+    fn validate_rejects_out_of_range_limit() {
+        for limit in [0, 1001] {
+            let req = GetEventsRequest {
+                limit: Some(limit),
+                ..Default::default()
+            };
+
+            let err = req.validate().unwrap_err();
+            assert!(err.to_string().contains("between 1 and 1000"));
+        }
+    }
+
+    #[test]
+    fn build_url_includes_fields_projection() {
+        let base = Url::parse("https://example.com").unwrap();
+        let url = GetEventsRequest {
+            fields: Some(vec!["id".to_string(), "slug".to_string()]),
+            ..Default::default()
+        }
+        .build_url(&base);
+        assert_eq!(url.query(), Some("fields=id%2Cslug"));
+
+        let url = GetEventsRequest::default().build_url(&base);
+        assert!(url.query().unwrap_or_default().is_empty());
+    }
+
     #[test]
     fn build_url_includes_filters() {
         let base = Url::parse("https://example.com").unwrap();
@@ -91,6 +91,23 @@ where
     }
 }
 
+/// Maximum page size accepted by Gamma list endpoints.
+pub(crate) const MAX_PAGE_LIMIT: u32 = 1000;
+
+/// Validates a Gamma page size (1-1000).
+///
+/// Offsets are unsigned, so they need no further check.
+pub(crate) fn validate_page_limit(limit: Option<u32>) -> Result<()> {
+    if let Some(limit) = limit
+        && !(1..=MAX_PAGE_LIMIT).contains(&limit)
+    {
+        return Err(PolymarketError::bad_request(format!(
+            "limit must be between 1 and {MAX_PAGE_LIMIT}"
+        )));
+    }
+    Ok(())
+}
+
 /// Validates numeric tag IDs (all digits).
 pub(crate) fn validate_tag_id(tag_id: Option<&str>) -> Result<()> {
     if let Some(id) = tag_id {
@@ -107,6 +124,19 @@ pub(crate) fn validate_tag_id(tag_id: Option<&str>) -> Result<()> {
     Ok(())
 }
 
+/// Validates numeric series IDs (all digits).
+pub(crate) fn validate_series_id(id: &str) -> Result<()> {
+    if id.trim().is_empty() {
+        return Err(PolymarketError::bad_request("series id cannot be empty"));
+    }
+    if !id.chars().all(|c| c.is_ascii_digit()) {
+        return Err(PolymarketError::bad_request(
+            "series id must contain only digits",
+        ));
+    }
+    Ok(())
+}
+
 const ALLOWED_COMMENT_ENTITY_TYPES: [&str; 3] = ["Event", "Series", "market"];
 
 /// Validates the required parent entity filters for comments.
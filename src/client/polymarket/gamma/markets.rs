@@ -9,9 +9,16 @@ use crate::error::{PolymarketError, Result};
 use super::Client;
 use super::events::{Category, Collection, Event, OptimizedImage};
 use super::helpers::{
-    deserialize_option_f64, deserialize_option_i64, deserialize_option_u64, validate_tag_id,
+    deserialize_option_f64, deserialize_option_i64, deserialize_option_u64, validate_page_limit,
+    validate_tag_id,
 };
 use super::tags::Tag;
+use crate::client::ValidatableRequest;
+use crate::client::http::deserialize_checked;
+use crate::client::pagination::{DEFAULT_MAX_PAGES, collect_offset_pages};
+
+/// Page size used by [`Client::get_markets_for_game`].
+const GAME_MARKETS_PAGE_SIZE: u32 = 100;
 
 /// Market representation from the Gamma API.
 #[derive(Debug, Clone, Deserialize, Serialize)]
@@ -31,7 +38,9 @@ pub struct Market {
     pub category: Option<String>,
     #[serde(alias = "ammType")]
     pub amm_type: Option<String>,
-    pub liquidity: Option<String>,
+    /// Sent as either a JSON number or a numeric string.
+    #[serde(default, deserialize_with = "deserialize_option_f64")]
+    pub liquidity: Option<f64>,
     #[serde(alias = "sponsorName")]
     pub sponsor_name: Option<String>,
     #[serde(alias = "sponsorImage")]
@@ -55,7 +64,9 @@ pub struct Market {
     pub outcomes: Option<String>,
     #[serde(alias = "outcomePrices")]
     pub outcome_prices: Option<String>,
-    pub volume: Option<String>,
+    /// Sent as either a JSON number or a numeric string.
+    #[serde(default, deserialize_with = "deserialize_option_f64")]
+    pub volume: Option<f64>,
     pub active: Option<bool>,
     #[serde(alias = "marketType")]
     pub market_type: Option<String>,
@@ -407,6 +418,136 @@ pub struct Market {
     pub event_start_time: Option<String>,
 }
 
+/// UMA optimistic-oracle status of a market's resolution.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum UmaResolutionStatus {
+    /// An outcome has been proposed and is in its challenge window.
+    Proposed,
+    /// A proposal was challenged and the question was reset.
+    Challenged,
+    /// A proposal was disputed and escalated to a UMA vote.
+    Disputed,
+    /// The outcome is final.
+    Resolved,
+    /// A status this client does not know about yet.
+    Other(String),
+}
+
+impl UmaResolutionStatus {
+    pub fn as_str(&self) -> &str {
+        match self {
+            UmaResolutionStatus::Proposed => "proposed",
+            UmaResolutionStatus::Challenged => "challenged",
+            UmaResolutionStatus::Disputed => "disputed",
+            UmaResolutionStatus::Resolved => "resolved",
+            UmaResolutionStatus::Other(status) => status,
+        }
+    }
+}
+
+impl std::fmt::Display for UmaResolutionStatus {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl From<&str> for UmaResolutionStatus {
+    fn from(value: &str) -> Self {
+        match value.trim().to_ascii_lowercase().as_str() {
+            "proposed" => UmaResolutionStatus::Proposed,
+            "challenged" => UmaResolutionStatus::Challenged,
+            "disputed" => UmaResolutionStatus::Disputed,
+            "resolved" => UmaResolutionStatus::Resolved,
+            _ => UmaResolutionStatus::Other(value.to_string()),
+        }
+    }
+}
+
+/// Kind of a sports market, from [`Market::sports_market_type`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SportsMarketType {
+    /// Which side wins the game.
+    Moneyline,
+    /// Whether a side wins by more than the market's `line`.
+    Spreads,
+    /// Whether the combined score goes over the market's `line`.
+    Totals,
+    /// A type this client does not know about yet.
+    Other(String),
+}
+
+impl SportsMarketType {
+    pub fn as_str(&self) -> &str {
+        match self {
+            SportsMarketType::Moneyline => "moneyline",
+            SportsMarketType::Spreads => "spreads",
+            SportsMarketType::Totals => "totals",
+            SportsMarketType::Other(market_type) => market_type,
+        }
+    }
+}
+
+impl std::fmt::Display for SportsMarketType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl From<&str> for SportsMarketType {
+    fn from(value: &str) -> Self {
+        match value.trim().to_ascii_lowercase().as_str() {
+            "moneyline" => SportsMarketType::Moneyline,
+            "spreads" => SportsMarketType::Spreads,
+            "totals" => SportsMarketType::Totals,
+            _ => SportsMarketType::Other(value.to_string()),
+        }
+    }
+}
+
+/// Keep only the sports markets (see [`Market::is_sports`]), e.g. of a
+/// [`Client::get_markets`] result.
+pub fn sports_only(markets: impl IntoIterator<Item = Market>) -> Vec<Market> {
+    markets
+        .into_iter()
+        .filter(|market| market.is_sports())
+        .collect()
+}
+
+impl Market {
+    /// Whether the market belongs to a sports game, i.e. has a
+    /// [`game_id`](Self::game_id) or a
+    /// [`sports_market_type`](Self::sports_market_type).
+    pub fn is_sports(&self) -> bool {
+        let set = |field: &Option<String>| field.as_deref().is_some_and(|v| !v.trim().is_empty());
+        set(&self.game_id) || set(&self.sports_market_type)
+    }
+
+    /// Typed [`sports_market_type`](Self::sports_market_type), if set.
+    pub fn sports_type(&self) -> Option<SportsMarketType> {
+        self.sports_market_type
+            .as_deref()
+            .filter(|market_type| !market_type.trim().is_empty())
+            .map(SportsMarketType::from)
+    }
+
+    /// Typed [`uma_resolution_status`](Self::uma_resolution_status), if set.
+    pub fn uma_status(&self) -> Option<UmaResolutionStatus> {
+        self.uma_resolution_status
+            .as_deref()
+            .filter(|status| !status.trim().is_empty())
+            .map(UmaResolutionStatus::from)
+    }
+
+    /// Whether the current resolution proposal has been challenged or
+    /// disputed, i.e. the outcome is contested.
+    pub fn is_disputed(&self) -> bool {
+        matches!(
+            self.uma_status(),
+            Some(UmaResolutionStatus::Challenged | UmaResolutionStatus::Disputed)
+        )
+    }
+}
+
 /// Request parameters for listing markets.
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct GetMarketsRequest<'a> {
@@ -437,21 +578,23 @@ pub struct GetMarketsRequest<'a> {
     pub question_ids: Option<Vec<String>>,
     pub include_tag: Option<bool>,
     pub closed: Option<bool>,
+    /// Only return these fields (sent as a comma-separated `fields` parameter)
+    /// to reduce payload size. Fields left out deserialize as `None`.
+    pub fields: Option<Vec<String>>,
+}
+
+impl ValidatableRequest for GetMarketsRequest<'_> {
+    fn validate(&self) -> Result<()> {
+        validate_page_limit(self.limit)?;
+        validate_tag_id(self.tag_id)?;
+        Ok(())
+    }
 }
 
 impl<'a> GetMarketsRequest<'a> {
     /// Validates request parameters before sending.
     pub fn validate(&self) -> Result<()> {
-        if let Some(limit) = self.limit
-            && !(1..=1000).contains(&limit)
-        {
-            return Err(PolymarketError::bad_request(
-                "limit must be between 1 and 1000",
-            ));
-        }
-
-        validate_tag_id(self.tag_id)?;
-        Ok(())
+        ValidatableRequest::validate(self)
     }
 
     /// Builds the request URL using the provided base URL.
@@ -555,6 +698,11 @@ impl<'a> GetMarketsRequest<'a> {
             if let Some(closed) = self.closed {
                 pairs.append_pair("closed", &closed.to_string());
             }
+            if let Some(fields) = &self.fields
+                && !fields.is_empty()
+            {
+                pairs.append_pair("fields", &fields.join(","));
+            }
         }
         url
     }
@@ -573,14 +721,60 @@ impl Client {
         trace!(url = %url, method = "GET", "sending HTTP request");
         let response = self.http_client.get(url).send().await?;
         let response = self.check_response(response).await?;
-        let markets: Vec<Market> = response.json().await?;
+        let markets: Vec<Market> = deserialize_checked(response).await?;
         trace!(count = markets.len(), "received markets");
         Ok(markets)
     }
 
+    /// Lists every market for a sports game, following pagination.
+    ///
+    /// `sports_market_types` optionally narrows the result (e.g. `moneyline`).
+    #[instrument(skip(self, sports_market_types), fields(game_id = %game_id), level = "trace")]
+    pub async fn get_markets_for_game(
+        &self,
+        game_id: &str,
+        sports_market_types: Option<Vec<String>>,
+    ) -> Result<Vec<Market>> {
+        if game_id.trim().is_empty() {
+            return Err(PolymarketError::bad_request("game_id cannot be empty"));
+        }
+        let markets = collect_offset_pages(
+            GAME_MARKETS_PAGE_SIZE as usize,
+            0,
+            DEFAULT_MAX_PAGES,
+            |offset| {
+                self.get_markets(GetMarketsRequest {
+                    limit: Some(GAME_MARKETS_PAGE_SIZE),
+                    offset: Some(offset as u32),
+                    game_id: Some(game_id),
+                    sports_market_types: sports_market_types.clone(),
+                    ..Default::default()
+                })
+            },
+        )
+        .await?;
+        trace!(count = markets.len(), "received game markets");
+        Ok(markets)
+    }
+
+    /// Gets a single market by ID from `/markets/{id}`.
+    ///
+    /// Shorthand for [`get_market_by_id`](Self::get_market_by_id) without tags.
+    pub async fn get_market(&self, id: &str) -> Result<Market> {
+        self.get_market_by_id(id, None).await
+    }
+
     /// Gets a market by its ID.
+    ///
+    /// Returns [`PolymarketError::NotFound`] if no market has this ID, and
+    /// [`PolymarketError::BadRequest`] for an empty ID. Releases up to 0.0.7
+    /// surfaced a missing market as [`PolymarketError::Api`]; see
+    /// `CHANGELOG.md`.
     #[instrument(skip(self), fields(id = %id), level = "trace")]
     pub async fn get_market_by_id(&self, id: &str, include_tag: Option<bool>) -> Result<Market> {
+        if id.trim().is_empty() {
+            return Err(PolymarketError::bad_request("market id cannot be empty"));
+        }
         let mut url = self.build_url(&format!("markets/{}", id));
         {
             let mut pairs = url.query_pairs_mut();
@@ -588,12 +782,8 @@ impl Client {
                 pairs.append_pair("include_tag", &include_tag.to_string());
             }
         }
-        trace!(url = %url, method = "GET", "sending HTTP request");
-        let response = self.http_client.get(url).send().await?;
-        let response = self.check_response(response).await?;
-        let market: Market = response.json().await?;
-        trace!(market_id = %market.id, "received market");
-        Ok(market)
+        self.fetch_market(url, Some(format!("market '{}'", id)))
+            .await
     }
 
     /// Lists tags attached to a market by ID.
@@ -603,7 +793,7 @@ impl Client {
         trace!(url = %url, method = "GET", "sending HTTP request");
         let response = self.http_client.get(url).send().await?;
         let response = self.check_response(response).await?;
-        let tags: Vec<Tag> = response.json().await?;
+        let tags: Vec<Tag> = deserialize_checked(response).await?;
         trace!(count = tags.len(), "received tags");
         Ok(tags)
     }
@@ -622,18 +812,246 @@ impl Client {
                 pairs.append_pair("include_tag", &include_tag.to_string());
             }
         }
-        trace!(url = %url, method = "GET", "sending HTTP request");
-        let response = self.http_client.get(url).send().await?;
-        let response = self.check_response(response).await?;
-        let market: Market = response.json().await?;
-        trace!(market_id = %market.id, "received market");
-        Ok(market)
+        self.fetch_market(url, None).await
+    }
+
+    /// Fetches a single market from `url`.
+    ///
+    /// Concurrent calls for the same URL share one upstream request. A 404 is
+    /// reported as [`PolymarketError::NotFound`] when `not_found` is given;
+    /// the mapping is part of the coalescing key so callers only share a
+    /// request whose errors they would have gotten themselves.
+    async fn fetch_market(&self, url: Url, not_found: Option<String>) -> Result<Market> {
+        let client = self.clone();
+        let key = (url.clone(), not_found.clone());
+        self.market_flights
+            .run(key, async move {
+                trace!(url = %url, method = "GET", "sending HTTP request");
+                let response = client.http_client.get(url).send().await?;
+                if let Some(what) = not_found
+                    && response.status() == reqwest::StatusCode::NOT_FOUND
+                {
+                    return Err(PolymarketError::not_found(what));
+                }
+                let response = client.check_response(response).await?;
+                let market: Market = deserialize_checked(response).await?;
+                trace!(market_id = %market.id, "received market");
+                Ok(market)
+            })
+            .await
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use wiremock::matchers::{method, path, query_param};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    #[tokio::test]
+    async fn get_market_returns_single_market() {
+        let mock_server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/markets/12345"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "id": "12345",
+                "question": "Will it rain tomorrow?",
+                "active": true,
+                "liquidityNum": 1500.5
+            })))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        let client = Client::with_base_url(&mock_server.uri()).unwrap();
+        let market = client.get_market("12345").await.unwrap();
+        assert_eq!(market.id, "12345");
+        assert_eq!(market.question.as_deref(), Some("Will it rain tomorrow?"));
+        assert_eq!(market.liquidity_num, Some(1500.5));
+    }
+
+    #[tokio::test]
+    async fn concurrent_get_market_calls_share_one_request() {
+        let mock_server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/markets/12345"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .set_body_json(serde_json::json!({ "id": "12345" }))
+                    .set_delay(std::time::Duration::from_millis(100)),
+            )
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        let client = Client::with_base_url(&mock_server.uri()).unwrap();
+        let results =
+            futures_util::future::join_all((0..10).map(|_| client.get_market("12345"))).await;
+        for result in results {
+            assert_eq!(result.unwrap().id, "12345");
+        }
+        assert_eq!(mock_server.received_requests().await.unwrap().len(), 1);
+    }
+
+    #[tokio::test]
+    async fn get_markets_for_game_collects_all_markets() {
+        let mock_server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/markets"))
+            .and(query_param("game_id", "nba-123"))
+            .and(query_param("sports_market_types", "moneyline"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!([
+                { "id": "1", "gameId": "nba-123" },
+                { "id": "2", "gameId": "nba-123" }
+            ])))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        let client = Client::with_base_url(&mock_server.uri()).unwrap();
+        let markets = client
+            .get_markets_for_game("nba-123", Some(vec!["moneyline".to_string()]))
+            .await
+            .unwrap();
+        let ids: Vec<_> = markets.iter().map(|m| m.id.as_str()).collect();
+        assert_eq!(ids, ["1", "2"]);
+        assert!(client.get_markets_for_game(" ", None).await.is_err());
+    }
+
+    #[test]
+    fn liquidity_and_volume_accept_strings_and_numbers() {
+        let from_strings: Market = serde_json::from_value(serde_json::json!({
+            "id": "1",
+            "liquidity": "12345.6",
+            "volume": " 789 "
+        }))
+        .unwrap();
+        assert_eq!(from_strings.liquidity, Some(12345.6));
+        assert_eq!(from_strings.volume, Some(789.0));
+
+        let from_numbers: Market = serde_json::from_value(serde_json::json!({
+            "id": "1",
+            "liquidity": 12345.6,
+            "volume": 789
+        }))
+        .unwrap();
+        assert_eq!(from_numbers.liquidity, Some(12345.6));
+        assert_eq!(from_numbers.volume, Some(789.0));
+
+        let missing: Market = serde_json::from_value(serde_json::json!({
+            "id": "1",
+            "liquidity": "",
+            "volume": null
+        }))
+        .unwrap();
+        assert_eq!(missing.liquidity, None);
+        assert_eq!(missing.volume, None);
+    }
+
+    #[test]
+    fn disputed_market_status_is_typed() {
+        let disputed: Market = serde_json::from_value(serde_json::json!({
+            "id": "1",
+            "umaResolutionStatus": "disputed",
+            "umaResolutionStatuses": "[\"proposed\", \"disputed\"]"
+        }))
+        .unwrap();
+        assert_eq!(disputed.uma_status(), Some(UmaResolutionStatus::Disputed));
+        assert!(disputed.is_disputed());
+
+        let resolved: Market = serde_json::from_value(serde_json::json!({
+            "id": "2",
+            "umaResolutionStatus": "resolved"
+        }))
+        .unwrap();
+        assert_eq!(resolved.uma_status(), Some(UmaResolutionStatus::Resolved));
+        assert!(!resolved.is_disputed());
+
+        let unset: Market = serde_json::from_value(serde_json::json!({"id": "3"})).unwrap();
+        assert_eq!(unset.uma_status(), None);
+        assert!(!unset.is_disputed());
+    }
+
+    #[test]
+    fn sports_markets_are_classified() {
+        let game: Market = serde_json::from_value(serde_json::json!({
+            "id": "1",
+            "gameId": "nba-lal-bos-2025-01-01",
+            "sportsMarketType": "spreads",
+            "line": -4.5
+        }))
+        .unwrap();
+        assert!(game.is_sports());
+        assert_eq!(game.sports_type(), Some(SportsMarketType::Spreads));
+
+        let election: Market = serde_json::from_value(serde_json::json!({
+            "id": "2",
+            "question": "Who will win the election?",
+            "gameId": ""
+        }))
+        .unwrap();
+        assert!(!election.is_sports());
+        assert_eq!(election.sports_type(), None);
+
+        let prop: Market = serde_json::from_value(serde_json::json!({
+            "id": "3",
+            "sportsMarketType": "player_points"
+        }))
+        .unwrap();
+        assert_eq!(
+            prop.sports_type(),
+            Some(SportsMarketType::Other("player_points".to_string()))
+        );
+
+        let ids: Vec<String> = sports_only([game, election, prop])
+            .into_iter()
+            .map(|market| market.id)
+            .collect();
+        assert_eq!(ids, vec!["1", "3"]);
+    }
+
+    #[tokio::test]
+    async fn get_market_maps_404_to_not_found() {
+        let mock_server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/markets/999"))
+            .respond_with(ResponseTemplate::new(404).set_body_string("not found"))
+            .mount(&mock_server)
+            .await;
+
+        let client = Client::with_base_url(&mock_server.uri()).unwrap();
+        let err = client.get_market("999").await.unwrap_err();
+        assert!(matches!(err, PolymarketError::NotFound(_)), "got {err:?}");
+    }
+
+    #[tokio::test]
+    async fn get_market_and_get_market_by_id_share_not_found() {
+        let mock_server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/markets/999"))
+            .respond_with(
+                ResponseTemplate::new(404)
+                    .set_body_string("not found")
+                    .set_delay(std::time::Duration::from_millis(100)),
+            )
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        let client = Client::with_base_url(&mock_server.uri()).unwrap();
+        let (short, by_id) = tokio::join!(
+            client.get_market("999"),
+            client.get_market_by_id("999", None)
+        );
+
+        for result in [short, by_id] {
+            let err = result.unwrap_err();
+            assert!(
+                matches!(err.unshared(), PolymarketError::NotFound(_)),
+                "got {err:?}"
+            );
+        }
+    }
 
     #[test]
     fn validate_rejects_invalid_tag_id() {
@@ -675,13 +1093,43 @@ mod tests {
     }
 
     #[test]
-    fn validate_rejects_invalid_limit() {
-        let req = GetMarketsRequest {
-            limit: Some(0),
+    fn build_url_includes_fields_projection() {
+        let base = Url::parse("https://example.com").unwrap();
+        let url = GetMarketsRequest {
+            limit: Some(5),
+            fields: Some(vec![
+                "id".to_string(),
+                "question".to_string(),
+                "liquidityNum".to_string(),
+            ]),
             ..Default::default()
-        };
+        }
+        .build_url(&base);
 
-        let err = req.validate().unwrap_err();
-        assert!(err.to_string().contains("limit"));
+        let query = url.query().unwrap_or_default();
+        assert!(
+            query.contains("fields=id%2Cquestion%2CliquidityNum"),
+            "missing fields in query: {query}"
+        );
+    }
+
+    #[test]
+    fn validate_rejects_invalid_limit() {
+        for limit in [0, 1001] {
+            let req = GetMarketsRequest {
+                limit: Some(limit),
+                ..Default::default()
+            };
+
+            let err = req.validate().unwrap_err();
+            assert!(err.to_string().contains("limit"));
+        }
+        for limit in [1, 1000] {
+            let req = GetMarketsRequest {
+                limit: Some(limit),
+                ..Default::default()
+            };
+            assert!(req.validate().is_ok());
+        }
     }
 }
@@ -11,6 +11,8 @@ use super::comments::CommentProfile;
 use super::events::Event;
 use super::helpers::validate_tag_id;
 use super::tags::Tag;
+use crate::client::ValidatableRequest;
+use crate::client::http::deserialize_checked;
 
 /// Flexible search response container.
 #[derive(Debug, Clone, Deserialize, Serialize)]
@@ -50,9 +52,8 @@ pub struct SearchRequest<'a> {
     pub optimized: Option<bool>,
 }
 
-impl<'a> SearchRequest<'a> {
-    /// Validates request parameters.
-    pub fn validate(&self) -> Result<()> {
+impl ValidatableRequest for SearchRequest<'_> {
+    fn validate(&self) -> Result<()> {
         if self.q.trim().is_empty() {
             return Err(crate::error::PolymarketError::bad_request(
                 "query cannot be empty",
@@ -65,6 +66,13 @@ impl<'a> SearchRequest<'a> {
         }
         Ok(())
     }
+}
+
+impl<'a> SearchRequest<'a> {
+    /// Validates request parameters.
+    pub fn validate(&self) -> Result<()> {
+        ValidatableRequest::validate(self)
+    }
 
     pub(crate) fn build_url(&self, base_url: &Url) -> Url {
         let mut url = base_url.clone();
@@ -133,7 +141,7 @@ impl Client {
         trace!(url = %url, method = "GET", "sending HTTP request");
         let response = self.http_client.get(url).send().await?;
         let response = self.check_response(response).await?;
-        let results: SearchResults = response.json().await?;
+        let results: SearchResults = deserialize_checked(response).await?;
         trace!("received search results");
         Ok(results)
     }
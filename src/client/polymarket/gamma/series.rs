@@ -8,11 +8,16 @@ use crate::error::{PolymarketError, Result};
 
 use super::Client;
 use super::events::{Category, Collection, EventChat};
-use super::helpers::{deserialize_option_f64, deserialize_option_i64, deserialize_option_u64};
+use super::helpers::{
+    deserialize_option_f64, deserialize_option_i64, deserialize_option_u64, validate_page_limit,
+    validate_series_id,
+};
 use super::tags::Tag;
 
 // Forward declaration
 use super::events::Event;
+use crate::client::ValidatableRequest;
+use crate::client::http::deserialize_checked;
 
 /// A Gamma series, often grouping recurring events or markets.
 #[derive(Debug, Clone, Deserialize, Serialize)]
@@ -79,6 +84,15 @@ pub struct Series {
 /// Lightweight series representation for nested responses.
 pub type SeriesSummary = Series;
 
+/// A series with its events split out, from [`Client::get_series_detail`].
+#[derive(Debug, Clone)]
+pub struct SeriesDetail {
+    /// The series; its `events` field is always `None`.
+    pub series: Series,
+    /// Events of the series, empty unless requested.
+    pub events: Vec<Event>,
+}
+
 /// Request parameters for listing series.
 #[derive(Debug, Clone, Default)]
 pub struct GetSeriesRequest<'a> {
@@ -94,9 +108,9 @@ pub struct GetSeriesRequest<'a> {
     pub recurrence: Option<&'a str>,
 }
 
-impl<'a> GetSeriesRequest<'a> {
-    /// Validates request parameters before sending.
-    pub fn validate(&self) -> Result<()> {
+impl ValidatableRequest for GetSeriesRequest<'_> {
+    fn validate(&self) -> Result<()> {
+        validate_page_limit(self.limit)?;
         if let Some(slug) = self.slug
             && slug.trim().is_empty()
         {
@@ -104,6 +118,13 @@ impl<'a> GetSeriesRequest<'a> {
         }
         Ok(())
     }
+}
+
+impl<'a> GetSeriesRequest<'a> {
+    /// Validates request parameters before sending.
+    pub fn validate(&self) -> Result<()> {
+        ValidatableRequest::validate(self)
+    }
 
     /// Builds the request URL using the provided base URL.
     pub(crate) fn build_url(&self, base_url: &Url) -> Url {
@@ -158,11 +179,12 @@ impl Client {
     /// Lists series with optional filters.
     #[instrument(skip(self, request), level = "trace")]
     pub async fn get_series(&self, request: GetSeriesRequest<'_>) -> Result<Vec<Series>> {
+        request.validate()?;
         let url = request.build_url(&self.base_url);
         trace!(url = %url, method = "GET", "sending HTTP request");
         let response = self.http_client.get(url).send().await?;
         let response = self.check_response(response).await?;
-        let series: Vec<Series> = response.json().await?;
+        let series: Vec<Series> = deserialize_checked(response).await?;
         trace!(count = series.len(), "received series");
         Ok(series)
     }
@@ -180,8 +202,72 @@ impl Client {
         trace!(url = %url, method = "GET", "sending HTTP request");
         let response = self.http_client.get(url).send().await?;
         let response = self.check_response(response).await?;
-        let series: Series = response.json().await?;
+        let series: Series = deserialize_checked(response).await?;
         trace!(series_id = %series.id, "received series");
         Ok(series)
     }
+
+    /// Gets a series by its numeric ID, optionally with its events.
+    ///
+    /// Events are returned in [`SeriesDetail::events`] when `include_events`
+    /// is true and dropped otherwise.
+    #[instrument(skip(self), fields(id = %id), level = "trace")]
+    pub async fn get_series_detail(&self, id: &str, include_events: bool) -> Result<SeriesDetail> {
+        validate_series_id(id)?;
+        let mut series = self.get_series_by_id(id, None).await?;
+        let events = series.events.take().unwrap_or_default();
+        Ok(SeriesDetail {
+            series,
+            events: if include_events { events } else { Vec::new() },
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use wiremock::matchers::{method, path};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    #[tokio::test]
+    async fn get_series_detail_parses_nested_events() {
+        let mock_server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/series/10345"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "id": "10345",
+                "slug": "nba-2026",
+                "title": "NBA 2026",
+                "volume": "125000.5",
+                "events": [
+                    {
+                        "id": "901",
+                        "slug": "lal-vs-bos",
+                        "title": "Lakers vs Celtics",
+                        "markets": [{"id": "5501", "question": "Will the Lakers win?"}]
+                    },
+                    {"id": "902", "slug": "nyk-vs-mia", "title": "Knicks vs Heat"}
+                ]
+            })))
+            .expect(2)
+            .mount(&mock_server)
+            .await;
+
+        let client = Client::with_base_url(&mock_server.uri()).unwrap();
+        let detail = client.get_series_detail("10345", true).await.unwrap();
+
+        assert_eq!(detail.series.slug.as_deref(), Some("nba-2026"));
+        assert_eq!(detail.series.volume, Some(125000.5));
+        assert!(detail.series.events.is_none());
+        let event_ids: Vec<&str> = detail.events.iter().map(|e| e.id.as_str()).collect();
+        assert_eq!(event_ids, ["901", "902"]);
+        let markets = detail.events[0].markets.as_ref().unwrap();
+        assert_eq!(markets[0].question.as_deref(), Some("Will the Lakers win?"));
+
+        let without = client.get_series_detail("10345", false).await.unwrap();
+        assert!(without.events.is_empty());
+
+        assert!(client.get_series_detail("abc", true).await.is_err());
+        assert!(client.get_series_detail(" ", true).await.is_err());
+    }
 }
@@ -6,6 +6,9 @@ use tracing::{instrument, trace};
 use crate::error::Result;
 
 use super::Client;
+use super::helpers::validate_page_limit;
+use crate::client::ValidatableRequest;
+use crate::client::http::deserialize_checked;
 
 /// Team representation returned by the Gamma API.
 #[derive(Debug, Clone, Deserialize, Serialize)]
@@ -89,6 +92,12 @@ impl<'a> GetTeamsRequest<'a> {
     }
 }
 
+impl ValidatableRequest for GetTeamsRequest<'_> {
+    fn validate(&self) -> Result<()> {
+        validate_page_limit(self.limit)
+    }
+}
+
 // -----------------------------------------------------------------------------
 // Client implementation
 // -----------------------------------------------------------------------------
@@ -97,11 +106,12 @@ impl Client {
     /// Lists sports teams with optional filters.
     #[instrument(skip(self, request), level = "trace")]
     pub async fn get_teams(&self, request: GetTeamsRequest<'_>) -> Result<Vec<Team>> {
+        request.validate()?;
         let url = request.build_url(&self.base_url);
         trace!(url = %url, method = "GET", "sending HTTP request");
         let response = self.http_client.get(url).send().await?;
         let response = self.check_response(response).await?;
-        let teams: Vec<Team> = response.json().await?;
+        let teams: Vec<Team> = deserialize_checked(response).await?;
         trace!(count = teams.len(), "received teams");
         Ok(teams)
     }
@@ -113,7 +123,7 @@ impl Client {
         trace!(url = %url, method = "GET", "sending HTTP request");
         let response = self.http_client.get(url).send().await?;
         let response = self.check_response(response).await?;
-        let sports: Vec<SportMetadata> = response.json().await?;
+        let sports: Vec<SportMetadata> = deserialize_checked(response).await?;
         trace!(count = sports.len(), "received sports");
         Ok(sports)
     }
@@ -7,6 +7,9 @@ use url::Url;
 use crate::error::Result;
 
 use super::Client;
+use super::helpers::validate_page_limit;
+use crate::client::ValidatableRequest;
+use crate::client::http::deserialize_checked;
 
 /// Tag representation from the Gamma API.
 #[derive(Debug, Clone, Deserialize, Serialize)]
@@ -129,6 +132,12 @@ impl<'a> GetTagsRequest<'a> {
     }
 }
 
+impl ValidatableRequest for GetTagsRequest<'_> {
+    fn validate(&self) -> Result<()> {
+        validate_page_limit(self.limit)
+    }
+}
+
 // -----------------------------------------------------------------------------
 // Client implementation
 // -----------------------------------------------------------------------------
@@ -137,11 +146,12 @@ impl Client {
     /// Lists tags with optional pagination.
     #[instrument(skip(self, request), level = "trace")]
     pub async fn get_tags(&self, request: GetTagsRequest<'_>) -> Result<Vec<Tag>> {
+        request.validate()?;
         let url = request.build_url(&self.base_url);
         trace!(url = %url, method = "GET", "sending HTTP request");
         let response = self.http_client.get(url).send().await?;
         let response = self.check_response(response).await?;
-        let tags: Vec<Tag> = response.json().await?;
+        let tags: Vec<Tag> = deserialize_checked(response).await?;
         trace!(count = tags.len(), "received tags");
         Ok(tags)
     }
@@ -153,7 +163,7 @@ impl Client {
         trace!(url = %url, method = "GET", "sending HTTP request");
         let response = self.http_client.get(url).send().await?;
         let response = self.check_response(response).await?;
-        let tag: Tag = response.json().await?;
+        let tag: Tag = deserialize_checked(response).await?;
         trace!(tag_id = %tag.id, "received tag");
         Ok(tag)
     }
@@ -171,7 +181,7 @@ impl Client {
         trace!(url = %url, method = "GET", "sending HTTP request");
         let response = self.http_client.get(url).send().await?;
         let response = self.check_response(response).await?;
-        let tag: Tag = response.json().await?;
+        let tag: Tag = deserialize_checked(response).await?;
         trace!(tag_id = %tag.id, "received tag");
         Ok(tag)
     }
@@ -197,7 +207,7 @@ impl Client {
         trace!(url = %url, method = "GET", "sending HTTP request");
         let response = self.http_client.get(url).send().await?;
         let response = self.check_response(response).await?;
-        let relationships: Vec<TagRelationship> = response.json().await?;
+        let relationships: Vec<TagRelationship> = deserialize_checked(response).await?;
         trace!(count = relationships.len(), "received tag relationships");
         Ok(relationships)
     }
@@ -223,7 +233,7 @@ impl Client {
         trace!(url = %url, method = "GET", "sending HTTP request");
         let response = self.http_client.get(url).send().await?;
         let response = self.check_response(response).await?;
-        let relationships: Vec<TagRelationship> = response.json().await?;
+        let relationships: Vec<TagRelationship> = deserialize_checked(response).await?;
         trace!(count = relationships.len(), "received tag relationships");
         Ok(relationships)
     }
@@ -249,7 +259,7 @@ impl Client {
         trace!(url = %url, method = "GET", "sending HTTP request");
         let response = self.http_client.get(url).send().await?;
         let response = self.check_response(response).await?;
-        let tags: Vec<Tag> = response.json().await?;
+        let tags: Vec<Tag> = deserialize_checked(response).await?;
         trace!(count = tags.len(), "received related tags");
         Ok(tags)
     }
@@ -275,7 +285,7 @@ impl Client {
         trace!(url = %url, method = "GET", "sending HTTP request");
         let response = self.http_client.get(url).send().await?;
         let response = self.check_response(response).await?;
-        let tags: Vec<Tag> = response.json().await?;
+        let tags: Vec<Tag> = deserialize_checked(response).await?;
         trace!(count = tags.len(), "received related tags");
         Ok(tags)
     }
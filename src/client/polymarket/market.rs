@@ -0,0 +1,119 @@
+//! Outcome classification shared across Polymarket APIs.
+//!
+//! Polymarket exposes outcomes as free-form strings (`"Yes"`/`"No"` for binary
+//! markets, team or candidate names otherwise). These helpers map an outcome
+//! list to a typed [`MarketKind`] so callers don't have to hardcode parsing.
+
+use serde::{Deserialize, Serialize};
+
+/// Shape of a market's outcome set.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum MarketKind {
+    /// A Yes/No market, with the index of each side in the outcome list.
+    Binary { yes_index: usize, no_index: usize },
+    /// Any other outcome set (multi-candidate, or two named teams).
+    Categorical { count: usize },
+}
+
+impl MarketKind {
+    /// Returns true for Yes/No markets.
+    pub fn is_binary(&self) -> bool {
+        matches!(self, MarketKind::Binary { .. })
+    }
+}
+
+/// Classify a list of outcomes as binary (Yes/No) or categorical.
+///
+/// A market is binary only when it has exactly two outcomes and they are
+/// "Yes" and "No" (case-insensitive, in either order). Two-outcome markets
+/// with other labels, such as team names, are categorical.
+///
+/// # Example
+///
+/// ```
+/// use polymarket_hft::client::polymarket::market::{MarketKind, classify_outcomes};
+///
+/// let outcomes = vec!["Yes".to_string(), "No".to_string()];
+/// assert_eq!(
+///     classify_outcomes(&outcomes),
+///     MarketKind::Binary { yes_index: 0, no_index: 1 }
+/// );
+/// ```
+pub fn classify_outcomes(outcomes: &[String]) -> MarketKind {
+    if outcomes.len() == 2
+        && let (Some(yes_index), Some(no_index)) = (yes_index(outcomes), no_index(outcomes))
+    {
+        return MarketKind::Binary {
+            yes_index,
+            no_index,
+        };
+    }
+    MarketKind::Categorical {
+        count: outcomes.len(),
+    }
+}
+
+/// Find the index of the "Yes" outcome (case-insensitive).
+pub fn yes_index(outcomes: &[String]) -> Option<usize> {
+    find_outcome(outcomes, "yes")
+}
+
+/// Find the index of the "No" outcome (case-insensitive).
+pub fn no_index(outcomes: &[String]) -> Option<usize> {
+    find_outcome(outcomes, "no")
+}
+
+fn find_outcome(outcomes: &[String], label: &str) -> Option<usize> {
+    outcomes
+        .iter()
+        .position(|o| o.trim().eq_ignore_ascii_case(label))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn outcomes(items: &[&str]) -> Vec<String> {
+        items.iter().map(|s| s.to_string()).collect()
+    }
+
+    #[test]
+    fn classifies_yes_no_market_as_binary() {
+        assert_eq!(
+            classify_outcomes(&outcomes(&["Yes", "No"])),
+            MarketKind::Binary {
+                yes_index: 0,
+                no_index: 1
+            }
+        );
+        assert_eq!(
+            classify_outcomes(&outcomes(&["no", "YES"])),
+            MarketKind::Binary {
+                yes_index: 1,
+                no_index: 0
+            }
+        );
+    }
+
+    #[test]
+    fn classifies_multi_candidate_market_as_categorical() {
+        let kind = classify_outcomes(&outcomes(&["Alice", "Bob", "Carol"]));
+        assert_eq!(kind, MarketKind::Categorical { count: 3 });
+        assert!(!kind.is_binary());
+    }
+
+    #[test]
+    fn classifies_two_team_market_as_categorical() {
+        assert_eq!(
+            classify_outcomes(&outcomes(&["Lakers", "Celtics"])),
+            MarketKind::Categorical { count: 2 }
+        );
+    }
+
+    #[test]
+    fn finds_yes_index() {
+        assert_eq!(yes_index(&outcomes(&["No", " Yes "])), Some(1));
+        assert_eq!(yes_index(&outcomes(&["Alice", "Bob"])), None);
+        assert_eq!(no_index(&outcomes(&["Yes", "No"])), Some(1));
+    }
+}
@@ -12,7 +12,11 @@ pub struct PriceChanges {
     pub market: String,
 
     /// Price changes array.
-    #[serde(rename = "pc", default)]
+    #[serde(
+        rename = "pc",
+        default,
+        deserialize_with = "crate::json::deserialize_null_as_empty_vec"
+    )]
     pub price_changes: Vec<PriceChange>,
 
     /// Timestamp.
@@ -66,11 +70,17 @@ pub struct OrderBookLevel {
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct AggOrderbook {
     /// Ask levels.
-    #[serde(default)]
+    #[serde(
+        default,
+        deserialize_with = "crate::json::deserialize_null_as_empty_vec"
+    )]
     pub asks: Vec<OrderBookLevel>,
 
     /// Bid levels.
-    #[serde(default)]
+    #[serde(
+        default,
+        deserialize_with = "crate::json::deserialize_null_as_empty_vec"
+    )]
     pub bids: Vec<OrderBookLevel>,
 
     /// Asset ID.
@@ -158,7 +168,10 @@ pub struct ClobMarket {
     pub market: String,
 
     /// Asset IDs (ERC1155).
-    #[serde(default)]
+    #[serde(
+        default,
+        deserialize_with = "crate::json::deserialize_null_as_empty_vec"
+    )]
     pub asset_ids: Vec<String>,
 
     /// Minimum order size.
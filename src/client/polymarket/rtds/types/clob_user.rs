@@ -138,7 +138,10 @@ pub struct ClobUserTrade {
     pub maker_address: String,
 
     /// Maker orders in this trade.
-    #[serde(default)]
+    #[serde(
+        default,
+        deserialize_with = "crate::json::deserialize_null_as_empty_vec"
+    )]
     pub maker_orders: Vec<MakerOrder>,
 
     /// Market ID.
@@ -35,7 +35,10 @@ pub struct PriceHistorical {
     pub symbol: String,
 
     /// Historical data points.
-    #[serde(default)]
+    #[serde(
+        default,
+        deserialize_with = "crate::json::deserialize_null_as_empty_vec"
+    )]
     pub data: Vec<PricePoint>,
 }
 
@@ -6,8 +6,8 @@
 mod job;
 mod settings;
 
-pub use job::{IngestionJob, Schedule};
+pub use job::{IngestionJob, SamplingMode, Schedule, load_jobs};
 pub use settings::{
     AppConfig, DataSourcesConfig, HttpClientConfigSerde, IngestionConfig, LocalStorageConfigSerde,
-    ServerConfig, StorageBackendType, StorageConfig,
+    ServerConfig, StalenessConfig, StorageBackendType, StorageConfig,
 };
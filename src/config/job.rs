@@ -1,5 +1,8 @@
 //! Ingestion job configuration.
 
+use std::path::Path;
+use std::time::Duration;
+
 use serde::{Deserialize, Serialize};
 
 use crate::DataSource;
@@ -10,7 +13,7 @@ const MIN_INTERVAL_SECS: u64 = 10;
 /// Ingestion job configuration.
 ///
 /// Jobs are managed via web UI and stored in the database.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct IngestionJob {
     /// Unique name for this job.
     pub name: String,
@@ -30,6 +33,81 @@ pub struct IngestionJob {
     /// Whether this job is enabled (default: true).
     #[serde(default = "default_enabled")]
     pub enabled: bool,
+    /// Only store metrics whose value or labels differ from the latest
+    /// stored point (default: false).
+    ///
+    /// Useful for slow-moving metrics such as the daily Fear & Greed index.
+    #[serde(default)]
+    pub only_on_change: bool,
+    /// Store only a sample of fetched points (default: every point).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sampling: Option<SamplingMode>,
+    /// Inclusive `[min, max]` range for fetched values (default: unbounded).
+    ///
+    /// Out-of-range and non-finite values are dropped with a warning, or
+    /// clamped into range with [`clamp_values`](Self::clamp_values).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub value_bounds: Option<(f64, f64)>,
+    /// Clamp out-of-range values to [`value_bounds`](Self::value_bounds)
+    /// instead of dropping them (default: false).
+    #[serde(default)]
+    pub clamp_values: bool,
+}
+
+/// How [`IngestionJob::sampling`] thins out stored points, per series.
+///
+/// In YAML: `sampling: {every_nth: 5}` or `sampling: {min_interval_secs: 60}`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(from = "SamplingRepr", into = "SamplingRepr")]
+pub enum SamplingMode {
+    /// Store the first of every N fetched points. Counts are kept in memory
+    /// and restart when the job is rescheduled or the process restarts.
+    EveryNth(u32),
+    /// Store a point only if it is at least this much newer than the latest
+    /// stored point.
+    MinInterval(Duration),
+}
+
+impl SamplingMode {
+    fn validate(&self) -> anyhow::Result<()> {
+        match self {
+            SamplingMode::EveryNth(0) => anyhow::bail!("Sampling every_nth must be at least 1"),
+            SamplingMode::MinInterval(interval) if interval.as_secs() == 0 => {
+                anyhow::bail!("Sampling min_interval_secs must be at least 1")
+            }
+            _ => Ok(()),
+        }
+    }
+}
+
+/// Serialized form of [`SamplingMode`], keyed like [`Schedule`].
+#[derive(Serialize, Deserialize)]
+#[serde(untagged)]
+enum SamplingRepr {
+    EveryNth { every_nth: u32 },
+    MinInterval { min_interval_secs: u64 },
+}
+
+impl From<SamplingRepr> for SamplingMode {
+    fn from(repr: SamplingRepr) -> Self {
+        match repr {
+            SamplingRepr::EveryNth { every_nth } => SamplingMode::EveryNth(every_nth),
+            SamplingRepr::MinInterval { min_interval_secs } => {
+                SamplingMode::MinInterval(Duration::from_secs(min_interval_secs))
+            }
+        }
+    }
+}
+
+impl From<SamplingMode> for SamplingRepr {
+    fn from(mode: SamplingMode) -> Self {
+        match mode {
+            SamplingMode::EveryNth(every_nth) => SamplingRepr::EveryNth { every_nth },
+            SamplingMode::MinInterval(interval) => SamplingRepr::MinInterval {
+                min_interval_secs: interval.as_secs(),
+            },
+        }
+    }
 }
 
 impl IngestionJob {
@@ -39,6 +117,8 @@ impl IngestionJob {
     /// - The schedule is invalid (see `Schedule::validate`)
     /// - The name is empty
     /// - The method is empty
+    /// - The sampling mode keeps no points apart
+    /// - The value bounds are not finite or `min > max`
     pub fn validate(&self) -> anyhow::Result<()> {
         if self.name.trim().is_empty() {
             anyhow::bail!("Job name cannot be empty");
@@ -47,8 +127,59 @@ impl IngestionJob {
             anyhow::bail!("Job method cannot be empty");
         }
         self.schedule.validate()?;
+        if let Some(sampling) = &self.sampling {
+            sampling.validate()?;
+        }
+        if let Some((min, max)) = self.value_bounds
+            && !(min.is_finite() && max.is_finite() && min <= max)
+        {
+            anyhow::bail!("Value bounds [{min}, {max}] must be finite with min <= max");
+        }
         Ok(())
     }
+
+    /// Check that the job's data source is among `enabled_sources`.
+    ///
+    /// An empty list enables every source.
+    pub fn check_source_enabled(&self, enabled_sources: &[DataSource]) -> anyhow::Result<()> {
+        if !enabled_sources.is_empty() && !enabled_sources.contains(&self.datasource) {
+            anyhow::bail!(
+                "Job '{}' uses data source '{}', which is not in datasources.enabled_sources",
+                self.name,
+                self.datasource
+            );
+        }
+        Ok(())
+    }
+}
+
+/// Load ingestion jobs from every `.yaml`/`.yml` file in `dir`.
+///
+/// Each file holds one job. Files are read in name order. Jobs are parsed
+/// but not validated; call [`IngestionJob::validate`] on each.
+pub fn load_jobs(dir: &Path) -> anyhow::Result<Vec<IngestionJob>> {
+    let entries = std::fs::read_dir(dir)
+        .map_err(|e| anyhow::anyhow!("Failed to read jobs dir '{}': {}", dir.display(), e))?;
+    let mut paths = Vec::new();
+    for entry in entries {
+        let path = entry?.path();
+        let is_yaml = path
+            .extension()
+            .is_some_and(|ext| ext == "yaml" || ext == "yml");
+        if is_yaml && path.is_file() {
+            paths.push(path);
+        }
+    }
+    paths.sort();
+
+    paths
+        .iter()
+        .map(|path| {
+            let content = std::fs::read_to_string(path)?;
+            serde_yaml::from_str(&content)
+                .map_err(|e| anyhow::anyhow!("Failed to parse job '{}': {}", path.display(), e))
+        })
+        .collect()
 }
 
 fn default_retention_days() -> u32 {
@@ -60,13 +191,20 @@ fn default_enabled() -> bool {
 }
 
 /// Schedule configuration for ingestion jobs.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(untagged)]
 pub enum Schedule {
     /// Interval-based scheduling.
     Interval {
         /// Interval in seconds between job executions.
         interval_secs: u64,
+        /// Align runs to wall-clock multiples of the interval (default: false).
+        ///
+        /// When enabled, the next run is scheduled relative to the intended tick
+        /// rather than the completion time of the previous run, so execution
+        /// latency does not accumulate as drift.
+        #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+        aligned: bool,
     },
     /// Cron-based scheduling.
     Cron {
@@ -98,13 +236,16 @@ impl Schedule {
                 interval_secs
             );
         }
-        Ok(Schedule::Interval { interval_secs })
+        Ok(Schedule::Interval {
+            interval_secs,
+            aligned: false,
+        })
     }
 
     /// Validate the schedule configuration.
     pub fn validate(&self) -> anyhow::Result<()> {
         match self {
-            Schedule::Interval { interval_secs } => {
+            Schedule::Interval { interval_secs, .. } => {
                 if *interval_secs < MIN_INTERVAL_SECS {
                     anyhow::bail!(
                         "Interval must be at least {} seconds, got {}",
@@ -153,11 +294,95 @@ interval_secs: 3600
         assert_eq!(job.datasource, DataSource::AlternativeMe);
         assert_eq!(job.method, "get_fear_and_greed");
         match job.schedule {
-            Schedule::Interval { interval_secs } => assert_eq!(interval_secs, 3600),
+            Schedule::Interval { interval_secs, .. } => assert_eq!(interval_secs, 3600),
             _ => panic!("Expected Interval schedule"),
         }
     }
 
+    #[test]
+    fn test_parse_aligned_interval_schedule() {
+        let yaml = r#"
+name: test_job
+datasource: alternativeme
+method: get_fear_and_greed
+interval_secs: 60
+aligned: true
+"#;
+        let job: IngestionJob = serde_yaml::from_str(yaml).unwrap();
+        match job.schedule {
+            Schedule::Interval {
+                interval_secs,
+                aligned,
+            } => {
+                assert_eq!(interval_secs, 60);
+                assert!(aligned);
+            }
+            _ => panic!("Expected Interval schedule"),
+        }
+    }
+
+    #[test]
+    fn test_parse_sampling_mode() {
+        let yaml = r#"
+name: test_job
+datasource: alternativeme
+method: get_fear_and_greed
+interval_secs: 60
+sampling:
+  min_interval_secs: 300
+"#;
+        let job: IngestionJob = serde_yaml::from_str(yaml).unwrap();
+        assert_eq!(
+            job.sampling,
+            Some(SamplingMode::MinInterval(Duration::from_secs(300)))
+        );
+
+        let every_nth: SamplingMode = serde_yaml::from_str("every_nth: 5").unwrap();
+        assert_eq!(every_nth, SamplingMode::EveryNth(5));
+        let json = serde_json::to_string(&every_nth).unwrap();
+        assert_eq!(json, r#"{"every_nth":5}"#);
+        assert_eq!(
+            serde_json::from_str::<SamplingMode>(&json).unwrap(),
+            every_nth
+        );
+        let job = IngestionJob {
+            sampling: Some(SamplingMode::EveryNth(0)),
+            ..job
+        };
+        assert!(
+            job.validate()
+                .unwrap_err()
+                .to_string()
+                .contains("every_nth")
+        );
+    }
+
+    #[test]
+    fn test_parse_value_bounds() {
+        let yaml = r#"
+name: test_job
+datasource: alternativeme
+method: get_fear_and_greed
+interval_secs: 60
+value_bounds: [0, 100]
+"#;
+        let job: IngestionJob = serde_yaml::from_str(yaml).unwrap();
+        assert_eq!(job.value_bounds, Some((0.0, 100.0)));
+        assert!(!job.clamp_values);
+        assert!(job.validate().is_ok());
+
+        let job = IngestionJob {
+            value_bounds: Some((100.0, 0.0)),
+            ..job
+        };
+        assert!(
+            job.validate()
+                .unwrap_err()
+                .to_string()
+                .contains("min <= max")
+        );
+    }
+
     #[test]
     fn test_parse_cron_schedule() {
         let yaml = r#"
@@ -248,7 +473,7 @@ enabled: false
     fn test_schedule_new_interval_valid() {
         let schedule = Schedule::new_interval(60).unwrap();
         match schedule {
-            Schedule::Interval { interval_secs } => assert_eq!(interval_secs, 60),
+            Schedule::Interval { interval_secs, .. } => assert_eq!(interval_secs, 60),
             _ => panic!("Expected Interval schedule"),
         }
     }
@@ -266,10 +491,17 @@ enabled: false
             name: "test".to_string(),
             datasource: DataSource::AlternativeMe,
             method: "get_fear_and_greed".to_string(),
-            schedule: Schedule::Interval { interval_secs: 60 },
+            schedule: Schedule::Interval {
+                interval_secs: 60,
+                aligned: false,
+            },
             params: None,
             retention_days: 7,
             enabled: true,
+            only_on_change: false,
+            sampling: None,
+            value_bounds: None,
+            clamp_values: false,
         };
         assert!(job.validate().is_ok());
     }
@@ -280,10 +512,17 @@ enabled: false
             name: "  ".to_string(),
             datasource: DataSource::AlternativeMe,
             method: "get_fear_and_greed".to_string(),
-            schedule: Schedule::Interval { interval_secs: 60 },
+            schedule: Schedule::Interval {
+                interval_secs: 60,
+                aligned: false,
+            },
             params: None,
             retention_days: 7,
             enabled: true,
+            only_on_change: false,
+            sampling: None,
+            value_bounds: None,
+            clamp_values: false,
         };
         let result = job.validate();
         assert!(result.is_err());
@@ -302,6 +541,10 @@ enabled: false
             params: None,
             retention_days: 7,
             enabled: true,
+            only_on_change: false,
+            sampling: None,
+            value_bounds: None,
+            clamp_values: false,
         };
         let result = job.validate();
         assert!(result.is_err());
@@ -1,9 +1,11 @@
 //! Application settings configuration.
 
 use serde::Deserialize;
+use std::collections::HashMap;
 use std::path::PathBuf;
 use std::time::Duration;
 
+use crate::DataSource;
 use crate::client::http::HttpClientConfig;
 
 // ============================================================================
@@ -15,9 +17,11 @@ const DEFAULT_CACHE_TTL_SECS: u64 = 900; // 15 minutes
 const DEFAULT_CACHE_MAX_CAPACITY: u64 = 100_000;
 const DEFAULT_CLEANUP_INTERVAL_SECS: u64 = 3600; // 1 hour
 const DEFAULT_METADATA_REFRESH_INTERVAL_SECS: u64 = 300; // 5 minutes
+const DEFAULT_JOB_RECONCILE_INTERVAL_SECS: u64 = 60; // 1 minute
 const DEFAULT_RETENTION_DAYS: u32 = 365; // 1 year
 const DEFAULT_HOST: &str = "127.0.0.1";
 const DEFAULT_PORT: u16 = 8080;
+const DEFAULT_STALE_AFTER_SECS: u64 = 300; // 5 minutes
 
 // ============================================================================
 // Application Configuration
@@ -62,6 +66,9 @@ pub struct ServerConfig {
     /// Port to listen on.
     #[serde(default)]
     pub port: u16,
+    /// When the status page flags a metric as stale.
+    #[serde(default)]
+    pub staleness: StalenessConfig,
 }
 
 impl Default for ServerConfig {
@@ -69,6 +76,38 @@ impl Default for ServerConfig {
         Self {
             host: DEFAULT_HOST.to_string(),
             port: DEFAULT_PORT,
+            staleness: StalenessConfig::default(),
+        }
+    }
+}
+
+/// Age thresholds after which a metric's latest value counts as stale.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default)]
+pub struct StalenessConfig {
+    /// Threshold in seconds for sources without an override (default: 300).
+    pub default_secs: u64,
+    /// Per-source thresholds in seconds, keyed by source name.
+    pub sources: HashMap<String, u64>,
+}
+
+impl StalenessConfig {
+    /// Whether a value from `source` that is `age_seconds` old is stale.
+    pub fn is_stale(&self, source: &str, age_seconds: i64) -> bool {
+        let threshold = self
+            .sources
+            .get(source)
+            .copied()
+            .unwrap_or(self.default_secs);
+        age_seconds > i64::try_from(threshold).unwrap_or(i64::MAX)
+    }
+}
+
+impl Default for StalenessConfig {
+    fn default() -> Self {
+        Self {
+            default_secs: DEFAULT_STALE_AFTER_SECS,
+            sources: HashMap::new(),
         }
     }
 }
@@ -89,12 +128,22 @@ pub struct StorageConfig {
     /// Metadata refresh interval in seconds (default: 300 = 5 minutes).
     #[serde(default)]
     pub metadata_refresh_interval_secs: u64,
+    /// Interval in seconds for syncing scheduled jobs with the database (default: 60).
+    #[serde(default)]
+    pub job_reconcile_interval_secs: u64,
     /// Global data retention period in days (default: 365 = 1 year).
     #[serde(default)]
     pub retention_days: u32,
     /// Local storage configuration (when backend = "local").
     #[serde(default)]
     pub local: Option<LocalStorageConfigSerde>,
+    /// Skip scheduled fetches while the storage health check fails (default: false).
+    #[serde(default)]
+    pub skip_fetch_when_unhealthy: bool,
+    /// Distinct values kept per label of each metric before the label is
+    /// dropped (default: 0, no limit).
+    #[serde(default)]
+    pub max_label_values: usize,
 }
 
 impl Default for StorageConfig {
@@ -103,8 +152,11 @@ impl Default for StorageConfig {
             backend: StorageBackendType::default(),
             cleanup_interval_secs: DEFAULT_CLEANUP_INTERVAL_SECS,
             metadata_refresh_interval_secs: DEFAULT_METADATA_REFRESH_INTERVAL_SECS,
+            job_reconcile_interval_secs: DEFAULT_JOB_RECONCILE_INTERVAL_SECS,
             retention_days: DEFAULT_RETENTION_DAYS,
             local: None,
+            skip_fetch_when_unhealthy: false,
+            max_label_values: 0,
         }
     }
 }
@@ -132,6 +184,9 @@ pub struct LocalStorageConfigSerde {
     /// Maximum number of entries in the cache.
     #[serde(default)]
     pub cache_max_capacity: u64,
+    /// Label keys promoted to indexed columns for fast filtering.
+    #[serde(default)]
+    pub indexed_labels: Vec<String>,
 }
 
 impl Default for LocalStorageConfigSerde {
@@ -140,6 +195,7 @@ impl Default for LocalStorageConfigSerde {
             db_path: PathBuf::from(DEFAULT_DB_PATH),
             cache_ttl_secs: DEFAULT_CACHE_TTL_SECS,
             cache_max_capacity: DEFAULT_CACHE_MAX_CAPACITY,
+            indexed_labels: Vec::new(),
         }
     }
 }
@@ -150,6 +206,7 @@ impl From<LocalStorageConfigSerde> for crate::LocalStorageConfig {
             db_path: serde.db_path,
             cache_ttl: Duration::from_secs(serde.cache_ttl_secs),
             cache_max_capacity: serde.cache_max_capacity,
+            indexed_labels: serde.indexed_labels,
         }
     }
 }
@@ -164,6 +221,9 @@ pub struct DataSourcesConfig {
     #[serde(default)]
     pub alternativeme: Option<HttpClientConfigSerde>,
     // Future: coingecko, coinmarketcap, polymarket
+    /// Data sources jobs may ingest from (default: empty, meaning all).
+    #[serde(default)]
+    pub enabled_sources: Vec<DataSource>,
 }
 
 /// Serde-friendly version of HttpClientConfig.
@@ -181,6 +241,9 @@ pub struct HttpClientConfigSerde {
     /// User-Agent header value.
     #[serde(default)]
     pub user_agent: Option<String>,
+    /// Proxy URL (`http://`, `https://` or `socks5://`, optionally with credentials).
+    #[serde(default)]
+    pub proxy: Option<String>,
 }
 
 impl From<HttpClientConfigSerde> for HttpClientConfig {
@@ -199,6 +262,9 @@ impl From<HttpClientConfigSerde> for HttpClientConfig {
         if let Some(v) = s.user_agent {
             config.user_agent = v;
         }
+        if let Some(v) = s.proxy {
+            config.proxy = Some(v);
+        }
 
         config
     }
@@ -229,11 +295,13 @@ mod tests {
             connect_timeout_secs: Some(20),
             max_retries: Some(5),
             user_agent: Some("test-agent".to_string()),
+            proxy: Some("http://127.0.0.1:8080".to_string()),
         };
         let config: HttpClientConfig = serde.into();
         assert_eq!(config.timeout, Duration::from_secs(60));
         assert_eq!(config.connect_timeout, Duration::from_secs(20));
         assert_eq!(config.max_retries, 5);
         assert_eq!(config.user_agent, "test-agent");
+        assert_eq!(config.proxy.as_deref(), Some("http://127.0.0.1:8080"));
     }
 }
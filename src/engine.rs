@@ -0,0 +1,37 @@
+//! Analytics engine.
+//!
+//! Pure computations over fetched or stored market data (price averages,
+//! aggregations, running statistics, trade velocity, neg-risk conversion).
+//! Nothing in this module performs I/O unless stated; [`convert_value`]
+//! fetches exchange rates from CoinGecko and the [`PriceProvider`] adapters
+//! fetch spot prices from CoinGecko or CoinMarketCap.
+
+mod averages;
+mod catalog;
+mod currency;
+mod debounce;
+mod fill;
+mod neg_risk;
+mod price;
+mod reconcile;
+mod stats;
+mod timestamp;
+mod velocity;
+
+pub use averages::{twap, vwap};
+pub use catalog::normalize_metric_catalog;
+pub use currency::convert_value;
+pub use debounce::Debouncer;
+pub use fill::{FillResult, simulate_limit_fill};
+pub use neg_risk::{ConversionOpportunity, neg_risk_conversion_value};
+pub use price::{
+    CoinGeckoPriceProvider, CoinMarketCapPriceProvider, FallbackPriceProvider, PriceProvider,
+    PriceQuote,
+};
+pub use reconcile::{ReconstructedPosition, positions_from_trades};
+pub use stats::{RunningStats, StatsSnapshot, running_stats};
+pub use timestamp::{
+    datetime_from_millis, datetime_from_secs, parse_timestamp, parse_timestamp_i64,
+    parse_timestamp_str,
+};
+pub use velocity::{VelocityBucket, market_velocity};
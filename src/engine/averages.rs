@@ -0,0 +1,98 @@
+//! Time- and volume-weighted price averages.
+
+use crate::client::polymarket::data::Trade;
+
+/// Time-weighted average price over `(timestamp, price)` points.
+///
+/// Each price is weighted by the time until the next point, so the last
+/// point only marks the end of the window and carries no weight itself.
+/// Points are sorted by timestamp first. Returns the single price for one
+/// point, the plain mean if all points share a timestamp, and NaN if
+/// `points` is empty.
+pub fn twap(points: &[(i64, f64)]) -> f64 {
+    match points {
+        [] => f64::NAN,
+        [(_, price)] => *price,
+        _ => {
+            let mut sorted = points.to_vec();
+            sorted.sort_by_key(|(ts, _)| *ts);
+
+            let (weighted, total) =
+                sorted
+                    .windows(2)
+                    .fold((0.0, 0.0), |(weighted, total), pair| {
+                        let duration = (pair[1].0 - pair[0].0) as f64;
+                        (weighted + pair[0].1 * duration, total + duration)
+                    });
+
+            if total > 0.0 {
+                weighted / total
+            } else {
+                sorted.iter().map(|(_, p)| p).sum::<f64>() / sorted.len() as f64
+            }
+        }
+    }
+}
+
+/// Volume-weighted average price of a set of trades.
+///
+/// Each trade's price is weighted by its size. Returns NaN if there are no
+/// trades or their total size is zero.
+pub fn vwap(trades: &[Trade]) -> f64 {
+    let (notional, volume) = trades.iter().fold((0.0, 0.0), |(notional, volume), trade| {
+        (notional + trade.price * trade.size, volume + trade.size)
+    });
+    if volume > 0.0 {
+        notional / volume
+    } else {
+        f64::NAN
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::client::polymarket::data::TradeSide;
+    use crate::testutil::sample_trade;
+
+    fn trade(price: f64, size: f64) -> Trade {
+        sample_trade(1_700_000_000, TradeSide::Buy, price, size)
+    }
+
+    #[test]
+    fn twap_of_evenly_spaced_points_is_the_mean() {
+        // The final point closes the window at t=40.
+        let points = [(0, 0.2), (10, 0.4), (20, 0.6), (30, 0.8), (40, 0.5)];
+        let mean = (0.2 + 0.4 + 0.6 + 0.8) / 4.0;
+        assert!((twap(&points) - mean).abs() < 1e-12);
+    }
+
+    #[test]
+    fn twap_weights_by_duration() {
+        // 0.2 holds for 30s, 0.8 for 10s.
+        let points = [(30, 0.8), (0, 0.2), (40, 0.0)];
+        assert!((twap(&points) - 0.35).abs() < 1e-12);
+    }
+
+    #[test]
+    fn twap_edge_cases() {
+        assert!(twap(&[]).is_nan());
+        assert_eq!(twap(&[(5, 0.42)]), 0.42);
+        assert!((twap(&[(5, 0.2), (5, 0.4)]) - 0.3).abs() < 1e-12);
+    }
+
+    #[test]
+    fn vwap_weights_large_trade_more() {
+        let trades = [trade(0.40, 1.0), trade(0.60, 9.0)];
+        let result = vwap(&trades);
+        assert!((result - 0.58).abs() < 1e-12);
+        // Well above the unweighted mean of 0.5.
+        assert!(result > 0.5);
+    }
+
+    #[test]
+    fn vwap_of_no_volume_is_nan() {
+        assert!(vwap(&[]).is_nan());
+        assert!(vwap(&[trade(0.5, 0.0)]).is_nan());
+    }
+}
@@ -0,0 +1,44 @@
+//! Canonical ordering for the metric catalog.
+
+/// Sort `(source, name)` pairs and drop duplicates.
+///
+/// Every storage backend and the web layer pass the metric catalog through
+/// this function, so UIs list metrics in the same order regardless of where
+/// the catalog came from. Pairs are ordered by source, then name, comparing
+/// bytes (matching SQLite's default `BINARY` collation).
+pub fn normalize_metric_catalog(mut catalog: Vec<(String, String)>) -> Vec<(String, String)> {
+    catalog.sort_unstable();
+    catalog.dedup();
+    catalog
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn pair(source: &str, name: &str) -> (String, String) {
+        (source.to_string(), name.to_string())
+    }
+
+    #[test]
+    fn sorts_by_source_then_name_and_dedups() {
+        let catalog = vec![
+            pair("coingecko", "price"),
+            pair("alternativeme", "fear_and_greed"),
+            pair("coingecko", "market_cap"),
+            pair("alternativeme", "fear_and_greed"),
+            pair("Zeta", "a"),
+            pair("coingecko", "price"),
+        ];
+
+        assert_eq!(
+            normalize_metric_catalog(catalog),
+            vec![
+                pair("Zeta", "a"),
+                pair("alternativeme", "fear_and_greed"),
+                pair("coingecko", "market_cap"),
+                pair("coingecko", "price"),
+            ]
+        );
+    }
+}
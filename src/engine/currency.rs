@@ -0,0 +1,116 @@
+//! USD to fiat/crypto currency conversion backed by CoinGecko rates.
+
+use std::sync::OnceLock;
+use std::time::Duration;
+
+use moka::future::Cache;
+
+use crate::client::coingecko::{CgError, Client, SimplePriceRequest};
+
+/// Coin used as a USD proxy when asking CoinGecko for exchange rates.
+const USD_PROXY_COIN: &str = "usd-coin";
+
+/// How long a fetched rate is reused before asking CoinGecko again.
+const RATE_TTL: Duration = Duration::from_secs(60);
+
+/// Rates keyed by (client base URL, lowercase target currency).
+fn rate_cache() -> &'static Cache<(String, String), f64> {
+    static CACHE: OnceLock<Cache<(String, String), f64>> = OnceLock::new();
+    CACHE.get_or_init(|| {
+        Cache::builder()
+            .max_capacity(256)
+            .time_to_live(RATE_TTL)
+            .build()
+    })
+}
+
+/// Convert a USD amount into `target` (e.g. `"eur"`, `"gbp"`).
+///
+/// The USD->target rate is the CoinGecko price of USDC in `target`, fetched
+/// with `get_simple_price` and cached for a minute. Converting to `"usd"`
+/// returns the amount unchanged without a request.
+///
+/// # Errors
+///
+/// Returns [`CgError::Api`] if CoinGecko has no rate for `target`.
+pub async fn convert_value(
+    amount_usd: f64,
+    target: &str,
+    cg_client: &Client,
+) -> Result<f64, CgError> {
+    let target = target.trim().to_lowercase();
+    if target == "usd" {
+        return Ok(amount_usd);
+    }
+
+    let key = (cg_client.base_url().to_string(), target.clone());
+    if let Some(rate) = rate_cache().get(&key).await {
+        return Ok(amount_usd * rate);
+    }
+
+    let prices = cg_client
+        .get_simple_price(SimplePriceRequest {
+            ids: USD_PROXY_COIN.to_string(),
+            vs_currencies: target.clone(),
+            ..Default::default()
+        })
+        .await?;
+    let rate = prices
+        .get(USD_PROXY_COIN)
+        .and_then(|rates| rates.get(&target).copied().flatten())
+        .ok_or_else(|| CgError::Api(format!("no USD rate available for currency '{target}'")))?;
+
+    rate_cache().insert(key, rate).await;
+    Ok(amount_usd * rate)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use wiremock::matchers::{method, path, query_param};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    #[tokio::test]
+    async fn converts_with_cached_rate() {
+        let mock_server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/simple/price"))
+            .and(query_param("ids", "usd-coin"))
+            .and(query_param("vs_currencies", "eur"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .set_body_json(serde_json::json!({"usd-coin": {"eur": 0.92}})),
+            )
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+        let client = Client::new("test-key").with_base_url(mock_server.uri());
+
+        let eur = convert_value(100.0, "EUR", &client).await.unwrap();
+        assert!((eur - 92.0).abs() < 1e-9);
+
+        // Served from the cache: the mock expects a single request.
+        let eur = convert_value(50.0, "eur", &client).await.unwrap();
+        assert!((eur - 46.0).abs() < 1e-9);
+    }
+
+    #[tokio::test]
+    async fn usd_target_needs_no_request() {
+        let client = Client::new("test-key").with_base_url("http://127.0.0.1:9".to_string());
+        assert_eq!(convert_value(12.5, "usd", &client).await.unwrap(), 12.5);
+    }
+
+    #[tokio::test]
+    async fn missing_rate_is_an_error() {
+        let mock_server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/simple/price"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({})))
+            .mount(&mock_server)
+            .await;
+        let client = Client::new("test-key").with_base_url(mock_server.uri());
+
+        let err = convert_value(1.0, "xyz", &client).await.unwrap_err();
+        assert!(matches!(err, CgError::Api(_)));
+    }
+}
@@ -0,0 +1,123 @@
+//! Confirmation debouncing for threshold alerts.
+
+/// Suppresses flapping alerts on a value oscillating around a threshold.
+///
+/// Feed it one observation per sample with [`observe`](Self::observe). The
+/// alert fires once the condition has held for `K` consecutive samples
+/// and/or for a minimum duration, and then stays quiet until a sample where
+/// the condition is false resets it.
+#[derive(Debug, Clone)]
+pub struct Debouncer {
+    min_samples: u32,
+    min_duration_ms: i64,
+    /// Consecutive samples the condition has held for.
+    streak: u32,
+    /// Timestamp (ms) of the first sample in the current streak.
+    since: Option<i64>,
+    fired: bool,
+}
+
+impl Debouncer {
+    /// Fire after the condition holds for `k` consecutive samples.
+    ///
+    /// `k` of 0 or 1 fires on the first matching sample.
+    pub fn consecutive(k: u32) -> Self {
+        Self {
+            min_samples: k.max(1),
+            min_duration_ms: 0,
+            streak: 0,
+            since: None,
+            fired: false,
+        }
+    }
+
+    /// Fire once the condition has held for at least `duration`, measured
+    /// between sample timestamps.
+    pub fn for_duration(duration: std::time::Duration) -> Self {
+        Self::consecutive(1).with_min_duration(duration)
+    }
+
+    /// Additionally require the condition to hold for `duration`.
+    pub fn with_min_duration(mut self, duration: std::time::Duration) -> Self {
+        self.min_duration_ms = i64::try_from(duration.as_millis()).unwrap_or(i64::MAX);
+        self
+    }
+
+    /// Record a sample at `timestamp` (unix millis).
+    ///
+    /// Returns `true` exactly once per excursion: on the sample that first
+    /// satisfies the confirmation requirements.
+    pub fn observe(&mut self, condition: bool, timestamp: i64) -> bool {
+        if !condition {
+            self.reset();
+            return false;
+        }
+
+        self.streak = self.streak.saturating_add(1);
+        let since = *self.since.get_or_insert(timestamp);
+        if self.fired
+            || self.streak < self.min_samples
+            || timestamp.saturating_sub(since) < self.min_duration_ms
+        {
+            return false;
+        }
+        self.fired = true;
+        true
+    }
+
+    /// Whether the alert has fired and not yet recovered.
+    pub fn is_active(&self) -> bool {
+        self.fired
+    }
+
+    /// Forget the current streak, as if the condition had recovered.
+    pub fn reset(&mut self) {
+        self.streak = 0;
+        self.since = None;
+        self.fired = false;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Duration;
+
+    #[test]
+    fn oscillating_series_fires_once_after_k_confirmations() {
+        let threshold = 50.0;
+        let series = [
+            49.0, 51.0, 49.5, 52.0, 48.0, 51.0, 53.0, 55.0, 54.0, 56.0, 52.0,
+        ];
+        let mut debouncer = Debouncer::consecutive(3);
+
+        let fired: Vec<usize> = series
+            .iter()
+            .enumerate()
+            .filter(|(i, value)| debouncer.observe(**value > threshold, *i as i64 * 1_000))
+            .map(|(i, _)| i)
+            .collect();
+
+        // Only the run starting at index 5 reaches three samples above 50.
+        assert_eq!(fired, vec![7]);
+        assert!(debouncer.is_active());
+
+        assert!(!debouncer.observe(false, 11_000));
+        assert!(!debouncer.is_active());
+    }
+
+    #[test]
+    fn duration_requirement_uses_sample_timestamps() {
+        let mut debouncer = Debouncer::for_duration(Duration::from_secs(60));
+
+        assert!(!debouncer.observe(true, 0));
+        assert!(!debouncer.observe(true, 30_000));
+        assert!(debouncer.observe(true, 60_000));
+        assert!(!debouncer.observe(true, 90_000));
+
+        // Recovery restarts the clock.
+        assert!(!debouncer.observe(false, 100_000));
+        assert!(!debouncer.observe(true, 110_000));
+        assert!(debouncer.observe(true, 170_000));
+    }
+}
@@ -0,0 +1,106 @@
+//! Limit-order fill simulation against recorded trades.
+
+use crate::client::polymarket::data::{Trade, TradeSide};
+
+/// Outcome of [`simulate_limit_fill`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct FillResult {
+    /// Quantity filled, at most the order size.
+    pub filled_size: f64,
+    /// Size-weighted average fill price, or `None` if nothing filled.
+    pub avg_price: Option<f64>,
+    /// Number of trades that contributed to the fill.
+    pub trades_used: usize,
+}
+
+impl FillResult {
+    /// Whether the whole order size was filled.
+    pub fn is_full(&self, size: f64) -> bool {
+        self.filled_size >= size
+    }
+}
+
+/// Simulate whether a limit order would have filled given subsequent trades.
+///
+/// Trades are walked in the given order. A buy at `order_price` fills
+/// against trades priced at or below it; a sell against trades at or above
+/// it. Each qualifying trade fills up to its size at the trade's price until
+/// `size` is reached. Queue position and the order's own market impact are
+/// ignored, so results are an upper bound on real fills.
+pub fn simulate_limit_fill(
+    order_price: f64,
+    side: TradeSide,
+    subsequent_trades: &[Trade],
+    size: f64,
+) -> FillResult {
+    let crosses = |price: f64| match side {
+        TradeSide::Buy => price <= order_price,
+        TradeSide::Sell => price >= order_price,
+    };
+
+    let mut filled = 0.0;
+    let mut notional = 0.0;
+    let mut trades_used = 0;
+    for trade in subsequent_trades {
+        if filled >= size {
+            break;
+        }
+        if trade.size <= 0.0 || !crosses(trade.price) {
+            continue;
+        }
+        let qty = trade.size.min(size - filled);
+        filled += qty;
+        notional += qty * trade.price;
+        trades_used += 1;
+    }
+
+    FillResult {
+        filled_size: filled,
+        avg_price: (filled > 0.0).then(|| notional / filled),
+        trades_used,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testutil::sample_trade;
+
+    fn trade(price: f64, size: f64) -> Trade {
+        sample_trade(1_700_000_000, TradeSide::Sell, price, size)
+    }
+
+    #[test]
+    fn full_fill_stops_at_order_size() {
+        let trades = [trade(0.55, 10.0), trade(0.50, 30.0), trade(0.48, 50.0)];
+        let result = simulate_limit_fill(0.50, TradeSide::Buy, &trades, 25.0);
+
+        assert!(result.is_full(25.0));
+        assert_eq!(result.filled_size, 25.0);
+        assert_eq!(result.trades_used, 1);
+        assert_eq!(result.avg_price, Some(0.50));
+    }
+
+    #[test]
+    fn partial_fill_averages_qualifying_trades() {
+        let trades = [trade(0.62, 10.0), trade(0.58, 5.0), trade(0.60, 15.0)];
+        let result = simulate_limit_fill(0.60, TradeSide::Sell, &trades, 40.0);
+
+        assert!(!result.is_full(40.0));
+        assert_eq!(result.filled_size, 25.0);
+        assert_eq!(result.trades_used, 2);
+        let expected = (0.62 * 10.0 + 0.60 * 15.0) / 25.0;
+        assert!((result.avg_price.unwrap() - expected).abs() < 1e-12);
+    }
+
+    #[test]
+    fn no_fill_when_no_trade_crosses() {
+        let trades = [trade(0.55, 10.0), trade(0.51, 10.0)];
+        let result = simulate_limit_fill(0.50, TradeSide::Buy, &trades, 5.0);
+
+        assert_eq!(result.filled_size, 0.0);
+        assert_eq!(result.avg_price, None);
+        assert_eq!(result.trades_used, 0);
+        assert_eq!(simulate_limit_fill(0.50, TradeSide::Buy, &[], 5.0), result);
+    }
+}
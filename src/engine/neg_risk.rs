@@ -0,0 +1,141 @@
+//! Conversion payoff of NO positions in negative-risk events.
+
+use std::collections::BTreeMap;
+
+use crate::client::polymarket::data::Position;
+
+/// Outcome index of NO tokens in binary Polymarket markets.
+const NO_OUTCOME_INDEX: i32 = 1;
+
+/// Result of [`neg_risk_conversion_value`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct ConversionOpportunity {
+    /// Slug of the neg-risk event the positions belong to.
+    pub event_slug: String,
+    /// Condition IDs of the markets whose NO tokens are converted, sorted.
+    pub condition_ids: Vec<String>,
+    /// NO tokens converted in each market.
+    pub size: f64,
+    /// USDC received for the conversion, `size * (k - 1)`.
+    pub collateral_out: f64,
+    /// Current value of the converted NO tokens, `size * Σ cur_price`.
+    pub market_value: f64,
+    /// `collateral_out - market_value`; positive when converting beats
+    /// selling at current prices.
+    pub edge: f64,
+}
+
+/// Risk-free payoff of converting NO positions held across the markets of
+/// one negative-risk event.
+///
+/// In a neg-risk event exactly one market resolves YES, so one NO token in
+/// each of `k` markets pays out at least `k - 1` USDC whatever happens. The
+/// neg-risk adapter lets that set be converted straight into `k - 1` USDC
+/// plus one YES token in every other market of the event. For NO sizes
+/// `s_1..s_k` with current prices `p_1..p_k`:
+///
+/// ```text
+/// size           = min(s_i)
+/// collateral_out = size * (k - 1)
+/// market_value   = size * Σ p_i
+/// edge           = collateral_out - market_value
+/// ```
+///
+/// The YES tokens received for markets outside the set are worth at least
+/// zero and are left out, so `edge` is a lower bound.
+///
+/// Only NO positions (`outcome_index == 1`) with `negative_risk` set are
+/// considered; sizes in the same market are added up. Returns `None` if they
+/// span more than one event or cover fewer than two markets.
+pub fn neg_risk_conversion_value(positions: &[Position]) -> Option<ConversionOpportunity> {
+    let mut event_slug: Option<&str> = None;
+    // condition_id -> (size, price)
+    let mut markets: BTreeMap<&str, (f64, f64)> = BTreeMap::new();
+    for position in positions {
+        if !position.negative_risk
+            || position.outcome_index != NO_OUTCOME_INDEX
+            || position.size <= 0.0
+        {
+            continue;
+        }
+        match event_slug {
+            Some(slug) if slug != position.event_slug => return None,
+            _ => event_slug = Some(&position.event_slug),
+        }
+        let entry = markets
+            .entry(position.condition_id.as_str())
+            .or_insert((0.0, position.cur_price));
+        entry.0 += position.size;
+    }
+
+    if markets.len() < 2 {
+        return None;
+    }
+    let size = markets
+        .values()
+        .map(|&(size, _)| size)
+        .fold(f64::INFINITY, f64::min);
+    let collateral_out = size * (markets.len() - 1) as f64;
+    let market_value = size * markets.values().map(|&(_, price)| price).sum::<f64>();
+
+    Some(ConversionOpportunity {
+        event_slug: event_slug?.to_string(),
+        condition_ids: markets.keys().map(|id| id.to_string()).collect(),
+        size,
+        collateral_out,
+        market_value,
+        edge: collateral_out - market_value,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testutil::sample_position;
+
+    fn no_position(condition_id: &str, size: f64, cur_price: f64) -> Position {
+        let mut position = sample_position(condition_id, size, cur_price);
+        position.outcome = "No".to_string();
+        position.outcome_index = 1;
+        position.event_slug = "who-wins".to_string();
+        position.negative_risk = true;
+        position
+    }
+
+    #[test]
+    fn converts_smallest_common_size() {
+        let positions = [
+            no_position("0xa", 100.0, 0.60),
+            no_position("0xb", 40.0, 0.70),
+            no_position("0xb", 20.0, 0.70),
+            no_position("0xc", 80.0, 0.55),
+        ];
+
+        let opportunity = neg_risk_conversion_value(&positions).unwrap();
+
+        assert_eq!(opportunity.event_slug, "who-wins");
+        assert_eq!(opportunity.condition_ids, vec!["0xa", "0xb", "0xc"]);
+        assert_eq!(opportunity.size, 60.0);
+        // Three NO sets convert into two USDC each.
+        assert!((opportunity.collateral_out - 120.0).abs() < 1e-9);
+        assert!((opportunity.market_value - 60.0 * 1.85).abs() < 1e-9);
+        assert!((opportunity.edge - 9.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn ignores_yes_and_rejects_mixed_events() {
+        let mut yes = no_position("0xc", 50.0, 0.45);
+        yes.outcome_index = 0;
+        assert_eq!(
+            neg_risk_conversion_value(&[no_position("0xa", 10.0, 0.5), yes]),
+            None
+        );
+
+        let mut other = no_position("0xb", 10.0, 0.5);
+        other.event_slug = "other-event".to_string();
+        assert_eq!(
+            neg_risk_conversion_value(&[no_position("0xa", 10.0, 0.5), other]),
+            None
+        );
+    }
+}
@@ -0,0 +1,364 @@
+//! Spot prices from interchangeable upstream sources.
+
+use crate::DataSource;
+use crate::client::BoxFuture;
+use crate::client::{coingecko, coinmarketcap};
+
+use super::parse_timestamp_str;
+
+/// Price of one asset in one currency, whichever source it came from.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PriceQuote {
+    /// Ticker symbol as requested, uppercased (e.g. "BTC").
+    pub symbol: String,
+    /// Quote currency, lowercased (e.g. "usd").
+    pub currency: String,
+    /// Price of one unit of `symbol` in `currency`.
+    pub price: f64,
+    /// When the source last updated the price, in Unix seconds.
+    pub updated_at: Option<i64>,
+    /// Source that produced the quote.
+    pub source: DataSource,
+}
+
+/// Source of spot prices keyed by ticker symbol.
+pub trait PriceProvider: Send + Sync {
+    /// Source the quotes come from.
+    fn source(&self) -> DataSource;
+
+    /// Latest price of `symbol` (e.g. "BTC") in `currency` (e.g. "usd").
+    fn quote<'a>(
+        &'a self,
+        symbol: &'a str,
+        currency: &'a str,
+    ) -> BoxFuture<'a, anyhow::Result<PriceQuote>>;
+}
+
+/// [`PriceProvider`] over the CoinGecko `/simple/price` endpoint.
+///
+/// Symbols are mapped to coin ids with a [`coingecko::SymbolResolver`];
+/// ambiguous symbols resolve to the coin with the largest market cap.
+pub struct CoinGeckoPriceProvider {
+    client: coingecko::Client,
+    resolver: coingecko::SymbolResolver,
+}
+
+impl CoinGeckoPriceProvider {
+    /// Creates a provider resolving symbols with the default cache TTL.
+    pub fn new(client: coingecko::Client) -> Self {
+        let resolver = coingecko::SymbolResolver::new(client.clone());
+        Self { client, resolver }
+    }
+}
+
+impl PriceProvider for CoinGeckoPriceProvider {
+    fn source(&self) -> DataSource {
+        DataSource::CoinGecko
+    }
+
+    fn quote<'a>(
+        &'a self,
+        symbol: &'a str,
+        currency: &'a str,
+    ) -> BoxFuture<'a, anyhow::Result<PriceQuote>> {
+        Box::pin(async move {
+            let currency = currency.to_lowercase();
+            let id = self
+                .resolver
+                .try_resolve(symbol, true)
+                .await?
+                .ok_or_else(|| anyhow::anyhow!("CoinGecko has no coin for symbol '{symbol}'"))?;
+            let prices = self
+                .client
+                .get_simple_price(coingecko::SimplePriceRequest {
+                    ids: id.clone(),
+                    vs_currencies: currency.clone(),
+                    include_last_updated_at: Some(true),
+                    ..Default::default()
+                })
+                .await?;
+            let fields = prices
+                .get(&id)
+                .ok_or_else(|| anyhow::anyhow!("CoinGecko returned no price for '{id}'"))?;
+            let price =
+                fields.get(&currency).copied().flatten().ok_or_else(|| {
+                    anyhow::anyhow!("CoinGecko has no '{currency}' price for '{id}'")
+                })?;
+            Ok(PriceQuote {
+                symbol: symbol.to_uppercase(),
+                currency,
+                price,
+                updated_at: fields
+                    .get("last_updated_at")
+                    .copied()
+                    .flatten()
+                    .map(|ts| ts as i64),
+                source: DataSource::CoinGecko,
+            })
+        })
+    }
+}
+
+/// [`PriceProvider`] over the CoinMarketCap `/v2/cryptocurrency/quotes/latest`
+/// endpoint.
+///
+/// When several coins share a symbol the first one listed is used, which
+/// CoinMarketCap orders by rank.
+pub struct CoinMarketCapPriceProvider {
+    client: coinmarketcap::Client,
+}
+
+impl CoinMarketCapPriceProvider {
+    /// Creates a provider over `client`.
+    pub fn new(client: coinmarketcap::Client) -> Self {
+        Self { client }
+    }
+}
+
+impl PriceProvider for CoinMarketCapPriceProvider {
+    fn source(&self) -> DataSource {
+        DataSource::CoinMarketCap
+    }
+
+    fn quote<'a>(
+        &'a self,
+        symbol: &'a str,
+        currency: &'a str,
+    ) -> BoxFuture<'a, anyhow::Result<PriceQuote>> {
+        Box::pin(async move {
+            let symbol = symbol.to_uppercase();
+            let convert = currency.to_uppercase();
+            let response = self
+                .client
+                .get_quotes_latest(coinmarketcap::GetQuotesLatestRequest {
+                    symbol: Some(symbol.clone()),
+                    convert: Some(convert.clone()),
+                    ..Default::default()
+                })
+                .await?;
+            let coin = response
+                .data
+                .iter()
+                .find(|(key, _)| key.eq_ignore_ascii_case(&symbol))
+                .and_then(|(_, coins)| coins.first())
+                .ok_or_else(|| {
+                    anyhow::anyhow!("CoinMarketCap has no coin for symbol '{symbol}'")
+                })?;
+            let quote = coin
+                .quote
+                .iter()
+                .find(|(key, _)| key.eq_ignore_ascii_case(&convert))
+                .map(|(_, quote)| quote)
+                .ok_or_else(|| {
+                    anyhow::anyhow!("CoinMarketCap returned no '{convert}' quote for '{symbol}'")
+                })?;
+            let price = quote.price.ok_or_else(|| {
+                anyhow::anyhow!("CoinMarketCap has no '{convert}' price for '{symbol}'")
+            })?;
+            Ok(PriceQuote {
+                symbol,
+                currency: convert.to_lowercase(),
+                price,
+                updated_at: parse_timestamp_str(&quote.last_updated),
+                source: DataSource::CoinMarketCap,
+            })
+        })
+    }
+}
+
+/// [`PriceProvider`] that asks each inner provider in order and returns the
+/// first quote.
+///
+/// Failures are logged and the next provider is tried; the call fails only
+/// if every provider does, with all their errors in the message.
+pub struct FallbackPriceProvider(pub Vec<Box<dyn PriceProvider>>);
+
+impl PriceProvider for FallbackPriceProvider {
+    /// Always `Custom("fallback")`; each [`PriceQuote`] names the provider
+    /// that produced it.
+    fn source(&self) -> DataSource {
+        DataSource::Custom("fallback".to_string())
+    }
+
+    fn quote<'a>(
+        &'a self,
+        symbol: &'a str,
+        currency: &'a str,
+    ) -> BoxFuture<'a, anyhow::Result<PriceQuote>> {
+        Box::pin(async move {
+            let mut errors = Vec::new();
+            for provider in &self.0 {
+                match provider.quote(symbol, currency).await {
+                    Ok(quote) => return Ok(quote),
+                    Err(e) => {
+                        tracing::warn!(
+                            source = %provider.source(),
+                            symbol,
+                            currency,
+                            error = %e,
+                            "price provider failed, trying next"
+                        );
+                        errors.push(format!("{}: {e}", provider.source()));
+                    }
+                }
+            }
+            if errors.is_empty() {
+                anyhow::bail!("no price providers configured");
+            }
+            anyhow::bail!(
+                "no provider could quote {symbol} in {currency}: {}",
+                errors.join("; ")
+            )
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use wiremock::matchers::{method, path, query_param};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    struct FailingProvider;
+
+    impl PriceProvider for FailingProvider {
+        fn source(&self) -> DataSource {
+            DataSource::CoinGecko
+        }
+
+        fn quote<'a>(
+            &'a self,
+            _: &'a str,
+            _: &'a str,
+        ) -> BoxFuture<'a, anyhow::Result<PriceQuote>> {
+            Box::pin(async { anyhow::bail!("upstream unavailable") })
+        }
+    }
+
+    #[tokio::test]
+    async fn coingecko_quotes_the_resolved_coin() {
+        let mock_server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/coins/list"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!([
+                {"id": "bitcoin", "symbol": "btc", "name": "Bitcoin"},
+            ])))
+            .mount(&mock_server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/coins/markets"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!([])))
+            .mount(&mock_server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/simple/price"))
+            .and(query_param("ids", "bitcoin"))
+            .and(query_param("vs_currencies", "usd"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "bitcoin": {"usd": 42000.5, "last_updated_at": 1_704_067_200}
+            })))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+        let client = coingecko::Client::new("test-key").with_base_url(mock_server.uri());
+
+        let quote = CoinGeckoPriceProvider::new(client)
+            .quote("BTC", "USD")
+            .await
+            .unwrap();
+
+        assert_eq!(
+            quote,
+            PriceQuote {
+                symbol: "BTC".to_string(),
+                currency: "usd".to_string(),
+                price: 42000.5,
+                updated_at: Some(1_704_067_200),
+                source: DataSource::CoinGecko,
+            }
+        );
+    }
+
+    #[tokio::test]
+    async fn coingecko_reports_why_the_coin_list_failed() {
+        let mock_server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/coins/list"))
+            .respond_with(ResponseTemplate::new(401).set_body_string("invalid api key"))
+            .mount(&mock_server)
+            .await;
+        let client = coingecko::Client::new("test-key").with_base_url(mock_server.uri());
+
+        let err = CoinGeckoPriceProvider::new(client)
+            .quote("btc", "usd")
+            .await
+            .unwrap_err();
+
+        assert!(err.to_string().contains("invalid api key"), "{err}");
+    }
+
+    #[tokio::test]
+    async fn falls_back_to_next_provider_on_error() {
+        let mock_server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/v2/cryptocurrency/quotes/latest"))
+            .and(query_param("symbol", "BTC"))
+            .and(query_param("convert", "USD"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "status": {
+                    "timestamp": "2024-01-01T00:00:00.000Z",
+                    "error_code": 0,
+                    "error_message": null,
+                    "elapsed": 0,
+                    "credit_count": 1
+                },
+                "data": {
+                    "BTC": [{
+                        "id": 1,
+                        "name": "Bitcoin",
+                        "symbol": "BTC",
+                        "slug": "bitcoin",
+                        "last_updated": "2024-01-01T00:00:00.000Z",
+                        "quote": {
+                            "USD": {
+                                "price": 42000.5,
+                                "last_updated": "2024-01-01T00:00:00.000Z"
+                            }
+                        }
+                    }]
+                }
+            })))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+        let cmc = coinmarketcap::Client::new("test-key").with_base_url(mock_server.uri());
+
+        let provider = FallbackPriceProvider(vec![
+            Box::new(FailingProvider),
+            Box::new(CoinMarketCapPriceProvider::new(cmc)),
+        ]);
+        let quote = provider.quote("btc", "usd").await.unwrap();
+
+        assert_eq!(
+            quote,
+            PriceQuote {
+                symbol: "BTC".to_string(),
+                currency: "usd".to_string(),
+                price: 42000.5,
+                updated_at: Some(1_704_067_200),
+                source: DataSource::CoinMarketCap,
+            }
+        );
+
+        assert_eq!(
+            provider.source(),
+            DataSource::Custom("fallback".to_string())
+        );
+
+        let err = FallbackPriceProvider(vec![Box::new(FailingProvider)])
+            .quote("btc", "usd")
+            .await
+            .unwrap_err();
+        assert!(err.to_string().contains("upstream unavailable"), "{err}");
+    }
+}
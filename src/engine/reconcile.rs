@@ -0,0 +1,96 @@
+//! Position reconstruction from a trade history.
+
+use std::collections::HashMap;
+
+use crate::client::polymarket::data::{Trade, TradeSide};
+
+/// Net holding of one outcome token rebuilt from trades.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ReconstructedPosition {
+    /// Net size: buys minus sells. Negative if more was sold than bought.
+    pub size: f64,
+    /// Size-weighted average entry price of the open size, 0 when flat.
+    pub avg_price: f64,
+}
+
+/// Rebuild net positions from trades, keyed by `(asset, outcome_index)`.
+///
+/// Trades are applied in timestamp order. Adding to a position moves the
+/// average entry price; reducing it leaves the average unchanged. If a trade
+/// flips the position, the remainder opens at that trade's price. Flat
+/// positions are kept with zero size so every traded asset appears.
+pub fn positions_from_trades(trades: &[Trade]) -> HashMap<(String, i32), ReconstructedPosition> {
+    let mut ordered: Vec<&Trade> = trades.iter().collect();
+    ordered.sort_by_key(|trade| trade.timestamp);
+
+    let mut positions: HashMap<(String, i32), ReconstructedPosition> = HashMap::new();
+    for trade in ordered {
+        let position = positions
+            .entry((trade.asset.clone(), trade.outcome_index))
+            .or_insert(ReconstructedPosition {
+                size: 0.0,
+                avg_price: 0.0,
+            });
+        let delta = match trade.side {
+            TradeSide::Buy => trade.size,
+            TradeSide::Sell => -trade.size,
+        };
+        let new_size = position.size + delta;
+
+        if new_size == 0.0 {
+            position.avg_price = 0.0;
+        } else if position.size == 0.0 || position.size.signum() == delta.signum() {
+            // Opening or adding: blend the entry price.
+            position.avg_price = (position.avg_price * position.size.abs()
+                + trade.price * delta.abs())
+                / new_size.abs();
+        } else if new_size.signum() != position.size.signum() {
+            // Flipped: the remainder was opened at this trade's price.
+            position.avg_price = trade.price;
+        }
+        position.size = new_size;
+    }
+    positions
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testutil::sample_trade as trade;
+
+    fn only_position(trades: &[Trade]) -> ReconstructedPosition {
+        let positions = positions_from_trades(trades);
+        assert_eq!(positions.len(), 1);
+        *positions.values().next().unwrap()
+    }
+
+    #[test]
+    fn buys_then_partial_sell_keep_average_entry() {
+        let trades = [
+            trade(3, TradeSide::Sell, 0.70, 60.0),
+            trade(1, TradeSide::Buy, 0.40, 50.0),
+            trade(2, TradeSide::Buy, 0.60, 50.0),
+        ];
+        let position = only_position(&trades);
+
+        assert!((position.size - 40.0).abs() < 1e-12);
+        assert!((position.avg_price - 0.50).abs() < 1e-12);
+    }
+
+    #[test]
+    fn closing_and_flipping_reset_average() {
+        let closed = only_position(&[
+            trade(1, TradeSide::Buy, 0.40, 10.0),
+            trade(2, TradeSide::Sell, 0.45, 10.0),
+        ]);
+        assert_eq!(closed.size, 0.0);
+        assert_eq!(closed.avg_price, 0.0);
+
+        let flipped = only_position(&[
+            trade(1, TradeSide::Buy, 0.40, 10.0),
+            trade(2, TradeSide::Sell, 0.45, 15.0),
+        ]);
+        assert!((flipped.size + 5.0).abs() < 1e-12);
+        assert_eq!(flipped.avg_price, 0.45);
+    }
+}
@@ -0,0 +1,112 @@
+//! Running statistics over a stream of values.
+
+use futures_util::{Stream, StreamExt};
+
+/// Count, min, max and mean of the values seen so far.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct StatsSnapshot {
+    /// Number of values seen.
+    pub count: u64,
+    /// Smallest value, NaN before the first update.
+    pub min: f64,
+    /// Largest value, NaN before the first update.
+    pub max: f64,
+    /// Mean value, NaN before the first update.
+    pub mean: f64,
+}
+
+/// Constant-memory running statistics.
+///
+/// The mean is updated incrementally (Welford), so it stays accurate over
+/// long streams without keeping a running sum. NaN values are ignored.
+#[derive(Debug, Clone, Default)]
+pub struct RunningStats {
+    count: u64,
+    min: f64,
+    max: f64,
+    mean: f64,
+}
+
+impl RunningStats {
+    /// Creates stats with no values seen.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds one value.
+    pub fn update(&mut self, value: f64) {
+        if value.is_nan() {
+            return;
+        }
+        self.count += 1;
+        if self.count == 1 {
+            self.min = value;
+            self.max = value;
+            self.mean = value;
+            return;
+        }
+        self.min = self.min.min(value);
+        self.max = self.max.max(value);
+        self.mean += (value - self.mean) / self.count as f64;
+    }
+
+    /// Current statistics.
+    pub fn snapshot(&self) -> StatsSnapshot {
+        if self.count == 0 {
+            return StatsSnapshot {
+                count: 0,
+                min: f64::NAN,
+                max: f64::NAN,
+                mean: f64::NAN,
+            };
+        }
+        StatsSnapshot {
+            count: self.count,
+            min: self.min,
+            max: self.max,
+            mean: self.mean,
+        }
+    }
+}
+
+/// Yields updated statistics after each value of `values`.
+pub fn running_stats<S>(values: S) -> impl Stream<Item = StatsSnapshot>
+where
+    S: Stream<Item = f64>,
+{
+    values.scan(RunningStats::new(), |stats, value| {
+        stats.update(value);
+        std::future::ready(Some(stats.snapshot()))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn stats_over_known_sequence() {
+        let values = [4.0, 7.0, 13.0, f64::NAN, 16.0, 1.0];
+        let snapshots: Vec<StatsSnapshot> = running_stats(futures_util::stream::iter(values))
+            .collect()
+            .await;
+
+        assert_eq!(snapshots.len(), values.len());
+        assert_eq!(snapshots[0].mean, 4.0);
+        // NaN leaves the stats unchanged.
+        assert_eq!(snapshots[3], snapshots[2]);
+
+        let last = snapshots.last().unwrap();
+        assert_eq!(last.count, 5);
+        assert_eq!(last.min, 1.0);
+        assert_eq!(last.max, 16.0);
+        assert!((last.mean - 8.2).abs() < 1e-12);
+    }
+
+    #[test]
+    fn empty_stats_are_nan() {
+        let snapshot = RunningStats::new().snapshot();
+        assert_eq!(snapshot.count, 0);
+        assert!(snapshot.min.is_nan() && snapshot.max.is_nan() && snapshot.mean.is_nan());
+    }
+}
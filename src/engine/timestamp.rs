@@ -0,0 +1,146 @@
+//! Tolerant parsing of upstream timestamps.
+
+use chrono::{DateTime, Utc};
+use serde_json::Value;
+
+/// Numeric timestamps at or above this are taken as milliseconds.
+///
+/// 10^12 seconds is tens of thousands of years away, while 10^12
+/// milliseconds is September 2001.
+const MILLIS_THRESHOLD: f64 = 1e12;
+
+/// Parse a timestamp into Unix seconds.
+///
+/// Accepts the forms upstream APIs use:
+/// - integers or floats (`1551157200`), as CoinGecko sends
+/// - stringified numbers (`"1551157200"`), as Alternative.me sends
+/// - RFC 3339 strings (`"2024-01-01T00:00:00.000Z"`), as CoinMarketCap sends
+///
+/// Numbers at or above 10^12 are read as milliseconds and truncated to
+/// seconds. Returns `None` for anything else, including negative times.
+pub fn parse_timestamp(value: &Value) -> Option<i64> {
+    match value {
+        Value::Number(n) => non_negative(from_number(n.as_f64()?)?),
+        Value::String(s) => parse_timestamp_str(s),
+        _ => None,
+    }
+}
+
+/// Parse a stringified number or RFC 3339 string into Unix seconds.
+///
+/// The string forms of [`parse_timestamp`], for fields already typed as
+/// strings.
+pub fn parse_timestamp_str(s: &str) -> Option<i64> {
+    let s = s.trim();
+    let secs = match s.parse::<f64>() {
+        Ok(n) => from_number(n)?,
+        Err(_) => chrono::DateTime::parse_from_rfc3339(s).ok()?.timestamp(),
+    };
+    non_negative(secs)
+}
+
+/// Normalise a Unix timestamp in seconds or milliseconds to seconds.
+///
+/// The integer form of [`parse_timestamp`], for fields already typed as
+/// integers.
+pub fn parse_timestamp_i64(n: i64) -> Option<i64> {
+    non_negative(from_number(n as f64)?)
+}
+
+/// Unix seconds as a UTC datetime.
+///
+/// Out-of-range timestamps map to the Unix epoch, so callers formatting
+/// stored or upstream times never have to handle a missing value.
+pub fn datetime_from_secs(secs: i64) -> DateTime<Utc> {
+    DateTime::from_timestamp(secs, 0).unwrap_or_default()
+}
+
+/// Unix milliseconds as a UTC datetime; see [`datetime_from_secs`] for how
+/// out-of-range timestamps are handled.
+pub fn datetime_from_millis(millis: i64) -> DateTime<Utc> {
+    DateTime::from_timestamp_millis(millis).unwrap_or_default()
+}
+
+fn non_negative(secs: i64) -> Option<i64> {
+    (secs >= 0).then_some(secs)
+}
+
+fn from_number(n: f64) -> Option<i64> {
+    if !n.is_finite() {
+        return None;
+    }
+    let secs = if n.abs() >= MILLIS_THRESHOLD {
+        n / 1000.0
+    } else {
+        n
+    };
+    Some(secs.trunc() as i64)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn parses_integer_unix_seconds_and_millis() {
+        assert_eq!(parse_timestamp(&json!(1551157200)), Some(1551157200));
+        assert_eq!(parse_timestamp(&json!(1551157200.7)), Some(1551157200));
+        assert_eq!(parse_timestamp(&json!(1709395200000_i64)), Some(1709395200));
+    }
+
+    #[test]
+    fn parses_stringified_unix() {
+        assert_eq!(parse_timestamp(&json!("1551157200")), Some(1551157200));
+        assert_eq!(parse_timestamp(&json!(" 1709395200000 ")), Some(1709395200));
+    }
+
+    #[test]
+    fn parses_rfc3339() {
+        assert_eq!(
+            parse_timestamp(&json!("2024-01-01T00:00:00.000Z")),
+            Some(1704067200)
+        );
+        assert_eq!(
+            parse_timestamp(&json!("2024-01-01T02:00:00+02:00")),
+            Some(1704067200)
+        );
+    }
+
+    #[test]
+    fn parses_str_forms_directly() {
+        assert_eq!(parse_timestamp_str(" 1551157200 "), Some(1551157200));
+        assert_eq!(
+            parse_timestamp_str("2024-01-01T00:00:00.000Z"),
+            Some(1704067200)
+        );
+        assert_eq!(parse_timestamp_str("-5"), None);
+    }
+
+    #[test]
+    fn parses_integers_directly() {
+        assert_eq!(parse_timestamp_i64(1551157200), Some(1551157200));
+        assert_eq!(parse_timestamp_i64(1709395200000), Some(1709395200));
+        assert_eq!(parse_timestamp_i64(-5), None);
+    }
+
+    #[test]
+    fn rejects_other_values() {
+        assert_eq!(parse_timestamp(&json!("not_a_number")), None);
+        assert_eq!(parse_timestamp(&json!("12-19-2023")), None);
+        assert_eq!(parse_timestamp(&json!(-5)), None);
+        assert_eq!(parse_timestamp(&json!(null)), None);
+        assert_eq!(parse_timestamp(&json!(true)), None);
+    }
+
+    #[test]
+    fn out_of_range_datetimes_map_to_the_epoch() {
+        assert_eq!(datetime_from_secs(1_700_000_000).timestamp(), 1_700_000_000);
+        assert_eq!(
+            datetime_from_millis(1_700_000_000_000).timestamp(),
+            1_700_000_000
+        );
+        assert_eq!(datetime_from_secs(i64::MAX), DateTime::<Utc>::UNIX_EPOCH);
+        assert_eq!(datetime_from_millis(i64::MIN), DateTime::<Utc>::UNIX_EPOCH);
+    }
+}
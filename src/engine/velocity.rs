@@ -0,0 +1,92 @@
+//! Trade velocity bucketed by time.
+
+use std::collections::BTreeMap;
+
+use crate::client::polymarket::data::{Trade, TradeSide};
+
+/// Trade activity within one time bucket.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct VelocityBucket {
+    /// Start of the bucket (seconds), a multiple of the bucket width.
+    pub bucket_ts: i64,
+    /// Number of trades in the bucket.
+    pub trade_count: usize,
+    /// Total traded size.
+    pub volume: f64,
+    /// Size traded by buyers.
+    pub buy_volume: f64,
+    /// Size traded by sellers.
+    pub sell_volume: f64,
+}
+
+/// Count and volume of trades per `bucket_secs` window.
+///
+/// Trades are grouped by `timestamp` into windows aligned to multiples of
+/// `bucket_secs`. Only buckets containing trades are returned, oldest first.
+/// Returns an empty vector if `bucket_secs` is not positive.
+pub fn market_velocity(trades: &[Trade], bucket_secs: i64) -> Vec<VelocityBucket> {
+    if bucket_secs <= 0 {
+        return Vec::new();
+    }
+
+    let mut buckets: BTreeMap<i64, VelocityBucket> = BTreeMap::new();
+    for trade in trades {
+        let bucket_ts = trade.timestamp.div_euclid(bucket_secs) * bucket_secs;
+        let bucket = buckets.entry(bucket_ts).or_insert(VelocityBucket {
+            bucket_ts,
+            trade_count: 0,
+            volume: 0.0,
+            buy_volume: 0.0,
+            sell_volume: 0.0,
+        });
+        bucket.trade_count += 1;
+        bucket.volume += trade.size;
+        match trade.side {
+            TradeSide::Buy => bucket.buy_volume += trade.size,
+            TradeSide::Sell => bucket.sell_volume += trade.size,
+        }
+    }
+    buckets.into_values().collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testutil::sample_trade;
+
+    fn trade(timestamp: i64, side: TradeSide, size: f64) -> Trade {
+        sample_trade(timestamp, side, 0.5, size)
+    }
+
+    #[test]
+    fn trades_split_into_two_buckets() {
+        let trades = [
+            trade(125, TradeSide::Sell, 4.0),
+            trade(60, TradeSide::Buy, 10.0),
+            trade(119, TradeSide::Sell, 2.5),
+            trade(90, TradeSide::Buy, 1.5),
+        ];
+        let buckets = market_velocity(&trades, 60);
+
+        assert_eq!(
+            buckets,
+            vec![
+                VelocityBucket {
+                    bucket_ts: 60,
+                    trade_count: 3,
+                    volume: 14.0,
+                    buy_volume: 11.5,
+                    sell_volume: 2.5,
+                },
+                VelocityBucket {
+                    bucket_ts: 120,
+                    trade_count: 1,
+                    volume: 4.0,
+                    buy_volume: 0.0,
+                    sell_volume: 4.0,
+                },
+            ]
+        );
+        assert!(market_velocity(&trades, 0).is_empty());
+    }
+}
@@ -21,6 +21,10 @@ pub enum PolymarketError {
     #[error("API error: {0}")]
     Api(String),
 
+    /// The requested resource does not exist (HTTP 404).
+    #[error("Not found: {0}")]
+    NotFound(String),
+
     /// URL parsing error.
     #[error("URL parsing error: {0}")]
     Url(#[from] url::ParseError),
@@ -33,11 +37,35 @@ pub enum PolymarketError {
     #[error("Serialization error: {0}")]
     Serde(#[from] serde_json::Error),
 
+    /// Response body was not JSON (e.g. an HTML gateway error page).
+    #[error(transparent)]
+    UnexpectedContentType(#[from] crate::client::http::UnexpectedContentType),
+
+    /// A paginator hit its `max_pages` safeguard.
+    #[error(transparent)]
+    MaxPagesExceeded(#[from] crate::client::pagination::MaxPagesExceeded),
+
+    /// An error shared by the callers of one coalesced request; see
+    /// [`Coalescer::run`](crate::client::coalesce::Coalescer::run).
+    #[error(transparent)]
+    Shared(std::sync::Arc<PolymarketError>),
+
     /// Generic error with custom message.
     #[error("{0}")]
     Other(String),
 }
 
+impl From<crate::client::http::ResponseError> for PolymarketError {
+    fn from(e: crate::client::http::ResponseError) -> Self {
+        use crate::client::http::ResponseError;
+        match e {
+            ResponseError::UnexpectedContentType(e) => Self::UnexpectedContentType(e),
+            ResponseError::Body(e) => Self::Http(e),
+            ResponseError::Json(e) => Self::Serde(e),
+        }
+    }
+}
+
 /// A specialized Result type for Polymarket SDK operations.
 pub type Result<T> = std::result::Result<T, PolymarketError>;
 
@@ -52,6 +80,11 @@ impl PolymarketError {
         Self::Api(msg.into())
     }
 
+    /// Creates a new not found error.
+    pub fn not_found<S: Into<String>>(msg: S) -> Self {
+        Self::NotFound(msg.into())
+    }
+
     /// Creates a new bad request error.
     pub fn bad_request<S: Into<String>>(msg: S) -> Self {
         Self::BadRequest(msg.into())
@@ -61,6 +94,14 @@ impl PolymarketError {
     pub fn other<S: Into<String>>(msg: S) -> Self {
         Self::Other(msg.into())
     }
+
+    /// The error itself, looking through [`Shared`](Self::Shared).
+    pub fn unshared(&self) -> &PolymarketError {
+        match self {
+            Self::Shared(inner) => inner.unshared(),
+            other => other,
+        }
+    }
 }
 
 #[cfg(test)]
@@ -107,6 +148,13 @@ mod tests {
         assert_eq!(err.to_string(), "API error: test");
     }
 
+    #[test]
+    fn test_display_not_found() {
+        let err = PolymarketError::not_found("market 42");
+        assert!(matches!(err, PolymarketError::NotFound(_)));
+        assert_eq!(err.to_string(), "Not found: market 42");
+    }
+
     #[test]
     fn test_display_bad_request() {
         let err = PolymarketError::BadRequest("test".to_string());
@@ -0,0 +1,384 @@
+//! JSON output helpers.
+//!
+//! Upstream APIs occasionally return values that parse to NaN or infinity.
+//! JSON has no representation for those, so [`to_sanitized_value`] replaces
+//! them with `null` and logs a warning instead of letting one bad field break
+//! an entire response.
+//!
+//! On the input side, [`deserialize_null_as_empty_vec`] accepts `null` where
+//! a list is expected.
+
+use serde::{Deserialize, Deserializer, Serialize, ser};
+
+/// Serialize `value` to a [`serde_json::Value`], replacing non-finite floats with `null`.
+///
+/// A warning is logged with the number of replaced values, so bad data stays
+/// visible without aborting the output.
+///
+/// # Example
+///
+/// ```
+/// use polymarket_hft::json::to_sanitized_value;
+///
+/// let value = to_sanitized_value(&vec![1.5, f64::NAN]).unwrap();
+/// assert_eq!(value, serde_json::json!([1.5, null]));
+/// ```
+pub fn to_sanitized_value<T: Serialize + ?Sized>(
+    value: &T,
+) -> serde_json::Result<serde_json::Value> {
+    warn_non_finite(value);
+    // serde_json maps NaN and infinities to `Value::Null`.
+    serde_json::to_value(value)
+}
+
+/// Log a warning if `value` contains non-finite floats, returning how many.
+///
+/// Call this before handing `value` to a serde_json serializer, which writes
+/// those floats as `null`.
+pub fn warn_non_finite<T: Serialize + ?Sized>(value: &T) -> usize {
+    let replaced = count_non_finite(value);
+    if replaced > 0 {
+        tracing::warn!(
+            count = replaced,
+            "replaced non-finite float values with null in JSON output"
+        );
+    }
+    replaced
+}
+
+/// Count the NaN and infinite floats reachable from `value`.
+///
+/// `serde_json::Value` cannot hold non-finite floats, so `value` is run
+/// through a serializer that only looks at floats and builds nothing. If
+/// serializing `value` fails, the floats seen before the failure are counted.
+pub fn count_non_finite<T: Serialize + ?Sized>(value: &T) -> usize {
+    let mut counter = NonFiniteCounter(0);
+    let _ = value.serialize(&mut counter);
+    counter.0
+}
+
+/// Serializer behind [`count_non_finite`].
+struct NonFiniteCounter(usize);
+
+/// Error of [`NonFiniteCounter`], raised only by `Serialize` impls.
+#[derive(Debug)]
+struct CountAborted;
+
+impl std::fmt::Display for CountAborted {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("serialization failed while counting non-finite floats")
+    }
+}
+
+impl std::error::Error for CountAborted {}
+
+impl ser::Error for CountAborted {
+    fn custom<T: std::fmt::Display>(_msg: T) -> Self {
+        CountAborted
+    }
+}
+
+impl ser::Serializer for &mut NonFiniteCounter {
+    type Ok = ();
+    type Error = CountAborted;
+    type SerializeSeq = Self;
+    type SerializeTuple = Self;
+    type SerializeTupleStruct = Self;
+    type SerializeTupleVariant = Self;
+    type SerializeMap = Self;
+    type SerializeStruct = Self;
+    type SerializeStructVariant = Self;
+
+    fn serialize_f32(self, v: f32) -> Result<(), CountAborted> {
+        self.0 += !v.is_finite() as usize;
+        Ok(())
+    }
+
+    fn serialize_f64(self, v: f64) -> Result<(), CountAborted> {
+        self.0 += !v.is_finite() as usize;
+        Ok(())
+    }
+
+    fn serialize_bool(self, _: bool) -> Result<(), CountAborted> {
+        Ok(())
+    }
+
+    fn serialize_i8(self, _: i8) -> Result<(), CountAborted> {
+        Ok(())
+    }
+
+    fn serialize_i16(self, _: i16) -> Result<(), CountAborted> {
+        Ok(())
+    }
+
+    fn serialize_i32(self, _: i32) -> Result<(), CountAborted> {
+        Ok(())
+    }
+
+    fn serialize_i64(self, _: i64) -> Result<(), CountAborted> {
+        Ok(())
+    }
+
+    fn serialize_u8(self, _: u8) -> Result<(), CountAborted> {
+        Ok(())
+    }
+
+    fn serialize_u16(self, _: u16) -> Result<(), CountAborted> {
+        Ok(())
+    }
+
+    fn serialize_u32(self, _: u32) -> Result<(), CountAborted> {
+        Ok(())
+    }
+
+    fn serialize_u64(self, _: u64) -> Result<(), CountAborted> {
+        Ok(())
+    }
+
+    fn serialize_char(self, _: char) -> Result<(), CountAborted> {
+        Ok(())
+    }
+
+    fn serialize_str(self, _: &str) -> Result<(), CountAborted> {
+        Ok(())
+    }
+
+    fn serialize_bytes(self, _: &[u8]) -> Result<(), CountAborted> {
+        Ok(())
+    }
+
+    fn serialize_none(self) -> Result<(), CountAborted> {
+        Ok(())
+    }
+
+    fn serialize_some<T: Serialize + ?Sized>(self, value: &T) -> Result<(), CountAborted> {
+        value.serialize(self)
+    }
+
+    fn serialize_unit(self) -> Result<(), CountAborted> {
+        Ok(())
+    }
+
+    fn serialize_unit_struct(self, _: &'static str) -> Result<(), CountAborted> {
+        Ok(())
+    }
+
+    fn serialize_unit_variant(
+        self,
+        _: &'static str,
+        _: u32,
+        _: &'static str,
+    ) -> Result<(), CountAborted> {
+        Ok(())
+    }
+
+    fn serialize_newtype_struct<T: Serialize + ?Sized>(
+        self,
+        _: &'static str,
+        value: &T,
+    ) -> Result<(), CountAborted> {
+        value.serialize(self)
+    }
+
+    fn serialize_newtype_variant<T: Serialize + ?Sized>(
+        self,
+        _: &'static str,
+        _: u32,
+        _: &'static str,
+        value: &T,
+    ) -> Result<(), CountAborted> {
+        value.serialize(self)
+    }
+
+    fn serialize_seq(self, _: Option<usize>) -> Result<Self, CountAborted> {
+        Ok(self)
+    }
+
+    fn serialize_tuple(self, _: usize) -> Result<Self, CountAborted> {
+        Ok(self)
+    }
+
+    fn serialize_tuple_struct(self, _: &'static str, _: usize) -> Result<Self, CountAborted> {
+        Ok(self)
+    }
+
+    fn serialize_tuple_variant(
+        self,
+        _: &'static str,
+        _: u32,
+        _: &'static str,
+        _: usize,
+    ) -> Result<Self, CountAborted> {
+        Ok(self)
+    }
+
+    fn serialize_map(self, _: Option<usize>) -> Result<Self, CountAborted> {
+        Ok(self)
+    }
+
+    fn serialize_struct(self, _: &'static str, _: usize) -> Result<Self, CountAborted> {
+        Ok(self)
+    }
+
+    fn serialize_struct_variant(
+        self,
+        _: &'static str,
+        _: u32,
+        _: &'static str,
+        _: usize,
+    ) -> Result<Self, CountAborted> {
+        Ok(self)
+    }
+
+    // The default formats `value` into a `String` first.
+    fn collect_str<T: std::fmt::Display + ?Sized>(self, _: &T) -> Result<(), CountAborted> {
+        Ok(())
+    }
+}
+
+/// Implements the element methods of the compound serializer traits by
+/// walking each element with the counter itself.
+macro_rules! count_elements {
+    ($($trait:ident :: $method:ident ( $($key:ident),* );)*) => {
+        $(
+            impl ser::$trait for &mut NonFiniteCounter {
+                type Ok = ();
+                type Error = CountAborted;
+
+                fn $method<T: Serialize + ?Sized>(
+                    &mut self,
+                    $($key: &'static str,)*
+                    value: &T,
+                ) -> Result<(), CountAborted> {
+                    $(let _ = $key;)*
+                    value.serialize(&mut **self)
+                }
+
+                fn end(self) -> Result<(), CountAborted> {
+                    Ok(())
+                }
+            }
+        )*
+    };
+}
+
+count_elements! {
+    SerializeSeq::serialize_element();
+    SerializeTuple::serialize_element();
+    SerializeTupleStruct::serialize_field();
+    SerializeTupleVariant::serialize_field();
+    SerializeStruct::serialize_field(key);
+    SerializeStructVariant::serialize_field(key);
+}
+
+impl ser::SerializeMap for &mut NonFiniteCounter {
+    type Ok = ();
+    type Error = CountAborted;
+
+    fn serialize_key<T: Serialize + ?Sized>(&mut self, key: &T) -> Result<(), CountAborted> {
+        key.serialize(&mut **self)
+    }
+
+    fn serialize_value<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), CountAborted> {
+        value.serialize(&mut **self)
+    }
+
+    fn end(self) -> Result<(), CountAborted> {
+        Ok(())
+    }
+}
+
+/// Deserialize a list that upstream may send as `null`, yielding an empty `Vec`.
+///
+/// A field using it through `deserialize_with` stays required; add `default`
+/// as well only where upstream may leave the field out.
+pub fn deserialize_null_as_empty_vec<'de, D, T>(deserializer: D) -> Result<Vec<T>, D::Error>
+where
+    D: Deserializer<'de>,
+    T: Deserialize<'de>,
+{
+    Ok(Option::<Vec<T>>::deserialize(deserializer)?.unwrap_or_default())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashMap;
+
+    #[derive(Serialize)]
+    struct Quote {
+        symbol: String,
+        price: f64,
+        change: Option<f32>,
+        history: Vec<f64>,
+        extra: HashMap<String, f64>,
+    }
+
+    #[test]
+    fn replaces_non_finite_floats_with_null() {
+        let quote = Quote {
+            symbol: "BTC".to_string(),
+            price: f64::NAN,
+            change: Some(f32::INFINITY),
+            history: vec![1.0, f64::NEG_INFINITY],
+            extra: HashMap::from([("spread".to_string(), 0.5)]),
+        };
+
+        assert_eq!(count_non_finite(&quote), 3);
+        let value = to_sanitized_value(&quote).unwrap();
+        assert_eq!(
+            value,
+            serde_json::json!({
+                "symbol": "BTC",
+                "price": null,
+                "change": null,
+                "history": [1.0, null],
+                "extra": {"spread": 0.5},
+            })
+        );
+    }
+
+    #[test]
+    fn counts_floats_inside_enums_and_tuples() {
+        #[derive(Serialize)]
+        enum Tick {
+            Trade(f64),
+            Quote { bid: f32, ask: f32 },
+            Spread(f64, f64),
+        }
+
+        let ticks = (
+            Tick::Trade(f64::NAN),
+            Tick::Quote {
+                bid: 0.5,
+                ask: f32::INFINITY,
+            },
+            Tick::Spread(f64::NEG_INFINITY, 1.0),
+        );
+        assert_eq!(count_non_finite(&ticks), 3);
+    }
+
+    #[test]
+    fn finite_values_are_untouched() {
+        let values = vec![0.0, -1.25, 1e300];
+        assert_eq!(count_non_finite(&values), 0);
+        assert_eq!(
+            to_sanitized_value(&values).unwrap(),
+            serde_json::json!([0.0, -1.25, 1e300])
+        );
+    }
+
+    #[test]
+    fn null_and_missing_lists_deserialize_empty() {
+        #[derive(Deserialize)]
+        struct Page {
+            #[serde(default, deserialize_with = "deserialize_null_as_empty_vec")]
+            items: Vec<u32>,
+        }
+
+        let parse = |json| serde_json::from_value::<Page>(json).unwrap().items;
+        assert_eq!(parse(serde_json::json!({"items": [1, 2]})), vec![1, 2]);
+        assert!(parse(serde_json::json!({"items": null})).is_empty());
+        assert!(parse(serde_json::json!({})).is_empty());
+    }
+}
@@ -27,15 +27,19 @@
 
 pub mod client;
 pub mod config;
+pub mod engine;
 pub mod error;
+pub mod json;
 pub mod scheduler;
 pub mod serve;
 pub mod storage;
 pub mod task;
+#[cfg(test)]
+pub(crate) mod testutil;
 pub mod web;
 
 pub use error::{PolymarketError, Result};
 pub use scheduler::SchedulerHandle;
 pub use storage::{
-    DataSource, LocalStorage, LocalStorageConfig, Metric, MetricUnit, StorageBackend,
+    DataSource, LocalStorage, LocalStorageConfig, Metric, MetricUnit, StorageBackend, ToMetrics,
 };
@@ -2,6 +2,15 @@ use clap::{Parser, Subcommand};
 use tracing_subscriber::EnvFilter;
 
 mod cli;
+// The library's fixtures, compiled into the binary's tests as well; not every
+// fixture is used here.
+#[cfg(test)]
+#[allow(dead_code)]
+#[path = "testutil.rs"]
+mod testutil;
+// Lets the fixtures' `crate::client` paths resolve to the library.
+#[cfg(test)]
+use polymarket_hft::client;
 
 use cli::{ds, serve};
 
@@ -61,4 +70,76 @@ mod tests {
             _ => panic!("expected ds command"),
         }
     }
+
+    #[test]
+    fn parses_print_url_flag() {
+        let cli = Cli::parse_from([
+            "polymarket",
+            "ds",
+            "data",
+            "get-trades",
+            "--market",
+            "0xdd22472e552920b8438158ea7238bfadfa4f736aa4cee91a6b86c39ead110917",
+            "--print-url",
+        ]);
+        match cli.command {
+            Commands::Ds(ref cmd) => match cmd.as_ref() {
+                ds::DsCommands::Data(data::DataCommands::GetTrades { params }) => {
+                    assert!(params.print_url);
+                }
+                _ => panic!("expected get-trades command"),
+            },
+            _ => panic!("expected ds command"),
+        }
+    }
+
+    #[test]
+    fn rejects_out_of_range_page_size() {
+        let parse = |page_size: &str| {
+            Cli::try_parse_from([
+                "polymarket",
+                "ds",
+                "data",
+                "export-trades",
+                "--user",
+                "0x56687bf447db6ffa42ffe2204a05edaa20f55839",
+                "--out",
+                "trades.ndjson",
+                "--page-size",
+                page_size,
+            ])
+        };
+
+        assert!(parse("500").is_ok());
+        assert!(parse("0").is_err());
+        assert!(parse("10001").is_err());
+    }
+
+    #[tokio::test]
+    async fn validate_only_checks_request_without_sending() {
+        let run = |user: &'static str| async move {
+            let cli = Cli::parse_from([
+                "polymarket",
+                "ds",
+                "data",
+                "get-user-positions",
+                "--user",
+                user,
+                "--limit",
+                "50",
+                "--validate-only",
+            ]);
+            match cli.command {
+                Commands::Ds(cmd) => ds::handle(&cmd).await,
+                _ => panic!("expected ds command"),
+            }
+        };
+
+        // No server is reachable in tests, so success means nothing was sent.
+        run("0x56687bf447db6ffa42ffe2204a05edaa20f55839")
+            .await
+            .unwrap();
+        let err = run("not-an-address").await.unwrap_err();
+        assert!(err.to_string().contains("0x"), "{err}");
+    }
 }
@@ -7,13 +7,51 @@ use std::collections::HashMap;
 use std::sync::Arc;
 use std::time::Duration;
 
-use tokio::sync::RwLock;
+use serde::Serialize;
+use tokio::sync::{Mutex, RwLock};
 use tokio_cron_scheduler::{Job, JobScheduler};
+use tokio_util::sync::CancellationToken;
 use uuid::Uuid;
 
-use crate::client::DataSourceClient;
-use crate::config::{IngestionJob, Schedule};
-use crate::storage::{Event, EventType, StorageBackend};
+use crate::DataSource;
+use crate::client::{BoxFuture, DataSourceClient};
+use crate::config::{IngestionJob, SamplingMode, Schedule};
+use crate::storage::{
+    Event, EventType, LabelCardinalityGuard, Metric, MetricSink, StorageBackend, StorageSink,
+};
+
+/// Default pause between flushes of buffered task events.
+pub const DEFAULT_EVENT_FLUSH_INTERVAL: Duration = Duration::from_secs(5);
+
+/// Buffered task events that trigger a flush without waiting for the interval.
+const EVENT_BUFFER_FLUSH_THRESHOLD: usize = 100;
+
+/// Number of recent events scanned when looking up each job's last event.
+const JOB_STATUS_EVENT_SCAN_LIMIT: usize = 1000;
+
+/// Scheduling state of a stored job, as reported by [`SchedulerHandle::job_statuses`].
+#[derive(Debug, Clone, Serialize)]
+pub struct JobStatus {
+    /// Database ID.
+    pub id: i64,
+    pub name: String,
+    /// Whether the job is currently registered with the scheduler.
+    pub scheduled: bool,
+    pub schedule: Schedule,
+    /// Most recent task event for this job, if any.
+    pub last_event: Option<Event>,
+    /// Unix timestamp when the job last started a run.
+    pub last_run_at: Option<i64>,
+    /// Unix timestamp when the job runs next, while scheduled.
+    pub next_run_at: Option<i64>,
+}
+
+/// A job registered with the scheduler.
+struct ScheduledJob {
+    uuid: Uuid,
+    /// Definition the job was scheduled with, to detect later edits.
+    job: IngestionJob,
+}
 
 /// Handle for dynamic job scheduling operations.
 ///
@@ -24,62 +62,174 @@ use crate::storage::{Event, EventType, StorageBackend};
 #[derive(Clone)]
 pub struct SchedulerHandle {
     scheduler: JobScheduler,
-    /// Maps database job ID -> scheduler job UUID
-    job_map: Arc<RwLock<HashMap<i64, Uuid>>>,
+    /// Maps database job ID -> scheduler job UUID and the definition it runs
+    job_map: Arc<RwLock<HashMap<i64, ScheduledJob>>>,
+    /// Cancellation tokens for aligned interval jobs, keyed by their UUID.
+    ///
+    /// Aligned jobs run on their own task instead of inside `JobScheduler`.
+    aligned_tasks: Arc<RwLock<HashMap<Uuid, CancellationToken>>>,
     client: Arc<dyn DataSourceClient>,
+    /// Clients for specific data sources, used instead of `client`.
+    clients: Arc<HashMap<DataSource, Arc<dyn DataSourceClient>>>,
     storage: Arc<dyn StorageBackend>,
+    /// Destinations for fetched metrics; local storage is always the first.
+    sinks: Arc<Vec<Arc<dyn MetricSink>>>,
+    /// Skip fetching when the storage health check fails.
+    storage_preflight: bool,
+    /// Data sources jobs may use; empty allows all.
+    enabled_sources: Arc<Vec<DataSource>>,
+    /// Caps distinct label values per metric, if configured.
+    label_guard: Option<Arc<LabelCardinalityGuard>>,
+    /// Points seen per job and series, for `every_nth` sampling.
+    sample_counters: Arc<SampleCounters>,
+    /// Task run events waiting to be written in one batch.
+    events: EventBuffer,
+    /// Stops the periodic event flush on shutdown.
+    flush_token: CancellationToken,
     instance_id: String,
 }
 
 impl SchedulerHandle {
     /// Create a new SchedulerHandle.
+    ///
+    /// `client` fetches for every data source without a client registered
+    /// with [`with_client`](Self::with_client).
     pub async fn new(
         client: Arc<dyn DataSourceClient>,
         storage: Arc<dyn StorageBackend>,
         instance_id: String,
     ) -> anyhow::Result<Self> {
         let scheduler = JobScheduler::new().await?;
+        let sinks: Vec<Arc<dyn MetricSink>> = vec![Arc::new(StorageSink::new(storage.clone()))];
         Ok(Self {
             scheduler,
             job_map: Arc::new(RwLock::new(HashMap::new())),
+            aligned_tasks: Arc::new(RwLock::new(HashMap::new())),
             client,
+            clients: Arc::new(HashMap::new()),
+            events: EventBuffer::new(storage.clone()),
             storage,
+            sinks: Arc::new(sinks),
+            storage_preflight: false,
+            enabled_sources: Arc::new(Vec::new()),
+            label_guard: None,
+            sample_counters: Arc::new(SampleCounters::default()),
+            flush_token: CancellationToken::new(),
             instance_id,
         })
     }
 
+    /// Check storage health before each run and skip the fetch if it fails.
+    ///
+    /// Avoids spending upstream API calls on metrics that cannot be stored.
+    /// Only affects jobs scheduled after this call.
+    pub fn with_storage_preflight(mut self, enabled: bool) -> Self {
+        self.storage_preflight = enabled;
+        self
+    }
+
+    /// Reject jobs whose data source is not in `sources`.
+    ///
+    /// An empty list allows every source.
+    pub fn with_enabled_sources(mut self, sources: Vec<DataSource>) -> Self {
+        self.enabled_sources = Arc::new(sources);
+        self
+    }
+
+    /// Drop labels once a metric has `max_values` distinct values for them.
+    ///
+    /// Protects storage from jobs that emit a unique label per fetch. Zero
+    /// disables the limit. Only affects jobs scheduled after this call.
+    pub fn with_max_label_values(mut self, max_values: usize) -> Self {
+        self.label_guard =
+            (max_values > 0).then(|| Arc::new(LabelCardinalityGuard::new(max_values)));
+        self
+    }
+
+    /// Fetch jobs of `source` with `client` instead of the default client.
+    ///
+    /// Only affects jobs scheduled after this call.
+    pub fn with_client(mut self, source: DataSource, client: Arc<dyn DataSourceClient>) -> Self {
+        Arc::make_mut(&mut self.clients).insert(source, client);
+        self
+    }
+
+    /// Add a metric sink that receives every batch alongside local storage.
+    ///
+    /// Only affects jobs scheduled after this call.
+    pub fn with_sink(mut self, sink: Arc<dyn MetricSink>) -> Self {
+        Arc::make_mut(&mut self.sinks).push(sink);
+        self
+    }
+
     /// Schedule a job by database ID.
     ///
     /// Returns the scheduler UUID if successful.
-    /// If the job is disabled or already scheduled, returns an error.
+    /// If the job is disabled, uses a data source that is not enabled, or is
+    /// already scheduled, returns an error.
     pub async fn schedule_job(&self, job_id: i64, job: &IngestionJob) -> anyhow::Result<Uuid> {
         if !job.enabled {
             anyhow::bail!("Cannot schedule disabled job '{}'", job.name);
         }
+        job.check_source_enabled(&self.enabled_sources)?;
 
-        // Check for duplicate scheduling to prevent orphan tasks
-        if self.is_scheduled(job_id).await {
-            anyhow::bail!(
-                "Job '{}' (id={}) is already scheduled. Use reschedule_job() to update.",
-                job.name,
-                job_id
+        // Reserve the id under the write lock before adding the task, so two
+        // concurrent calls cannot both schedule it and orphan a task. The nil
+        // placeholder UUID is replaced once the task exists.
+        {
+            let mut map = self.job_map.write().await;
+            if map.contains_key(&job_id) {
+                anyhow::bail!(
+                    "Job '{}' (id={}) is already scheduled. Use reschedule_job() to update.",
+                    job.name,
+                    job_id
+                );
+            }
+            map.insert(
+                job_id,
+                ScheduledJob {
+                    uuid: Uuid::nil(),
+                    job: job.clone(),
+                },
             );
         }
 
-        let cron_job = self.create_ingestion_job(job)?;
-        let uuid = self.scheduler.add(cron_job).await?;
+        let (uuid, next_run_at) = match self.add_task(job).await {
+            Ok(added) => added,
+            Err(e) => {
+                self.job_map.write().await.remove(&job_id);
+                return Err(e);
+            }
+        };
 
-        {
+        let reserved = {
             let mut map = self.job_map.write().await;
-            map.insert(job_id, uuid);
+            match map.get_mut(&job_id) {
+                Some(scheduled) if scheduled.uuid.is_nil() => {
+                    scheduled.uuid = uuid;
+                    true
+                }
+                _ => false,
+            }
+        };
+        if !reserved {
+            // Unscheduled while the task was being added.
+            self.remove_task(uuid).await?;
+            anyhow::bail!(
+                "Job '{}' (id={}) was unscheduled while being scheduled",
+                job.name,
+                job_id
+            );
         }
+        record_next_run(&self.storage, &job.name, next_run_at).await;
 
         // Record TaskScheduled event
         let event = Event::new(
             &self.instance_id,
             EventType::TaskScheduled,
             format!("Task '{}' scheduled", job.name),
-        );
+        )
+        .with_job_name(&job.name);
         if let Err(e) = self.storage.store_event(&event).await {
             tracing::warn!(error = %e, "Failed to record task scheduled event");
         }
@@ -97,13 +247,19 @@ impl SchedulerHandle {
     ///
     /// If the job is not currently scheduled, this is a no-op.
     pub async fn unschedule_job(&self, job_id: i64) -> anyhow::Result<()> {
-        let uuid = {
+        let removed = {
             let mut map = self.job_map.write().await;
             map.remove(&job_id)
         };
 
-        if let Some(uuid) = uuid {
-            self.scheduler.remove(&uuid).await?;
+        if let Some(ScheduledJob { uuid, job }) = removed {
+            // A nil UUID is a reservation whose task is still being added;
+            // `schedule_job` removes that task itself.
+            if !uuid.is_nil() {
+                self.remove_task(uuid).await?;
+            }
+            self.sample_counters.clear_job(&job.name);
+            record_next_run(&self.storage, &job.name, None).await;
             tracing::info!(job_id = job_id, uuid = %uuid, "Job unscheduled");
         } else {
             tracing::debug!(job_id = job_id, "Job was not scheduled, nothing to remove");
@@ -111,6 +267,37 @@ impl SchedulerHandle {
         Ok(())
     }
 
+    /// Add the task for `job` to the scheduler, returning its UUID and first
+    /// run time (Unix seconds).
+    async fn add_task(&self, job: &IngestionJob) -> anyhow::Result<(Uuid, Option<i64>)> {
+        match &job.schedule {
+            Schedule::Interval {
+                interval_secs,
+                aligned: true,
+            } => {
+                let interval = Duration::from_secs(*interval_secs);
+                let uuid = self.spawn_aligned_job(job, interval).await;
+                Ok((uuid, Some(next_aligned_run(interval))))
+            }
+            _ => {
+                let cron_job = self.create_ingestion_job(job)?;
+                let uuid = self.scheduler.add(cron_job).await?;
+                let next = self.scheduler.clone().next_tick_for_job(uuid).await;
+                Ok((uuid, next.ok().flatten().map(|at| at.timestamp())))
+            }
+        }
+    }
+
+    /// Stop the task with `uuid`, whether aligned or cron-driven.
+    async fn remove_task(&self, uuid: Uuid) -> anyhow::Result<()> {
+        let aligned = self.aligned_tasks.write().await.remove(&uuid);
+        match aligned {
+            Some(token) => token.cancel(),
+            None => self.scheduler.remove(&uuid).await?,
+        }
+        Ok(())
+    }
+
     /// Update a job (remove old + add new if enabled).
     pub async fn reschedule_job(&self, job_id: i64, job: &IngestionJob) -> anyhow::Result<()> {
         // Always remove first
@@ -129,6 +316,48 @@ impl SchedulerHandle {
         map.contains_key(&job_id)
     }
 
+    /// Status of every job in the database, in database order.
+    ///
+    /// Combines the stored job list with the in-memory schedule map and the
+    /// latest task event (scheduled/started/completed/failed) for each job
+    /// among the most recent events.
+    pub async fn job_statuses(&self) -> anyhow::Result<Vec<JobStatus>> {
+        let records = self.storage.list_jobs().await?;
+        let events = self
+            .storage
+            .get_events(None, Some(JOB_STATUS_EVENT_SCAN_LIMIT))
+            .await?;
+        let map = self.job_map.read().await;
+
+        Ok(records
+            .into_iter()
+            .map(|record| {
+                // Events come back newest first.
+                let last_event = events
+                    .iter()
+                    .filter(|e| {
+                        matches!(
+                            e.event_type,
+                            EventType::TaskScheduled
+                                | EventType::TaskExecuted
+                                | EventType::TaskFailed
+                        )
+                    })
+                    .find(|e| e.job_name() == Some(record.job.name.as_str()))
+                    .cloned();
+                JobStatus {
+                    id: record.id,
+                    scheduled: map.contains_key(&record.id),
+                    name: record.job.name,
+                    schedule: record.job.schedule,
+                    last_event,
+                    last_run_at: record.last_run_at,
+                    next_run_at: record.next_run_at,
+                }
+            })
+            .collect())
+    }
+
     /// Load all enabled jobs from database and schedule them.
     pub async fn load_jobs_from_db(&self) -> anyhow::Result<usize> {
         let db_jobs = self.storage.list_jobs().await?;
@@ -163,19 +392,121 @@ impl SchedulerHandle {
         Ok(scheduled_count)
     }
 
+    /// Bring the scheduled jobs in line with the database.
+    ///
+    /// Unschedules jobs that were deleted or disabled since they were
+    /// scheduled, reschedules jobs whose definition changed, and schedules
+    /// enabled jobs that are not running yet. Returns the number of jobs that
+    /// were scheduled, rescheduled or unscheduled.
+    pub async fn reconcile_with_db(&self) -> anyhow::Result<usize> {
+        let records = self.storage.list_jobs().await?;
+        let scheduled: Vec<(i64, IngestionJob)> = self
+            .job_map
+            .read()
+            .await
+            .iter()
+            .map(|(id, scheduled)| (*id, scheduled.job.clone()))
+            .collect();
+
+        let mut changed = 0;
+        for (job_id, running) in scheduled {
+            let record = records.iter().find(|r| r.id == job_id);
+            if let Some(record) = record.filter(|r| r.job.enabled) {
+                if record.job != running {
+                    match self.reschedule_job(job_id, &record.job).await {
+                        Ok(()) => {
+                            changed += 1;
+                            tracing::info!(
+                                job_id = job_id,
+                                name = %record.job.name,
+                                "Reconcile: rescheduled updated job"
+                            );
+                        }
+                        Err(e) => {
+                            tracing::error!(
+                                job_id = job_id,
+                                name = %record.job.name,
+                                error = %e,
+                                "Reconcile: failed to reschedule job"
+                            );
+                        }
+                    }
+                }
+                continue;
+            }
+            self.unschedule_job(job_id).await?;
+            changed += 1;
+            tracing::info!(
+                job_id = job_id,
+                reason = if record.is_some() {
+                    "disabled"
+                } else {
+                    "deleted"
+                },
+                "Reconcile: unscheduled job"
+            );
+        }
+
+        for record in records {
+            if !record.job.enabled || self.is_scheduled(record.id).await {
+                continue;
+            }
+            match self.schedule_job(record.id, &record.job).await {
+                Ok(_) => {
+                    changed += 1;
+                    tracing::info!(
+                        job_id = record.id,
+                        name = %record.job.name,
+                        "Reconcile: scheduled job"
+                    );
+                }
+                Err(e) => {
+                    tracing::error!(
+                        job_id = record.id,
+                        name = %record.job.name,
+                        error = %e,
+                        "Reconcile: failed to schedule job"
+                    );
+                }
+            }
+        }
+
+        Ok(changed)
+    }
+
     /// Start the scheduler.
+    ///
+    /// Also starts flushing buffered task events every
+    /// [`DEFAULT_EVENT_FLUSH_INTERVAL`].
     pub async fn start(&self) -> anyhow::Result<()> {
         self.scheduler.start().await?;
+        tokio::spawn(
+            self.events
+                .clone()
+                .run_flush_loop(DEFAULT_EVENT_FLUSH_INTERVAL, self.flush_token.clone()),
+        );
         tracing::info!("Scheduler started");
         Ok(())
     }
 
     /// Shutdown the scheduler gracefully.
+    ///
+    /// Flushes buffered task events and every metric sink before returning.
     pub async fn shutdown(&self) -> anyhow::Result<()> {
         // JobScheduler::shutdown takes &mut self, but we have shared access.
         // Clone creates a new handle to the same internal scheduler.
         let mut scheduler = self.scheduler.clone();
         scheduler.shutdown().await?;
+        for (_, token) in self.aligned_tasks.write().await.drain() {
+            token.cancel();
+        }
+        self.flush_token.cancel();
+        self.events.flush().await;
+        for sink in self.sinks.iter() {
+            if let Err(e) = sink.flush().await {
+                tracing::error!(sink = sink.name(), error = %e, "Failed to flush sink on shutdown");
+            }
+        }
         tracing::info!("Scheduler shutdown complete");
         Ok(())
     }
@@ -190,6 +521,31 @@ impl SchedulerHandle {
         &self.storage
     }
 
+    /// Get the metric sinks.
+    pub fn sinks(&self) -> &[Arc<dyn MetricSink>] {
+        &self.sinks
+    }
+
+    /// Sampling counters shared by the scheduled runs.
+    pub fn sample_counters(&self) -> &SampleCounters {
+        &self.sample_counters
+    }
+
+    /// Context that scheduled runs of this handle execute with (for trigger_job).
+    pub fn run_context(&self) -> JobRunContext {
+        JobRunContext {
+            client: Arc::clone(&self.client),
+            clients: Arc::clone(&self.clients),
+            storage: Arc::clone(&self.storage),
+            sinks: Arc::clone(&self.sinks),
+            events: self.events.clone(),
+            instance_id: self.instance_id.clone(),
+            storage_preflight: self.storage_preflight,
+            label_guard: self.label_guard.clone(),
+            sample_counters: Arc::clone(&self.sample_counters),
+        }
+    }
+
     /// Get instance ID.
     pub fn instance_id(&self) -> &str {
         &self.instance_id
@@ -208,32 +564,52 @@ impl SchedulerHandle {
     // Internal helpers
     // =========================================================================
 
+    /// Spawn a drift-corrected loop for an aligned interval job.
+    ///
+    /// Returns a generated UUID that identifies the task in `job_map`.
+    async fn spawn_aligned_job(&self, job_config: &IngestionJob, interval: Duration) -> Uuid {
+        let uuid = Uuid::now_v7();
+        let token = CancellationToken::new();
+        self.aligned_tasks.write().await.insert(uuid, token.clone());
+
+        let ctx = self.run_context();
+        let job = Arc::new(job_config.clone());
+
+        tokio::spawn(run_aligned_interval(
+            interval,
+            Arc::new(SystemClock),
+            token,
+            move || {
+                let ctx = ctx.clone();
+                let job = Arc::clone(&job);
+                async move {
+                    execute_ingestion_job(&job, &ctx).await;
+                    record_next_run(&ctx.storage, &job.name, Some(next_aligned_run(interval)))
+                        .await;
+                }
+            },
+        ));
+
+        uuid
+    }
+
     fn create_ingestion_job(&self, job_config: &IngestionJob) -> anyhow::Result<Job> {
-        let client = Arc::clone(&self.client);
-        let storage = Arc::clone(&self.storage);
-        let job_name = job_config.name.clone();
-        let method = job_config.method.clone();
-        let params = job_config.params.clone();
-        let instance_id = self.instance_id.clone();
-
-        let job = match &job_config.schedule {
-            Schedule::Interval { interval_secs } => {
+        let ctx = self.run_context();
+        let job = Arc::new(job_config.clone());
+
+        let cron_job = match &job_config.schedule {
+            Schedule::Interval { interval_secs, .. } => {
                 let duration = Duration::from_secs(*interval_secs);
-                Job::new_repeated_async(duration, move |_uuid, _lock| {
-                    let client = Arc::clone(&client);
-                    let storage = Arc::clone(&storage);
-                    let job_name = job_name.clone();
-                    let method = method.clone();
-                    let params = params.clone();
-                    let instance_id = instance_id.clone();
+                Job::new_repeated_async(duration, move |uuid, mut lock| {
+                    let ctx = ctx.clone();
+                    let job = Arc::clone(&job);
                     Box::pin(async move {
-                        execute_ingestion_job(
-                            &job_name,
-                            &method,
-                            params,
-                            &client,
-                            &storage,
-                            &instance_id,
+                        execute_ingestion_job(&job, &ctx).await;
+                        let next = lock.next_tick_for_job(uuid).await;
+                        record_next_run(
+                            &ctx.storage,
+                            &job.name,
+                            next.ok().flatten().map(|at| at.timestamp()),
                         )
                         .await;
                     })
@@ -241,21 +617,16 @@ impl SchedulerHandle {
             }
             Schedule::Cron { cron } => {
                 let cron_expr = normalize_cron(cron);
-                Job::new_async(cron_expr.as_str(), move |_uuid, _lock| {
-                    let client = Arc::clone(&client);
-                    let storage = Arc::clone(&storage);
-                    let job_name = job_name.clone();
-                    let method = method.clone();
-                    let params = params.clone();
-                    let instance_id = instance_id.clone();
+                Job::new_async(cron_expr.as_str(), move |uuid, mut lock| {
+                    let ctx = ctx.clone();
+                    let job = Arc::clone(&job);
                     Box::pin(async move {
-                        execute_ingestion_job(
-                            &job_name,
-                            &method,
-                            params,
-                            &client,
-                            &storage,
-                            &instance_id,
+                        execute_ingestion_job(&job, &ctx).await;
+                        let next = lock.next_tick_for_job(uuid).await;
+                        record_next_run(
+                            &ctx.storage,
+                            &job.name,
+                            next.ok().flatten().map(|at| at.timestamp()),
                         )
                         .await;
                     })
@@ -263,7 +634,177 @@ impl SchedulerHandle {
             }
         };
 
-        Ok(job)
+        Ok(cron_job)
+    }
+}
+
+// =============================================================================
+// Aligned interval scheduling
+// =============================================================================
+
+/// Source of wall-clock time for aligned interval jobs.
+///
+/// Abstracted so that tick alignment can be tested without real sleeps.
+pub trait Clock: Send + Sync {
+    /// Current unix time in milliseconds.
+    fn now_millis(&self) -> i64;
+
+    /// Sleep until the given unix time in milliseconds.
+    fn sleep_until(&self, at_millis: i64) -> BoxFuture<'_, ()>;
+}
+
+/// Clock backed by the system time and tokio timers.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct SystemClock;
+
+impl Clock for SystemClock {
+    fn now_millis(&self) -> i64 {
+        chrono::Utc::now().timestamp_millis()
+    }
+
+    fn sleep_until(&self, at_millis: i64) -> BoxFuture<'_, ()> {
+        Box::pin(async move {
+            let wait = (at_millis - self.now_millis()).max(0) as u64;
+            tokio::time::sleep(Duration::from_millis(wait)).await;
+        })
+    }
+}
+
+/// Compute the next aligned tick strictly after `now_millis`.
+///
+/// Ticks fall on multiples of the interval: `floor(now / interval) * interval + interval`.
+pub fn next_aligned_tick(now_millis: i64, interval_millis: i64) -> i64 {
+    now_millis.div_euclid(interval_millis) * interval_millis + interval_millis
+}
+
+/// Run `task` on wall-clock-aligned ticks until `token` is cancelled.
+///
+/// The next tick is computed from the intended schedule rather than from the
+/// completion time of the previous run. If a run overruns the interval, the
+/// missed ticks are skipped and the loop resumes on the next boundary.
+pub async fn run_aligned_interval<F, Fut>(
+    interval: Duration,
+    clock: Arc<dyn Clock>,
+    token: CancellationToken,
+    mut task: F,
+) where
+    F: FnMut() -> Fut,
+    Fut: Future<Output = ()>,
+{
+    let interval_millis = (interval.as_millis() as i64).max(1);
+    loop {
+        let next = next_aligned_tick(clock.now_millis(), interval_millis);
+        tokio::select! {
+            _ = token.cancelled() => break,
+            _ = clock.sleep_until(next) => {}
+        }
+        if token.is_cancelled() {
+            break;
+        }
+        task().await;
+    }
+}
+
+// =============================================================================
+// Sampling
+// =============================================================================
+
+/// In-memory point counts per job and series for `every_nth` sampling.
+///
+/// Owned by the [`SchedulerHandle`]; a job's counts are dropped when it is
+/// unscheduled, so a rescheduled job starts counting afresh.
+#[derive(Debug, Default)]
+pub struct SampleCounters {
+    seen: std::sync::Mutex<HashMap<(String, String), u64>>,
+}
+
+impl SampleCounters {
+    /// Count a point of `series` in `job_name`, returning whether it is the
+    /// first of a run of `n`.
+    fn keep(&self, job_name: &str, series: String, n: u32) -> bool {
+        let mut seen = self.lock();
+        let count = seen.entry((job_name.to_string(), series)).or_default();
+        let keep = count.is_multiple_of(u64::from(n.max(1)));
+        *count += 1;
+        keep
+    }
+
+    /// Forget every count of `job_name`.
+    pub fn clear_job(&self, job_name: &str) {
+        self.lock().retain(|(job, _), _| job != job_name);
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, HashMap<(String, String), u64>> {
+        self.seen.lock().unwrap_or_else(|e| e.into_inner())
+    }
+}
+
+// =============================================================================
+// Event buffering
+// =============================================================================
+
+/// Collects task run events and writes them with [`StorageBackend::store_events`].
+///
+/// Frequent jobs would otherwise issue one tiny write per run. Events are
+/// flushed periodically by [`run_flush_loop`](Self::run_flush_loop), as soon
+/// as the buffer reaches a threshold, and on scheduler shutdown. Clones share
+/// the same buffer.
+#[derive(Clone)]
+pub struct EventBuffer {
+    storage: Arc<dyn StorageBackend>,
+    pending: Arc<Mutex<Vec<Event>>>,
+}
+
+impl EventBuffer {
+    /// Create an empty buffer that flushes into `storage`.
+    pub fn new(storage: Arc<dyn StorageBackend>) -> Self {
+        Self {
+            storage,
+            pending: Arc::new(Mutex::new(Vec::new())),
+        }
+    }
+
+    /// Queue an event, flushing immediately if the buffer is full.
+    pub async fn record(&self, event: Event) {
+        let full = {
+            let mut pending = self.pending.lock().await;
+            pending.push(event);
+            pending.len() >= EVENT_BUFFER_FLUSH_THRESHOLD
+        };
+        if full {
+            self.flush().await;
+        }
+    }
+
+    /// Write all queued events in one batch, returning how many were written.
+    ///
+    /// Events from a failed write are dropped with an error log, like a
+    /// failed `store_event`.
+    pub async fn flush(&self) -> usize {
+        let events = std::mem::take(&mut *self.pending.lock().await);
+        if events.is_empty() {
+            return 0;
+        }
+        match self.storage.store_events(&events).await {
+            Ok(()) => events.len(),
+            Err(e) => {
+                tracing::error!(count = events.len(), error = %e, "Failed to store task events");
+                0
+            }
+        }
+    }
+
+    /// Flush every `interval` until `token` is cancelled.
+    pub async fn run_flush_loop(self, interval: Duration, token: CancellationToken) {
+        let mut ticker = tokio::time::interval(interval);
+        loop {
+            tokio::select! {
+                _ = token.cancelled() => break,
+                _ = ticker.tick() => {
+                    self.flush().await;
+                }
+            }
+        }
     }
 }
 
@@ -281,41 +822,142 @@ fn normalize_cron(cron: &str) -> String {
     }
 }
 
-/// Execute an ingestion job (fetch data and store metrics).
+/// Everything a job run needs besides the job itself.
+///
+/// Scheduled runs take theirs from [`SchedulerHandle::run_context`]; cloning
+/// is cheap, so each run can own one.
+#[derive(Clone)]
+pub struct JobRunContext {
+    /// Client the metrics are fetched with, unless `clients` has one for
+    /// the job's data source.
+    pub client: Arc<dyn DataSourceClient>,
+    /// Clients for specific data sources.
+    pub clients: Arc<HashMap<DataSource, Arc<dyn DataSourceClient>>>,
+    /// Storage for run times and the change and sampling lookups.
+    pub storage: Arc<dyn StorageBackend>,
+    /// Destinations for fetched metrics.
+    pub sinks: Arc<Vec<Arc<dyn MetricSink>>>,
+    /// Buffer that run events are queued on.
+    pub events: EventBuffer,
+    /// Instance ID recorded on run events.
+    pub instance_id: String,
+    /// Skip fetching when the storage health check fails.
+    pub storage_preflight: bool,
+    /// Caps distinct label values per metric, if configured.
+    pub label_guard: Option<Arc<LabelCardinalityGuard>>,
+    /// Points seen per job and series, for `every_nth` sampling.
+    pub sample_counters: Arc<SampleCounters>,
+}
+
+impl JobRunContext {
+    /// Client that fetches jobs of `source`.
+    pub fn client_for(&self, source: &DataSource) -> &Arc<dyn DataSourceClient> {
+        self.clients.get(source).unwrap_or(&self.client)
+    }
+}
+
+/// Execute an ingestion job (fetch data and emit metrics to every sink).
+///
+/// Sinks are emitted to concurrently. The run is recorded as failed if any
+/// sink fails; run events are queued on `ctx.events`.
+///
+/// With `ctx.storage_preflight`, `storage.health_check()` runs first and the
+/// fetch is skipped (recorded as `TaskFailed`) if storage is unavailable.
 ///
 /// This is a public function so it can be called for manual job triggers.
-pub async fn execute_ingestion_job(
-    job_name: &str,
-    method: &str,
-    params: Option<serde_json::Value>,
-    client: &Arc<dyn DataSourceClient>,
-    storage: &Arc<dyn StorageBackend>,
-    instance_id: &str,
-) {
-    tracing::debug!(job = %job_name, method = %method, "Executing ingestion job");
+pub async fn execute_ingestion_job(job: &IngestionJob, ctx: &JobRunContext) {
+    let client = ctx.client_for(&job.datasource);
+    let JobRunContext {
+        storage,
+        sinks,
+        events,
+        instance_id,
+        storage_preflight,
+        label_guard,
+        sample_counters,
+        ..
+    } = ctx;
+    let job_name = job.name.as_str();
+    tracing::debug!(job = %job_name, method = %job.method, "Executing ingestion job");
+
+    if *storage_preflight && let Err(e) = storage.health_check().await {
+        tracing::warn!(job = %job_name, error = %e, "Storage unavailable, skipping fetch");
+        // Record TaskFailed event (best effort; storage is likely down)
+        let event = Event::new(
+            instance_id,
+            EventType::TaskFailed,
+            format!("Task '{}' skipped: storage unavailable: {}", job_name, e),
+        )
+        .with_job_name(job_name);
+        events.record(event).await;
+        return;
+    }
+
+    let started_at = chrono::Utc::now().timestamp();
+    if let Err(e) = storage.set_job_last_run(job_name, started_at).await {
+        tracing::warn!(job = %job_name, error = %e, "Failed to record job run time");
+    }
 
-    match client.fetch(method, params).await {
-        Ok(metrics) => {
+    match client.fetch(&job.method, job.params.clone()).await {
+        Ok(mut metrics) => {
             tracing::debug!(
                 job = %job_name,
                 count = metrics.len(),
                 "Fetched metrics"
             );
-            if let Err(e) = storage.store(&metrics).await {
-                tracing::error!(
+            if let Some(bounds) = job.value_bounds {
+                metrics = apply_value_bounds(job_name, bounds, job.clamp_values, metrics);
+            }
+            if let Some(guard) = label_guard {
+                for (series, label) in guard.apply(&mut metrics) {
+                    tracing::warn!(
+                        job = %job_name,
+                        metric = %series,
+                        label = %label,
+                        "Label exceeded the distinct value limit, dropping it"
+                    );
+                }
+            }
+            if job.only_on_change {
+                let fetched = metrics.len();
+                metrics = retain_changed(storage, metrics).await;
+                tracing::debug!(
+                    job = %job_name,
+                    unchanged = fetched - metrics.len(),
+                    "Skipped unchanged metrics"
+                );
+            }
+            if let Some(sampling) = job.sampling {
+                let fetched = metrics.len();
+                metrics =
+                    retain_sampled(storage, sample_counters, job_name, sampling, metrics).await;
+                tracing::debug!(
                     job = %job_name,
-                    error = %e,
-                    "Failed to store metrics"
+                    skipped = fetched - metrics.len(),
+                    "Skipped metrics by sampling"
                 );
+            }
+            let failures = if metrics.is_empty() {
+                Vec::new()
+            } else {
+                emit_to_sinks(sinks, &metrics).await
+            };
+            if !failures.is_empty() {
+                for failure in &failures {
+                    tracing::error!(job = %job_name, error = %failure, "Failed to emit metrics");
+                }
                 // Record TaskFailed event
                 let event = Event::new(
                     instance_id,
                     EventType::TaskFailed,
-                    format!("Task '{}' failed to store metrics: {}", job_name, e),
-                );
-                if let Err(e) = storage.store_event(&event).await {
-                    tracing::error!(error = %e, "Failed to record task failed event");
-                }
+                    format!(
+                        "Task '{}' failed to store metrics: {}",
+                        job_name,
+                        failures.join("; ")
+                    ),
+                )
+                .with_job_name(job_name);
+                events.record(event).await;
             } else {
                 // Record TaskExecuted event
                 let event = Event::new(
@@ -326,10 +968,9 @@ pub async fn execute_ingestion_job(
                         job_name,
                         metrics.len()
                     ),
-                );
-                if let Err(e) = storage.store_event(&event).await {
-                    tracing::error!(error = %e, "Failed to record task executed event");
-                }
+                )
+                .with_job_name(job_name);
+                events.record(event).await;
             }
         }
         Err(e) => {
@@ -343,31 +984,155 @@ pub async fn execute_ingestion_job(
                 instance_id,
                 EventType::TaskFailed,
                 format!("Task '{}' failed to fetch metrics: {}", job_name, e),
+            )
+            .with_job_name(job_name);
+            events.record(event).await;
+        }
+    }
+}
+
+/// Persist when `job_name` runs next, logging failures.
+async fn record_next_run(
+    storage: &Arc<dyn StorageBackend>,
+    job_name: &str,
+    next_run_at: Option<i64>,
+) {
+    if let Err(e) = storage.set_job_next_run(job_name, next_run_at).await {
+        tracing::warn!(job = %job_name, error = %e, "Failed to record next job run time");
+    }
+}
+
+/// Unix timestamp of the next aligned tick of `interval` after now.
+fn next_aligned_run(interval: Duration) -> i64 {
+    let interval_millis = (interval.as_millis() as i64).max(1);
+    next_aligned_tick(SystemClock.now_millis(), interval_millis) / 1000
+}
+
+/// Drop metrics whose value falls outside the inclusive `(min, max)` range,
+/// or clamp them into it when `clamp` is set.
+///
+/// Non-finite values are always dropped. Each adjusted metric is logged.
+fn apply_value_bounds(
+    job_name: &str,
+    (min, max): (f64, f64),
+    clamp: bool,
+    metrics: Vec<Metric>,
+) -> Vec<Metric> {
+    metrics
+        .into_iter()
+        .filter_map(|mut metric| {
+            if (min..=max).contains(&metric.value) {
+                return Some(metric);
+            }
+            if clamp && metric.value.is_finite() {
+                let clamped = metric.value.clamp(min, max);
+                tracing::warn!(
+                    job = %job_name,
+                    metric = %metric.name,
+                    value = metric.value,
+                    clamped,
+                    "Metric value out of bounds, clamping it"
+                );
+                metric.value = clamped;
+                return Some(metric);
+            }
+            tracing::warn!(
+                job = %job_name,
+                metric = %metric.name,
+                value = metric.value,
+                min,
+                max,
+                "Metric value out of bounds, dropping it"
             );
-            if let Err(e) = storage.store_event(&event).await {
-                tracing::error!(error = %e, "Failed to record task failed event");
+            None
+        })
+        .collect()
+}
+
+/// Drop metrics whose value matches the latest point of their series.
+///
+/// A series is the metric's source, name and labels, so each label set is
+/// compared with its own latest stored point. Within the batch, a metric is
+/// compared with the previous one kept for its series instead. A failed
+/// lookup counts as changed, so the metric is still stored.
+async fn retain_changed(storage: &Arc<dyn StorageBackend>, metrics: Vec<Metric>) -> Vec<Metric> {
+    let mut changed = Vec::with_capacity(metrics.len());
+    let mut batch_latest: HashMap<String, f64> = HashMap::new();
+    for metric in metrics {
+        let key = metric.series_key();
+        let previous = match batch_latest.get(&key) {
+            Some(value) => Some(*value),
+            None => storage
+                .get_latest_series(&metric.source.to_string(), &metric.name, &metric.labels)
+                .await
+                .ok()
+                .flatten()
+                .map(|prev| prev.value),
+        };
+        if previous != Some(metric.value) {
+            batch_latest.insert(key, metric.value);
+            changed.push(metric);
+        }
+    }
+    changed
+}
+
+/// Keep the metrics `sampling` selects for storage.
+///
+/// A series is the metric's source, name and labels (see
+/// [`Metric::series_key`]), so label sets of one metric are thinned
+/// independently. `MinInterval` compares each metric with the timestamp of
+/// the latest stored point of its series; a failed lookup keeps the metric.
+/// `EveryNth` counts points per job and series in `counters`.
+async fn retain_sampled(
+    storage: &Arc<dyn StorageBackend>,
+    counters: &SampleCounters,
+    job_name: &str,
+    sampling: SamplingMode,
+    metrics: Vec<Metric>,
+) -> Vec<Metric> {
+    match sampling {
+        SamplingMode::EveryNth(n) => metrics
+            .into_iter()
+            .filter(|metric| counters.keep(job_name, metric.series_key(), n))
+            .collect(),
+        SamplingMode::MinInterval(interval) => {
+            let interval_ms = i64::try_from(interval.as_millis()).unwrap_or(i64::MAX);
+            let mut kept = Vec::with_capacity(metrics.len());
+            for metric in metrics {
+                let latest = storage
+                    .get_latest_series(&metric.source.to_string(), &metric.name, &metric.labels)
+                    .await;
+                let too_soon = matches!(
+                    &latest,
+                    Ok(Some(prev)) if metric.timestamp - prev.timestamp < interval_ms
+                );
+                if !too_soon {
+                    kept.push(metric);
+                }
             }
+            kept
         }
     }
 }
 
+/// Emit metrics to all sinks concurrently, returning one message per failed sink.
+async fn emit_to_sinks(sinks: &[Arc<dyn MetricSink>], metrics: &[Metric]) -> Vec<String> {
+    let results = futures_util::future::join_all(sinks.iter().map(|sink| async move {
+        sink.emit(metrics)
+            .await
+            .map_err(|e| format!("{}: {}", sink.name(), e))
+    }))
+    .await;
+    results.into_iter().filter_map(|r| r.err()).collect()
+}
+
 /// Manually trigger a job execution without scheduling.
-pub async fn trigger_job(
-    job: &IngestionJob,
-    client: &Arc<dyn DataSourceClient>,
-    storage: &Arc<dyn StorageBackend>,
-    instance_id: &str,
-) {
+pub async fn trigger_job(job: &IngestionJob, ctx: &JobRunContext) {
     tracing::info!(name = %job.name, "Manually triggering job execution");
-    execute_ingestion_job(
-        &job.name,
-        &job.method,
-        job.params.clone(),
-        client,
-        storage,
-        instance_id,
-    )
-    .await;
+    execute_ingestion_job(job, ctx).await;
+    // Make the result of a manual run visible right away.
+    ctx.events.flush().await;
 }
 
 #[cfg(test)]
@@ -397,10 +1162,15 @@ mod tests {
             method: "get_fear_and_greed".to_string(),
             schedule: Schedule::Interval {
                 interval_secs: 3600,
+                aligned: false,
             },
             params: None,
             retention_days: 7,
             enabled: true,
+            only_on_change: false,
+            sampling: None,
+            value_bounds: None,
+            clamp_values: false,
         };
 
         // Schedule
@@ -410,7 +1180,7 @@ mod tests {
         // Verify UUID is stored
         {
             let map = handle.job_map.read().await;
-            assert_eq!(map.get(&1), Some(&uuid));
+            assert_eq!(map.get(&1).map(|scheduled| scheduled.uuid), Some(uuid));
         }
 
         // Unschedule
@@ -419,19 +1189,112 @@ mod tests {
     }
 
     #[tokio::test]
-    async fn test_schedule_disabled_job_fails() {
+    async fn test_job_statuses_reflect_job_map() {
         let handle = create_test_scheduler().await;
-
+        let mut ids = Vec::new();
+        for name in ["first", "second", "idle"] {
+            let job = IngestionJob {
+                name: name.to_string(),
+                datasource: DataSource::AlternativeMe,
+                method: "get_fear_and_greed".to_string(),
+                schedule: Schedule::Interval {
+                    interval_secs: 3600,
+                    aligned: false,
+                },
+                params: None,
+                retention_days: 7,
+                enabled: true,
+                only_on_change: false,
+                sampling: None,
+                value_bounds: None,
+                clamp_values: false,
+            };
+            let id = handle.storage().store_job(&job).await.unwrap();
+            if name != "idle" {
+                handle.schedule_job(id, &job).await.unwrap();
+            }
+            ids.push(id);
+        }
+
+        let statuses = handle.job_statuses().await.unwrap();
+        assert_eq!(statuses.len(), 3);
+        for status in &statuses {
+            let expect_scheduled = status.name != "idle";
+            assert_eq!(status.scheduled, expect_scheduled, "{}", status.name);
+            assert_eq!(
+                status.next_run_at.is_some(),
+                expect_scheduled,
+                "{}",
+                status.name
+            );
+            assert_eq!(
+                status.last_event.as_ref().map(|e| e.event_type),
+                expect_scheduled.then_some(EventType::TaskScheduled),
+                "{}",
+                status.name
+            );
+        }
+
+        handle.unschedule_job(ids[0]).await.unwrap();
+        let statuses = handle.job_statuses().await.unwrap();
+        let first = statuses.iter().find(|s| s.id == ids[0]).unwrap();
+        assert!(!first.scheduled);
+        assert_eq!(first.next_run_at, None);
+    }
+
+    #[tokio::test]
+    async fn test_concurrent_schedule_of_same_job_adds_one_task() {
+        let handle = create_test_scheduler().await;
+        let job = test_job("concurrent");
+
+        let (first, second) =
+            tokio::join!(handle.schedule_job(1, &job), handle.schedule_job(1, &job));
+
+        assert_eq!(first.is_ok() as u8 + second.is_ok() as u8, 1);
+        let uuid = first.or(second).unwrap();
+        let map = handle.job_map.read().await;
+        assert_eq!(map.len(), 1);
+        assert_eq!(map.get(&1).map(|scheduled| scheduled.uuid), Some(uuid));
+    }
+
+    #[tokio::test]
+    async fn test_unschedule_clears_every_nth_counts() {
+        let handle = create_test_scheduler().await;
+        let counters = handle.sample_counters();
+        let series = || "alternativeme:fear_and_greed".to_string();
+        assert!(counters.keep("sampled", series(), 3));
+        assert!(!counters.keep("sampled", series(), 3));
+        assert!(counters.keep("other", series(), 3));
+
+        handle.schedule_job(1, &test_job("sampled")).await.unwrap();
+        handle.unschedule_job(1).await.unwrap();
+
+        assert!(counters.keep("sampled", series(), 3));
+        assert!(!counters.keep("other", series(), 3));
+        // Another handle keeps its own counts.
+        let other = create_test_scheduler().await;
+        assert!(other.sample_counters().keep("sampled", series(), 3));
+    }
+
+    #[tokio::test]
+    async fn test_schedule_disabled_job_fails() {
+        let handle = create_test_scheduler().await;
+
         let job = IngestionJob {
             name: "disabled_job".to_string(),
             datasource: DataSource::AlternativeMe,
             method: "get_fear_and_greed".to_string(),
             schedule: Schedule::Interval {
                 interval_secs: 3600,
+                aligned: false,
             },
             params: None,
             retention_days: 7,
             enabled: false,
+            only_on_change: false,
+            sampling: None,
+            value_bounds: None,
+            clamp_values: false,
         };
 
         let result = handle.schedule_job(1, &job).await;
@@ -448,10 +1311,15 @@ mod tests {
             method: "get_fear_and_greed".to_string(),
             schedule: Schedule::Interval {
                 interval_secs: 3600,
+                aligned: false,
             },
             params: None,
             retention_days: 7,
             enabled: true,
+            only_on_change: false,
+            sampling: None,
+            value_bounds: None,
+            clamp_values: false,
         };
 
         // Schedule initially
@@ -470,4 +1338,867 @@ mod tests {
         handle.reschedule_job(1, &job).await.unwrap();
         assert!(handle.is_scheduled(1).await);
     }
+
+    #[tokio::test]
+    async fn test_reconcile_with_db() {
+        let handle = create_test_scheduler().await;
+        let job = IngestionJob {
+            name: "test_job".to_string(),
+            datasource: DataSource::AlternativeMe,
+            method: "get_fear_and_greed".to_string(),
+            schedule: Schedule::Interval {
+                interval_secs: 3600,
+                aligned: false,
+            },
+            params: None,
+            retention_days: 7,
+            enabled: true,
+            only_on_change: false,
+            sampling: None,
+            value_bounds: None,
+            clamp_values: false,
+        };
+        let id = handle.storage().store_job(&job).await.unwrap();
+
+        // Picks up a job that was added to the database directly.
+        assert_eq!(handle.reconcile_with_db().await.unwrap(), 1);
+        assert!(handle.is_scheduled(id).await);
+        assert_eq!(handle.reconcile_with_db().await.unwrap(), 0);
+
+        // Drops a job that was deleted behind the scheduler's back.
+        handle.storage().delete_job(id).await.unwrap();
+        assert_eq!(handle.reconcile_with_db().await.unwrap(), 1);
+        assert!(!handle.is_scheduled(id).await);
+        assert!(handle.job_map.read().await.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_reconcile_applies_changed_job_set() {
+        let handle = create_test_scheduler().await;
+        let job = |name: &str, interval_secs| IngestionJob {
+            name: name.to_string(),
+            datasource: DataSource::AlternativeMe,
+            method: "get_fear_and_greed".to_string(),
+            schedule: Schedule::Interval {
+                interval_secs,
+                aligned: false,
+            },
+            params: None,
+            retention_days: 7,
+            enabled: true,
+            only_on_change: false,
+            sampling: None,
+            value_bounds: None,
+            clamp_values: false,
+        };
+        let kept = handle
+            .storage()
+            .store_job(&job("kept", 3600))
+            .await
+            .unwrap();
+        let removed = handle
+            .storage()
+            .store_job(&job("removed", 3600))
+            .await
+            .unwrap();
+        assert_eq!(handle.reconcile_with_db().await.unwrap(), 2);
+
+        // Edit one job, delete another and add a third outside the scheduler.
+        handle
+            .storage()
+            .update_job(kept, &job("kept", 600))
+            .await
+            .unwrap();
+        handle.storage().delete_job(removed).await.unwrap();
+        let added = handle
+            .storage()
+            .store_job(&job("added", 3600))
+            .await
+            .unwrap();
+
+        assert_eq!(handle.reconcile_with_db().await.unwrap(), 3);
+        let map = handle.job_map.read().await;
+        let mut ids: Vec<i64> = map.keys().copied().collect();
+        ids.sort();
+        assert_eq!(ids, vec![kept, added]);
+        assert_eq!(map[&kept].job, job("kept", 600));
+        drop(map);
+
+        assert_eq!(handle.reconcile_with_db().await.unwrap(), 0);
+    }
+
+    #[tokio::test]
+    async fn test_shutdown_flushes_buffered_storage() {
+        let inner: Arc<dyn StorageBackend> = Arc::new(
+            LocalStorage::new_in_memory(LocalStorageConfig::default())
+                .await
+                .unwrap(),
+        );
+        let buffered = Arc::new(crate::storage::BufferedStorage::new(inner.clone()));
+        let client = Arc::new(AlternativeMeClient::new());
+        let handle = SchedulerHandle::new(client, buffered.clone(), "test-instance".to_string())
+            .await
+            .unwrap();
+
+        let metric = Metric::new(
+            DataSource::AlternativeMe,
+            "test",
+            1.0,
+            crate::MetricUnit::Index,
+        );
+        emit_to_sinks(handle.sinks(), &[metric]).await;
+        assert_eq!(buffered.pending().await, 1);
+        assert!(
+            inner
+                .get_latest("alternativeme", "test")
+                .await
+                .unwrap()
+                .is_none()
+        );
+
+        handle.shutdown().await.unwrap();
+        assert_eq!(buffered.pending().await, 0);
+        assert!(
+            inner
+                .get_latest("alternativeme", "test")
+                .await
+                .unwrap()
+                .is_some()
+        );
+    }
+
+    /// Manually advanced clock: sleeping jumps straight to the target time.
+    struct ManualClock {
+        now: std::sync::atomic::AtomicI64,
+    }
+
+    impl ManualClock {
+        fn advance(&self, millis: i64) {
+            self.now
+                .fetch_add(millis, std::sync::atomic::Ordering::SeqCst);
+        }
+    }
+
+    impl Clock for ManualClock {
+        fn now_millis(&self) -> i64 {
+            self.now.load(std::sync::atomic::Ordering::SeqCst)
+        }
+
+        fn sleep_until(&self, at_millis: i64) -> BoxFuture<'_, ()> {
+            self.now
+                .fetch_max(at_millis, std::sync::atomic::Ordering::SeqCst);
+            Box::pin(async {})
+        }
+    }
+
+    #[test]
+    fn test_next_aligned_tick() {
+        assert_eq!(next_aligned_tick(0, 60_000), 60_000);
+        assert_eq!(next_aligned_tick(59_999, 60_000), 60_000);
+        assert_eq!(next_aligned_tick(60_000, 60_000), 120_000);
+        assert_eq!(next_aligned_tick(61_234, 60_000), 120_000);
+    }
+
+    #[tokio::test]
+    async fn test_aligned_interval_does_not_drift() {
+        // Start mid-minute so the first tick has to snap to the boundary.
+        let clock = Arc::new(ManualClock {
+            now: std::sync::atomic::AtomicI64::new(1_700_000_012_345),
+        });
+        let token = CancellationToken::new();
+        let ticks = Arc::new(std::sync::Mutex::new(Vec::new()));
+
+        let task_clock = Arc::clone(&clock);
+        let task_token = token.clone();
+        let task_ticks = Arc::clone(&ticks);
+        run_aligned_interval(Duration::from_secs(60), clock.clone(), token, move || {
+            let mut ticks = task_ticks.lock().unwrap();
+            ticks.push(task_clock.now_millis());
+            // Simulate a slow execution (7s) on every run.
+            task_clock.advance(7_000);
+            if ticks.len() == 5 {
+                task_token.cancel();
+            }
+            async {}
+        })
+        .await;
+
+        let ticks = ticks.lock().unwrap();
+        assert_eq!(ticks.len(), 5);
+        for tick in ticks.iter() {
+            assert_eq!(tick % 60_000, 0, "tick {} is not minute-aligned", tick);
+        }
+        for pair in ticks.windows(2) {
+            assert_eq!(pair[1] - pair[0], 60_000);
+        }
+    }
+
+    #[tokio::test]
+    async fn test_aligned_interval_skips_overrun_ticks() {
+        let clock = Arc::new(ManualClock {
+            now: std::sync::atomic::AtomicI64::new(0),
+        });
+        let token = CancellationToken::new();
+        let ticks = Arc::new(std::sync::Mutex::new(Vec::new()));
+
+        let task_clock = Arc::clone(&clock);
+        let task_token = token.clone();
+        let task_ticks = Arc::clone(&ticks);
+        run_aligned_interval(Duration::from_secs(60), clock.clone(), token, move || {
+            let mut ticks = task_ticks.lock().unwrap();
+            ticks.push(task_clock.now_millis());
+            // Overrun the interval by 30s.
+            task_clock.advance(90_000);
+            if ticks.len() == 3 {
+                task_token.cancel();
+            }
+            async {}
+        })
+        .await;
+
+        assert_eq!(*ticks.lock().unwrap(), vec![60_000, 180_000, 300_000]);
+    }
+
+    #[tokio::test]
+    async fn test_schedule_and_unschedule_aligned_job() {
+        let handle = create_test_scheduler().await;
+
+        let job = IngestionJob {
+            name: "aligned_job".to_string(),
+            datasource: DataSource::AlternativeMe,
+            method: "get_fear_and_greed".to_string(),
+            schedule: Schedule::Interval {
+                interval_secs: 3600,
+                aligned: true,
+            },
+            params: None,
+            retention_days: 7,
+            enabled: true,
+            only_on_change: false,
+            sampling: None,
+            value_bounds: None,
+            clamp_values: false,
+        };
+
+        let uuid = handle.schedule_job(1, &job).await.unwrap();
+        assert!(handle.is_scheduled(1).await);
+        let token = handle
+            .aligned_tasks
+            .read()
+            .await
+            .get(&uuid)
+            .cloned()
+            .unwrap();
+
+        handle.unschedule_job(1).await.unwrap();
+        assert!(!handle.is_scheduled(1).await);
+        assert!(token.is_cancelled());
+        assert!(handle.aligned_tasks.read().await.is_empty());
+    }
+
+    #[derive(Default)]
+    struct StaticClient {
+        fetches: std::sync::atomic::AtomicUsize,
+        /// Label each metric with the fetch number, a unique value per run.
+        label_fetches: bool,
+        /// Move each fetch's timestamp this far past the previous one.
+        fetch_spacing_ms: i64,
+    }
+
+    impl DataSourceClient for StaticClient {
+        fn supported_methods(&self) -> Vec<crate::client::MethodMetadata> {
+            Vec::new()
+        }
+
+        fn fetch<'a>(
+            &'a self,
+            _method: &'a str,
+            _params: Option<serde_json::Value>,
+        ) -> BoxFuture<'a, anyhow::Result<Vec<Metric>>> {
+            let fetch = self
+                .fetches
+                .fetch_add(1, std::sync::atomic::Ordering::SeqCst);
+            let mut metric = Metric::new(
+                DataSource::AlternativeMe,
+                "fear_and_greed",
+                55.0,
+                crate::MetricUnit::Index,
+            );
+            if self.label_fetches {
+                metric = metric.with_label("fetch", fetch.to_string());
+            }
+            metric.timestamp += fetch as i64 * self.fetch_spacing_ms;
+            Box::pin(async { Ok(vec![metric]) })
+        }
+    }
+
+    #[derive(Default)]
+    struct RecordingSink {
+        received: std::sync::Mutex<Vec<Metric>>,
+    }
+
+    impl MetricSink for RecordingSink {
+        fn name(&self) -> &str {
+            "recording"
+        }
+
+        fn emit(&self, metrics: &[Metric]) -> BoxFuture<'_, anyhow::Result<()>> {
+            self.received.lock().unwrap().extend_from_slice(metrics);
+            Box::pin(async { Ok(()) })
+        }
+    }
+
+    #[tokio::test]
+    async fn test_execute_ingestion_job_fans_out_to_sinks() {
+        let storage: Arc<dyn StorageBackend> = Arc::new(
+            LocalStorage::new_in_memory(LocalStorageConfig::default())
+                .await
+                .unwrap(),
+        );
+        let client: Arc<dyn DataSourceClient> = Arc::new(StaticClient::default());
+        let recording = Arc::new(RecordingSink::default());
+        let handle = SchedulerHandle::new(client.clone(), storage.clone(), "test".to_string())
+            .await
+            .unwrap()
+            .with_sink(recording.clone());
+
+        let job = test_job("fan_out");
+        execute_ingestion_job(&job, &handle.run_context()).await;
+
+        let received = recording.received.lock().unwrap().clone();
+        assert_eq!(received.len(), 1);
+        assert_eq!(received[0].name, "fear_and_greed");
+        assert_eq!(received[0].value, 55.0);
+
+        // Local storage is still one of the sinks.
+        let stored = storage
+            .get_latest("alternativeme", "fear_and_greed")
+            .await
+            .unwrap();
+        assert_eq!(stored.map(|m| m.value), Some(55.0));
+    }
+
+    fn test_job(name: &str) -> IngestionJob {
+        IngestionJob {
+            name: name.to_string(),
+            datasource: DataSource::AlternativeMe,
+            method: "get_fear_and_greed".to_string(),
+            schedule: Schedule::Interval {
+                interval_secs: 3600,
+                aligned: false,
+            },
+            params: None,
+            retention_days: 7,
+            enabled: true,
+            only_on_change: false,
+            sampling: None,
+            value_bounds: None,
+            clamp_values: false,
+        }
+    }
+
+    fn run_context(
+        client: &Arc<dyn DataSourceClient>,
+        storage: &Arc<dyn StorageBackend>,
+        sinks: Vec<Arc<dyn MetricSink>>,
+    ) -> JobRunContext {
+        JobRunContext {
+            client: Arc::clone(client),
+            clients: Arc::default(),
+            storage: Arc::clone(storage),
+            sinks: Arc::new(sinks),
+            events: EventBuffer::new(storage.clone()),
+            instance_id: "test".to_string(),
+            storage_preflight: false,
+            label_guard: None,
+            sample_counters: Arc::default(),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_jobs_are_fetched_with_the_client_of_their_source() {
+        let storage: Arc<dyn StorageBackend> = Arc::new(
+            LocalStorage::new_in_memory(LocalStorageConfig::default())
+                .await
+                .unwrap(),
+        );
+        let default_client = Arc::new(StaticClient::default());
+        let polymarket_client = Arc::new(StaticClient::default());
+        let handle = SchedulerHandle::new(default_client.clone(), storage, "test".to_string())
+            .await
+            .unwrap()
+            .with_client(DataSource::Polymarket, polymarket_client.clone());
+        let fetches =
+            |client: &StaticClient| client.fetches.load(std::sync::atomic::Ordering::SeqCst);
+
+        let job = IngestionJob {
+            datasource: DataSource::Polymarket,
+            ..test_job("midpoints")
+        };
+        execute_ingestion_job(&job, &handle.run_context()).await;
+        assert_eq!(
+            (fetches(&default_client), fetches(&polymarket_client)),
+            (0, 1)
+        );
+
+        execute_ingestion_job(&test_job("fgi"), &handle.run_context()).await;
+        assert_eq!(
+            (fetches(&default_client), fetches(&polymarket_client)),
+            (1, 1)
+        );
+    }
+
+    #[tokio::test]
+    async fn test_high_cardinality_labels_are_dropped_past_limit() {
+        let storage: Arc<dyn StorageBackend> = Arc::new(
+            LocalStorage::new_in_memory(LocalStorageConfig::default())
+                .await
+                .unwrap(),
+        );
+        let client: Arc<dyn DataSourceClient> = Arc::new(StaticClient {
+            label_fetches: true,
+            ..Default::default()
+        });
+        let recording = Arc::new(RecordingSink::default());
+        let sinks: Vec<Arc<dyn MetricSink>> = vec![recording.clone()];
+        let ctx = JobRunContext {
+            label_guard: Some(Arc::new(LabelCardinalityGuard::new(3))),
+            ..run_context(&client, &storage, sinks)
+        };
+        let job = test_job("cardinality");
+
+        for _ in 0..5 {
+            execute_ingestion_job(&job, &ctx).await;
+        }
+
+        let labels: Vec<Option<String>> = recording
+            .received
+            .lock()
+            .unwrap()
+            .iter()
+            .map(|m| m.labels.get("fetch").cloned())
+            .collect();
+        assert_eq!(
+            labels,
+            vec![
+                Some("0".to_string()),
+                Some("1".to_string()),
+                Some("2".to_string()),
+                None,
+                None,
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_trigger_job_records_last_run_at() {
+        let storage: Arc<dyn StorageBackend> = Arc::new(
+            LocalStorage::new_in_memory(LocalStorageConfig::default())
+                .await
+                .unwrap(),
+        );
+        let client: Arc<dyn DataSourceClient> = Arc::new(StaticClient::default());
+        let sinks: Vec<Arc<dyn MetricSink>> = vec![Arc::new(StorageSink::new(storage.clone()))];
+        let ctx = run_context(&client, &storage, sinks);
+        let job = test_job("tracked");
+        let id = storage.store_job(&job).await.unwrap();
+        assert_eq!(
+            storage.get_job(id).await.unwrap().unwrap().last_run_at,
+            None
+        );
+
+        let before = chrono::Utc::now().timestamp();
+        trigger_job(&job, &ctx).await;
+        let after = chrono::Utc::now().timestamp();
+
+        let last_run_at = storage
+            .get_job(id)
+            .await
+            .unwrap()
+            .unwrap()
+            .last_run_at
+            .unwrap();
+        assert!((before..=after).contains(&last_run_at), "{last_run_at}");
+    }
+
+    #[tokio::test]
+    async fn test_value_bounds_drop_out_of_range_metrics() {
+        let storage: Arc<dyn StorageBackend> = Arc::new(
+            LocalStorage::new_in_memory(LocalStorageConfig::default())
+                .await
+                .unwrap(),
+        );
+        let client: Arc<dyn DataSourceClient> = Arc::new(StaticClient::default());
+        let sink = Arc::new(RecordingSink::default());
+        let sinks: Vec<Arc<dyn MetricSink>> = vec![sink.clone()];
+        let ctx = run_context(&client, &storage, sinks);
+
+        // The client always reports 55.
+        for bounds in [(0.0, 50.0), (0.0, 100.0)] {
+            let job = IngestionJob {
+                value_bounds: Some(bounds),
+                ..test_job("bounded")
+            };
+            execute_ingestion_job(&job, &ctx).await;
+        }
+        let received = sink.received.lock().unwrap().clone();
+        assert_eq!(received.len(), 1);
+        assert_eq!(received[0].value, 55.0);
+
+        let metric =
+            |value| Metric::new(DataSource::CoinGecko, "cap", value, crate::MetricUnit::USD);
+        let values = |metrics: Vec<Metric>| metrics.iter().map(|m| m.value).collect::<Vec<_>>();
+        let fetched = vec![
+            metric(-1.0),
+            metric(10.0),
+            metric(f64::NAN),
+            metric(f64::INFINITY),
+            metric(f64::NEG_INFINITY),
+        ];
+        assert_eq!(
+            values(apply_value_bounds("t", (0.0, 5.0), false, fetched.clone())),
+            Vec::<f64>::new()
+        );
+        assert_eq!(
+            values(apply_value_bounds("t", (0.0, 5.0), true, fetched)),
+            vec![0.0, 5.0]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_only_on_change_skips_identical_values() {
+        let storage: Arc<dyn StorageBackend> = Arc::new(
+            LocalStorage::new_in_memory(LocalStorageConfig::default())
+                .await
+                .unwrap(),
+        );
+        let client: Arc<dyn DataSourceClient> = Arc::new(StaticClient::default());
+        let sinks: Vec<Arc<dyn MetricSink>> = vec![Arc::new(StorageSink::new(storage.clone()))];
+        let ctx = run_context(&client, &storage, sinks);
+        let job = IngestionJob {
+            only_on_change: true,
+            ..test_job("on_change")
+        };
+
+        for _ in 0..2 {
+            execute_ingestion_job(&job, &ctx).await;
+        }
+
+        let stored = storage
+            .query_range(
+                Some("alternativeme"),
+                Some("fear_and_greed"),
+                0,
+                i64::MAX,
+                None,
+            )
+            .await
+            .unwrap();
+        assert_eq!(stored.len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_only_on_change_compares_each_label_set() {
+        let storage: Arc<dyn StorageBackend> = Arc::new(
+            LocalStorage::new_in_memory(LocalStorageConfig::default())
+                .await
+                .unwrap(),
+        );
+        let price = |symbol: &str, value: f64| {
+            Metric::new(
+                DataSource::CoinGecko,
+                "price",
+                value,
+                crate::MetricUnit::USD,
+            )
+            .with_label("symbol", symbol)
+        };
+        storage.store(&[price("BTC", 1.0)]).await.unwrap();
+        storage.store(&[price("ETH", 2.0)]).await.unwrap();
+
+        let batch = vec![
+            price("BTC", 1.0),
+            price("ETH", 2.0),
+            price("ETH", 3.0),
+            price("ETH", 3.0),
+        ];
+        let changed = retain_changed(&storage, batch).await;
+
+        let changed: Vec<(String, f64)> = changed
+            .into_iter()
+            .map(|m| (m.labels["symbol"].clone(), m.value))
+            .collect();
+        assert_eq!(changed, vec![("ETH".to_string(), 3.0)]);
+    }
+
+    #[tokio::test]
+    async fn test_min_interval_sampling_skips_fetch_within_interval() {
+        let storage: Arc<dyn StorageBackend> = Arc::new(
+            LocalStorage::new_in_memory(LocalStorageConfig::default())
+                .await
+                .unwrap(),
+        );
+        // Fetches land 30s apart.
+        let client: Arc<dyn DataSourceClient> = Arc::new(StaticClient {
+            fetch_spacing_ms: 30_000,
+            ..Default::default()
+        });
+        let sinks: Vec<Arc<dyn MetricSink>> = vec![Arc::new(StorageSink::new(storage.clone()))];
+        let ctx = run_context(&client, &storage, sinks);
+        let job = IngestionJob {
+            sampling: Some(SamplingMode::MinInterval(Duration::from_secs(60))),
+            ..test_job("sampled")
+        };
+
+        for _ in 0..3 {
+            execute_ingestion_job(&job, &ctx).await;
+        }
+
+        let stored = storage
+            .query_range(
+                Some("alternativeme"),
+                Some("fear_and_greed"),
+                0,
+                i64::MAX,
+                None,
+            )
+            .await
+            .unwrap();
+        // The second fetch is 30s after the first stored point and skipped;
+        // the third is 60s after it and kept.
+        assert_eq!(stored.len(), 2);
+        let gap = (stored[0].timestamp - stored[1].timestamp).abs();
+        assert!((60_000..61_000).contains(&gap), "gap {gap}");
+    }
+
+    #[tokio::test]
+    async fn test_sampling_is_per_label_set() {
+        let storage: Arc<dyn StorageBackend> = Arc::new(
+            LocalStorage::new_in_memory(LocalStorageConfig::default())
+                .await
+                .unwrap(),
+        );
+        let price = |symbol: &str, timestamp: i64| {
+            Metric::new(DataSource::CoinGecko, "price", 1.0, crate::MetricUnit::USD)
+                .with_label("symbol", symbol)
+                .with_timestamp(timestamp)
+        };
+        let symbols = |metrics: Vec<Metric>| {
+            metrics
+                .into_iter()
+                .map(|m| m.labels["symbol"].clone())
+                .collect::<Vec<_>>()
+        };
+
+        // Each label set keeps its own every_nth count.
+        let counters = SampleCounters::default();
+        let sampling = SamplingMode::EveryNth(2);
+        let batch = || vec![price("BTC", 0), price("ETH", 0)];
+        let first = retain_sampled(&storage, &counters, "job", sampling, batch()).await;
+        let second = retain_sampled(&storage, &counters, "job", sampling, batch()).await;
+        assert_eq!(symbols(first), vec!["BTC", "ETH"]);
+        assert!(second.is_empty());
+
+        // A recent BTC point does not hold back ETH.
+        storage.store(&[price("BTC", 100_000)]).await.unwrap();
+        let sampling = SamplingMode::MinInterval(Duration::from_secs(60));
+        let batch = vec![price("BTC", 130_000), price("ETH", 130_000)];
+        let kept = retain_sampled(&storage, &counters, "job", sampling, batch).await;
+        assert_eq!(symbols(kept), vec!["ETH"]);
+    }
+
+    /// Storage that fails its health check and records attempted events.
+    struct UnhealthyStorage {
+        events: std::sync::Mutex<Vec<Event>>,
+    }
+
+    impl StorageBackend for UnhealthyStorage {
+        fn store(&self, _metrics: &[Metric]) -> BoxFuture<'_, anyhow::Result<()>> {
+            Box::pin(async { anyhow::bail!("storage down") })
+        }
+
+        fn get_latest(
+            &self,
+            _source: &str,
+            _name: &str,
+        ) -> BoxFuture<'_, anyhow::Result<Option<Metric>>> {
+            Box::pin(async { anyhow::bail!("storage down") })
+        }
+
+        fn query_range(
+            &self,
+            _source: Option<&str>,
+            _name: Option<&str>,
+            _start: i64,
+            _end: i64,
+            _limit: Option<usize>,
+        ) -> BoxFuture<'_, anyhow::Result<Vec<Metric>>> {
+            Box::pin(async { anyhow::bail!("storage down") })
+        }
+
+        fn get_value_as_of(
+            &self,
+            _source: &str,
+            _name: &str,
+            _at: i64,
+        ) -> BoxFuture<'_, anyhow::Result<Option<Metric>>> {
+            Box::pin(async { anyhow::bail!("storage down") })
+        }
+
+        fn query_with_label_key(
+            &self,
+            _source: Option<&str>,
+            _name: Option<&str>,
+            _label_key: &str,
+            _start: i64,
+            _end: i64,
+            _limit: Option<usize>,
+        ) -> BoxFuture<'_, anyhow::Result<Vec<Metric>>> {
+            Box::pin(async { anyhow::bail!("storage down") })
+        }
+
+        fn health_check(&self) -> BoxFuture<'_, anyhow::Result<()>> {
+            Box::pin(async { anyhow::bail!("connection refused") })
+        }
+
+        fn cleanup_before(&self, _cutoff_timestamp: i64) -> BoxFuture<'_, anyhow::Result<u64>> {
+            Box::pin(async { anyhow::bail!("storage down") })
+        }
+
+        fn get_available_metrics(&self) -> BoxFuture<'_, anyhow::Result<Vec<(String, String)>>> {
+            Box::pin(async { anyhow::bail!("storage down") })
+        }
+
+        fn store_event(&self, event: &Event) -> BoxFuture<'_, anyhow::Result<()>> {
+            self.events.lock().unwrap().push(event.clone());
+            Box::pin(async { Ok(()) })
+        }
+
+        fn store_events(&self, events: &[Event]) -> BoxFuture<'_, anyhow::Result<()>> {
+            self.events.lock().unwrap().extend_from_slice(events);
+            Box::pin(async { Ok(()) })
+        }
+
+        fn get_events(
+            &self,
+            _instance_id: Option<&str>,
+            _limit: Option<usize>,
+        ) -> BoxFuture<'_, anyhow::Result<Vec<Event>>> {
+            Box::pin(async { anyhow::bail!("storage down") })
+        }
+
+        fn get_distinct_instance_ids(&self) -> BoxFuture<'_, anyhow::Result<Vec<String>>> {
+            Box::pin(async { anyhow::bail!("storage down") })
+        }
+
+        fn failure_counts_by_job(
+            &self,
+            _since: i64,
+        ) -> BoxFuture<'_, anyhow::Result<Vec<(String, usize)>>> {
+            Box::pin(async { anyhow::bail!("storage down") })
+        }
+
+        fn store_job(&self, _job: &IngestionJob) -> BoxFuture<'_, anyhow::Result<i64>> {
+            Box::pin(async { anyhow::bail!("storage down") })
+        }
+
+        fn update_job(&self, _id: i64, _job: &IngestionJob) -> BoxFuture<'_, anyhow::Result<()>> {
+            Box::pin(async { anyhow::bail!("storage down") })
+        }
+
+        fn delete_job(&self, _id: i64) -> BoxFuture<'_, anyhow::Result<()>> {
+            Box::pin(async { anyhow::bail!("storage down") })
+        }
+
+        fn get_job(
+            &self,
+            _id: i64,
+        ) -> BoxFuture<'_, anyhow::Result<Option<crate::storage::JobRecord>>> {
+            Box::pin(async { anyhow::bail!("storage down") })
+        }
+
+        fn list_jobs(&self) -> BoxFuture<'_, anyhow::Result<Vec<crate::storage::JobRecord>>> {
+            Box::pin(async { anyhow::bail!("storage down") })
+        }
+
+        fn set_job_last_run<'a>(
+            &'a self,
+            _name: &'a str,
+            _at: i64,
+        ) -> BoxFuture<'a, anyhow::Result<()>> {
+            Box::pin(async { anyhow::bail!("storage down") })
+        }
+
+        fn set_job_next_run<'a>(
+            &'a self,
+            _name: &'a str,
+            _at: Option<i64>,
+        ) -> BoxFuture<'a, anyhow::Result<()>> {
+            Box::pin(async { anyhow::bail!("storage down") })
+        }
+    }
+
+    #[tokio::test]
+    async fn test_storage_preflight_skips_fetch_when_unhealthy() {
+        let unhealthy = Arc::new(UnhealthyStorage {
+            events: std::sync::Mutex::new(Vec::new()),
+        });
+        let storage: Arc<dyn StorageBackend> = unhealthy.clone();
+        let static_client = Arc::new(StaticClient::default());
+        let client: Arc<dyn DataSourceClient> = static_client.clone();
+        let sinks: Vec<Arc<dyn MetricSink>> = vec![Arc::new(StorageSink::new(storage.clone()))];
+        let job = test_job("preflight");
+        let mut ctx = JobRunContext {
+            storage_preflight: true,
+            ..run_context(&client, &storage, sinks)
+        };
+
+        execute_ingestion_job(&job, &ctx).await;
+        assert_eq!(ctx.events.flush().await, 1);
+
+        assert_eq!(
+            static_client
+                .fetches
+                .load(std::sync::atomic::Ordering::SeqCst),
+            0
+        );
+        let events = unhealthy.events.lock().unwrap().clone();
+        assert_eq!(events.len(), 1);
+        assert_eq!(events[0].event_type, EventType::TaskFailed);
+        assert!(events[0].message.contains("storage unavailable"));
+
+        // Without the preflight the fetch still happens.
+        ctx.storage_preflight = false;
+        execute_ingestion_job(&job, &ctx).await;
+        assert_eq!(
+            static_client
+                .fetches
+                .load(std::sync::atomic::Ordering::SeqCst),
+            1
+        );
+    }
+
+    #[tokio::test]
+    async fn test_event_buffer_batches_until_flush() {
+        let storage: Arc<dyn StorageBackend> = Arc::new(
+            LocalStorage::new_in_memory(LocalStorageConfig::default())
+                .await
+                .unwrap(),
+        );
+        let buffer = EventBuffer::new(storage.clone());
+        for i in 0..3 {
+            buffer
+                .record(Event::new(
+                    "test",
+                    EventType::TaskExecuted,
+                    format!("Task 'job{}' executed successfully, 1 metrics", i),
+                ))
+                .await;
+        }
+        assert!(storage.get_events(None, None).await.unwrap().is_empty());
+
+        assert_eq!(buffer.flush().await, 3);
+        assert_eq!(storage.get_events(None, None).await.unwrap().len(), 3);
+        assert_eq!(buffer.flush().await, 0);
+    }
 }
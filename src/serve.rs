@@ -2,20 +2,26 @@
 //!
 //! Starts the data ingestion server with configured data sources and jobs.
 
-use std::path::PathBuf;
+use std::collections::{HashMap, HashSet};
+use std::path::{Path, PathBuf};
 use std::sync::Arc;
 use tokio::sync::RwLock;
 use tokio_util::sync::CancellationToken;
 use uuid::Uuid;
 
+use crate::client::DataSourceClient;
 use crate::client::alternativeme::Client as AlternativeMeClient;
 use crate::client::http::HttpClientConfig;
-use crate::config::{AppConfig, StorageBackendType, StorageConfig};
+use crate::client::polymarket::clob::Client as ClobClient;
+use crate::config::{
+    AppConfig, HttpClientConfigSerde, IngestionJob, StorageBackendType, StorageConfig,
+};
 use crate::scheduler::SchedulerHandle;
 use crate::storage::local::LocalStorage;
-use crate::storage::{Event, EventType};
+use crate::storage::sqlite::SqliteStorage;
+use crate::storage::{Event, EventType, LatestCache, RecentBuffer};
 use crate::task::TaskManager;
-use crate::{LocalStorageConfig, StorageBackend};
+use crate::{DataSource, LocalStorageConfig, StorageBackend};
 
 /// Run the server with the given configuration file.
 pub async fn run(config_path: PathBuf) -> anyhow::Result<()> {
@@ -33,15 +39,27 @@ pub async fn run(config_path: PathBuf) -> anyhow::Result<()> {
         "Server configuration loaded"
     );
 
-    // Build data source client
-    let http_config = config
+    let enabled_sources = config.datasources.enabled_sources.clone();
+    if !enabled_sources.is_empty() {
+        tracing::info!(sources = ?enabled_sources, "Restricting jobs to enabled data sources");
+    }
+
+    // Build data source clients
+    let common = config
         .datasources
-        .alternativeme
+        .common
+        .clone()
         .map(HttpClientConfig::from)
-        .or_else(|| config.datasources.common.map(HttpClientConfig::from))
         .unwrap_or_default();
-    let client = Arc::new(AlternativeMeClient::with_config(http_config));
-    tracing::info!("Alternative.me client initialized");
+    let alternativeme = config
+        .datasources
+        .alternativeme
+        .clone()
+        .map(HttpClientConfig::from)
+        .unwrap_or_else(|| common.clone());
+    let clients = ingestion_clients(alternativeme, common)?;
+    let client = Arc::clone(&clients[&DataSource::AlternativeMe]);
+    tracing::info!(sources = ?clients.keys().collect::<Vec<_>>(), "Data source clients initialized");
 
     // Jobs are now managed entirely via web UI and stored in database
     tracing::info!("Jobs will be loaded from database (manage via /jobs page)");
@@ -59,14 +77,34 @@ pub async fn run(config_path: PathBuf) -> anyhow::Result<()> {
     // Create shared metadata cache
     let metadata_cache = Arc::new(RwLock::new(Vec::new()));
 
+    // Latest value per metric, fed by the scheduler and read by the dashboard
+    let latest = Arc::new(LatestCache::default());
+
+    // Recent points per metric for the status page sparklines
+    let recent = Arc::new(RecentBuffer::default());
+
     // Create cancellation token for coordinated shutdown
     let shutdown_token = CancellationToken::new();
 
     // Create shared scheduler handle (used by both TaskManager and web handlers)
-    let scheduler =
-        SchedulerHandle::new(client.clone(), storage.clone(), instance_id.clone()).await?;
+    let scheduler = SchedulerHandle::new(client.clone(), storage.clone(), instance_id.clone())
+        .await?
+        .with_storage_preflight(storage_config.skip_fetch_when_unhealthy)
+        .with_enabled_sources(enabled_sources)
+        .with_max_label_values(storage_config.max_label_values)
+        .with_sink(latest.clone())
+        .with_sink(recent.clone());
+    let scheduler = clients
+        .into_iter()
+        .fold(scheduler, |scheduler, (source, client)| {
+            scheduler.with_client(source, client)
+        });
     tracing::info!("Scheduler handle created");
 
+    let reload_scheduler = scheduler.clone();
+    let reload_storage = storage.clone();
+    let jobs_dir = config.ingestion.as_ref().map(|i| i.jobs_dir.clone());
+
     // Create task manager (handles ingestion, cleanup, and metadata refresh)
     let task_manager = TaskManager::new(
         scheduler.clone(),
@@ -83,6 +121,9 @@ pub async fn run(config_path: PathBuf) -> anyhow::Result<()> {
         instance_id.clone(),
         client,
         scheduler,
+        latest,
+        config.server.staleness.clone(),
+        recent,
     );
 
     let addr = format!("{}:{}", config.server.host, config.server.port);
@@ -105,12 +146,18 @@ pub async fn run(config_path: PathBuf) -> anyhow::Result<()> {
     // Spawn signal handler task
     let signal_token = shutdown_token.clone();
     tokio::spawn(async move {
-        if let Ok(()) = tokio::signal::ctrl_c().await {
-            tracing::info!("Shutdown signal received (Ctrl+C)");
-            signal_token.cancel();
-        }
+        wait_for_shutdown_signal().await;
+        signal_token.cancel();
     });
 
+    // Re-read job files and the database on SIGHUP
+    tokio::spawn(reload_jobs_on_hangup(
+        reload_scheduler,
+        reload_storage,
+        jobs_dir,
+        shutdown_token.clone(),
+    ));
+
     // Run web server and task manager concurrently
     // Both will stop when the shutdown_token is cancelled
     let (server_result, manager_result) = tokio::join!(
@@ -132,10 +179,371 @@ pub async fn run(config_path: PathBuf) -> anyhow::Result<()> {
         tracing::warn!(error = %e, "Failed to record service stop event");
     }
 
+    // Persist metrics still held by a buffered backend
+    if let Err(e) = storage.flush().await {
+        tracing::error!(error = %e, "Failed to flush storage on shutdown");
+    }
+
     tracing::info!("Server stopped");
     Ok(())
 }
 
+/// Clients that ingestion jobs fetch with, by data source.
+///
+/// `alternativeme` configures the Alternative.me client and `common` the
+/// Polymarket CLOB client.
+fn ingestion_clients(
+    alternativeme: HttpClientConfig,
+    common: HttpClientConfig,
+) -> anyhow::Result<HashMap<DataSource, Arc<dyn DataSourceClient>>> {
+    let mut clients: HashMap<DataSource, Arc<dyn DataSourceClient>> = HashMap::new();
+    clients.insert(
+        DataSource::AlternativeMe,
+        Arc::new(AlternativeMeClient::with_config(alternativeme)),
+    );
+    clients.insert(
+        DataSource::Polymarket,
+        Arc::new(ClobClient::with_middleware_client(common.build()?)),
+    );
+    Ok(clients)
+}
+
+/// Wait for Ctrl+C, or SIGTERM on Unix.
+async fn wait_for_shutdown_signal() {
+    let ctrl_c = async {
+        if let Err(e) = tokio::signal::ctrl_c().await {
+            tracing::error!(error = %e, "Failed to listen for Ctrl+C");
+            std::future::pending::<()>().await;
+        }
+    };
+
+    #[cfg(unix)]
+    let terminate = async {
+        match tokio::signal::unix::signal(tokio::signal::unix::SignalKind::terminate()) {
+            Ok(mut signal) => {
+                signal.recv().await;
+            }
+            Err(e) => {
+                tracing::error!(error = %e, "Failed to listen for SIGTERM");
+                std::future::pending::<()>().await;
+            }
+        }
+    };
+    #[cfg(not(unix))]
+    let terminate = std::future::pending::<()>();
+
+    tokio::select! {
+        _ = ctrl_c => tracing::info!("Shutdown signal received (Ctrl+C)"),
+        _ = terminate => tracing::info!("Shutdown signal received (SIGTERM)"),
+    }
+}
+
+/// Reload jobs on every SIGHUP until `token` is cancelled.
+///
+/// When `jobs_dir` is set, its job files are synced into the database first
+/// (see [`sync_job_files`]); the scheduler is then reconciled with the
+/// database, so edits to either the files or the jobs table take effect
+/// without a restart. The names loaded from files are remembered across
+/// reloads so that only file-backed jobs are disabled when their file goes.
+#[cfg(unix)]
+async fn reload_jobs_on_hangup(
+    scheduler: SchedulerHandle,
+    storage: Arc<dyn StorageBackend>,
+    jobs_dir: Option<PathBuf>,
+    token: CancellationToken,
+) {
+    let mut hangup = match tokio::signal::unix::signal(tokio::signal::unix::SignalKind::hangup()) {
+        Ok(signal) => signal,
+        Err(e) => {
+            tracing::error!(error = %e, "Failed to listen for SIGHUP");
+            return;
+        }
+    };
+
+    let mut file_jobs = HashSet::new();
+    loop {
+        tokio::select! {
+            _ = token.cancelled() => return,
+            received = hangup.recv() => {
+                if received.is_none() {
+                    return;
+                }
+            }
+        }
+        tracing::info!("SIGHUP received, reloading jobs");
+        if let Some(dir) = &jobs_dir {
+            match sync_job_files(storage.as_ref(), dir, &mut file_jobs).await {
+                Ok(changed) => tracing::info!(changed = changed, "Job files synced"),
+                Err(e) => tracing::error!(error = %e, "Failed to sync job files"),
+            }
+        }
+        match scheduler.reconcile_with_db().await {
+            Ok(changed) => tracing::info!(changed = changed, "Jobs reloaded"),
+            Err(e) => tracing::error!(error = %e, "Failed to reload jobs"),
+        }
+    }
+}
+
+#[cfg(not(unix))]
+async fn reload_jobs_on_hangup(
+    _scheduler: SchedulerHandle,
+    _storage: Arc<dyn StorageBackend>,
+    _jobs_dir: Option<PathBuf>,
+    _token: CancellationToken,
+) {
+}
+
+/// Upsert the job files in `jobs_dir` into storage, matching by name, and
+/// disable stored jobs whose file was removed.
+///
+/// `file_jobs` holds the names loaded from files by earlier syncs and is
+/// replaced with the names found now. Only jobs named there are disabled when
+/// their file disappears, so jobs created through the web UI or API, which
+/// never have a file, are left alone.
+///
+/// Invalid job files are logged and skipped; the stored job of the same name
+/// is left untouched. Returns the number of jobs created, updated or disabled.
+async fn sync_job_files(
+    storage: &dyn StorageBackend,
+    jobs_dir: &Path,
+    file_jobs: &mut HashSet<String>,
+) -> anyhow::Result<usize> {
+    let jobs = crate::config::load_jobs(jobs_dir)?;
+    let records = storage.list_jobs().await?;
+    let names: HashSet<String> = jobs.iter().map(|job| job.name.clone()).collect();
+
+    let mut changed = 0;
+    for job in &jobs {
+        if let Err(e) = job.validate() {
+            tracing::error!(name = %job.name, error = %e, "Skipping invalid job file");
+            continue;
+        }
+        match records.iter().find(|r| r.job.name == job.name) {
+            Some(record) if record.job == *job => {}
+            Some(record) => {
+                storage.update_job(record.id, job).await?;
+                changed += 1;
+                tracing::info!(job_id = record.id, name = %job.name, "Job file updated");
+            }
+            None => {
+                let job_id = storage.store_job(job).await?;
+                changed += 1;
+                tracing::info!(job_id = job_id, name = %job.name, "Job file added");
+            }
+        }
+    }
+
+    for record in &records {
+        let name = record.job.name.as_str();
+        if record.job.enabled && file_jobs.contains(name) && !names.contains(name) {
+            let mut job = record.job.clone();
+            job.enabled = false;
+            storage.update_job(record.id, &job).await?;
+            changed += 1;
+            tracing::info!(
+                job_id = record.id,
+                name = %job.name,
+                "Job file removed, disabled job"
+            );
+        }
+    }
+
+    *file_jobs = names;
+    Ok(changed)
+}
+
+/// Validate a configuration file and the jobs stored in its database
+/// without starting the server.
+///
+/// Returns every problem found; an empty list means the configuration is
+/// ready to serve.
+pub async fn check(config_path: &Path) -> Vec<String> {
+    match AppConfig::from_file(&config_path.to_path_buf()) {
+        Ok(config) => {
+            let mut problems = check_config(&config);
+            problems.extend(check_stored_jobs(&config).await);
+            problems
+        }
+        Err(e) => vec![format!(
+            "failed to load config '{}': {}",
+            config_path.display(),
+            e
+        )],
+    }
+}
+
+/// Validate jobs, storage, and HTTP settings of a loaded configuration.
+pub fn check_config(config: &AppConfig) -> Vec<String> {
+    let mut problems = Vec::new();
+
+    // Ingestion jobs
+    if let Some(ingestion) = &config.ingestion {
+        match crate::config::load_jobs(&ingestion.jobs_dir) {
+            Ok(jobs) => {
+                let clients = check_clients();
+                let mut names = HashSet::new();
+                for job in &jobs {
+                    problems.extend(check_job(
+                        job,
+                        &config.datasources.enabled_sources,
+                        &clients,
+                    ));
+                    if !names.insert(job.name.as_str()) {
+                        problems.push(format!("job '{}': duplicate job name", job.name));
+                    }
+                }
+            }
+            Err(e) => problems.push(e.to_string()),
+        }
+    }
+
+    // Storage
+    let storage = config.storage.clone().unwrap_or_default();
+    match storage.backend {
+        StorageBackendType::Local => {
+            let local = storage.local.unwrap_or_default();
+            if local.db_path.as_os_str().is_empty() {
+                problems.push("storage.local.db_path cannot be empty".to_string());
+            }
+        }
+        StorageBackendType::External => {
+            problems.push("storage.backend 'external' is not yet implemented".to_string());
+        }
+    }
+
+    // HTTP clients
+    let http_configs = [
+        ("common", &config.datasources.common),
+        ("alternativeme", &config.datasources.alternativeme),
+    ];
+    for (name, http) in http_configs {
+        if let Some(http) = http {
+            check_http_config(name, http, &mut problems);
+        }
+    }
+
+    problems
+}
+
+/// Validate the enabled jobs stored in the configured database, which `serve`
+/// schedules on startup.
+///
+/// The database is opened read-only, so checking never migrates it. Skipped
+/// when the database does not exist yet.
+pub async fn check_stored_jobs(config: &AppConfig) -> Vec<String> {
+    let storage = config.storage.clone().unwrap_or_default();
+    if storage.backend != StorageBackendType::Local {
+        return Vec::new();
+    }
+    let local: LocalStorageConfig = storage.local.unwrap_or_default().into();
+    if !local.db_path.exists() {
+        return Vec::new();
+    }
+    let db_path = local.db_path;
+    let records = match SqliteStorage::open_read_only(&db_path).await {
+        Ok(storage) => storage.list_jobs().await,
+        Err(e) => Err(e),
+    };
+    let clients = check_clients();
+    match records {
+        Ok(records) => records
+            .iter()
+            .filter(|record| record.job.enabled)
+            .flat_map(|record| {
+                check_job(&record.job, &config.datasources.enabled_sources, &clients)
+            })
+            .map(|problem| format!("database: {}", problem))
+            .collect(),
+        Err(e) => vec![format!(
+            "failed to read jobs from '{}': {}",
+            db_path.display(),
+            e
+        )],
+    }
+}
+
+/// Clients to check job methods against.
+///
+/// Only their method metadata is used, which does not depend on HTTP settings.
+fn check_clients() -> HashMap<DataSource, Arc<dyn DataSourceClient>> {
+    ingestion_clients(HttpClientConfig::default(), HttpClientConfig::default()).unwrap_or_default()
+}
+
+/// Problems that would keep `job` from being scheduled or fetched.
+///
+/// Besides the job's own validation and the enabled sources, the method and
+/// its required params are checked against the client `serve` fetches the
+/// job's data source with.
+fn check_job(
+    job: &IngestionJob,
+    enabled_sources: &[DataSource],
+    clients: &HashMap<DataSource, Arc<dyn DataSourceClient>>,
+) -> Vec<String> {
+    let mut problems = Vec::new();
+    if let Err(e) = job.validate() {
+        problems.push(format!("job '{}': {}", job.name, e));
+    }
+    if let Err(e) = job.check_source_enabled(enabled_sources) {
+        problems.push(e.to_string());
+        return problems;
+    }
+    let Some(client) = clients.get(&job.datasource) else {
+        problems.push(format!(
+            "job '{}': data source '{}' has no ingestion client",
+            job.name, job.datasource
+        ));
+        return problems;
+    };
+    match client
+        .supported_methods()
+        .into_iter()
+        .find(|m| m.method == job.method)
+    {
+        Some(method) => {
+            for param in method.params.iter().filter(|p| p.required) {
+                let given = job
+                    .params
+                    .as_ref()
+                    .and_then(|params| params.get(param.name))
+                    .is_some_and(|value| !value.is_null());
+                if !given {
+                    problems.push(format!(
+                        "job '{}': method '{}' requires param '{}'",
+                        job.name, job.method, param.name
+                    ));
+                }
+            }
+        }
+        None => problems.push(format!(
+            "job '{}': unknown method '{}' for data source '{}'",
+            job.name, job.method, job.datasource
+        )),
+    }
+    problems
+}
+
+fn check_http_config(name: &str, http: &HttpClientConfigSerde, problems: &mut Vec<String>) {
+    if http.timeout_secs == Some(0) {
+        problems.push(format!(
+            "datasources.{}.timeout_secs must be greater than 0",
+            name
+        ));
+    }
+    if http.connect_timeout_secs == Some(0) {
+        problems.push(format!(
+            "datasources.{}.connect_timeout_secs must be greater than 0",
+            name
+        ));
+    }
+    if http
+        .user_agent
+        .as_deref()
+        .is_some_and(|ua| ua.trim().is_empty())
+    {
+        problems.push(format!("datasources.{}.user_agent cannot be empty", name));
+    }
+}
+
 /// Create storage backend based on configuration.
 async fn create_storage(config: &StorageConfig) -> anyhow::Result<Arc<dyn StorageBackend>> {
     match config.backend {
@@ -154,3 +562,278 @@ async fn create_storage(config: &StorageConfig) -> anyhow::Result<Arc<dyn Storag
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn check_reports_invalid_cron() {
+        let dir = std::env::temp_dir().join(format!("polymarket-hft-check-{}", Uuid::now_v7()));
+        let jobs_dir = dir.join("jobs");
+        std::fs::create_dir_all(&jobs_dir).unwrap();
+        std::fs::write(
+            jobs_dir.join("fgi.yaml"),
+            "name: fgi\ndatasource: alternativeme\nmethod: get_fear_and_greed\ncron: \"not a cron\"\n",
+        )
+        .unwrap();
+        let config_path = dir.join("config.yaml");
+        std::fs::write(
+            &config_path,
+            format!("ingestion:\n  jobs_dir: \"{}\"\n", jobs_dir.display()),
+        )
+        .unwrap();
+
+        let problems = check(&config_path).await;
+        let _ = std::fs::remove_dir_all(&dir);
+
+        assert_eq!(problems.len(), 1, "{problems:?}");
+        assert!(
+            problems[0].starts_with("job 'fgi': Invalid cron expression 'not a cron'"),
+            "{}",
+            problems[0]
+        );
+    }
+
+    #[tokio::test]
+    async fn check_rejects_jobs_for_disabled_sources() {
+        let dir = std::env::temp_dir().join(format!("polymarket-hft-check-{}", Uuid::now_v7()));
+        let jobs_dir = dir.join("jobs");
+        std::fs::create_dir_all(&jobs_dir).unwrap();
+        std::fs::write(
+            jobs_dir.join("fgi.yaml"),
+            "name: fgi\ndatasource: alternativeme\nmethod: get_fear_and_greed\ninterval_secs: 3600\n",
+        )
+        .unwrap();
+        std::fs::write(
+            jobs_dir.join("btc.yaml"),
+            "name: btc\ndatasource: coingecko\nmethod: get_price\ninterval_secs: 3600\n",
+        )
+        .unwrap();
+        let config_path = dir.join("config.yaml");
+        std::fs::write(
+            &config_path,
+            format!(
+                "datasources:\n  enabled_sources: [polymarket, alternativeme]\ningestion:\n  jobs_dir: \"{}\"\n",
+                jobs_dir.display()
+            ),
+        )
+        .unwrap();
+
+        let problems = check(&config_path).await;
+        let _ = std::fs::remove_dir_all(&dir);
+
+        assert_eq!(
+            problems,
+            vec![
+                "Job 'btc' uses data source 'coingecko', which is not in datasources.enabled_sources"
+                    .to_string()
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn check_validates_methods_against_the_client_of_the_data_source() {
+        let dir = std::env::temp_dir().join(format!("polymarket-hft-check-{}", Uuid::now_v7()));
+        let jobs_dir = dir.join("jobs");
+        std::fs::create_dir_all(&jobs_dir).unwrap();
+        std::fs::write(
+            jobs_dir.join("mids.yaml"),
+            "name: mids\ndatasource: polymarket\nmethod: get_midpoints\ninterval_secs: 60\nparams:\n  token_ids: [\"1\", \"2\"]\n",
+        )
+        .unwrap();
+        std::fs::write(
+            jobs_dir.join("fgi.yaml"),
+            "name: fgi\ndatasource: polymarket\nmethod: get_fear_and_greed\ninterval_secs: 60\n",
+        )
+        .unwrap();
+        std::fs::write(
+            jobs_dir.join("btc.yaml"),
+            "name: btc\ndatasource: coingecko\nmethod: get_price\ninterval_secs: 60\n",
+        )
+        .unwrap();
+        let config_path = dir.join("config.yaml");
+        std::fs::write(
+            &config_path,
+            format!("ingestion:\n  jobs_dir: \"{}\"\n", jobs_dir.display()),
+        )
+        .unwrap();
+
+        let mut problems = check(&config_path).await;
+        let _ = std::fs::remove_dir_all(&dir);
+
+        problems.sort();
+        assert_eq!(
+            problems,
+            vec![
+                "job 'btc': data source 'coingecko' has no ingestion client".to_string(),
+                "job 'fgi': unknown method 'get_fear_and_greed' for data source 'polymarket'"
+                    .to_string(),
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn sync_job_files_upserts_and_disables_by_name() {
+        let storage = LocalStorage::new_in_memory(LocalStorageConfig::default())
+            .await
+            .unwrap();
+        let stored_fgi: crate::config::IngestionJob = serde_yaml::from_str(
+            "name: fgi\ndatasource: alternativeme\nmethod: get_fear_and_greed\ninterval_secs: 60\n",
+        )
+        .unwrap();
+        let mut stored_old = stored_fgi.clone();
+        stored_old.name = "old".to_string();
+        let fgi_id = storage.store_job(&stored_fgi).await.unwrap();
+        let old_id = storage.store_job(&stored_old).await.unwrap();
+
+        let dir = std::env::temp_dir().join(format!("polymarket-hft-sync-{}", Uuid::now_v7()));
+        std::fs::create_dir_all(&dir).unwrap();
+        std::fs::write(
+            dir.join("fgi.yaml"),
+            "name: fgi\ndatasource: alternativeme\nmethod: get_fear_and_greed\ninterval_secs: 3600\n",
+        )
+        .unwrap();
+        std::fs::write(
+            dir.join("global.yaml"),
+            "name: global\ndatasource: alternativeme\nmethod: get_global\ninterval_secs: 600\n",
+        )
+        .unwrap();
+
+        // "old" is known to come from a file that has since been removed.
+        let mut file_jobs = HashSet::from(["fgi".to_string(), "old".to_string()]);
+        let changed = sync_job_files(&storage, &dir, &mut file_jobs)
+            .await
+            .unwrap();
+        let unchanged = sync_job_files(&storage, &dir, &mut file_jobs)
+            .await
+            .unwrap();
+        let _ = std::fs::remove_dir_all(&dir);
+
+        assert_eq!(changed, 3);
+        assert_eq!(unchanged, 0);
+        assert_eq!(
+            file_jobs,
+            HashSet::from(["fgi".to_string(), "global".to_string()])
+        );
+        let fgi = storage.get_job(fgi_id).await.unwrap().unwrap();
+        assert_eq!(
+            fgi.job.schedule,
+            crate::config::Schedule::Interval {
+                interval_secs: 3600,
+                aligned: false,
+            }
+        );
+        assert!(!storage.get_job(old_id).await.unwrap().unwrap().job.enabled);
+        let jobs = storage.list_jobs().await.unwrap();
+        assert!(jobs.iter().any(|r| r.job.name == "global" && r.job.enabled));
+    }
+
+    #[tokio::test]
+    async fn sync_job_files_keeps_jobs_created_without_a_file() {
+        let storage = LocalStorage::new_in_memory(LocalStorageConfig::default())
+            .await
+            .unwrap();
+        let ui_job: IngestionJob = serde_yaml::from_str(
+            "name: ui\ndatasource: alternativeme\nmethod: get_fear_and_greed\ninterval_secs: 60\n",
+        )
+        .unwrap();
+        let ui_id = storage.store_job(&ui_job).await.unwrap();
+
+        let dir = std::env::temp_dir().join(format!("polymarket-hft-sync-{}", Uuid::now_v7()));
+        std::fs::create_dir_all(&dir).unwrap();
+        std::fs::write(
+            dir.join("global.yaml"),
+            "name: global\ndatasource: alternativeme\nmethod: get_global\ninterval_secs: 600\n",
+        )
+        .unwrap();
+
+        let mut file_jobs = HashSet::new();
+        for _ in 0..2 {
+            sync_job_files(&storage, &dir, &mut file_jobs)
+                .await
+                .unwrap();
+        }
+        let _ = std::fs::remove_dir_all(&dir);
+
+        assert!(storage.get_job(ui_id).await.unwrap().unwrap().job.enabled);
+    }
+
+    #[tokio::test]
+    async fn check_validates_jobs_stored_in_the_database() {
+        let dir = std::env::temp_dir().join(format!("polymarket-hft-check-{}", Uuid::now_v7()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let db_path = dir.join("metrics.db");
+        let storage = LocalStorage::new(LocalStorageConfig {
+            db_path: db_path.clone(),
+            ..Default::default()
+        })
+        .await
+        .unwrap();
+        let job: IngestionJob = serde_yaml::from_str(
+            "name: ticker\ndatasource: alternativeme\nmethod: get_ticker\ncron: \"not a cron\"\n",
+        )
+        .unwrap();
+        storage.store_job(&job).await.unwrap();
+        let mut disabled = job.clone();
+        disabled.name = "disabled".to_string();
+        disabled.enabled = false;
+        storage.store_job(&disabled).await.unwrap();
+        drop(storage);
+        let config_path = dir.join("config.yaml");
+        std::fs::write(
+            &config_path,
+            format!(
+                "storage:\n  backend: local\n  local:\n    db_path: \"{}\"\n",
+                db_path.display()
+            ),
+        )
+        .unwrap();
+
+        let problems = check(&config_path).await;
+        let _ = std::fs::remove_dir_all(&dir);
+
+        assert_eq!(problems.len(), 2, "{problems:?}");
+        assert!(
+            problems[0].starts_with("database: job 'ticker': Invalid cron expression"),
+            "{}",
+            problems[0]
+        );
+        assert_eq!(
+            problems[1],
+            "database: job 'ticker': method 'get_ticker' requires param 'target'"
+        );
+    }
+
+    #[tokio::test]
+    async fn check_leaves_an_outdated_database_unmigrated() {
+        let dir = std::env::temp_dir().join(format!("polymarket-hft-check-{}", Uuid::now_v7()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let db_path = dir.join("metrics.db");
+        // An empty file is a valid database at schema version 0.
+        std::fs::write(&db_path, b"").unwrap();
+        let config_path = dir.join("config.yaml");
+        std::fs::write(
+            &config_path,
+            format!(
+                "storage:\n  backend: local\n  local:\n    db_path: \"{}\"\n",
+                db_path.display()
+            ),
+        )
+        .unwrap();
+
+        let problems = check(&config_path).await;
+        let len = std::fs::metadata(&db_path).unwrap().len();
+        let _ = std::fs::remove_dir_all(&dir);
+
+        assert_eq!(problems.len(), 1, "{problems:?}");
+        assert!(problems[0].contains("schema version 0"), "{}", problems[0]);
+        assert_eq!(len, 0);
+    }
+
+    #[tokio::test]
+    async fn check_accepts_example_config() {
+        let path = Path::new(env!("CARGO_MANIFEST_DIR")).join("configs/config.example.yaml");
+        assert!(check(&path).await.is_empty());
+    }
+}
@@ -3,14 +3,27 @@
 //! This module provides data models and storage backends for metrics persistence.
 //! Supports both local (SQLite + memory cache) and external (Redis + TimescaleDB) storage.
 
+pub mod buffered;
 pub mod cache;
+pub mod cardinality;
+pub mod latest;
 pub mod local;
+pub mod migrations;
 pub mod model;
+pub mod recent;
+pub mod replay;
+pub mod sink;
 pub mod sqlite;
 
 // Re-export BoxFuture from client module to avoid duplication
 pub use crate::client::BoxFuture;
 
+use std::collections::HashMap;
+use std::sync::Arc;
+
+/// A metric name and the label set of one of its series.
+pub type SeriesId = (String, HashMap<String, String>);
+
 /// Storage backend trait for metrics persistence.
 ///
 /// Implementations handle both hot data caching and cold data persistence.
@@ -22,6 +35,25 @@ pub trait StorageBackend: Send + Sync {
     /// persistence (for cold data) as appropriate.
     fn store(&self, metrics: &[model::Metric]) -> BoxFuture<'_, anyhow::Result<()>>;
 
+    /// Persist any metrics still held in memory by [`store`](Self::store).
+    ///
+    /// Called on shutdown. The default is a no-op for backends that write
+    /// straight through.
+    fn flush(&self) -> BoxFuture<'_, anyhow::Result<()>> {
+        Box::pin(async { Ok(()) })
+    }
+
+    /// Receive every batch of metrics after it has been stored.
+    ///
+    /// Batches are published on a broadcast channel, so writes never wait
+    /// for subscribers; a subscriber that falls behind gets
+    /// [`RecvError::Lagged`](tokio::sync::broadcast::error::RecvError::Lagged)
+    /// and misses the oldest batches. Returns `None` for backends that do not
+    /// publish writes (the default).
+    fn subscribe(&self) -> Option<tokio::sync::broadcast::Receiver<Arc<[model::Metric]>>> {
+        None
+    }
+
     /// Get the latest value for a metric by source and name.
     ///
     /// Returns `None` if the metric is not found.
@@ -31,6 +63,137 @@ pub trait StorageBackend: Send + Sync {
         name: &str,
     ) -> BoxFuture<'_, anyhow::Result<Option<model::Metric>>>;
 
+    /// Get the latest point of the series of `source` and `name` whose labels
+    /// are exactly `labels`.
+    ///
+    /// Returns `None` if the series has no points. The default only looks at
+    /// [`get_latest`](Self::get_latest) and returns `None` when that point
+    /// belongs to another label set; backends should override it.
+    fn get_latest_series<'a>(
+        &'a self,
+        source: &'a str,
+        name: &'a str,
+        labels: &'a HashMap<String, String>,
+    ) -> BoxFuture<'a, anyhow::Result<Option<model::Metric>>> {
+        Box::pin(async move {
+            Ok(self
+                .get_latest(source, name)
+                .await?
+                .filter(|latest| latest.labels == *labels))
+        })
+    }
+
+    /// Get the value a metric had at `at` (unix millis): the latest point
+    /// at or before that time.
+    ///
+    /// Returns `None` if there is no such point.
+    fn get_value_as_of(
+        &self,
+        source: &str,
+        name: &str,
+        at: i64,
+    ) -> BoxFuture<'_, anyhow::Result<Option<model::Metric>>>;
+
+    /// Get the value the series of `source`, `name` and exactly `labels` had
+    /// at `at` (unix millis): its latest point at or before that time.
+    ///
+    /// Returns `None` if there is no such point. The default only looks at
+    /// [`get_value_as_of`](Self::get_value_as_of) and returns `None` when that
+    /// point belongs to another label set; backends should override it.
+    fn get_series_value_as_of<'a>(
+        &'a self,
+        source: &'a str,
+        name: &'a str,
+        labels: &'a HashMap<String, String>,
+        at: i64,
+    ) -> BoxFuture<'a, anyhow::Result<Option<model::Metric>>> {
+        Box::pin(async move {
+            Ok(self
+                .get_value_as_of(source, name, at)
+                .await?
+                .filter(|point| point.labels == *labels))
+        })
+    }
+
+    /// Relative change of a metric over the last `window_secs`, as a
+    /// fraction (`0.1` is +10%).
+    ///
+    /// Compares the latest value with the value as of `now - window_secs`
+    /// (see [`get_value_as_of`](Self::get_value_as_of)). Returns `None` if
+    /// either point is missing or the earlier value is zero.
+    fn pct_change(
+        &self,
+        source: &str,
+        name: &str,
+        window_secs: i64,
+    ) -> BoxFuture<'_, anyhow::Result<Option<f64>>> {
+        let source = source.to_string();
+        let name = name.to_string();
+        let since = chrono::Utc::now().timestamp_millis() - window_secs.saturating_mul(1000);
+        Box::pin(async move {
+            let Some(latest) = self.get_latest(&source, &name).await? else {
+                return Ok(None);
+            };
+            let Some(base) = self.get_value_as_of(&source, &name, since).await? else {
+                return Ok(None);
+            };
+            if base.value == 0.0 {
+                return Ok(None);
+            }
+            Ok(Some((latest.value - base.value) / base.value))
+        })
+    }
+
+    /// The `top_n` series of `source` with the largest absolute relative
+    /// change over the last `window_secs`.
+    ///
+    /// Every label set of a metric (see
+    /// [`get_available_series`](Self::get_available_series)) is ranked on its
+    /// own, comparing its latest point with its point as of
+    /// `now - window_secs` like [`pct_change`](Self::pct_change) does. Series
+    /// missing either point, or whose earlier value is zero, are skipped.
+    fn top_movers(
+        &self,
+        source: &str,
+        window_secs: i64,
+        top_n: usize,
+    ) -> BoxFuture<'_, anyhow::Result<Vec<model::Mover>>> {
+        let source = source.to_string();
+        let since = chrono::Utc::now().timestamp_millis() - window_secs.saturating_mul(1000);
+        Box::pin(async move {
+            let mut movers = Vec::new();
+            for (name, labels) in self.get_available_series(&source).await? {
+                let Some(latest) = self.get_latest_series(&source, &name, &labels).await? else {
+                    continue;
+                };
+                let Some(base) = self
+                    .get_series_value_as_of(&source, &name, &labels, since)
+                    .await?
+                else {
+                    continue;
+                };
+                if base.value == 0.0 {
+                    continue;
+                }
+                let mover = model::Mover {
+                    label_symbol: labels.get("symbol").cloned(),
+                    pct_change: (latest.value - base.value) / base.value,
+                    name,
+                    labels,
+                };
+                movers.push((latest.series_key(), mover));
+            }
+            movers.sort_by(|(a_key, a), (b_key, b)| {
+                b.pct_change
+                    .abs()
+                    .total_cmp(&a.pct_change.abs())
+                    .then_with(|| a_key.cmp(b_key))
+            });
+            movers.truncate(top_n);
+            Ok(movers.into_iter().map(|(_, mover)| mover).collect())
+        })
+    }
+
     /// Query metrics within a time range.
     ///
     /// # Arguments
@@ -48,6 +211,46 @@ pub trait StorageBackend: Send + Sync {
         limit: Option<usize>,
     ) -> BoxFuture<'_, anyhow::Result<Vec<model::Metric>>>;
 
+    /// Query one page of metrics within a time range, oldest first.
+    ///
+    /// Intended for streaming exports: callers advance `offset` by the number
+    /// of rows returned until a page shorter than `limit` comes back.
+    ///
+    /// The default implementation loads the whole range with
+    /// [`query_range`](Self::query_range) for every page; backends that can
+    /// page natively should override it.
+    fn query_range_page(
+        &self,
+        source: Option<&str>,
+        name: Option<&str>,
+        start: i64,
+        end: i64,
+        limit: usize,
+        offset: usize,
+    ) -> BoxFuture<'_, anyhow::Result<Vec<model::Metric>>> {
+        let source = source.map(|s| s.to_string());
+        let name = name.map(|s| s.to_string());
+        Box::pin(async move {
+            let mut metrics = self
+                .query_range(source.as_deref(), name.as_deref(), start, end, None)
+                .await?;
+            metrics.sort_by_key(|m| m.timestamp);
+            Ok(metrics.into_iter().skip(offset).take(limit).collect())
+        })
+    }
+
+    /// Query metrics within a time range whose labels contain `label_key`,
+    /// regardless of its value. Newest first.
+    fn query_with_label_key(
+        &self,
+        source: Option<&str>,
+        name: Option<&str>,
+        label_key: &str,
+        start: i64,
+        end: i64,
+        limit: Option<usize>,
+    ) -> BoxFuture<'_, anyhow::Result<Vec<model::Metric>>>;
+
     /// Perform a health check on the storage backend.
     fn health_check(&self) -> BoxFuture<'_, anyhow::Result<()>>;
 
@@ -59,9 +262,32 @@ pub trait StorageBackend: Send + Sync {
     /// Get available metrics (source, name) pairs.
     fn get_available_metrics(&self) -> BoxFuture<'_, anyhow::Result<Vec<(String, String)>>>;
 
+    /// Get every distinct metric name and label set stored for `source`.
+    ///
+    /// The default returns one series per metric name, labelled like its
+    /// [`get_latest`](Self::get_latest) point; backends should override it.
+    fn get_available_series(&self, source: &str) -> BoxFuture<'_, anyhow::Result<Vec<SeriesId>>> {
+        let source = source.to_string();
+        Box::pin(async move {
+            let mut series = Vec::new();
+            for (metric_source, name) in self.get_available_metrics().await? {
+                if metric_source != source {
+                    continue;
+                }
+                if let Some(latest) = self.get_latest(&source, &name).await? {
+                    series.push((name, latest.labels));
+                }
+            }
+            Ok(series)
+        })
+    }
+
     /// Store a system event.
     fn store_event(&self, event: &model::Event) -> BoxFuture<'_, anyhow::Result<()>>;
 
+    /// Store a batch of system events in one write.
+    fn store_events(&self, events: &[model::Event]) -> BoxFuture<'_, anyhow::Result<()>>;
+
     /// Get events with optional instance ID filter.
     ///
     /// # Arguments
@@ -76,6 +302,14 @@ pub trait StorageBackend: Send + Sync {
     /// Get distinct instance IDs from events.
     fn get_distinct_instance_ids(&self) -> BoxFuture<'_, anyhow::Result<Vec<String>>>;
 
+    /// Count `TaskFailed` events per job since `since` (unix millis).
+    ///
+    /// Sorted by count descending, then job name.
+    fn failure_counts_by_job(
+        &self,
+        since: i64,
+    ) -> BoxFuture<'_, anyhow::Result<Vec<(String, usize)>>>;
+
     // =========================================================================
     // Job Management
     // =========================================================================
@@ -100,11 +334,40 @@ pub trait StorageBackend: Send + Sync {
 
     /// List all jobs from the database.
     fn list_jobs(&self) -> BoxFuture<'_, anyhow::Result<Vec<model::JobRecord>>>;
+
+    /// Record that the job named `name` started a run at `at` (Unix seconds).
+    ///
+    /// The default is a no-op for backends that do not track run times.
+    fn set_job_last_run<'a>(
+        &'a self,
+        _name: &'a str,
+        _at: i64,
+    ) -> BoxFuture<'a, anyhow::Result<()>> {
+        Box::pin(async { Ok(()) })
+    }
+
+    /// Record when the job named `name` runs next (Unix seconds), or clear it
+    /// with `None`.
+    ///
+    /// The default is a no-op for backends that do not track run times.
+    fn set_job_next_run<'a>(
+        &'a self,
+        _name: &'a str,
+        _at: Option<i64>,
+    ) -> BoxFuture<'a, anyhow::Result<()>> {
+        Box::pin(async { Ok(()) })
+    }
 }
 
 // ============================================================================
 // Re-exports
 // ============================================================================
 
+pub use buffered::BufferedStorage;
+pub use cardinality::LabelCardinalityGuard;
+pub use latest::LatestCache;
 pub use local::{LocalStorage, LocalStorageConfig};
-pub use model::{DataSource, Event, EventType, JobRecord, Metric, MetricUnit};
+pub use model::{DataSource, Event, EventType, JobRecord, Metric, MetricUnit, Mover, ToMetrics};
+pub use recent::RecentBuffer;
+pub use replay::replay;
+pub use sink::{MetricSink, StorageSink, WebhookSink};
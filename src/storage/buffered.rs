@@ -0,0 +1,277 @@
+//! Write-behind buffering in front of another storage backend.
+//!
+//! [`BufferedStorage`] collects metrics in memory and writes them to the
+//! wrapped backend in larger batches. Everything else is passed through.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use tokio::sync::Mutex;
+
+use super::model::{Event, JobRecord, Metric};
+use super::{BoxFuture, StorageBackend};
+use crate::config::IngestionJob;
+
+/// Default number of buffered metrics that triggers a write.
+pub const DEFAULT_BUFFER_CAPACITY: usize = 1000;
+
+/// Storage backend that batches metric writes to an inner backend.
+///
+/// Stored metrics are held in memory until `capacity` of them are pending
+/// or [`flush`](StorageBackend::flush) is called; reads only see metrics
+/// that have been written through. Flush before dropping it, or buffered
+/// metrics are lost.
+pub struct BufferedStorage {
+    inner: Arc<dyn StorageBackend>,
+    buffer: Mutex<Vec<Metric>>,
+    capacity: usize,
+}
+
+impl BufferedStorage {
+    /// Wrap `inner`, writing once [`DEFAULT_BUFFER_CAPACITY`] metrics are pending.
+    pub fn new(inner: Arc<dyn StorageBackend>) -> Self {
+        Self::with_capacity(inner, DEFAULT_BUFFER_CAPACITY)
+    }
+
+    /// Wrap `inner`, writing once `capacity` metrics are pending.
+    pub fn with_capacity(inner: Arc<dyn StorageBackend>, capacity: usize) -> Self {
+        Self {
+            inner,
+            buffer: Mutex::new(Vec::new()),
+            capacity,
+        }
+    }
+
+    /// Number of metrics waiting to be written.
+    pub async fn pending(&self) -> usize {
+        self.buffer.lock().await.len()
+    }
+
+    /// Write the buffered metrics, putting them back if the write fails.
+    async fn write_buffered(&self, buffer: &mut Vec<Metric>) -> anyhow::Result<()> {
+        if buffer.is_empty() {
+            return Ok(());
+        }
+        let batch = std::mem::take(buffer);
+        if let Err(e) = self.inner.store(&batch).await {
+            *buffer = batch;
+            return Err(e);
+        }
+        Ok(())
+    }
+}
+
+impl StorageBackend for BufferedStorage {
+    fn store(&self, metrics: &[Metric]) -> BoxFuture<'_, anyhow::Result<()>> {
+        let metrics = metrics.to_vec();
+        Box::pin(async move {
+            let mut buffer = self.buffer.lock().await;
+            buffer.extend(metrics);
+            if buffer.len() >= self.capacity {
+                self.write_buffered(&mut buffer).await?;
+            }
+            Ok(())
+        })
+    }
+
+    fn flush(&self) -> BoxFuture<'_, anyhow::Result<()>> {
+        Box::pin(async move {
+            let mut buffer = self.buffer.lock().await;
+            self.write_buffered(&mut buffer).await?;
+            self.inner.flush().await
+        })
+    }
+
+    fn subscribe(&self) -> Option<tokio::sync::broadcast::Receiver<Arc<[Metric]>>> {
+        self.inner.subscribe()
+    }
+
+    fn get_latest(
+        &self,
+        source: &str,
+        name: &str,
+    ) -> BoxFuture<'_, anyhow::Result<Option<Metric>>> {
+        self.inner.get_latest(source, name)
+    }
+
+    fn get_latest_series<'a>(
+        &'a self,
+        source: &'a str,
+        name: &'a str,
+        labels: &'a HashMap<String, String>,
+    ) -> BoxFuture<'a, anyhow::Result<Option<Metric>>> {
+        self.inner.get_latest_series(source, name, labels)
+    }
+
+    fn get_value_as_of(
+        &self,
+        source: &str,
+        name: &str,
+        at: i64,
+    ) -> BoxFuture<'_, anyhow::Result<Option<Metric>>> {
+        self.inner.get_value_as_of(source, name, at)
+    }
+
+    fn get_series_value_as_of<'a>(
+        &'a self,
+        source: &'a str,
+        name: &'a str,
+        labels: &'a HashMap<String, String>,
+        at: i64,
+    ) -> BoxFuture<'a, anyhow::Result<Option<Metric>>> {
+        self.inner.get_series_value_as_of(source, name, labels, at)
+    }
+
+    fn query_range(
+        &self,
+        source: Option<&str>,
+        name: Option<&str>,
+        start: i64,
+        end: i64,
+        limit: Option<usize>,
+    ) -> BoxFuture<'_, anyhow::Result<Vec<Metric>>> {
+        self.inner.query_range(source, name, start, end, limit)
+    }
+
+    fn query_range_page(
+        &self,
+        source: Option<&str>,
+        name: Option<&str>,
+        start: i64,
+        end: i64,
+        limit: usize,
+        offset: usize,
+    ) -> BoxFuture<'_, anyhow::Result<Vec<Metric>>> {
+        self.inner
+            .query_range_page(source, name, start, end, limit, offset)
+    }
+
+    fn query_with_label_key(
+        &self,
+        source: Option<&str>,
+        name: Option<&str>,
+        label_key: &str,
+        start: i64,
+        end: i64,
+        limit: Option<usize>,
+    ) -> BoxFuture<'_, anyhow::Result<Vec<Metric>>> {
+        self.inner
+            .query_with_label_key(source, name, label_key, start, end, limit)
+    }
+
+    fn health_check(&self) -> BoxFuture<'_, anyhow::Result<()>> {
+        self.inner.health_check()
+    }
+
+    fn cleanup_before(&self, cutoff_timestamp: i64) -> BoxFuture<'_, anyhow::Result<u64>> {
+        self.inner.cleanup_before(cutoff_timestamp)
+    }
+
+    fn get_available_metrics(&self) -> BoxFuture<'_, anyhow::Result<Vec<(String, String)>>> {
+        self.inner.get_available_metrics()
+    }
+
+    fn get_available_series(
+        &self,
+        source: &str,
+    ) -> BoxFuture<'_, anyhow::Result<Vec<super::SeriesId>>> {
+        self.inner.get_available_series(source)
+    }
+
+    fn store_event(&self, event: &Event) -> BoxFuture<'_, anyhow::Result<()>> {
+        self.inner.store_event(event)
+    }
+
+    fn store_events(&self, events: &[Event]) -> BoxFuture<'_, anyhow::Result<()>> {
+        self.inner.store_events(events)
+    }
+
+    fn get_events(
+        &self,
+        instance_id: Option<&str>,
+        limit: Option<usize>,
+    ) -> BoxFuture<'_, anyhow::Result<Vec<Event>>> {
+        self.inner.get_events(instance_id, limit)
+    }
+
+    fn get_distinct_instance_ids(&self) -> BoxFuture<'_, anyhow::Result<Vec<String>>> {
+        self.inner.get_distinct_instance_ids()
+    }
+
+    fn failure_counts_by_job(
+        &self,
+        since: i64,
+    ) -> BoxFuture<'_, anyhow::Result<Vec<(String, usize)>>> {
+        self.inner.failure_counts_by_job(since)
+    }
+
+    fn store_job(&self, job: &IngestionJob) -> BoxFuture<'_, anyhow::Result<i64>> {
+        self.inner.store_job(job)
+    }
+
+    fn update_job(&self, id: i64, job: &IngestionJob) -> BoxFuture<'_, anyhow::Result<()>> {
+        self.inner.update_job(id, job)
+    }
+
+    fn delete_job(&self, id: i64) -> BoxFuture<'_, anyhow::Result<()>> {
+        self.inner.delete_job(id)
+    }
+
+    fn get_job(&self, id: i64) -> BoxFuture<'_, anyhow::Result<Option<JobRecord>>> {
+        self.inner.get_job(id)
+    }
+
+    fn list_jobs(&self) -> BoxFuture<'_, anyhow::Result<Vec<JobRecord>>> {
+        self.inner.list_jobs()
+    }
+
+    fn set_job_last_run<'a>(&'a self, name: &'a str, at: i64) -> BoxFuture<'a, anyhow::Result<()>> {
+        self.inner.set_job_last_run(name, at)
+    }
+
+    fn set_job_next_run<'a>(
+        &'a self,
+        name: &'a str,
+        at: Option<i64>,
+    ) -> BoxFuture<'a, anyhow::Result<()>> {
+        self.inner.set_job_next_run(name, at)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::storage::local::{LocalStorage, LocalStorageConfig};
+    use crate::{DataSource, MetricUnit};
+
+    #[tokio::test]
+    async fn test_buffered_storage_writes_at_capacity() {
+        let inner: Arc<dyn StorageBackend> = Arc::new(
+            LocalStorage::new_in_memory(LocalStorageConfig::default())
+                .await
+                .unwrap(),
+        );
+        let storage = BufferedStorage::with_capacity(inner.clone(), 2);
+        let metric = Metric::new(DataSource::AlternativeMe, "test", 1.0, MetricUnit::Index);
+
+        storage.store(std::slice::from_ref(&metric)).await.unwrap();
+        assert_eq!(storage.pending().await, 1);
+        assert!(
+            inner
+                .get_latest("alternativeme", "test")
+                .await
+                .unwrap()
+                .is_none()
+        );
+
+        storage.store(&[metric]).await.unwrap();
+        assert_eq!(storage.pending().await, 0);
+        assert!(
+            inner
+                .get_latest("alternativeme", "test")
+                .await
+                .unwrap()
+                .is_some()
+        );
+    }
+}
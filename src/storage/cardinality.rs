@@ -0,0 +1,115 @@
+//! Guard against unbounded label cardinality.
+//!
+//! A job that puts a unique value in a label on every fetch (a timestamp,
+//! a request ID) creates a new series each time and bloats storage and its
+//! indices. [`LabelCardinalityGuard`] caps the distinct values kept per
+//! label of each metric.
+
+use std::collections::{HashMap, HashSet};
+use std::sync::Mutex;
+
+use super::model::Metric;
+
+/// Series key (`source::name`) and label key.
+type LabelId = (String, String);
+
+#[derive(Default)]
+struct Seen {
+    /// Distinct values recorded per label, at most the limit each.
+    values: HashMap<LabelId, HashSet<String>>,
+    /// Labels that have hit the limit.
+    capped: HashSet<LabelId>,
+}
+
+/// Caps the number of distinct values per label of each (source, name).
+///
+/// Values seen before the limit was reached keep being stored; a label with
+/// a new value past the limit is removed from that metric, which is stored
+/// without it.
+pub struct LabelCardinalityGuard {
+    max_values: usize,
+    seen: Mutex<Seen>,
+}
+
+impl LabelCardinalityGuard {
+    /// Creates a guard allowing `max_values` distinct values per label.
+    pub fn new(max_values: usize) -> Self {
+        Self {
+            max_values,
+            seen: Mutex::new(Seen::default()),
+        }
+    }
+
+    /// Drop labels from `metrics` whose value would exceed the limit.
+    ///
+    /// Returns the `(source::name, label)` pairs that reached the limit in
+    /// this call, so callers can warn once per label.
+    pub fn apply(&self, metrics: &mut [Metric]) -> Vec<(String, String)> {
+        let mut seen = self.seen.lock().unwrap_or_else(|e| e.into_inner());
+        let Seen { values, capped } = &mut *seen;
+        let mut newly_capped = Vec::new();
+
+        for metric in metrics {
+            let series = metric.state_key();
+            metric.labels.retain(|key, value| {
+                let id = (series.clone(), key.clone());
+                let known = values.entry(id.clone()).or_default();
+                if known.contains(value.as_str()) {
+                    return true;
+                }
+                if known.len() < self.max_values {
+                    known.insert(value.clone());
+                    return true;
+                }
+                if capped.insert(id.clone()) {
+                    newly_capped.push(id);
+                }
+                false
+            });
+        }
+        newly_capped
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{DataSource, MetricUnit};
+
+    #[test]
+    fn labels_past_the_limit_are_dropped() {
+        let guard = LabelCardinalityGuard::new(2);
+        let mut metrics: Vec<Metric> = (0..4)
+            .map(|i| {
+                Metric::new(DataSource::AlternativeMe, "fgi", 1.0, MetricUnit::Index)
+                    .with_label("fetched_at", i.to_string())
+                    .with_label("period", "1d")
+            })
+            .collect();
+
+        let capped = guard.apply(&mut metrics);
+
+        assert_eq!(
+            capped,
+            vec![("alternativeme::fgi".to_string(), "fetched_at".to_string())]
+        );
+        let kept: Vec<Option<&str>> = metrics
+            .iter()
+            .map(|m| m.labels.get("fetched_at").map(String::as_str))
+            .collect();
+        assert_eq!(kept, vec![Some("0"), Some("1"), None, None]);
+        // Low-cardinality labels are untouched.
+        assert!(metrics.iter().all(|m| m.labels["period"] == "1d"));
+
+        // Known values still pass; the cap is only reported once.
+        let mut again = vec![
+            Metric::new(DataSource::AlternativeMe, "fgi", 1.0, MetricUnit::Index)
+                .with_label("fetched_at", "1"),
+            Metric::new(DataSource::AlternativeMe, "fgi", 1.0, MetricUnit::Index)
+                .with_label("fetched_at", "9"),
+        ];
+        assert!(guard.apply(&mut again).is_empty());
+        assert_eq!(again[0].labels["fetched_at"], "1");
+        assert!(again[1].labels.is_empty());
+    }
+}
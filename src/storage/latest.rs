@@ -0,0 +1,235 @@
+//! Write-through cache of the latest value per series.
+//!
+//! [`LatestCache`] is registered as a [`MetricSink`] so every ingested batch
+//! updates it, and dashboard handlers read from it before asking storage.
+
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+
+use moka::future::Cache;
+
+use super::model::Metric;
+use super::sink::MetricSink;
+use super::{BoxFuture, StorageBackend};
+
+/// Default number of (source, name) pairs kept by [`LatestCache`].
+pub const DEFAULT_LATEST_CACHE_CAPACITY: u64 = 10_000;
+
+/// Latest metric of each label set of one pair, keyed by
+/// [`Metric::series_key`].
+type LabelSets = Arc<Mutex<HashMap<String, Metric>>>;
+
+/// Bounded cache of the most recent metric for each series: each label set
+/// of a (source, name) pair.
+///
+/// Entries never expire by age; the least recently used pairs are evicted,
+/// with all their label sets, once the capacity is reached. Share it behind
+/// an `Arc` between the scheduler (as a sink) and the web handlers.
+pub struct LatestCache {
+    latest: Cache<String, LabelSets>,
+}
+
+impl LatestCache {
+    /// Creates a cache holding at most `max_capacity` pairs.
+    pub fn new(max_capacity: u64) -> Self {
+        Self {
+            latest: Cache::builder().max_capacity(max_capacity).build(),
+        }
+    }
+
+    /// Record a batch, keeping whichever value per series has the newest timestamp.
+    pub async fn record(&self, metrics: &[Metric]) {
+        for metric in metrics {
+            let sets = self
+                .latest
+                .get_with(metric.state_key(), async { LabelSets::default() })
+                .await;
+            let mut sets = lock(&sets);
+            let key = metric.series_key();
+            let newer = match sets.get(&key) {
+                Some(existing) => metric.timestamp >= existing.timestamp,
+                None => true,
+            };
+            if newer {
+                sets.insert(key, metric.clone());
+            }
+        }
+    }
+
+    /// Cached latest value of the series whose labels are exactly `labels`,
+    /// without touching storage.
+    pub async fn get(
+        &self,
+        source: &str,
+        name: &str,
+        labels: &HashMap<String, String>,
+    ) -> Option<Metric> {
+        let sets = self.latest.get(&format!("{}::{}", source, name)).await?;
+        let key = Metric::series_key_for(source, name, labels);
+        lock(&sets).get(&key).cloned()
+    }
+
+    /// Cached latest value of every label set of `source` and `name`, ordered
+    /// by series key, without touching storage.
+    pub async fn label_sets(&self, source: &str, name: &str) -> Vec<Metric> {
+        let Some(sets) = self.latest.get(&format!("{}::{}", source, name)).await else {
+            return Vec::new();
+        };
+        let mut metrics: Vec<(String, Metric)> = lock(&sets)
+            .iter()
+            .map(|(key, metric)| (key.clone(), metric.clone()))
+            .collect();
+        metrics.sort_by(|(a, _), (b, _)| a.cmp(b));
+        metrics.into_iter().map(|(_, metric)| metric).collect()
+    }
+
+    /// Cached latest value of the series whose labels are exactly `labels`,
+    /// loading it from `storage` on a miss.
+    pub async fn get_or_load(
+        &self,
+        storage: &dyn StorageBackend,
+        source: &str,
+        name: &str,
+        labels: &HashMap<String, String>,
+    ) -> anyhow::Result<Option<Metric>> {
+        if let Some(metric) = self.get(source, name, labels).await {
+            return Ok(Some(metric));
+        }
+        let metric = storage.get_latest_series(source, name, labels).await?;
+        if let Some(metric) = &metric {
+            self.record(std::slice::from_ref(metric)).await;
+        }
+        Ok(metric)
+    }
+
+    /// Cached [`label_sets`](Self::label_sets) of `source` and `name`.
+    ///
+    /// If none are cached, only the newest point in `storage`, whatever its
+    /// labels, is loaded; the other label sets appear as they are ingested.
+    pub async fn label_sets_or_load(
+        &self,
+        storage: &dyn StorageBackend,
+        source: &str,
+        name: &str,
+    ) -> anyhow::Result<Vec<Metric>> {
+        let cached = self.label_sets(source, name).await;
+        if !cached.is_empty() {
+            return Ok(cached);
+        }
+        let metric = storage.get_latest(source, name).await?;
+        if let Some(metric) = &metric {
+            self.record(std::slice::from_ref(metric)).await;
+        }
+        Ok(metric.into_iter().collect())
+    }
+}
+
+fn lock(sets: &LabelSets) -> std::sync::MutexGuard<'_, HashMap<String, Metric>> {
+    sets.lock().unwrap_or_else(|e| e.into_inner())
+}
+
+impl Default for LatestCache {
+    fn default() -> Self {
+        Self::new(DEFAULT_LATEST_CACHE_CAPACITY)
+    }
+}
+
+impl MetricSink for LatestCache {
+    fn name(&self) -> &str {
+        "latest_cache"
+    }
+
+    fn emit(&self, metrics: &[Metric]) -> BoxFuture<'_, anyhow::Result<()>> {
+        let metrics = metrics.to_vec();
+        Box::pin(async move {
+            self.record(&metrics).await;
+            Ok(())
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::storage::{LocalStorage, LocalStorageConfig};
+    use crate::{DataSource, MetricUnit};
+
+    #[tokio::test]
+    async fn emitted_metric_is_cached_without_storage() {
+        let cache = LatestCache::default();
+        let metric = Metric::new(DataSource::AlternativeMe, "fgi", 55.0, MetricUnit::Index);
+
+        cache.emit(std::slice::from_ref(&metric)).await.unwrap();
+
+        let cached = cache
+            .get("alternativeme", "fgi", &HashMap::new())
+            .await
+            .unwrap();
+        assert_eq!(cached.value, 55.0);
+    }
+
+    #[tokio::test]
+    async fn older_values_do_not_replace_newer_ones() {
+        let cache = LatestCache::default();
+        let mut newer = Metric::new(DataSource::AlternativeMe, "fgi", 2.0, MetricUnit::Index);
+        newer.timestamp = 2_000;
+        let mut older = newer.clone();
+        older.value = 1.0;
+        older.timestamp = 1_000;
+
+        cache.record(&[newer, older]).await;
+        let cached = cache.get("alternativeme", "fgi", &HashMap::new()).await;
+        assert_eq!(cached.unwrap().value, 2.0);
+    }
+
+    #[tokio::test]
+    async fn miss_falls_back_to_storage_and_fills_cache() {
+        let storage = LocalStorage::new_in_memory(LocalStorageConfig::default())
+            .await
+            .unwrap();
+        let metric = Metric::new(DataSource::AlternativeMe, "fgi", 42.0, MetricUnit::Index);
+        storage.store(std::slice::from_ref(&metric)).await.unwrap();
+
+        let cache = LatestCache::default();
+        let labels = HashMap::new();
+        assert!(cache.get("alternativeme", "fgi", &labels).await.is_none());
+
+        let loaded = cache
+            .get_or_load(&storage, "alternativeme", "fgi", &labels)
+            .await
+            .unwrap();
+        assert_eq!(loaded.unwrap().value, 42.0);
+        assert!(cache.get("alternativeme", "fgi", &labels).await.is_some());
+    }
+
+    #[tokio::test]
+    async fn label_sets_are_cached_separately() {
+        let cache = LatestCache::default();
+        let price = |symbol: &str, value: f64, timestamp: i64| {
+            Metric::new(DataSource::CoinGecko, "price", value, MetricUnit::USD)
+                .with_label("symbol", symbol)
+                .with_timestamp(timestamp)
+        };
+
+        cache
+            .record(&[price("BTC", 100.0, 1_000), price("ETH", 10.0, 2_000)])
+            .await;
+
+        let btc = HashMap::from([("symbol".to_string(), "BTC".to_string())]);
+        let cached = cache.get("coingecko", "price", &btc).await;
+        assert_eq!(cached.unwrap().value, 100.0);
+        let values: Vec<f64> = cache
+            .label_sets("coingecko", "price")
+            .await
+            .iter()
+            .map(|m| m.value)
+            .collect();
+        assert_eq!(values, vec![100.0, 10.0]);
+        assert!(
+            cache
+                .get("coingecko", "price", &HashMap::new())
+                .await
+                .is_none()
+        );
+    }
+}
@@ -7,10 +7,15 @@ use super::cache::MemoryCache;
 use super::sqlite::SqliteStorage;
 use super::{BoxFuture, StorageBackend};
 use crate::Metric;
+use std::collections::HashMap;
 use std::path::PathBuf;
 use std::sync::Arc;
 
 use std::time::Duration;
+use tokio::sync::broadcast;
+
+/// Stored batches buffered for each [`subscribe`](StorageBackend::subscribe)r.
+const STORED_CHANNEL_CAPACITY: usize = 256;
 
 /// Combined local storage with memory cache and SQLite persistence.
 ///
@@ -19,6 +24,7 @@ use std::time::Duration;
 pub struct LocalStorage {
     cache: Arc<MemoryCache>,
     sqlite: Arc<SqliteStorage>,
+    stored: broadcast::Sender<Arc<[Metric]>>,
 }
 
 impl LocalStorage {
@@ -28,8 +34,13 @@ impl LocalStorage {
             config.cache_ttl,
             config.cache_max_capacity,
         ));
-        let sqlite = Arc::new(SqliteStorage::open(&config.db_path).await?);
-        Ok(Self { cache, sqlite })
+        let sqlite = Arc::new(
+            SqliteStorage::open(&config.db_path)
+                .await?
+                .with_indexed_labels(config.indexed_labels)
+                .await?,
+        );
+        Ok(Self::from_parts(cache, sqlite))
     }
 
     /// Creates a LocalStorage with in-memory SQLite (for testing).
@@ -38,8 +49,22 @@ impl LocalStorage {
             config.cache_ttl,
             config.cache_max_capacity,
         ));
-        let sqlite = Arc::new(SqliteStorage::open_in_memory().await?);
-        Ok(Self { cache, sqlite })
+        let sqlite = Arc::new(
+            SqliteStorage::open_in_memory()
+                .await?
+                .with_indexed_labels(config.indexed_labels)
+                .await?,
+        );
+        Ok(Self::from_parts(cache, sqlite))
+    }
+
+    fn from_parts(cache: Arc<MemoryCache>, sqlite: Arc<SqliteStorage>) -> Self {
+        let (stored, _) = broadcast::channel(STORED_CHANNEL_CAPACITY);
+        Self {
+            cache,
+            sqlite,
+            stored,
+        }
     }
 
     /// Access the underlying SQLite storage for cleanup operations.
@@ -61,10 +86,18 @@ impl StorageBackend for LocalStorage {
             // Write-through: update cache and persist to SQLite
             self.cache.put_batch(&metrics).await;
             self.sqlite.insert_batch(&metrics).await?;
+            // Skip building the shared batch when nobody is listening.
+            if self.stored.receiver_count() > 0 {
+                let _ = self.stored.send(metrics.into());
+            }
             Ok(())
         })
     }
 
+    fn subscribe(&self) -> Option<broadcast::Receiver<Arc<[Metric]>>> {
+        Some(self.stored.subscribe())
+    }
+
     fn get_latest(
         &self,
         source: &str,
@@ -82,6 +115,50 @@ impl StorageBackend for LocalStorage {
         })
     }
 
+    fn get_latest_series<'a>(
+        &'a self,
+        source: &'a str,
+        name: &'a str,
+        labels: &'a HashMap<String, String>,
+    ) -> BoxFuture<'a, anyhow::Result<Option<Metric>>> {
+        Box::pin(async move {
+            // The cached point is the newest of any label set, so it is only
+            // the answer when its labels match.
+            if let Some(metric) = self.cache.get(source, name).await
+                && metric.labels == *labels
+            {
+                return Ok(Some(metric));
+            }
+            self.sqlite.get_latest_series(source, name, labels).await
+        })
+    }
+
+    fn get_value_as_of(
+        &self,
+        source: &str,
+        name: &str,
+        at: i64,
+    ) -> BoxFuture<'_, anyhow::Result<Option<Metric>>> {
+        let source = source.to_string();
+        let name = name.to_string();
+        // The cache only holds the newest value, so always ask SQLite.
+        Box::pin(async move { self.sqlite.get_value_as_of(&source, &name, at).await })
+    }
+
+    fn get_series_value_as_of<'a>(
+        &'a self,
+        source: &'a str,
+        name: &'a str,
+        labels: &'a HashMap<String, String>,
+        at: i64,
+    ) -> BoxFuture<'a, anyhow::Result<Option<Metric>>> {
+        Box::pin(async move {
+            self.sqlite
+                .get_series_value_as_of(source, name, labels, at)
+                .await
+        })
+    }
+
     fn query_range(
         &self,
         source: Option<&str>,
@@ -106,6 +183,57 @@ impl StorageBackend for LocalStorage {
         })
     }
 
+    fn query_range_page(
+        &self,
+        source: Option<&str>,
+        name: Option<&str>,
+        start: i64,
+        end: i64,
+        limit: usize,
+        offset: usize,
+    ) -> BoxFuture<'_, anyhow::Result<Vec<Metric>>> {
+        let source = source.map(|s| s.to_string());
+        let name = name.map(|s| s.to_string());
+        Box::pin(async move {
+            self.sqlite
+                .query_range_page(
+                    source.as_deref(),
+                    name.as_deref(),
+                    start,
+                    end,
+                    limit,
+                    offset,
+                )
+                .await
+        })
+    }
+
+    fn query_with_label_key(
+        &self,
+        source: Option<&str>,
+        name: Option<&str>,
+        label_key: &str,
+        start: i64,
+        end: i64,
+        limit: Option<usize>,
+    ) -> BoxFuture<'_, anyhow::Result<Vec<Metric>>> {
+        let source = source.map(|s| s.to_string());
+        let name = name.map(|s| s.to_string());
+        let label_key = label_key.to_string();
+        Box::pin(async move {
+            self.sqlite
+                .query_with_label_key(
+                    source.as_deref(),
+                    name.as_deref(),
+                    &label_key,
+                    start,
+                    end,
+                    limit.unwrap_or(1000),
+                )
+                .await
+        })
+    }
+
     fn health_check(&self) -> BoxFuture<'_, anyhow::Result<()>> {
         Box::pin(async move { self.sqlite.health_check().await })
     }
@@ -118,11 +246,24 @@ impl StorageBackend for LocalStorage {
         Box::pin(async move { self.sqlite.get_available_metrics().await })
     }
 
+    fn get_available_series(
+        &self,
+        source: &str,
+    ) -> BoxFuture<'_, anyhow::Result<Vec<super::SeriesId>>> {
+        let source = source.to_string();
+        Box::pin(async move { self.sqlite.get_available_series(&source).await })
+    }
+
     fn store_event(&self, event: &super::model::Event) -> BoxFuture<'_, anyhow::Result<()>> {
         let event = event.clone();
         Box::pin(async move { self.sqlite.insert_event(&event).await })
     }
 
+    fn store_events(&self, events: &[super::model::Event]) -> BoxFuture<'_, anyhow::Result<()>> {
+        let events = events.to_vec();
+        Box::pin(async move { self.sqlite.insert_events(&events).await })
+    }
+
     fn get_events(
         &self,
         instance_id: Option<&str>,
@@ -140,6 +281,13 @@ impl StorageBackend for LocalStorage {
         Box::pin(async move { self.sqlite.get_distinct_instance_ids().await })
     }
 
+    fn failure_counts_by_job(
+        &self,
+        since: i64,
+    ) -> BoxFuture<'_, anyhow::Result<Vec<(String, usize)>>> {
+        Box::pin(async move { self.sqlite.failure_counts_by_job(since).await })
+    }
+
     // =========================================================================
     // Job Management
     // =========================================================================
@@ -169,6 +317,18 @@ impl StorageBackend for LocalStorage {
     fn list_jobs(&self) -> BoxFuture<'_, anyhow::Result<Vec<super::model::JobRecord>>> {
         Box::pin(async move { self.sqlite.list_jobs().await })
     }
+
+    fn set_job_last_run<'a>(&'a self, name: &'a str, at: i64) -> BoxFuture<'a, anyhow::Result<()>> {
+        Box::pin(async move { self.sqlite.set_job_last_run(name, at).await })
+    }
+
+    fn set_job_next_run<'a>(
+        &'a self,
+        name: &'a str,
+        at: Option<i64>,
+    ) -> BoxFuture<'a, anyhow::Result<()>> {
+        Box::pin(async move { self.sqlite.set_job_next_run(name, at).await })
+    }
 }
 
 /// Configuration for LocalStorage.
@@ -180,6 +340,9 @@ pub struct LocalStorageConfig {
     pub cache_ttl: Duration,
     /// Maximum number of entries in the cache.
     pub cache_max_capacity: u64,
+    /// Label keys promoted to indexed columns; see
+    /// [`SqliteStorage::with_indexed_labels`].
+    pub indexed_labels: Vec<String>,
 }
 
 impl Default for LocalStorageConfig {
@@ -188,6 +351,7 @@ impl Default for LocalStorageConfig {
             db_path: PathBuf::from("data/metrics.db"),
             cache_ttl: Duration::from_secs(900), // 15 minutes
             cache_max_capacity: 100_000,
+            indexed_labels: Vec::new(),
         }
     }
 }
@@ -235,6 +399,158 @@ mod tests {
         assert!(result.is_some());
     }
 
+    #[tokio::test]
+    async fn test_pct_change_over_window() {
+        let storage = LocalStorage::new_in_memory(LocalStorageConfig::default())
+            .await
+            .unwrap();
+        let now = chrono::Utc::now().timestamp_millis();
+        let window_secs = 24 * 60 * 60;
+        let start = now - window_secs * 1000;
+        storage
+            .store(&[
+                Metric::new(DataSource::AlternativeMe, "test", 100.0, MetricUnit::Index)
+                    .with_timestamp(start),
+                Metric::new(DataSource::AlternativeMe, "test", 110.0, MetricUnit::Index)
+                    .with_timestamp(now),
+            ])
+            .await
+            .unwrap();
+
+        let change = storage
+            .pct_change("alternativeme", "test", window_secs)
+            .await
+            .unwrap()
+            .unwrap();
+        assert!((change - 0.10).abs() < 1e-9, "got {change}");
+
+        // No point exists before the window starts.
+        assert!(
+            storage
+                .pct_change("alternativeme", "test", 2 * window_secs)
+                .await
+                .unwrap()
+                .is_none()
+        );
+    }
+
+    #[tokio::test]
+    async fn test_top_movers_ranks_by_absolute_change() {
+        let storage = LocalStorage::new_in_memory(LocalStorageConfig::default())
+            .await
+            .unwrap();
+        let now = chrono::Utc::now().timestamp_millis();
+        let window_secs = 24 * 60 * 60;
+        let start = now - window_secs * 1000;
+        let price = |symbol: &str, value: f64, timestamp: i64| {
+            Metric::new(
+                DataSource::AlternativeMe,
+                format!("{}_price", symbol.to_lowercase()),
+                value,
+                MetricUnit::USD,
+            )
+            .with_label("symbol", symbol)
+            .with_timestamp(timestamp)
+        };
+        storage
+            .store(&[
+                price("BTC", 100.0, start),
+                price("BTC", 105.0, now),
+                price("ETH", 100.0, start),
+                price("ETH", 80.0, now),
+                price("SOL", 100.0, start),
+                price("SOL", 112.0, now),
+                // Only exists inside the window, so it has no base value.
+                price("DOGE", 1.0, now),
+                Metric::new(DataSource::Polymarket, "volume", 1.0, MetricUnit::USD)
+                    .with_timestamp(start),
+                Metric::new(DataSource::Polymarket, "volume", 9.0, MetricUnit::USD)
+                    .with_timestamp(now),
+            ])
+            .await
+            .unwrap();
+
+        let movers = storage
+            .top_movers("alternativeme", window_secs, 2)
+            .await
+            .unwrap();
+        let ranked: Vec<(&str, Option<&str>)> = movers
+            .iter()
+            .map(|m| (m.name.as_str(), m.label_symbol.as_deref()))
+            .collect();
+        assert_eq!(
+            ranked,
+            vec![("eth_price", Some("ETH")), ("sol_price", Some("SOL"))]
+        );
+        assert!((movers[0].pct_change + 0.20).abs() < 1e-9);
+        assert!((movers[1].pct_change - 0.12).abs() < 1e-9);
+
+        let all = storage
+            .top_movers("alternativeme", window_secs, 10)
+            .await
+            .unwrap();
+        assert_eq!(all.len(), 3);
+        assert_eq!(all[2].name, "btc_price");
+    }
+
+    #[tokio::test]
+    async fn test_top_movers_ranks_each_label_set() {
+        let storage = LocalStorage::new_in_memory(LocalStorageConfig::default())
+            .await
+            .unwrap();
+        let now = chrono::Utc::now().timestamp_millis();
+        let window_secs = 60 * 60;
+        let start = now - window_secs * 1000;
+        let price = |symbol: &str, value: f64, timestamp: i64| {
+            Metric::new(DataSource::AlternativeMe, "price", value, MetricUnit::USD)
+                .with_label("symbol", symbol)
+                .with_timestamp(timestamp)
+        };
+        storage
+            .store(&[
+                price("BTC", 100.0, start),
+                price("ETH", 100.0, start),
+                price("BTC", 101.0, now),
+                price("ETH", 150.0, now),
+            ])
+            .await
+            .unwrap();
+
+        let movers = storage
+            .top_movers("alternativeme", window_secs, 10)
+            .await
+            .unwrap();
+        let ranked: Vec<(&str, Option<&str>)> = movers
+            .iter()
+            .map(|m| (m.name.as_str(), m.label_symbol.as_deref()))
+            .collect();
+        assert_eq!(ranked, vec![("price", Some("ETH")), ("price", Some("BTC"))]);
+        assert!((movers[0].pct_change - 0.50).abs() < 1e-9);
+        assert!((movers[1].pct_change - 0.01).abs() < 1e-9);
+        assert_eq!(
+            movers[1].labels.get("symbol").map(String::as_str),
+            Some("BTC")
+        );
+    }
+
+    #[tokio::test]
+    async fn test_subscribers_receive_stored_batches() {
+        let storage = LocalStorage::new_in_memory(LocalStorageConfig::default())
+            .await
+            .unwrap();
+        let mut stored = storage.subscribe().unwrap();
+
+        let batch = vec![
+            Metric::new(DataSource::AlternativeMe, "a", 1.0, MetricUnit::Index),
+            Metric::new(DataSource::AlternativeMe, "b", 2.0, MetricUnit::Index),
+        ];
+        storage.store(&batch).await.unwrap();
+
+        let received = stored.recv().await.unwrap();
+        assert_eq!(&*received, batch.as_slice());
+        assert!(stored.try_recv().is_err());
+    }
+
     #[tokio::test]
     async fn test_local_storage_health_check() {
         let config = LocalStorageConfig::default();
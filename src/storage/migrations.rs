@@ -0,0 +1,284 @@
+//! Versioned schema migrations for the SQLite store.
+//!
+//! The applied version is kept in SQLite's `user_version` pragma; a database
+//! created before migrations existed reads as version 0. [`run`] applies
+//! every newer migration in order, each in its own transaction together with
+//! the version bump, so a failed migration leaves the previous version intact.
+
+use anyhow::bail;
+use sqlx::SqliteConnection;
+use sqlx::sqlite::SqlitePool;
+
+/// One schema change.
+enum Step {
+    /// A statement run as is.
+    Sql(&'static str),
+    /// Add a column unless it is already there, for databases that got it
+    /// from the unversioned schema.
+    AddColumn {
+        table: &'static str,
+        column: &'static str,
+        definition: &'static str,
+    },
+}
+
+struct Migration {
+    version: i64,
+    description: &'static str,
+    steps: &'static [Step],
+}
+
+/// Migrations in version order. Append new ones; never edit applied ones.
+const MIGRATIONS: &[Migration] = &[
+    Migration {
+        version: 1,
+        description: "initial schema",
+        steps: &[
+            Step::Sql(
+                r#"
+                CREATE TABLE IF NOT EXISTS metrics (
+                    id INTEGER PRIMARY KEY AUTOINCREMENT,
+                    source TEXT NOT NULL,
+                    name TEXT NOT NULL,
+                    value REAL NOT NULL,
+                    timestamp INTEGER NOT NULL,
+                    unit TEXT,
+                    labels TEXT,
+                    created_at INTEGER NOT NULL DEFAULT (strftime('%s', 'now'))
+                )
+                "#,
+            ),
+            Step::Sql(
+                r#"
+                CREATE INDEX IF NOT EXISTS idx_metrics_source_name_ts
+                ON metrics(source, name, timestamp DESC)
+                "#,
+            ),
+            Step::Sql(
+                r#"
+                CREATE INDEX IF NOT EXISTS idx_metrics_timestamp
+                ON metrics(timestamp DESC)
+                "#,
+            ),
+            Step::Sql(
+                r#"
+                CREATE TABLE IF NOT EXISTS events (
+                    id INTEGER PRIMARY KEY AUTOINCREMENT,
+                    instance_id TEXT NOT NULL,
+                    event_type TEXT NOT NULL,
+                    message TEXT NOT NULL,
+                    payload TEXT,
+                    timestamp INTEGER NOT NULL
+                )
+                "#,
+            ),
+            Step::Sql(
+                r#"
+                CREATE INDEX IF NOT EXISTS idx_events_instance_ts
+                ON events(instance_id, timestamp DESC)
+                "#,
+            ),
+            Step::Sql(
+                r#"
+                CREATE TABLE IF NOT EXISTS jobs (
+                    id INTEGER PRIMARY KEY AUTOINCREMENT,
+                    name TEXT NOT NULL UNIQUE,
+                    datasource TEXT NOT NULL,
+                    method TEXT NOT NULL,
+                    schedule TEXT NOT NULL,
+                    params TEXT,
+                    retention_days INTEGER NOT NULL DEFAULT 7,
+                    enabled INTEGER NOT NULL DEFAULT 1,
+                    created_at INTEGER NOT NULL DEFAULT (strftime('%s', 'now')),
+                    updated_at INTEGER NOT NULL DEFAULT (strftime('%s', 'now'))
+                )
+                "#,
+            ),
+            Step::Sql(
+                r#"
+                CREATE INDEX IF NOT EXISTS idx_jobs_enabled
+                ON jobs(enabled)
+                "#,
+            ),
+        ],
+    },
+    Migration {
+        version: 2,
+        description: "jobs.only_on_change",
+        steps: &[Step::AddColumn {
+            table: "jobs",
+            column: "only_on_change",
+            definition: "INTEGER NOT NULL DEFAULT 0",
+        }],
+    },
+    Migration {
+        version: 3,
+        description: "jobs.sampling",
+        steps: &[Step::Sql("ALTER TABLE jobs ADD COLUMN sampling TEXT")],
+    },
+    Migration {
+        version: 4,
+        description: "jobs.last_run_at and jobs.next_run_at",
+        steps: &[
+            Step::Sql("ALTER TABLE jobs ADD COLUMN last_run_at INTEGER"),
+            Step::Sql("ALTER TABLE jobs ADD COLUMN next_run_at INTEGER"),
+        ],
+    },
+    Migration {
+        version: 5,
+        description: "jobs.value_bounds and jobs.clamp_values",
+        steps: &[
+            Step::Sql("ALTER TABLE jobs ADD COLUMN value_bounds TEXT"),
+            Step::Sql("ALTER TABLE jobs ADD COLUMN clamp_values INTEGER NOT NULL DEFAULT 0"),
+        ],
+    },
+];
+
+/// Schema version of a fully migrated database.
+pub const LATEST_VERSION: i64 = MIGRATIONS[MIGRATIONS.len() - 1].version;
+
+/// Bring the database up to [`LATEST_VERSION`], returning the version it
+/// was at before.
+///
+/// Fails without changing anything if the database is newer than this build
+/// knows about.
+pub async fn run(pool: &SqlitePool) -> anyhow::Result<i64> {
+    let mut conn = pool.acquire().await?;
+    let from = schema_version(&mut conn).await?;
+    if from > LATEST_VERSION {
+        bail!(
+            "database schema version {from} is newer than the latest supported version {LATEST_VERSION}"
+        );
+    }
+
+    for migration in MIGRATIONS.iter().filter(|m| m.version > from) {
+        tracing::info!(
+            version = migration.version,
+            "Applying storage migration: {}",
+            migration.description
+        );
+        let mut tx = sqlx::Connection::begin(&mut *conn).await?;
+        for step in migration.steps {
+            match step {
+                Step::Sql(sql) => {
+                    sqlx::query(sql).execute(&mut *tx).await?;
+                }
+                Step::AddColumn {
+                    table,
+                    column,
+                    definition,
+                } => add_column_if_missing(&mut tx, table, column, definition).await?,
+            }
+        }
+        // PRAGMA values cannot be bound as parameters.
+        sqlx::query(&format!("PRAGMA user_version = {}", migration.version))
+            .execute(&mut *tx)
+            .await?;
+        tx.commit().await?;
+    }
+    Ok(from)
+}
+
+/// Version recorded in the database.
+pub async fn schema_version(conn: &mut SqliteConnection) -> anyhow::Result<i64> {
+    let (version,): (i64,) = sqlx::query_as("PRAGMA user_version")
+        .fetch_one(&mut *conn)
+        .await?;
+    Ok(version)
+}
+
+/// Add `column` to `table` unless it already exists.
+async fn add_column_if_missing(
+    conn: &mut SqliteConnection,
+    table: &str,
+    column: &str,
+    definition: &str,
+) -> anyhow::Result<()> {
+    let exists: Option<(String,)> =
+        sqlx::query_as("SELECT name FROM pragma_table_info($1) WHERE name = $2")
+            .bind(table)
+            .bind(column)
+            .fetch_optional(&mut *conn)
+            .await?;
+    if exists.is_none() {
+        sqlx::query(&format!(
+            "ALTER TABLE {} ADD COLUMN {} {}",
+            table, column, definition
+        ))
+        .execute(&mut *conn)
+        .await?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::storage::sqlite::SqliteStorage;
+    use sqlx::sqlite::{SqliteConnectOptions, SqlitePoolOptions};
+    use std::str::FromStr;
+
+    #[tokio::test]
+    async fn version_zero_database_is_migrated_in_place() {
+        let dir =
+            std::env::temp_dir().join(format!("polymarket-hft-migrate-{}", uuid::Uuid::now_v7()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let path = dir.join("metrics.db");
+
+        // A database written before versioning: no only_on_change column and
+        // user_version left at 0.
+        let options = SqliteConnectOptions::from_str(&format!("sqlite:{}", path.display()))
+            .unwrap()
+            .create_if_missing(true);
+        let old = SqlitePoolOptions::new()
+            .max_connections(1)
+            .connect_with(options)
+            .await
+            .unwrap();
+        let mut conn = old.acquire().await.unwrap();
+        for step in MIGRATIONS[0].steps {
+            if let Step::Sql(sql) = step {
+                sqlx::query(sql).execute(&mut *conn).await.unwrap();
+            }
+        }
+        sqlx::query(
+            "INSERT INTO metrics (source, name, value, timestamp, unit) \
+             VALUES ('alternativeme', 'fgi', 42.0, 1000, 'index')",
+        )
+        .execute(&mut *conn)
+        .await
+        .unwrap();
+        sqlx::query(
+            "INSERT INTO jobs (name, datasource, method, schedule) \
+             VALUES ('fgi', 'alternativeme', 'get_fear_and_greed', '{\"interval_secs\":60}')",
+        )
+        .execute(&mut *conn)
+        .await
+        .unwrap();
+        assert_eq!(schema_version(&mut conn).await.unwrap(), 0);
+        drop(conn);
+        old.close().await;
+
+        let storage = SqliteStorage::open(&path).await.unwrap();
+
+        let metric = storage
+            .get_latest("alternativeme", "fgi")
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(metric.value, 42.0);
+        let jobs = storage.list_jobs().await.unwrap();
+        assert_eq!(jobs.len(), 1);
+        assert_eq!(jobs[0].job.name, "fgi");
+        assert!(!jobs[0].job.only_on_change);
+        assert_eq!(storage.schema_version().await.unwrap(), LATEST_VERSION);
+        drop(storage);
+
+        // Reopening an up-to-date database applies nothing.
+        let reopened = SqliteStorage::open(&path).await.unwrap();
+        assert_eq!(reopened.schema_version().await.unwrap(), LATEST_VERSION);
+        assert_eq!(reopened.list_jobs().await.unwrap().len(), 1);
+        drop(reopened);
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+}
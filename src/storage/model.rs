@@ -3,6 +3,7 @@
 //! This module defines core data structures for the storage layer,
 //! including metrics and states that can be collected from various data sources.
 
+use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 use std::time::{SystemTime, UNIX_EPOCH};
 
@@ -83,6 +84,23 @@ pub enum MetricUnit {
     Bps,
 }
 
+impl MetricUnit {
+    /// Number of decimal places used when showing a value of this unit.
+    pub fn display_precision(&self) -> usize {
+        match self {
+            MetricUnit::Index | MetricUnit::Count => 0,
+            MetricUnit::Percent | MetricUnit::USD => 2,
+            MetricUnit::Ratio => 4,
+            MetricUnit::Bps => 1,
+        }
+    }
+
+    /// Format `value` with this unit's [`display_precision`](Self::display_precision).
+    pub fn format_value(&self, value: f64) -> String {
+        format!("{:.*}", self.display_precision(), value)
+    }
+}
+
 impl std::fmt::Display for MetricUnit {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
@@ -172,10 +190,50 @@ impl Metric {
         self
     }
 
+    /// Returns `timestamp` (milliseconds) as a UTC datetime, via
+    /// [`datetime_from_millis`](crate::engine::datetime_from_millis).
+    pub fn as_datetime(&self) -> DateTime<Utc> {
+        crate::engine::datetime_from_millis(self.timestamp)
+    }
+
     /// Returns the state key in the format "source::name".
     pub fn state_key(&self) -> String {
         format!("{}::{}", self.source, self.name)
     }
+
+    /// Returns a key identifying the series: the [`state_key`](Self::state_key)
+    /// followed by the labels sorted by key, e.g. `"source::name{symbol=BTC}"`.
+    ///
+    /// Metrics without labels use the plain state key.
+    pub fn series_key(&self) -> String {
+        Self::series_key_for(&self.source.to_string(), &self.name, &self.labels)
+    }
+
+    /// The [`series_key`](Self::series_key) of metrics with this source, name
+    /// and labels.
+    pub fn series_key_for(
+        source: &str,
+        name: &str,
+        labels: &std::collections::HashMap<String, String>,
+    ) -> String {
+        let state_key = format!("{}::{}", source, name);
+        if labels.is_empty() {
+            return state_key;
+        }
+        let mut labels: Vec<_> = labels.iter().collect();
+        labels.sort();
+        let labels: Vec<String> = labels
+            .into_iter()
+            .map(|(key, value)| format!("{}={}", key, value))
+            .collect();
+        format!("{}{{{}}}", state_key, labels.join(","))
+    }
+}
+
+/// Conversion of a typed API response into metrics for ingestion.
+pub trait ToMetrics {
+    /// Converts the response to metrics stamped with the current time.
+    fn to_metrics(&self) -> Vec<Metric>;
 }
 
 // =============================================================================
@@ -296,6 +354,30 @@ impl Event {
         self.payload = Some(payload);
         self
     }
+
+    /// Returns `timestamp` (milliseconds) as a UTC datetime, via
+    /// [`datetime_from_millis`](crate::engine::datetime_from_millis).
+    pub fn as_datetime(&self) -> DateTime<Utc> {
+        crate::engine::datetime_from_millis(self.timestamp)
+    }
+
+    /// Records the job this event is about in the payload's `job_name` field,
+    /// keeping any other payload fields.
+    pub fn with_job_name(mut self, job_name: impl Into<String>) -> Self {
+        let job_name = serde_json::Value::String(job_name.into());
+        match &mut self.payload {
+            Some(serde_json::Value::Object(fields)) => {
+                fields.insert("job_name".to_string(), job_name);
+            }
+            _ => self.payload = Some(serde_json::json!({ "job_name": job_name })),
+        }
+        self
+    }
+
+    /// Job name recorded in the payload's `job_name` field.
+    pub fn job_name(&self) -> Option<&str> {
+        self.payload.as_ref()?.get("job_name")?.as_str()
+    }
 }
 
 // =============================================================================
@@ -320,6 +402,13 @@ pub struct JobRecord {
 
     /// Unix timestamp when the job was last updated.
     pub updated_at: i64,
+
+    /// Unix timestamp when the job last started a run.
+    pub last_run_at: Option<i64>,
+
+    /// Unix timestamp when the scheduler next runs the job; `None` while it
+    /// is not scheduled.
+    pub next_run_at: Option<i64>,
 }
 
 impl JobRecord {
@@ -330,14 +419,79 @@ impl JobRecord {
             job,
             created_at,
             updated_at,
+            last_run_at: None,
+            next_run_at: None,
         }
     }
 }
 
+/// A series ranked by [`top_movers`](super::StorageBackend::top_movers).
+#[derive(Debug, Clone, PartialEq)]
+pub struct Mover {
+    /// Metric name.
+    pub name: String,
+    /// Labels of the series.
+    pub labels: std::collections::HashMap<String, String>,
+    /// `symbol` label of the series, if set.
+    pub label_symbol: Option<String>,
+    /// Relative change over the window, as a fraction (`0.1` is +10%).
+    pub pct_change: f64,
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_format_value_uses_unit_precision() {
+        assert_eq!(MetricUnit::Count.format_value(1234.0), "1234");
+        assert_eq!(
+            MetricUnit::USD.format_value(1_234_567_890.123),
+            "1234567890.12"
+        );
+        assert_eq!(MetricUnit::Percent.format_value(12.3456), "12.35");
+        assert_eq!(MetricUnit::Index.format_value(54.6), "55");
+        assert_eq!(MetricUnit::Ratio.format_value(0.123456), "0.1235");
+        assert_eq!(MetricUnit::Bps.format_value(12.34), "12.3");
+    }
+
+    #[test]
+    fn test_as_datetime_converts_millis() {
+        let metric = Metric::new(DataSource::AlternativeMe, "fgi", 1.0, MetricUnit::Index)
+            .with_timestamp(1_700_000_000_123);
+        assert_eq!(
+            metric.as_datetime().to_rfc3339(),
+            "2023-11-14T22:13:20.123+00:00"
+        );
+
+        let mut event = Event::new("test", EventType::ServiceStart, "started");
+        event.timestamp = 1_700_000_000_000;
+        assert_eq!(
+            event.as_datetime().to_rfc3339(),
+            "2023-11-14T22:13:20+00:00"
+        );
+    }
+
+    #[test]
+    fn test_event_job_name() {
+        let event = Event::new(
+            "test",
+            EventType::TaskFailed,
+            "Task 'it's fgi' failed to fetch metrics: timeout",
+        )
+        .with_job_name("it's fgi");
+        assert_eq!(event.job_name(), Some("it's fgi"));
+
+        let event = Event::new("test", EventType::JobDeleted, "Job deleted")
+            .with_payload(serde_json::json!({ "job_id": 7 }))
+            .with_job_name("fgi");
+        assert_eq!(event.job_name(), Some("fgi"));
+        assert_eq!(event.payload.unwrap()["job_id"], 7);
+
+        let event = Event::new("test", EventType::ServiceStart, "Service started");
+        assert_eq!(event.job_name(), None);
+    }
+
     #[test]
     fn test_metric_new() {
         let metric = Metric::new(
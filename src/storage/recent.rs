@@ -0,0 +1,161 @@
+//! In-memory ring buffer of recent points per series.
+//!
+//! [`RecentBuffer`] is registered as a [`MetricSink`] so every ingested batch
+//! is appended to it, and dashboard handlers draw sparklines from it without
+//! querying storage.
+
+use std::collections::{HashMap, VecDeque};
+use std::sync::Mutex;
+
+use super::BoxFuture;
+use super::model::Metric;
+use super::sink::MetricSink;
+
+/// Default number of points kept per series.
+pub const DEFAULT_RECENT_POINTS: usize = 60;
+
+/// Last `capacity` points of each series, oldest evicted first.
+///
+/// A series is one label set of a (source, name) pair (see
+/// [`Metric::series_key`]), so points with different labels never share a
+/// buffer.
+///
+/// Share it behind an `Arc` between the scheduler (as a sink) and the web
+/// handlers.
+pub struct RecentBuffer {
+    capacity: usize,
+    series: Mutex<HashMap<String, VecDeque<Metric>>>,
+}
+
+impl RecentBuffer {
+    /// Creates a buffer keeping at most `capacity` points per series.
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            capacity,
+            series: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// Append a batch, keeping each series ordered by timestamp.
+    pub fn record(&self, metrics: &[Metric]) {
+        if self.capacity == 0 {
+            return;
+        }
+        let mut series = self.lock();
+        for metric in metrics {
+            let points = series.entry(metric.series_key()).or_default();
+            let at = points.partition_point(|p| p.timestamp <= metric.timestamp);
+            points.insert(at, metric.clone());
+            if points.len() > self.capacity {
+                points.pop_front();
+            }
+        }
+    }
+
+    /// Up to `n` most recent points of the series of `source` and `name`
+    /// whose labels are exactly `labels`, newest first.
+    pub fn recent(
+        &self,
+        source: &str,
+        name: &str,
+        labels: &HashMap<String, String>,
+        n: usize,
+    ) -> Vec<Metric> {
+        self.lock()
+            .get(&Metric::series_key_for(source, name, labels))
+            .map(|points| points.iter().rev().take(n).cloned().collect())
+            .unwrap_or_default()
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, HashMap<String, VecDeque<Metric>>> {
+        self.series.lock().unwrap_or_else(|e| e.into_inner())
+    }
+}
+
+impl Default for RecentBuffer {
+    fn default() -> Self {
+        Self::new(DEFAULT_RECENT_POINTS)
+    }
+}
+
+impl MetricSink for RecentBuffer {
+    fn name(&self) -> &str {
+        "recent_buffer"
+    }
+
+    fn emit(&self, metrics: &[Metric]) -> BoxFuture<'_, anyhow::Result<()>> {
+        self.record(metrics);
+        Box::pin(async { Ok(()) })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{DataSource, MetricUnit};
+
+    #[tokio::test]
+    async fn recent_returns_newest_first_bounded() {
+        let buffer = RecentBuffer::new(3);
+        let metrics: Vec<Metric> = (1..=5)
+            .map(|i| {
+                Metric::new(
+                    DataSource::AlternativeMe,
+                    "fgi",
+                    i as f64,
+                    MetricUnit::Index,
+                )
+                .with_timestamp(i * 1_000)
+            })
+            .collect();
+        for metric in &metrics {
+            buffer.emit(std::slice::from_ref(metric)).await.unwrap();
+        }
+
+        let values = |n| -> Vec<f64> {
+            buffer
+                .recent("alternativeme", "fgi", &HashMap::new(), n)
+                .iter()
+                .map(|m| m.value)
+                .collect()
+        };
+        assert_eq!(values(10), vec![5.0, 4.0, 3.0]);
+        assert_eq!(values(2), vec![5.0, 4.0]);
+        assert!(
+            buffer
+                .recent("alternativeme", "other", &HashMap::new(), 3)
+                .is_empty()
+        );
+    }
+
+    #[test]
+    fn label_sets_are_buffered_separately() {
+        let buffer = RecentBuffer::new(3);
+        let price = |symbol: &str, value: f64, timestamp: i64| {
+            Metric::new(DataSource::CoinGecko, "price", value, MetricUnit::USD)
+                .with_label("symbol", symbol)
+                .with_timestamp(timestamp)
+        };
+        buffer.record(&[
+            price("BTC", 100.0, 1_000),
+            price("ETH", 10.0, 2_000),
+            price("BTC", 101.0, 3_000),
+        ]);
+
+        let values = |symbol: &str| -> Vec<f64> {
+            let labels = HashMap::from([("symbol".to_string(), symbol.to_string())]);
+            buffer
+                .recent("coingecko", "price", &labels, 10)
+                .iter()
+                .map(|m| m.value)
+                .collect()
+        };
+        assert_eq!(values("BTC"), vec![101.0, 100.0]);
+        assert_eq!(values("ETH"), vec![10.0]);
+        assert!(
+            buffer
+                .recent("coingecko", "price", &HashMap::new(), 10)
+                .is_empty()
+        );
+    }
+}
@@ -0,0 +1,153 @@
+//! Pluggable metric sinks.
+//!
+//! A [`MetricSink`] receives every batch of metrics produced by an ingestion
+//! job. Local storage is one sink ([`StorageSink`]); others forward metrics
+//! to external systems, such as [`WebhookSink`].
+
+use std::sync::Arc;
+
+use reqwest_middleware::ClientWithMiddleware;
+
+use super::model::Metric;
+use super::{BoxFuture, StorageBackend};
+use crate::client::http;
+
+/// Destination for batches of ingested metrics.
+pub trait MetricSink: Send + Sync {
+    /// Short name used in logs and events.
+    fn name(&self) -> &str;
+
+    /// Emit a batch of metrics.
+    fn emit(&self, metrics: &[Metric]) -> BoxFuture<'_, anyhow::Result<()>>;
+
+    /// Deliver anything still buffered. Called on shutdown; no-op by default.
+    fn flush(&self) -> BoxFuture<'_, anyhow::Result<()>> {
+        Box::pin(async { Ok(()) })
+    }
+}
+
+// =============================================================================
+// StorageSink
+// =============================================================================
+
+/// Sink that persists metrics through a [`StorageBackend`].
+#[derive(Clone)]
+pub struct StorageSink {
+    storage: Arc<dyn StorageBackend>,
+}
+
+impl StorageSink {
+    /// Wrap a storage backend as a sink.
+    pub fn new(storage: Arc<dyn StorageBackend>) -> Self {
+        Self { storage }
+    }
+}
+
+impl MetricSink for StorageSink {
+    fn name(&self) -> &str {
+        "storage"
+    }
+
+    fn emit(&self, metrics: &[Metric]) -> BoxFuture<'_, anyhow::Result<()>> {
+        self.storage.store(metrics)
+    }
+
+    fn flush(&self) -> BoxFuture<'_, anyhow::Result<()>> {
+        self.storage.flush()
+    }
+}
+
+// =============================================================================
+// WebhookSink
+// =============================================================================
+
+/// Sink that POSTs each batch as a JSON array to a URL.
+///
+/// Non-2xx responses are reported as errors.
+#[derive(Clone)]
+pub struct WebhookSink {
+    url: String,
+    client: ClientWithMiddleware,
+}
+
+impl WebhookSink {
+    /// Create a webhook sink using the default HTTP client.
+    pub fn new(url: impl Into<String>) -> anyhow::Result<Self> {
+        Ok(Self::with_client(url, http::build_default_client()?))
+    }
+
+    /// Create a webhook sink using a custom HTTP client.
+    pub fn with_client(url: impl Into<String>, client: ClientWithMiddleware) -> Self {
+        Self {
+            url: url.into(),
+            client,
+        }
+    }
+}
+
+impl MetricSink for WebhookSink {
+    fn name(&self) -> &str {
+        "webhook"
+    }
+
+    fn emit(&self, metrics: &[Metric]) -> BoxFuture<'_, anyhow::Result<()>> {
+        let body = serde_json::to_vec(metrics);
+        Box::pin(async move {
+            let response = self
+                .client
+                .post(&self.url)
+                .header("Content-Type", "application/json")
+                .body(body?)
+                .send()
+                .await?;
+            let status = response.status();
+            if !status.is_success() {
+                anyhow::bail!("webhook {} returned {}", self.url, status);
+            }
+            Ok(())
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{DataSource, MetricUnit};
+    use wiremock::matchers::{body_json, method, path};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    #[tokio::test]
+    async fn webhook_sink_posts_metrics_as_json() {
+        let mock_server = MockServer::start().await;
+        let metrics = vec![Metric::new(
+            DataSource::AlternativeMe,
+            "fear_and_greed",
+            42.0,
+            MetricUnit::Index,
+        )];
+
+        Mock::given(method("POST"))
+            .and(path("/hook"))
+            .and(body_json(serde_json::to_value(&metrics).unwrap()))
+            .respond_with(ResponseTemplate::new(204))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        let sink = WebhookSink::new(format!("{}/hook", mock_server.uri())).unwrap();
+        sink.emit(&metrics).await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn webhook_sink_reports_error_status() {
+        let mock_server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .respond_with(ResponseTemplate::new(400))
+            .mount(&mock_server)
+            .await;
+
+        let sink = WebhookSink::new(mock_server.uri()).unwrap();
+        let err = sink.emit(&[]).await.unwrap_err();
+        assert!(err.to_string().contains("400"));
+    }
+}
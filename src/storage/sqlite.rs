@@ -4,13 +4,25 @@
 
 use super::model::{Event, EventType};
 use crate::{DataSource, Metric, MetricUnit};
+use anyhow::bail;
 use sqlx::sqlite::{SqliteConnectOptions, SqlitePool, SqlitePoolOptions};
+use std::collections::{HashMap, HashSet};
 use std::path::Path;
 use std::str::FromStr;
+use std::time::Duration;
+
+/// Default size of the connection pool for file databases.
+pub const DEFAULT_MAX_CONNECTIONS: u32 = 5;
+
+/// Default time to wait for a free pooled connection before failing.
+pub const DEFAULT_ACQUIRE_TIMEOUT: Duration = Duration::from_secs(30);
 
 /// SQLite-based persistent storage for time-series metrics.
 pub struct SqliteStorage {
     pool: SqlitePool,
+    /// Label keys promoted to indexed columns; see
+    /// [`with_indexed_labels`](Self::with_indexed_labels).
+    indexed_labels: HashSet<String>,
 }
 
 impl SqliteStorage {
@@ -19,6 +31,21 @@ impl SqliteStorage {
     /// # Arguments
     /// * `path` - Path to the SQLite database file
     pub async fn open(path: impl AsRef<Path>) -> anyhow::Result<Self> {
+        Self::open_with_options(path, DEFAULT_MAX_CONNECTIONS, DEFAULT_ACQUIRE_TIMEOUT).await
+    }
+
+    /// Opens or creates a SQLite database with a custom connection pool.
+    ///
+    /// # Arguments
+    /// * `path` - Path to the SQLite database file
+    /// * `max_connections` - Maximum number of pooled connections
+    /// * `acquire_timeout` - How long a query waits for a free connection
+    ///   before failing with [`sqlx::Error::PoolTimedOut`]
+    pub async fn open_with_options(
+        path: impl AsRef<Path>,
+        max_connections: u32,
+        acquire_timeout: Duration,
+    ) -> anyhow::Result<Self> {
         let path_str = path.as_ref().to_string_lossy();
 
         // Ensure parent directory exists
@@ -32,18 +59,51 @@ impl SqliteStorage {
             .create_if_missing(true)
             .journal_mode(sqlx::sqlite::SqliteJournalMode::Wal)
             .synchronous(sqlx::sqlite::SqliteSynchronous::Normal)
-            .busy_timeout(std::time::Duration::from_secs(5));
+            .busy_timeout(Duration::from_secs(5));
 
         let pool = SqlitePoolOptions::new()
-            .max_connections(5)
+            .max_connections(max_connections)
+            .acquire_timeout(acquire_timeout)
             .connect_with(options)
             .await?;
 
-        let storage = Self { pool };
+        let storage = Self {
+            pool,
+            indexed_labels: HashSet::new(),
+        };
         storage.init_schema().await?;
         Ok(storage)
     }
 
+    /// Opens an existing database without writing to it.
+    ///
+    /// No migrations are run, so this fails unless the schema is already at
+    /// [`LATEST_VERSION`](super::migrations::LATEST_VERSION).
+    pub async fn open_read_only(path: impl AsRef<Path>) -> anyhow::Result<Self> {
+        let options =
+            SqliteConnectOptions::from_str(&format!("sqlite:{}", path.as_ref().to_string_lossy()))?
+                .read_only(true)
+                .busy_timeout(Duration::from_secs(5));
+
+        let pool = SqlitePoolOptions::new()
+            .max_connections(1)
+            .connect_with(options)
+            .await?;
+
+        let storage = Self {
+            pool,
+            indexed_labels: HashSet::new(),
+        };
+        let version = storage.schema_version().await?;
+        if version != super::migrations::LATEST_VERSION {
+            anyhow::bail!(
+                "database schema version {version} does not match the supported version {}",
+                super::migrations::LATEST_VERSION
+            );
+        }
+        Ok(storage)
+    }
+
     /// Opens an in-memory database (for testing).
     pub async fn open_in_memory() -> anyhow::Result<Self> {
         let options = SqliteConnectOptions::from_str("sqlite::memory:")?
@@ -55,105 +115,87 @@ impl SqliteStorage {
             .connect_with(options)
             .await?;
 
-        let storage = Self { pool };
+        let storage = Self {
+            pool,
+            indexed_labels: HashSet::new(),
+        };
         storage.init_schema().await?;
         Ok(storage)
     }
 
     async fn init_schema(&self) -> anyhow::Result<()> {
-        sqlx::query(
-            r#"
-            CREATE TABLE IF NOT EXISTS metrics (
-                id INTEGER PRIMARY KEY AUTOINCREMENT,
-                source TEXT NOT NULL,
-                name TEXT NOT NULL,
-                value REAL NOT NULL,
-                timestamp INTEGER NOT NULL,
-                unit TEXT,
-                labels TEXT,
-                created_at INTEGER NOT NULL DEFAULT (strftime('%s', 'now'))
-            )
-            "#,
-        )
-        .execute(&self.pool)
-        .await?;
-
-        // Create index for efficient queries
-        sqlx::query(
-            r#"
-            CREATE INDEX IF NOT EXISTS idx_metrics_source_name_ts
-            ON metrics(source, name, timestamp DESC)
-            "#,
-        )
-        .execute(&self.pool)
-        .await?;
-
-        sqlx::query(
-            r#"
-            CREATE INDEX IF NOT EXISTS idx_metrics_timestamp
-            ON metrics(timestamp DESC)
-            "#,
-        )
-        .execute(&self.pool)
-        .await?;
-
-        // Create events table
-        sqlx::query(
-            r#"
-            CREATE TABLE IF NOT EXISTS events (
-                id INTEGER PRIMARY KEY AUTOINCREMENT,
-                instance_id TEXT NOT NULL,
-                event_type TEXT NOT NULL,
-                message TEXT NOT NULL,
-                payload TEXT,
-                timestamp INTEGER NOT NULL
-            )
-            "#,
-        )
-        .execute(&self.pool)
-        .await?;
-
-        // Create index for events
-        sqlx::query(
-            r#"
-            CREATE INDEX IF NOT EXISTS idx_events_instance_ts
-            ON events(instance_id, timestamp DESC)
-            "#,
-        )
-        .execute(&self.pool)
-        .await?;
+        super::migrations::run(&self.pool).await?;
+        Ok(())
+    }
 
-        // Create jobs table
-        sqlx::query(
-            r#"
-            CREATE TABLE IF NOT EXISTS jobs (
-                id INTEGER PRIMARY KEY AUTOINCREMENT,
-                name TEXT NOT NULL UNIQUE,
-                datasource TEXT NOT NULL,
-                method TEXT NOT NULL,
-                schedule TEXT NOT NULL,
-                params TEXT,
-                retention_days INTEGER NOT NULL DEFAULT 7,
-                enabled INTEGER NOT NULL DEFAULT 1,
-                created_at INTEGER NOT NULL DEFAULT (strftime('%s', 'now')),
-                updated_at INTEGER NOT NULL DEFAULT (strftime('%s', 'now'))
+    /// Promote label keys to indexed columns for fast filtering with
+    /// [`query_by_label`](Self::query_by_label).
+    ///
+    /// Each key gets a virtual generated column `label_<key>` computed as
+    /// `json_extract(labels, '$.<key>')` plus an index on it, so existing
+    /// rows are covered without a rewrite. Both are created only if missing.
+    ///
+    /// The columns and indexes are added to the database outside the
+    /// versioned [`migrations`](super::migrations): they are not reflected in
+    /// the schema version, stay in the file when a key is left out later, and
+    /// are not dropped by any migration.
+    ///
+    /// Keys must be non-empty and consist of lowercase ASCII letters, digits
+    /// and underscores. SQLite column names are case-insensitive while label
+    /// keys are not, so `Symbol` and `symbol` could not get columns of their
+    /// own.
+    pub async fn with_indexed_labels<I, S>(mut self, keys: I) -> anyhow::Result<Self>
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        for key in keys {
+            let key = key.into();
+            if key.is_empty()
+                || !key
+                    .chars()
+                    .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '_')
+            {
+                bail!(
+                    "invalid indexed label key {key:?}: use lowercase ASCII letters, digits and '_'"
+                );
+            }
+            let column = label_column(&key);
+            let exists: Option<(String,)> = sqlx::query_as(
+                "SELECT name FROM pragma_table_xinfo('metrics') WHERE name = $1 COLLATE NOCASE",
             )
-            "#,
-        )
-        .execute(&self.pool)
-        .await?;
+            .bind(&column)
+            .fetch_optional(&self.pool)
+            .await?;
+            if exists.is_none() {
+                // Generated column expressions cannot take bound parameters;
+                // the key was validated above.
+                sqlx::query(&format!(
+                    "ALTER TABLE metrics ADD COLUMN {column} \
+                     GENERATED ALWAYS AS (json_extract(labels, '$.{key}')) VIRTUAL"
+                ))
+                .execute(&self.pool)
+                .await?;
+            }
+            sqlx::query(&format!(
+                "CREATE INDEX IF NOT EXISTS idx_metrics_{column} ON metrics({column}, timestamp DESC)"
+            ))
+            .execute(&self.pool)
+            .await?;
+            self.indexed_labels.insert(key);
+        }
+        Ok(self)
+    }
 
-        // Create index for jobs
-        sqlx::query(
-            r#"
-            CREATE INDEX IF NOT EXISTS idx_jobs_enabled
-            ON jobs(enabled)
-            "#,
-        )
-        .execute(&self.pool)
-        .await?;
+    /// Label keys promoted with [`with_indexed_labels`](Self::with_indexed_labels).
+    pub fn indexed_labels(&self) -> &HashSet<String> {
+        &self.indexed_labels
+    }
 
-        Ok(())
+    /// Schema version of the database; see [`super::migrations`].
+    pub async fn schema_version(&self) -> anyhow::Result<i64> {
+        let mut conn = self.pool.acquire().await?;
+        super::migrations::schema_version(&mut conn).await
     }
 
     /// Insert a batch of metrics using multi-row INSERT for better performance.
@@ -239,6 +281,96 @@ impl SqliteStorage {
         row.map(|r| r.try_into()).transpose()
     }
 
+    /// Query the latest metric for a given source and name whose labels are
+    /// exactly `labels`.
+    ///
+    /// Walks the series newest first through the `(source, name, timestamp)`
+    /// index, so the cost grows with the number of newer points of other
+    /// label sets.
+    pub async fn get_latest_series(
+        &self,
+        source: &str,
+        name: &str,
+        labels: &HashMap<String, String>,
+    ) -> anyhow::Result<Option<Metric>> {
+        self.latest_series_point(source, name, labels, None).await
+    }
+
+    /// Query the latest metric at or before `at` (unix millis) for a given
+    /// source and name whose labels are exactly `labels`.
+    pub async fn get_series_value_as_of(
+        &self,
+        source: &str,
+        name: &str,
+        labels: &HashMap<String, String>,
+        at: i64,
+    ) -> anyhow::Result<Option<Metric>> {
+        self.latest_series_point(source, name, labels, Some(at))
+            .await
+    }
+
+    async fn latest_series_point(
+        &self,
+        source: &str,
+        name: &str,
+        labels: &HashMap<String, String>,
+        at: Option<i64>,
+    ) -> anyhow::Result<Option<Metric>> {
+        use sqlx::QueryBuilder;
+
+        let mut builder: QueryBuilder<sqlx::Sqlite> = QueryBuilder::new(
+            "SELECT source, name, value, timestamp, unit, labels FROM metrics WHERE source = ",
+        );
+        builder.push_bind(source);
+        builder.push(" AND name = ");
+        builder.push_bind(name);
+        if let Some(at) = at {
+            builder.push(" AND timestamp <= ");
+            builder.push_bind(at);
+        }
+        // Unlabelled metrics store NULL, which json_each reads as no labels.
+        builder.push(" AND (SELECT COUNT(*) FROM json_each(metrics.labels)) = ");
+        builder.push_bind(labels.len() as i64);
+        for (key, value) in labels {
+            builder.push(" AND (SELECT value FROM json_each(metrics.labels) WHERE key = ");
+            builder.push_bind(key);
+            builder.push(") = ");
+            builder.push_bind(value);
+        }
+        builder.push(" ORDER BY timestamp DESC LIMIT 1");
+
+        let row = builder
+            .build_query_as::<MetricRow>()
+            .fetch_optional(&self.pool)
+            .await?;
+        row.map(|r| r.try_into()).transpose()
+    }
+
+    /// Query the latest metric at or before `at` (unix millis).
+    pub async fn get_value_as_of(
+        &self,
+        source: &str,
+        name: &str,
+        at: i64,
+    ) -> anyhow::Result<Option<Metric>> {
+        let row: Option<MetricRow> = sqlx::query_as(
+            r#"
+            SELECT source, name, value, timestamp, unit, labels
+            FROM metrics
+            WHERE source = $1 AND name = $2 AND timestamp <= $3
+            ORDER BY timestamp DESC
+            LIMIT 1
+            "#,
+        )
+        .bind(source)
+        .bind(name)
+        .bind(at)
+        .fetch_optional(&self.pool)
+        .await?;
+
+        row.map(|r| r.try_into()).transpose()
+    }
+
     /// Query metrics within a time range.
     pub async fn query_range(
         &self,
@@ -276,6 +408,147 @@ impl SqliteStorage {
         rows.into_iter().map(|r| r.try_into()).collect()
     }
 
+    /// Query metrics within a time range that carry `label_key`, whatever its value.
+    pub async fn query_with_label_key(
+        &self,
+        source: Option<&str>,
+        name: Option<&str>,
+        label_key: &str,
+        start: i64,
+        end: i64,
+        limit: usize,
+    ) -> anyhow::Result<Vec<Metric>> {
+        use sqlx::QueryBuilder;
+
+        let mut builder: QueryBuilder<sqlx::Sqlite> = QueryBuilder::new(
+            "SELECT source, name, value, timestamp, unit, labels FROM metrics WHERE timestamp >= ",
+        );
+        builder.push_bind(start);
+        builder.push(" AND timestamp <= ");
+        builder.push_bind(end);
+
+        if let Some(s) = source {
+            builder.push(" AND source = ");
+            builder.push_bind(s);
+        }
+        if let Some(n) = name {
+            builder.push(" AND name = ");
+            builder.push_bind(n);
+        }
+
+        // json_each matches the key literally, so keys need no path escaping.
+        builder.push(" AND EXISTS (SELECT 1 FROM json_each(metrics.labels) WHERE key = ");
+        builder.push_bind(label_key);
+        builder.push(")");
+
+        builder.push(" ORDER BY timestamp DESC LIMIT ");
+        builder.push_bind(limit as i64);
+
+        let rows = builder
+            .build_query_as::<MetricRow>()
+            .fetch_all(&self.pool)
+            .await?;
+        rows.into_iter().map(|r| r.try_into()).collect()
+    }
+
+    /// Query metrics whose label `label_key` equals `label_value`, newest first.
+    ///
+    /// Uses the indexed column when the key was promoted with
+    /// [`with_indexed_labels`](Self::with_indexed_labels) and falls back to a
+    /// `json_each` scan otherwise; the results are the same either way.
+    #[allow(clippy::too_many_arguments)]
+    pub async fn query_by_label(
+        &self,
+        source: Option<&str>,
+        name: Option<&str>,
+        label_key: &str,
+        label_value: &str,
+        start: i64,
+        end: i64,
+        limit: usize,
+    ) -> anyhow::Result<Vec<Metric>> {
+        use sqlx::QueryBuilder;
+
+        let mut builder: QueryBuilder<sqlx::Sqlite> = QueryBuilder::new(
+            "SELECT source, name, value, timestamp, unit, labels FROM metrics WHERE ",
+        );
+        if self.indexed_labels.contains(label_key) {
+            builder.push(label_column(label_key));
+        } else {
+            // json_each avoids building a JSON path from an unchecked key.
+            builder.push("(SELECT value FROM json_each(metrics.labels) WHERE key = ");
+            builder.push_bind(label_key);
+            builder.push(")");
+        }
+        builder.push(" = ");
+        builder.push_bind(label_value);
+        builder.push(" AND timestamp >= ");
+        builder.push_bind(start);
+        builder.push(" AND timestamp <= ");
+        builder.push_bind(end);
+
+        if let Some(s) = source {
+            builder.push(" AND source = ");
+            builder.push_bind(s);
+        }
+        if let Some(n) = name {
+            builder.push(" AND name = ");
+            builder.push_bind(n);
+        }
+
+        builder.push(" ORDER BY timestamp DESC LIMIT ");
+        builder.push_bind(limit as i64);
+
+        let rows = builder
+            .build_query_as::<MetricRow>()
+            .fetch_all(&self.pool)
+            .await?;
+        rows.into_iter().map(|r| r.try_into()).collect()
+    }
+
+    /// Query one page of metrics within a time range, ordered oldest first.
+    ///
+    /// Ties on timestamp are broken by insertion order so that consecutive
+    /// pages neither skip nor repeat rows.
+    pub async fn query_range_page(
+        &self,
+        source: Option<&str>,
+        name: Option<&str>,
+        start: i64,
+        end: i64,
+        limit: usize,
+        offset: usize,
+    ) -> anyhow::Result<Vec<Metric>> {
+        use sqlx::QueryBuilder;
+
+        let mut builder: QueryBuilder<sqlx::Sqlite> = QueryBuilder::new(
+            "SELECT source, name, value, timestamp, unit, labels FROM metrics WHERE timestamp >= ",
+        );
+        builder.push_bind(start);
+        builder.push(" AND timestamp <= ");
+        builder.push_bind(end);
+
+        if let Some(s) = source {
+            builder.push(" AND source = ");
+            builder.push_bind(s);
+        }
+        if let Some(n) = name {
+            builder.push(" AND name = ");
+            builder.push_bind(n);
+        }
+
+        builder.push(" ORDER BY timestamp ASC, id ASC LIMIT ");
+        builder.push_bind(limit as i64);
+        builder.push(" OFFSET ");
+        builder.push_bind(offset as i64);
+
+        let rows = builder
+            .build_query_as::<MetricRow>()
+            .fetch_all(&self.pool)
+            .await?;
+        rows.into_iter().map(|r| r.try_into()).collect()
+    }
+
     /// Delete metrics older than the specified timestamp.
     ///
     /// Returns the number of deleted rows.
@@ -307,7 +580,36 @@ impl SqliteStorage {
             let name: String = row.try_get("name")?;
             metrics.push((source, name));
         }
-        Ok(metrics)
+        Ok(crate::engine::normalize_metric_catalog(metrics))
+    }
+
+    /// Get every distinct metric name and label set stored for `source`,
+    /// ordered by name.
+    ///
+    /// Scans the rows of `source`, since labels are not indexed.
+    pub async fn get_available_series(&self, source: &str) -> anyhow::Result<Vec<super::SeriesId>> {
+        let rows: Vec<(String, Option<String>)> = sqlx::query_as(
+            "SELECT DISTINCT name, labels FROM metrics WHERE source = $1 ORDER BY name",
+        )
+        .bind(source)
+        .fetch_all(&self.pool)
+        .await?;
+
+        // The same labels can be stored as differently ordered JSON objects.
+        let mut seen = HashSet::new();
+        let mut series = Vec::new();
+        for (name, labels) in rows {
+            let labels: HashMap<String, String> = match labels {
+                Some(json) => serde_json::from_str(&json)?,
+                None => HashMap::new(),
+            };
+            let mut key: Vec<_> = labels.iter().collect();
+            key.sort();
+            if seen.insert(format!("{name}{key:?}")) {
+                series.push((name, labels));
+            }
+        }
+        Ok(series)
     }
 
     /// Insert a single event.
@@ -335,6 +637,47 @@ impl SqliteStorage {
         Ok(())
     }
 
+    /// Insert many events in one transaction.
+    pub async fn insert_events(&self, events: &[Event]) -> anyhow::Result<()> {
+        use sqlx::QueryBuilder;
+
+        if events.is_empty() {
+            return Ok(());
+        }
+        // 5 columns per row keeps each statement well under SQLite's variable limit.
+        const BATCH_SIZE: usize = 100;
+
+        let rows = events
+            .iter()
+            .map(|event| {
+                let payload = event
+                    .payload
+                    .as_ref()
+                    .map(serde_json::to_string)
+                    .transpose()?;
+                Ok((event, payload))
+            })
+            .collect::<anyhow::Result<Vec<_>>>()?;
+
+        let mut tx = self.pool.begin().await?;
+        for chunk in rows.chunks(BATCH_SIZE) {
+            let mut builder: QueryBuilder<sqlx::Sqlite> = QueryBuilder::new(
+                "INSERT INTO events (instance_id, event_type, message, payload, timestamp) ",
+            );
+            builder.push_values(chunk, |mut row, (event, payload)| {
+                row.push_bind(&event.instance_id)
+                    .push_bind(event.event_type.to_string())
+                    .push_bind(&event.message)
+                    .push_bind(payload)
+                    .push_bind(event.timestamp);
+            });
+            builder.build().execute(&mut *tx).await?;
+        }
+        tx.commit().await?;
+
+        Ok(())
+    }
+
     /// Query events with optional instance ID filter.
     pub async fn query_events(
         &self,
@@ -378,6 +721,31 @@ impl SqliteStorage {
         Ok(ids)
     }
 
+    /// Count `TaskFailed` events per job since `since` (unix millis).
+    ///
+    /// Jobs are identified by the `job_name` payload field set with
+    /// [`Event::with_job_name`]; events without one are skipped.
+    pub async fn failure_counts_by_job(&self, since: i64) -> anyhow::Result<Vec<(String, usize)>> {
+        let rows: Vec<(String, i64)> = sqlx::query_as(
+            r#"
+            SELECT json_extract(payload, '$.job_name') AS job_name, COUNT(*) AS failures
+            FROM events
+            WHERE event_type = $1 AND timestamp >= $2 AND job_name IS NOT NULL
+            GROUP BY job_name
+            ORDER BY failures DESC, job_name ASC
+            "#,
+        )
+        .bind(EventType::TaskFailed.to_string())
+        .bind(since)
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(rows
+            .into_iter()
+            .map(|(name, count)| (name, count as usize))
+            .collect())
+    }
+
     // =========================================================================
     // Job Management
     // =========================================================================
@@ -388,11 +756,21 @@ impl SqliteStorage {
     pub async fn insert_job(&self, job: &crate::config::IngestionJob) -> anyhow::Result<i64> {
         let schedule = serde_json::to_string(&job.schedule)?;
         let params = job.params.as_ref().map(serde_json::to_string).transpose()?;
+        let sampling = job
+            .sampling
+            .as_ref()
+            .map(serde_json::to_string)
+            .transpose()?;
+        let value_bounds = job
+            .value_bounds
+            .as_ref()
+            .map(serde_json::to_string)
+            .transpose()?;
 
         let result = sqlx::query(
             r#"
-            INSERT INTO jobs (name, datasource, method, schedule, params, retention_days, enabled)
-            VALUES ($1, $2, $3, $4, $5, $6, $7)
+            INSERT INTO jobs (name, datasource, method, schedule, params, retention_days, enabled, only_on_change, sampling, value_bounds, clamp_values)
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11)
             "#,
         )
         .bind(&job.name)
@@ -402,6 +780,10 @@ impl SqliteStorage {
         .bind(params)
         .bind(job.retention_days as i64)
         .bind(job.enabled)
+        .bind(job.only_on_change)
+        .bind(sampling)
+        .bind(value_bounds)
+        .bind(job.clamp_values)
         .execute(&self.pool)
         .await?;
 
@@ -416,6 +798,16 @@ impl SqliteStorage {
     ) -> anyhow::Result<()> {
         let schedule = serde_json::to_string(&job.schedule)?;
         let params = job.params.as_ref().map(serde_json::to_string).transpose()?;
+        let sampling = job
+            .sampling
+            .as_ref()
+            .map(serde_json::to_string)
+            .transpose()?;
+        let value_bounds = job
+            .value_bounds
+            .as_ref()
+            .map(serde_json::to_string)
+            .transpose()?;
 
         sqlx::query(
             r#"
@@ -427,8 +819,12 @@ impl SqliteStorage {
                 params = $5,
                 retention_days = $6,
                 enabled = $7,
+                only_on_change = $8,
+                sampling = $9,
+                value_bounds = $10,
+                clamp_values = $11,
                 updated_at = strftime('%s', 'now')
-            WHERE id = $8
+            WHERE id = $12
             "#,
         )
         .bind(&job.name)
@@ -438,6 +834,10 @@ impl SqliteStorage {
         .bind(params)
         .bind(job.retention_days as i64)
         .bind(job.enabled)
+        .bind(job.only_on_change)
+        .bind(sampling)
+        .bind(value_bounds)
+        .bind(job.clamp_values)
         .bind(id)
         .execute(&self.pool)
         .await?;
@@ -445,6 +845,26 @@ impl SqliteStorage {
         Ok(())
     }
 
+    /// Record that the job named `name` started a run at `at` (Unix seconds).
+    pub async fn set_job_last_run(&self, name: &str, at: i64) -> anyhow::Result<()> {
+        sqlx::query("UPDATE jobs SET last_run_at = $1 WHERE name = $2")
+            .bind(at)
+            .bind(name)
+            .execute(&self.pool)
+            .await?;
+        Ok(())
+    }
+
+    /// Record when the job named `name` runs next (Unix seconds), or clear it.
+    pub async fn set_job_next_run(&self, name: &str, at: Option<i64>) -> anyhow::Result<()> {
+        sqlx::query("UPDATE jobs SET next_run_at = $1 WHERE name = $2")
+            .bind(at)
+            .bind(name)
+            .execute(&self.pool)
+            .await?;
+        Ok(())
+    }
+
     /// Delete a job by ID.
     pub async fn delete_job(&self, id: i64) -> anyhow::Result<()> {
         sqlx::query("DELETE FROM jobs WHERE id = $1")
@@ -458,7 +878,7 @@ impl SqliteStorage {
     pub async fn get_job(&self, id: i64) -> anyhow::Result<Option<super::model::JobRecord>> {
         let row: Option<JobRow> = sqlx::query_as(
             r#"
-            SELECT id, name, datasource, method, schedule, params, retention_days, enabled, created_at, updated_at
+            SELECT id, name, datasource, method, schedule, params, retention_days, enabled, only_on_change, sampling, value_bounds, clamp_values, created_at, updated_at, last_run_at, next_run_at
             FROM jobs
             WHERE id = $1
             "#,
@@ -474,7 +894,7 @@ impl SqliteStorage {
     pub async fn list_jobs(&self) -> anyhow::Result<Vec<super::model::JobRecord>> {
         let rows: Vec<JobRow> = sqlx::query_as(
             r#"
-            SELECT id, name, datasource, method, schedule, params, retention_days, enabled, created_at, updated_at
+            SELECT id, name, datasource, method, schedule, params, retention_days, enabled, only_on_change, sampling, value_bounds, clamp_values, created_at, updated_at, last_run_at, next_run_at
             FROM jobs
             ORDER BY created_at DESC
             "#,
@@ -486,6 +906,11 @@ impl SqliteStorage {
     }
 }
 
+/// Name of the generated column holding label `key`.
+fn label_column(key: &str) -> String {
+    format!("label_{key}")
+}
+
 /// Internal row structure for SQLite query results.
 ///
 /// Maps database columns to `Metric` fields via `TryFrom`.
@@ -566,8 +991,14 @@ struct JobRow {
     params: Option<String>,
     retention_days: i64,
     enabled: bool,
+    only_on_change: bool,
+    sampling: Option<String>,
+    value_bounds: Option<String>,
+    clamp_values: bool,
     created_at: i64,
     updated_at: i64,
+    last_run_at: Option<i64>,
+    next_run_at: Option<i64>,
 }
 
 impl TryFrom<JobRow> for super::model::JobRecord {
@@ -578,6 +1009,11 @@ impl TryFrom<JobRow> for super::model::JobRecord {
         let schedule: crate::config::Schedule = serde_json::from_str(&row.schedule)?;
         let params: Option<serde_json::Value> =
             row.params.map(|s| serde_json::from_str(&s)).transpose()?;
+        let sampling = row.sampling.map(|s| serde_json::from_str(&s)).transpose()?;
+        let value_bounds = row
+            .value_bounds
+            .map(|s| serde_json::from_str(&s))
+            .transpose()?;
 
         let job = crate::config::IngestionJob {
             name: row.name,
@@ -587,14 +1023,16 @@ impl TryFrom<JobRow> for super::model::JobRecord {
             params,
             retention_days: row.retention_days as u32,
             enabled: row.enabled,
+            only_on_change: row.only_on_change,
+            sampling,
+            value_bounds,
+            clamp_values: row.clamp_values,
         };
 
-        Ok(super::model::JobRecord::new(
-            row.id,
-            job,
-            row.created_at,
-            row.updated_at,
-        ))
+        let mut record = super::model::JobRecord::new(row.id, job, row.created_at, row.updated_at);
+        record.last_run_at = row.last_run_at;
+        record.next_run_at = row.next_run_at;
+        Ok(record)
     }
 }
 
@@ -602,6 +1040,32 @@ impl TryFrom<JobRow> for super::model::JobRecord {
 mod tests {
     use super::*;
 
+    #[tokio::test]
+    async fn test_sqlite_storage_acquire_timeout() {
+        let dir =
+            std::env::temp_dir().join(format!("polymarket-hft-sqlite-{}", uuid::Uuid::now_v7()));
+        let storage =
+            SqliteStorage::open_with_options(dir.join("metrics.db"), 1, Duration::from_millis(50))
+                .await
+                .unwrap();
+
+        // Hold the only connection so the next query has to wait for it.
+        let held = storage.pool.acquire().await.unwrap();
+        let err = storage.health_check().await.unwrap_err();
+        assert!(
+            matches!(
+                err.downcast_ref::<sqlx::Error>(),
+                Some(sqlx::Error::PoolTimedOut)
+            ),
+            "got {err:?}"
+        );
+
+        drop(held);
+        storage.health_check().await.unwrap();
+        storage.pool.close().await;
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+
     #[tokio::test]
     async fn test_sqlite_storage_crud() {
         let storage = SqliteStorage::open_in_memory().await.unwrap();
@@ -640,6 +1104,224 @@ mod tests {
         assert_eq!(results.len(), 2);
     }
 
+    #[tokio::test]
+    async fn test_sqlite_storage_query_with_label_key() {
+        let storage = SqliteStorage::open_in_memory().await.unwrap();
+        let metrics = vec![
+            Metric::new(DataSource::CoinGecko, "price", 1.0, MetricUnit::USD)
+                .with_timestamp(100)
+                .with_label("symbol", "btc"),
+            Metric::new(DataSource::CoinGecko, "price", 2.0, MetricUnit::USD)
+                .with_timestamp(200)
+                .with_label("symbol", ""),
+            Metric::new(DataSource::CoinGecko, "price", 3.0, MetricUnit::USD)
+                .with_timestamp(300)
+                .with_label("currency", "usd"),
+            Metric::new(DataSource::CoinGecko, "price", 4.0, MetricUnit::USD).with_timestamp(400),
+        ];
+        storage.insert_batch(&metrics).await.unwrap();
+
+        let results = storage
+            .query_with_label_key(Some("coingecko"), Some("price"), "symbol", 0, 1000, 10)
+            .await
+            .unwrap();
+        let values: Vec<f64> = results.iter().map(|m| m.value).collect();
+        assert_eq!(values, vec![2.0, 1.0]);
+    }
+
+    #[tokio::test]
+    async fn test_sqlite_storage_query_by_indexed_label() {
+        let storage = SqliteStorage::open_in_memory()
+            .await
+            .unwrap()
+            .with_indexed_labels(["symbol"])
+            .await
+            .unwrap();
+        let metrics = vec![
+            Metric::new(DataSource::CoinGecko, "price", 1.0, MetricUnit::USD)
+                .with_timestamp(100)
+                .with_label("symbol", "btc"),
+            Metric::new(DataSource::CoinGecko, "price", 2.0, MetricUnit::USD)
+                .with_timestamp(200)
+                .with_label("symbol", "eth"),
+            Metric::new(DataSource::CoinGecko, "price", 3.0, MetricUnit::USD)
+                .with_timestamp(300)
+                .with_label("symbol", "btc"),
+            Metric::new(DataSource::CoinGecko, "price", 4.0, MetricUnit::USD).with_timestamp(400),
+        ];
+        storage.insert_batch(&metrics).await.unwrap();
+
+        let results = storage
+            .query_by_label(
+                Some("coingecko"),
+                Some("price"),
+                "symbol",
+                "btc",
+                0,
+                1000,
+                10,
+            )
+            .await
+            .unwrap();
+        let values: Vec<f64> = results.iter().map(|m| m.value).collect();
+        assert_eq!(values, vec![3.0, 1.0]);
+
+        // The promoted column is queryable directly and backed by an index.
+        let (count,): (i64,) =
+            sqlx::query_as("SELECT COUNT(*) FROM metrics WHERE label_symbol = 'eth'")
+                .fetch_one(&storage.pool)
+                .await
+                .unwrap();
+        assert_eq!(count, 1);
+        let plan: Vec<(i64, i64, i64, String)> = sqlx::query_as(
+            "EXPLAIN QUERY PLAN SELECT value FROM metrics WHERE label_symbol = 'eth'",
+        )
+        .fetch_all(&storage.pool)
+        .await
+        .unwrap();
+        assert!(
+            plan.iter()
+                .any(|(.., detail)| detail.contains("idx_metrics_label_symbol")),
+            "{plan:?}"
+        );
+
+        // Unpromoted keys give the same answer through a scan.
+        let unindexed = SqliteStorage::open_in_memory().await.unwrap();
+        unindexed.insert_batch(&metrics).await.unwrap();
+        let scanned = unindexed
+            .query_by_label(
+                Some("coingecko"),
+                Some("price"),
+                "symbol",
+                "btc",
+                0,
+                1000,
+                10,
+            )
+            .await
+            .unwrap();
+        assert_eq!(scanned.len(), 2);
+
+        assert!(
+            SqliteStorage::open_in_memory()
+                .await
+                .unwrap()
+                .with_indexed_labels(["bad key"])
+                .await
+                .is_err()
+        );
+        assert!(
+            SqliteStorage::open_in_memory()
+                .await
+                .unwrap()
+                .with_indexed_labels(["Symbol"])
+                .await
+                .is_err()
+        );
+    }
+
+    #[tokio::test]
+    async fn test_sqlite_storage_get_latest_series_matches_labels() {
+        let storage = SqliteStorage::open_in_memory().await.unwrap();
+        let metrics = vec![
+            Metric::new(DataSource::CoinGecko, "price", 1.0, MetricUnit::USD)
+                .with_timestamp(100)
+                .with_label("symbol", "btc"),
+            Metric::new(DataSource::CoinGecko, "price", 2.0, MetricUnit::USD)
+                .with_timestamp(200)
+                .with_label("symbol", "eth"),
+            Metric::new(DataSource::CoinGecko, "price", 3.0, MetricUnit::USD).with_timestamp(300),
+        ];
+        storage.insert_batch(&metrics).await.unwrap();
+
+        let latest = |labels: HashMap<String, String>| {
+            let storage = &storage;
+            async move {
+                storage
+                    .get_latest_series("coingecko", "price", &labels)
+                    .await
+                    .unwrap()
+                    .map(|m| m.value)
+            }
+        };
+        let symbol = |s: &str| HashMap::from([("symbol".to_string(), s.to_string())]);
+        assert_eq!(latest(symbol("btc")).await, Some(1.0));
+        assert_eq!(latest(symbol("eth")).await, Some(2.0));
+        assert_eq!(latest(HashMap::new()).await, Some(3.0));
+        assert_eq!(latest(symbol("sol")).await, None);
+    }
+
+    #[tokio::test]
+    async fn test_sqlite_storage_get_value_as_of() {
+        let storage = SqliteStorage::open_in_memory().await.unwrap();
+        let metrics = vec![
+            Metric::new(DataSource::AlternativeMe, "test", 1.0, MetricUnit::Index)
+                .with_timestamp(100),
+            Metric::new(DataSource::AlternativeMe, "test", 2.0, MetricUnit::Index)
+                .with_timestamp(200),
+            Metric::new(DataSource::AlternativeMe, "test", 3.0, MetricUnit::Index)
+                .with_timestamp(300),
+        ];
+        storage.insert_batch(&metrics).await.unwrap();
+
+        let as_of = storage
+            .get_value_as_of("alternativeme", "test", 250)
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(as_of.value, 2.0);
+        assert_eq!(as_of.timestamp, 200);
+
+        let exact = storage
+            .get_value_as_of("alternativeme", "test", 300)
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(exact.value, 3.0);
+
+        assert!(
+            storage
+                .get_value_as_of("alternativeme", "test", 50)
+                .await
+                .unwrap()
+                .is_none()
+        );
+    }
+
+    #[tokio::test]
+    async fn test_sqlite_storage_query_range_page() {
+        let storage = SqliteStorage::open_in_memory().await.unwrap();
+        // Several rows share a timestamp to exercise tie-breaking across pages.
+        let metrics: Vec<Metric> = (0..7)
+            .map(|i| {
+                Metric::new(
+                    DataSource::AlternativeMe,
+                    "test",
+                    i as f64,
+                    MetricUnit::Index,
+                )
+                .with_timestamp(100 + (i / 3) * 100)
+            })
+            .collect();
+        storage.insert_batch(&metrics).await.unwrap();
+
+        let mut values = Vec::new();
+        let mut offset = 0;
+        loop {
+            let page = storage
+                .query_range_page(Some("alternativeme"), None, 0, 1000, 3, offset)
+                .await
+                .unwrap();
+            offset += page.len();
+            values.extend(page.iter().map(|m| m.value));
+            if page.len() < 3 {
+                break;
+            }
+        }
+
+        assert_eq!(values, vec![0.0, 1.0, 2.0, 3.0, 4.0, 5.0, 6.0]);
+    }
+
     #[tokio::test]
     async fn test_sqlite_storage_cleanup() {
         let storage = SqliteStorage::open_in_memory().await.unwrap();
@@ -662,6 +1344,84 @@ mod tests {
         assert!(new.is_some());
     }
 
+    #[tokio::test]
+    async fn test_sqlite_failure_counts_by_job() {
+        let storage = SqliteStorage::open_in_memory().await.unwrap();
+        let event = |event_type, message: &str, timestamp| {
+            let name = message.split('\'').nth(1).unwrap();
+            let mut event = Event::new("test", event_type, message).with_job_name(name);
+            event.timestamp = timestamp;
+            event
+        };
+        for e in [
+            event(
+                EventType::TaskFailed,
+                "Task 'fgi' failed to fetch metrics: x",
+                1000,
+            ),
+            event(
+                EventType::TaskFailed,
+                "Task 'fgi' failed to store metrics: y",
+                2000,
+            ),
+            event(
+                EventType::TaskFailed,
+                "Task 'btc' failed to fetch metrics: z",
+                3000,
+            ),
+            event(
+                EventType::TaskFailed,
+                "Task 'old' failed to fetch metrics: z",
+                10,
+            ),
+            event(
+                EventType::TaskExecuted,
+                "Task 'btc' executed successfully, 1 metrics",
+                4000,
+            ),
+        ] {
+            storage.insert_event(&e).await.unwrap();
+        }
+        // Without a recorded job name the failure is not attributed to a job.
+        storage
+            .insert_event(&Event::new(
+                "test",
+                EventType::TaskFailed,
+                "Task 'fgi' failed: w",
+            ))
+            .await
+            .unwrap();
+
+        let counts = storage.failure_counts_by_job(500).await.unwrap();
+        assert_eq!(counts, vec![("fgi".to_string(), 2), ("btc".to_string(), 1)]);
+    }
+
+    #[tokio::test]
+    async fn test_sqlite_insert_events_batch() {
+        let storage = SqliteStorage::open_in_memory().await.unwrap();
+        let events: Vec<Event> = (0..50)
+            .map(|i| {
+                let mut event = Event::new(
+                    "test",
+                    EventType::TaskExecuted,
+                    format!("Task 'job{}' executed successfully, 1 metrics", i),
+                );
+                event.timestamp = 1000 + i;
+                event
+            })
+            .collect();
+
+        storage.insert_events(&events).await.unwrap();
+
+        let stored = storage.query_events(None, 100).await.unwrap();
+        assert_eq!(stored.len(), 50);
+        assert_eq!(
+            stored[0].message,
+            "Task 'job49' executed successfully, 1 metrics"
+        );
+        assert_eq!(stored[49].timestamp, 1000);
+    }
+
     #[tokio::test]
     async fn test_sqlite_storage_health_check() {
         let storage = SqliteStorage::open_in_memory().await.unwrap();
@@ -4,6 +4,7 @@
 //! - Data ingestion jobs (via SchedulerHandle)
 //! - System maintenance tasks (data cleanup)
 //! - Metadata refresh tasks
+//! - Job reconciliation with the database
 
 use std::sync::Arc;
 use std::time::Duration;
@@ -98,6 +99,16 @@ impl TaskManager {
             self.refresh_metadata().await;
         }
 
+        // Schedule Job Reconcile Task
+        if self.config.job_reconcile_interval_secs > 0 {
+            let reconcile_job = self.create_reconcile_job()?;
+            self.add_system_job(reconcile_job).await?;
+            tracing::info!(
+                interval_secs = self.config.job_reconcile_interval_secs,
+                "Job reconcile task scheduled"
+            );
+        }
+
         Ok(())
     }
 
@@ -145,6 +156,19 @@ impl TaskManager {
         .map_err(Into::into)
     }
 
+    fn create_reconcile_job(&self) -> anyhow::Result<Job> {
+        let scheduler = self.scheduler.clone();
+        let duration = Duration::from_secs(self.config.job_reconcile_interval_secs);
+
+        Job::new_repeated_async(duration, move |_uuid, _lock| {
+            let scheduler = scheduler.clone();
+            Box::pin(async move {
+                execute_reconcile_task(&scheduler).await;
+            })
+        })
+        .map_err(Into::into)
+    }
+
     async fn refresh_metadata(&self) {
         execute_metadata_refresh_task(&self.storage, &self.metadata_cache).await;
     }
@@ -192,7 +216,7 @@ async fn execute_metadata_refresh_task(
     match storage.get_available_metrics().await {
         Ok(metrics) => {
             let mut guard = cache.write().await;
-            *guard = metrics;
+            *guard = crate::engine::normalize_metric_catalog(metrics);
             tracing::debug!("Refreshed metadata cache");
         }
         Err(e) => {
@@ -200,3 +224,16 @@ async fn execute_metadata_refresh_task(
         }
     }
 }
+
+async fn execute_reconcile_task(scheduler: &SchedulerHandle) {
+    match scheduler.reconcile_with_db().await {
+        Ok(changed) => {
+            if changed > 0 {
+                tracing::info!(changed = changed, "Reconciled scheduled jobs with database");
+            }
+        }
+        Err(e) => {
+            tracing::error!(error = %e, "Failed to reconcile scheduled jobs");
+        }
+    }
+}
@@ -0,0 +1,191 @@
+//! Shared fixtures for unit tests.
+//!
+//! Canonical sample payloads for Data API records, so tests build on one
+//! copy of each shape and override only the fields they care about:
+//!
+//! ```ignore
+//! let mut trade = sample_trade_json();
+//! trade["price"] = json!(0.42);
+//! ```
+//!
+//! Engine tests that only vary a few numeric fields use the typed builders
+//! ([`sample_trade`], [`sample_position`]) instead.
+
+use futures_util::{SinkExt, StreamExt};
+use serde_json::{Value, json};
+use tokio_tungstenite::tungstenite::protocol::Message;
+
+use crate::client::polymarket::data::{Position, Trade, TradeSide};
+use wiremock::matchers::{method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+/// Proxy wallet used by the sample payloads.
+pub(crate) const SAMPLE_USER: &str = "0x56687bf447db6ffa42ffe2204a05edaa20f55839";
+
+/// Condition ID used by the sample payloads.
+pub(crate) const SAMPLE_CONDITION_ID: &str =
+    "0xdd22472e552920b8438158ea7238bfadfa4f736aa4cee91a6b86c39ead110917";
+
+/// A Data API `/trades` record: a BUY of 10 @ 0.5 at 1700000000.
+pub(crate) fn sample_trade_json() -> Value {
+    json!({
+        "proxyWallet": SAMPLE_USER,
+        "side": "BUY",
+        "asset": "123",
+        "conditionId": SAMPLE_CONDITION_ID,
+        "size": 10.0,
+        "price": 0.5,
+        "timestamp": 1_700_000_000,
+        "title": "Test market",
+        "slug": "test-market",
+        "icon": "",
+        "eventSlug": "test-event",
+        "outcome": "Yes",
+        "outcomeIndex": 0,
+        "name": "",
+        "pseudonym": "",
+        "bio": "",
+        "profileImage": "",
+        "profileImageOptimized": "",
+        "transactionHash": "0x0"
+    })
+}
+
+/// [`sample_trade_json`] as a [`Trade`] with the given timestamp, side, price
+/// and size.
+pub(crate) fn sample_trade(timestamp: i64, side: TradeSide, price: f64, size: f64) -> Trade {
+    let mut trade: Trade = serde_json::from_value(sample_trade_json()).unwrap();
+    trade.timestamp = timestamp;
+    trade.side = side;
+    trade.price = price;
+    trade.size = size;
+    trade
+}
+
+/// A Data API `/positions` record.
+pub(crate) fn sample_position_json() -> Value {
+    json!({
+        "proxyWallet": SAMPLE_USER,
+        "asset": "123",
+        "conditionId": SAMPLE_CONDITION_ID,
+        "size": 1.0,
+        "avgPrice": 0.5,
+        "initialValue": 10.0,
+        "currentValue": 12.0,
+        "cashPnl": 0.0,
+        "percentPnl": 0.0,
+        "totalBought": 10.0,
+        "realizedPnl": 0.0,
+        "percentRealizedPnl": 0.0,
+        "curPrice": 0.6,
+        "redeemable": false,
+        "mergeable": false,
+        "title": "Test market",
+        "slug": "slug",
+        "icon": "icon",
+        "eventSlug": "event",
+        "outcome": "Yes",
+        "outcomeIndex": 0,
+        "oppositeOutcome": "No",
+        "oppositeAsset": "opposite",
+        "endDate": "2025-12-31",
+        "negativeRisk": false
+    })
+}
+
+/// [`sample_position_json`] as a [`Position`] in the given market, with the
+/// given size and current price.
+pub(crate) fn sample_position(condition_id: &str, size: f64, cur_price: f64) -> Position {
+    let mut position: Position = serde_json::from_value(sample_position_json()).unwrap();
+    position.condition_id = condition_id.to_string();
+    position.size = size;
+    position.cur_price = cur_price;
+    position
+}
+
+/// A Data API `/closed-positions` record.
+pub(crate) fn sample_closed_position_json() -> Value {
+    json!({
+        "proxyWallet": SAMPLE_USER,
+        "asset": "123",
+        "conditionId": SAMPLE_CONDITION_ID,
+        "avgPrice": 0.5,
+        "totalBought": 10.0,
+        "realizedPnl": 2.0,
+        "curPrice": 1.0,
+        "timestamp": 1_700_000_000,
+        "title": "Test market",
+        "slug": "slug",
+        "icon": "icon",
+        "eventSlug": "event",
+        "outcome": "Yes",
+        "outcomeIndex": 0,
+        "oppositeOutcome": "No",
+        "oppositeAsset": "opposite",
+        "endDate": "2023-12-31"
+    })
+}
+
+/// A Data API `/activity` record of type TRADE.
+pub(crate) fn sample_activity_json() -> Value {
+    json!({
+        "proxyWallet": SAMPLE_USER,
+        "timestamp": 1_700_000_000,
+        "conditionId": SAMPLE_CONDITION_ID,
+        "type": "TRADE",
+        "size": 10.0,
+        "usdcSize": 5.0,
+        "transactionHash": "0x0",
+        "price": 0.5,
+        "asset": "123",
+        "side": "BUY",
+        "outcomeIndex": 0,
+        "title": "Test market",
+        "slug": "test-market",
+        "icon": "",
+        "eventSlug": "test-event",
+        "outcome": "Yes",
+        "name": "",
+        "pseudonym": "",
+        "bio": "",
+        "profileImage": "",
+        "profileImageOptimized": ""
+    })
+}
+
+/// Start a mock server answering `GET` on each path with its JSON body.
+///
+/// For tests that need query matching or call-count expectations, mount
+/// extra mocks on the returned server.
+pub(crate) async fn mock_json_server<'a>(
+    routes: impl IntoIterator<Item = (&'a str, Value)>,
+) -> MockServer {
+    let server = MockServer::start().await;
+    for (route, body) in routes {
+        Mock::given(method("GET"))
+            .and(path(route))
+            .respond_with(ResponseTemplate::new(200).set_body_json(body))
+            .mount(&server)
+            .await;
+    }
+    server
+}
+
+/// Serves one WebSocket connection on localhost, sending `replies` after
+/// the first client message and then ignoring everything.
+///
+/// Returns the `ws://` URL to connect to.
+pub(crate) async fn mock_socket(replies: Vec<String>) -> String {
+    let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let addr = listener.local_addr().unwrap();
+    tokio::spawn(async move {
+        let (stream, _) = listener.accept().await.unwrap();
+        let mut ws = tokio_tungstenite::accept_async(stream).await.unwrap();
+        let _subscription = ws.next().await;
+        for reply in replies {
+            ws.send(Message::Text(reply.into())).await.unwrap();
+        }
+        while ws.next().await.is_some() {}
+    });
+    format!("ws://{}", addr)
+}
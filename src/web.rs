@@ -6,4 +6,4 @@
 pub mod handlers;
 pub mod templates;
 
-pub use handlers::create_router;
+pub use handlers::{create_router, format_age};
@@ -4,17 +4,22 @@ use std::sync::Arc;
 
 use axum::{
     Form, Router,
+    body::{Body, Bytes},
     extract::{Path, Query, State},
     http::{StatusCode, header},
     response::IntoResponse,
     routing::{get, post},
 };
 
+use futures_util::{Stream, StreamExt, stream};
 use serde::Deserialize;
 
 use crate::client::DataSourceClient;
+use crate::client::pagination::offset_pages;
+use crate::config::StalenessConfig;
+use crate::engine::normalize_metric_catalog;
 use crate::scheduler::{self, SchedulerHandle};
-use crate::storage::{Event, EventType, StorageBackend};
+use crate::storage::{Event, EventType, LatestCache, Metric, RecentBuffer, StorageBackend};
 use tokio::sync::RwLock;
 use tracing;
 
@@ -31,6 +36,62 @@ use super::templates::{
 /// Default limit for events queries.
 const DEFAULT_EVENTS_LIMIT: usize = 100;
 
+/// Look-back window for the "top failing jobs" panel on the events page.
+const TOP_FAILING_JOBS_WINDOW_MS: i64 = 24 * 60 * 60 * 1000;
+
+/// Number of jobs shown in the "top failing jobs" panel.
+const TOP_FAILING_JOBS_LIMIT: usize = 5;
+
+/// Points drawn in each status page sparkline.
+const SPARKLINE_POINTS: usize = 30;
+
+/// Number of rows read from storage per page when streaming CSV exports.
+const EXPORT_PAGE_SIZE: usize = 1000;
+
+/// Header row for CSV metric exports.
+const CSV_HEADER: &str = "timestamp,source,name,value,unit,labels\n";
+
+/// Format an age in seconds as "just now", "5m", "2h" or "3d".
+///
+/// Units are truncated, so 119 seconds is "1m". Negative ages (clock skew)
+/// count as "just now".
+pub fn format_age(seconds: i64) -> String {
+    const MINUTE: i64 = 60;
+    const HOUR: i64 = 60 * MINUTE;
+    const DAY: i64 = 24 * HOUR;
+    match seconds {
+        ..MINUTE => "just now".to_string(),
+        MINUTE..HOUR => format!("{}m", seconds / MINUTE),
+        HOUR..DAY => format!("{}h", seconds / HOUR),
+        _ => format!("{}d", seconds / DAY),
+    }
+}
+
+/// Render values (oldest first) as a Unicode block sparkline.
+///
+/// Returns an empty string for fewer than two values; a flat series is drawn
+/// at the lowest level.
+fn sparkline(values: &[f64]) -> String {
+    const BARS: [char; 8] = ['▁', '▂', '▃', '▄', '▅', '▆', '▇', '█'];
+    if values.len() < 2 {
+        return String::new();
+    }
+    let min = values.iter().copied().fold(f64::INFINITY, f64::min);
+    let max = values.iter().copied().fold(f64::NEG_INFINITY, f64::max);
+    let range = max - min;
+    values
+        .iter()
+        .map(|v| {
+            let level = if range > 0.0 {
+                ((v - min) / range * (BARS.len() - 1) as f64).round() as usize
+            } else {
+                0
+            };
+            BARS[level.min(BARS.len() - 1)]
+        })
+        .collect()
+}
+
 /// Format a unix timestamp in milliseconds to UTC string with explicit UTC suffix.
 fn format_utc_time_millis(timestamp_ms: i64, fmt: &str) -> String {
     chrono::DateTime::from_timestamp_millis(timestamp_ms)
@@ -65,15 +126,25 @@ pub struct AppState {
     pub instance_id: String,
     pub client: Arc<dyn DataSourceClient>,
     pub scheduler: SchedulerHandle,
+    /// Latest value per metric, updated by the scheduler on every batch.
+    pub latest: Arc<LatestCache>,
+    /// Age thresholds for flagging stale metrics on the status page.
+    pub staleness: StalenessConfig,
+    /// Recent points per metric, updated by the scheduler on every batch.
+    pub recent: Arc<RecentBuffer>,
 }
 
 /// Create the Axum router with all routes.
+#[allow(clippy::too_many_arguments)]
 pub fn create_router(
     storage: Arc<dyn StorageBackend>,
     metadata_cache: Arc<RwLock<Vec<(String, String)>>>,
     instance_id: String,
     client: Arc<dyn DataSourceClient>,
     scheduler: SchedulerHandle,
+    latest: Arc<LatestCache>,
+    staleness: StalenessConfig,
+    recent: Arc<RecentBuffer>,
 ) -> Router {
     let state = AppState {
         storage,
@@ -81,6 +152,9 @@ pub fn create_router(
         instance_id,
         client,
         scheduler,
+        latest,
+        staleness,
+        recent,
     };
 
     Router::new()
@@ -96,7 +170,8 @@ pub fn create_router(
         .route("/partials/metrics", get(metrics_partial))
         // API routes
         .route("/api/metrics/latest", get(api_metrics_latest))
-        .route("/api/jobs", post(api_create_job))
+        .route("/api/metrics/export.csv", get(api_metrics_export_csv))
+        .route("/api/jobs", get(api_list_jobs).post(api_create_job))
         .route(
             "/api/jobs/{id}",
             get(api_get_job).put(api_update_job).delete(api_delete_job),
@@ -140,14 +215,35 @@ fn default_limit() -> usize {
 impl MetricsQuery {
     /// Convert time range preset to seconds.
     fn time_range_seconds(&self) -> i64 {
-        match self.time_range.as_str() {
-            "1h" => 3600,
-            "6h" => 6 * 3600,
-            "24h" => 24 * 3600,
-            "7d" => 7 * 24 * 3600,
-            "30d" => 30 * 24 * 3600,
-            _ => 3600, // default to 1 hour
-        }
+        time_range_seconds(&self.time_range)
+    }
+}
+
+/// Query parameters for CSV export (same filters as `MetricsQuery`, no limit).
+#[derive(Debug, Deserialize, Default)]
+pub struct ExportQuery {
+    /// Filter by data source.
+    #[serde(default)]
+    pub source: Option<String>,
+
+    /// Filter by metric name.
+    #[serde(default)]
+    pub name: Option<String>,
+
+    /// Time range preset: "1h", "6h", "24h", "7d", "30d".
+    #[serde(default = "default_time_range")]
+    pub time_range: String,
+}
+
+/// Convert a time range preset to seconds.
+fn time_range_seconds(time_range: &str) -> i64 {
+    match time_range {
+        "1h" => 3600,
+        "6h" => 6 * 3600,
+        "24h" => 24 * 3600,
+        "7d" => 7 * 24 * 3600,
+        "30d" => 30 * 24 * 3600,
+        _ => 3600, // default to 1 hour
     }
 }
 
@@ -155,25 +251,31 @@ impl MetricsQuery {
 // Handlers
 // =============================================================================
 
-/// Index page - renders the dashboard with statistics overview.
-async fn index(State(state): State<AppState>) -> impl IntoResponse {
-    let mut available_metrics = state.metadata_cache.read().await.clone();
+/// Returns the metric catalog from the metadata cache, in canonical order.
+///
+/// Falls back to storage (and fills the cache) when the cache is empty.
+async fn load_available_metrics(state: &AppState) -> Vec<(String, String)> {
+    let cached = state.metadata_cache.read().await.clone();
+    if !cached.is_empty() {
+        return normalize_metric_catalog(cached);
+    }
 
-    // Fallback: If cache is empty, try to fetch from storage
-    if available_metrics.is_empty() {
-        tracing::debug!("Metadata cache empty, fetching from storage");
-        if let Ok(metrics) = state.storage.get_available_metrics().await
-            && !metrics.is_empty()
-        {
+    tracing::debug!("Metadata cache empty, fetching from storage");
+    match state.storage.get_available_metrics().await {
+        Ok(metrics) if !metrics.is_empty() => {
+            let metrics = normalize_metric_catalog(metrics);
             let mut cache = state.metadata_cache.write().await;
             *cache = metrics.clone();
-            available_metrics = metrics;
-            tracing::debug!(
-                count = available_metrics.len(),
-                "Metadata cache updated from storage"
-            );
+            tracing::debug!(count = metrics.len(), "Metadata cache updated from storage");
+            metrics
         }
+        _ => Vec::new(),
     }
+}
+
+/// Index page - renders the dashboard with statistics overview.
+async fn index(State(state): State<AppState>) -> impl IntoResponse {
+    let available_metrics = load_available_metrics(&state).await;
 
     // Calculate statistics
     let total_status = available_metrics.len();
@@ -203,10 +305,14 @@ async fn index(State(state): State<AppState>) -> impl IntoResponse {
         entry.1.insert(name.clone());
 
         // Get latest timestamp for this source
-        if let Ok(Some(metric)) = state.storage.get_latest(source, name).await
-            && metric.timestamp > entry.2
+        if let Ok(label_sets) = state
+            .latest
+            .label_sets_or_load(state.storage.as_ref(), source, name)
+            .await
+            && let Some(latest) = label_sets.iter().map(|m| m.timestamp).max()
+            && latest > entry.2
         {
-            entry.2 = metric.timestamp;
+            entry.2 = latest;
         }
     }
 
@@ -265,23 +371,7 @@ async fn metrics_page(
     State(state): State<AppState>,
     Query(query): Query<MetricsQuery>,
 ) -> impl IntoResponse {
-    let mut available_metrics = state.metadata_cache.read().await.clone();
-
-    // Fallback: If cache is empty, try to fetch from storage
-    if available_metrics.is_empty() {
-        tracing::debug!("Metadata cache empty, fetching from storage");
-        if let Ok(metrics) = state.storage.get_available_metrics().await
-            && !metrics.is_empty()
-        {
-            let mut cache = state.metadata_cache.write().await;
-            *cache = metrics.clone();
-            available_metrics = metrics;
-            tracing::debug!(
-                count = available_metrics.len(),
-                "Metadata cache updated from storage"
-            );
-        }
-    }
+    let available_metrics = load_available_metrics(&state).await;
 
     let mut available_sources: Vec<String> =
         available_metrics.iter().map(|(s, _)| s.clone()).collect();
@@ -308,51 +398,66 @@ async fn metrics_page(
 /// Status page - shows the latest value of each metric from cache.
 async fn status(State(state): State<AppState>) -> impl IntoResponse {
     let now = chrono::Utc::now();
-    let mut available_metrics = state.metadata_cache.read().await.clone();
-
-    // Fallback: If cache is empty, try to fetch from storage
-    if available_metrics.is_empty() {
-        tracing::debug!("Metadata cache empty, fetching from storage");
-        if let Ok(metrics) = state.storage.get_available_metrics().await
-            && !metrics.is_empty()
-        {
-            let mut cache = state.metadata_cache.write().await;
-            *cache = metrics.clone();
-            available_metrics = metrics;
-            tracing::debug!(
-                count = available_metrics.len(),
-                "Metadata cache updated from storage"
-            );
-        }
+    StatusTemplate {
+        title: "System Status".to_string(),
+        metrics: latest_metric_views(&state, now).await,
+        last_updated: now.format("%Y-%m-%d %H:%M:%S UTC").to_string(),
     }
+}
+
+/// Builds the status page rows, with ages measured against `now`.
+async fn latest_metric_views(
+    state: &AppState,
+    now: chrono::DateTime<chrono::Utc>,
+) -> Vec<LatestMetricView> {
+    let available_metrics = load_available_metrics(state).await;
 
     let mut metrics = Vec::new();
     for (source, name) in available_metrics {
-        if let Ok(Some(metric)) = state.storage.get_latest(&source, &name).await {
-            let age_seconds = now.timestamp() - metric.timestamp;
-            let timestamp = chrono::DateTime::from_timestamp(metric.timestamp, 0)
+        let label_sets = state
+            .latest
+            .label_sets_or_load(state.storage.as_ref(), &source, &name)
+            .await
+            .unwrap_or_default();
+        for metric in label_sets {
+            let age_seconds = (now.timestamp_millis() - metric.timestamp) / 1000;
+            let timestamp = chrono::DateTime::from_timestamp_millis(metric.timestamp)
                 .map(|dt| dt.format("%Y-%m-%d %H:%M:%S").to_string())
                 .unwrap_or_else(|| metric.timestamp.to_string());
 
+            let source = metric.source.to_string();
+            let mut points: Vec<f64> = state
+                .recent
+                .recent(&source, &metric.name, &metric.labels, SPARKLINE_POINTS)
+                .iter()
+                .map(|m| m.value)
+                .collect();
+            points.reverse();
+            let mut labels: Vec<String> = metric
+                .labels
+                .iter()
+                .map(|(key, value)| format!("{}={}", key, value))
+                .collect();
+            labels.sort();
             metrics.push(LatestMetricView {
-                source: metric.source.to_string(),
+                sparkline: sparkline(&points),
+                stale: state.staleness.is_stale(&source, age_seconds),
+                key: metric.series_key(),
+                labels: labels.join(", "),
+                source,
                 name: metric.name,
-                value: format!("{:.4}", metric.value),
+                value: metric.unit.format_value(metric.value),
                 unit: metric.unit.to_string(),
                 timestamp,
+                age: format_age(age_seconds),
                 age_seconds,
             });
         }
     }
 
-    // Sort by source, then by name
-    metrics.sort_by(|a, b| (&a.source, &a.name).cmp(&(&b.source, &b.name)));
-
-    StatusTemplate {
-        title: "System Status".to_string(),
-        metrics,
-        last_updated: now.format("%Y-%m-%d %H:%M:%S UTC").to_string(),
-    }
+    // Sort by source, then by name and labels
+    metrics.sort_by(|a, b| (&a.source, &a.name, &a.labels).cmp(&(&b.source, &b.name, &b.labels)));
+    metrics
 }
 
 /// Query parameters for events page.
@@ -406,12 +511,23 @@ async fn events(
 
     let event_views: Vec<EventView> = events.into_iter().map(EventView::from_event).collect();
 
+    let since = chrono::Utc::now().timestamp_millis() - TOP_FAILING_JOBS_WINDOW_MS;
+    let mut top_failing_jobs = match state.storage.failure_counts_by_job(since).await {
+        Ok(counts) => counts,
+        Err(e) => {
+            tracing::warn!(error = %e, "Failed to count job failures");
+            Vec::new()
+        }
+    };
+    top_failing_jobs.truncate(TOP_FAILING_JOBS_LIMIT);
+
     EventsTemplate {
         title: "System Events".to_string(),
         events: event_views,
         instance_id: state.instance_id.clone(),
         available_instances,
         filter_instance: query.instance_id,
+        top_failing_jobs,
     }
 }
 
@@ -457,13 +573,21 @@ async fn api_metrics_latest(
         }
     };
 
+    let non_finite = metrics.iter().filter(|m| !m.value.is_finite()).count();
+    if non_finite > 0 {
+        tracing::warn!(
+            count = non_finite,
+            "replaced non-finite metric values with null in API response"
+        );
+    }
+
     let json_metrics: Vec<serde_json::Value> = metrics
         .into_iter()
         .map(|m| {
             serde_json::json!({
                 "source": m.source.to_string(),
                 "name": m.name,
-                "value": m.value,
+                "value": m.value.is_finite().then_some(m.value),
                 "timestamp": m.timestamp,
                 "labels": m.labels,
             })
@@ -473,6 +597,41 @@ async fn api_metrics_latest(
     axum::Json(json_metrics)
 }
 
+/// CSV export endpoint that streams metrics page by page.
+///
+/// Rows are read from storage in pages of `EXPORT_PAGE_SIZE` and written to
+/// the response as they arrive, so large ranges are never fully buffered.
+async fn api_metrics_export_csv(
+    State(state): State<AppState>,
+    Query(query): Query<ExportQuery>,
+) -> impl IntoResponse {
+    let now = chrono::Utc::now().timestamp_millis();
+    let start = now - time_range_seconds(&query.time_range) * 1000;
+
+    let source = query.source.filter(|s| !s.is_empty());
+    let name = query.name.filter(|s| !s.is_empty());
+
+    let body = Body::from_stream(metrics_csv_stream(
+        state.storage,
+        source,
+        name,
+        start,
+        now,
+        EXPORT_PAGE_SIZE,
+    ));
+
+    (
+        [
+            (header::CONTENT_TYPE, "text/csv; charset=utf-8"),
+            (
+                header::CONTENT_DISPOSITION,
+                "attachment; filename=\"metrics.csv\"",
+            ),
+        ],
+        body,
+    )
+}
+
 // =============================================================================
 // Jobs Handlers
 // =============================================================================
@@ -512,12 +671,29 @@ pub struct JobFormData {
     pub retention_days: u32,
     #[serde(default)]
     pub enabled: Option<String>,
+    #[serde(default)]
+    pub only_on_change: Option<String>,
 }
 
 fn default_retention() -> u32 {
     7
 }
 
+/// API endpoint listing every stored job with its scheduling status.
+async fn api_list_jobs(State(state): State<AppState>) -> impl IntoResponse {
+    match state.scheduler.job_statuses().await {
+        Ok(statuses) => axum::Json(statuses).into_response(),
+        Err(e) => {
+            tracing::error!(error = %e, "Failed to list job statuses");
+            (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                format!("Failed to list jobs: {}", e),
+            )
+                .into_response()
+        }
+    }
+}
+
 /// API endpoint to create a new job.
 async fn api_create_job(
     State(state): State<AppState>,
@@ -546,6 +722,7 @@ async fn api_create_job(
         match form.schedule_value.parse::<u64>() {
             Ok(secs) => crate::config::Schedule::Interval {
                 interval_secs: secs,
+                aligned: false,
             },
             Err(_) => {
                 return render_error("Invalid interval value. Must be a number.".to_string())
@@ -571,6 +748,10 @@ async fn api_create_job(
         params,
         retention_days: form.retention_days,
         enabled: form.enabled.is_some(),
+        only_on_change: form.only_on_change.is_some(),
+        sampling: None,
+        value_bounds: None,
+        clamp_values: false,
     };
 
     // Validate job configuration before storing
@@ -672,7 +853,7 @@ async fn api_get_job(State(state): State<AppState>, Path(id): Path<i64>) -> impl
     match state.storage.get_job(id).await {
         Ok(Some(record)) => {
             let (schedule_type, schedule_value) = match &record.job.schedule {
-                crate::config::Schedule::Interval { interval_secs } => {
+                crate::config::Schedule::Interval { interval_secs, .. } => {
                     ("interval".to_string(), interval_secs.to_string())
                 }
                 crate::config::Schedule::Cron { cron } => ("cron".to_string(), cron.clone()),
@@ -693,6 +874,7 @@ async fn api_get_job(State(state): State<AppState>, Path(id): Path<i64>) -> impl
                 "params": params,
                 "retention_days": record.job.retention_days,
                 "enabled": record.job.enabled,
+                "only_on_change": record.job.only_on_change,
             });
 
             axum::Json(job_data).into_response()
@@ -726,6 +908,7 @@ async fn api_update_job(
             params: form.params.clone(),
             retention_days: form.retention_days,
             enabled: form.enabled.clone(),
+            only_on_change: form.only_on_change.clone(),
         };
 
         JobFormTemplate {
@@ -741,6 +924,10 @@ async fn api_update_job(
         Err(_) => return render_error("Invalid datasource".to_string()).into_response(),
     };
 
+    // The form does not expose alignment, sampling or value bounds, so keep
+    // the stored settings.
+    let stored = state.storage.get_job(id).await.ok().flatten();
+
     // Parse schedule
     let schedule = if form.schedule_type == "cron" {
         crate::config::Schedule::Cron {
@@ -748,9 +935,22 @@ async fn api_update_job(
         }
     } else {
         match form.schedule_value.parse::<u64>() {
-            Ok(secs) => crate::config::Schedule::Interval {
-                interval_secs: secs,
-            },
+            Ok(secs) => {
+                let aligned = matches!(
+                    &stored,
+                    Some(crate::storage::JobRecord {
+                        job: crate::config::IngestionJob {
+                            schedule: crate::config::Schedule::Interval { aligned: true, .. },
+                            ..
+                        },
+                        ..
+                    })
+                );
+                crate::config::Schedule::Interval {
+                    interval_secs: secs,
+                    aligned,
+                }
+            }
             Err(_) => {
                 return render_error("Invalid interval value. Must be a number.".to_string())
                     .into_response();
@@ -775,6 +975,10 @@ async fn api_update_job(
         params,
         retention_days: form.retention_days,
         enabled: form.enabled.is_some(),
+        only_on_change: form.only_on_change.is_some(),
+        sampling: stored.as_ref().and_then(|record| record.job.sampling),
+        value_bounds: stored.as_ref().and_then(|record| record.job.value_bounds),
+        clamp_values: stored.is_some_and(|record| record.job.clamp_values),
     };
 
     // Validate job configuration before updating
@@ -841,13 +1045,7 @@ async fn api_trigger_job(State(state): State<AppState>, Path(id): Path<i64>) ->
     };
 
     // Execute job immediately
-    scheduler::trigger_job(
-        &job_record.job,
-        state.scheduler.client(),
-        state.scheduler.storage(),
-        state.scheduler.instance_id(),
-    )
-    .await;
+    scheduler::trigger_job(&job_record.job, &state.scheduler.run_context()).await;
 
     tracing::info!(job_id = id, job_name = %job_record.job.name, "Triggered job execution");
 
@@ -885,6 +1083,84 @@ async fn fetch_filtered_metrics(state: &AppState, query: &MetricsQuery) -> Vec<M
     }
 }
 
+/// Stream metrics as CSV: a header chunk followed by one chunk per storage page.
+///
+/// Paging is unbounded: storage pages end with a short page, and an export
+/// must not be truncated by a page budget.
+fn metrics_csv_stream(
+    storage: Arc<dyn StorageBackend>,
+    source: Option<String>,
+    name: Option<String>,
+    start: i64,
+    end: i64,
+    page_size: usize,
+) -> impl Stream<Item = anyhow::Result<Bytes>> + Send {
+    let header = stream::once(async { Ok(Bytes::from_static(CSV_HEADER.as_bytes())) });
+
+    let pages = offset_pages(page_size, 0, usize::MAX, move |offset| {
+        let storage = Arc::clone(&storage);
+        let source = source.clone();
+        let name = name.clone();
+        async move {
+            storage
+                .query_range_page(
+                    source.as_deref(),
+                    name.as_deref(),
+                    start,
+                    end,
+                    page_size,
+                    offset,
+                )
+                .await
+        }
+    });
+
+    let rows = pages.map(|page| {
+        let mut chunk = String::new();
+        for metric in &page? {
+            push_csv_row(&mut chunk, metric);
+        }
+        Ok(Bytes::from(chunk))
+    });
+
+    header.chain(rows)
+}
+
+/// Append a metric as a CSV row (labels are encoded as a JSON object).
+fn push_csv_row(out: &mut String, metric: &Metric) {
+    let labels = if metric.labels.is_empty() {
+        String::new()
+    } else {
+        serde_json::to_string(&metric.labels).unwrap_or_default()
+    };
+    let fields = [
+        metric.timestamp.to_string(),
+        metric.source.to_string(),
+        metric.name.clone(),
+        metric.value.to_string(),
+        metric.unit.to_string(),
+        labels,
+    ];
+    for (i, field) in fields.iter().enumerate() {
+        if i > 0 {
+            out.push(',');
+        }
+        push_csv_field(out, field);
+    }
+    out.push('\n');
+}
+
+/// Append a single CSV field, quoting it when it contains separators or quotes.
+fn push_csv_field(out: &mut String, field: &str) {
+    if field.contains([',', '"', '\n', '\r']) {
+        out.push('"');
+        out.push_str(&field.replace('"', "\"\""));
+        out.push('"');
+    } else {
+        out.push_str(field);
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -926,6 +1202,9 @@ mod tests {
             instance_id: "test-instance".to_string(),
             client,
             scheduler,
+            latest: Arc::new(LatestCache::default()),
+            staleness: StalenessConfig::default(),
+            recent: Arc::new(RecentBuffer::default()),
         };
 
         // 3. Call index handler (now only takes State, no Query)
@@ -939,4 +1218,190 @@ mod tests {
         assert_eq!(cache[0].0, "alternativeme");
         assert_eq!(cache[0].1, "test_fallback");
     }
+
+    #[test]
+    fn test_format_age_boundaries() {
+        assert_eq!(format_age(-5), "just now");
+        assert_eq!(format_age(0), "just now");
+        assert_eq!(format_age(59), "just now");
+        assert_eq!(format_age(60), "1m");
+        assert_eq!(format_age(3599), "59m");
+        assert_eq!(format_age(3600), "1h");
+        assert_eq!(format_age(86_399), "23h");
+        assert_eq!(format_age(86_400), "1d");
+        assert_eq!(format_age(3 * 86_400 + 5), "3d");
+    }
+
+    #[test]
+    fn test_sparkline_levels() {
+        assert_eq!(sparkline(&[1.0]), "");
+        assert_eq!(sparkline(&[0.0, 7.0, 3.5]), "▁█▅");
+        assert_eq!(sparkline(&[2.0, 2.0]), "▁▁");
+    }
+
+    #[test]
+    fn test_staleness_threshold_per_source() {
+        let staleness = StalenessConfig {
+            default_secs: 300,
+            sources: [("alternativeme".to_string(), 86_400)].into(),
+        };
+        assert!(!staleness.is_stale("coingecko", 300));
+        assert!(staleness.is_stale("coingecko", 301));
+        assert!(!staleness.is_stale("alternativeme", 3600));
+    }
+
+    async fn create_test_state() -> AppState {
+        let storage = Arc::new(
+            LocalStorage::new_in_memory(LocalStorageConfig::default())
+                .await
+                .unwrap(),
+        );
+        let client = Arc::new(AlternativeMeClient::new());
+        let scheduler =
+            SchedulerHandle::new(client.clone(), storage.clone(), "test-instance".to_string())
+                .await
+                .unwrap();
+
+        AppState {
+            storage,
+            metadata_cache: Arc::new(RwLock::new(Vec::new())),
+            instance_id: "test-instance".to_string(),
+            client,
+            scheduler,
+            latest: Arc::new(LatestCache::default()),
+            staleness: StalenessConfig::default(),
+            recent: Arc::new(RecentBuffer::default()),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_status_age_from_millisecond_timestamps() {
+        let state = create_test_state().await;
+        let now = chrono::Utc::now();
+        let fresh = Metric::new(DataSource::AlternativeMe, "fresh", 1.0, MetricUnit::Index);
+        let old = Metric::new(DataSource::AlternativeMe, "old", 2.0, MetricUnit::Index)
+            .with_timestamp(now.timestamp_millis() - 3_600_000);
+        state.storage.store(&[fresh, old]).await.unwrap();
+
+        let views = latest_metric_views(&state, now).await;
+
+        assert_eq!(views.len(), 2);
+        let fresh = views.iter().find(|v| v.name == "fresh").unwrap();
+        assert!((0..5).contains(&fresh.age_seconds), "{}", fresh.age_seconds);
+        assert!(!fresh.stale);
+        assert_eq!(fresh.age, "just now");
+        let old = views.iter().find(|v| v.name == "old").unwrap();
+        assert!(
+            (3599..=3601).contains(&old.age_seconds),
+            "{}",
+            old.age_seconds
+        );
+        assert!(old.stale);
+        assert_eq!(old.age, "1h");
+    }
+
+    #[tokio::test]
+    async fn test_status_shows_each_label_set() {
+        let state = create_test_state().await;
+        let now = chrono::Utc::now();
+        let price = |symbol: &str, value: f64| {
+            Metric::new(DataSource::CoinGecko, "price", value, MetricUnit::USD)
+                .with_label("symbol", symbol)
+        };
+        let metrics = [price("BTC", 100.0), price("ETH", 10.0)];
+        state.storage.store(&metrics).await.unwrap();
+        state.latest.record(&metrics).await;
+
+        let views = latest_metric_views(&state, now).await;
+
+        let rows: Vec<(&str, &str, &str)> = views
+            .iter()
+            .map(|v| (v.name.as_str(), v.labels.as_str(), v.value.as_str()))
+            .collect();
+        assert_eq!(
+            rows,
+            vec![
+                ("price", "symbol=BTC", "100.00"),
+                ("price", "symbol=ETH", "10.00")
+            ]
+        );
+        assert_ne!(views[0].key, views[1].key);
+    }
+
+    #[tokio::test]
+    async fn test_export_csv_streams_all_rows() {
+        let state = create_test_state().await;
+        let now = chrono::Utc::now().timestamp_millis();
+        let metrics: Vec<Metric> = (0..25)
+            .map(|i| {
+                Metric::new(
+                    DataSource::AlternativeMe,
+                    "fng",
+                    i as f64,
+                    MetricUnit::Index,
+                )
+                .with_timestamp(now - 60_000 + i)
+                .with_label("classification", "Fear, Extreme")
+            })
+            .collect();
+        state.storage.store(&metrics).await.unwrap();
+
+        let mut chunks = Box::pin(metrics_csv_stream(
+            state.storage.clone(),
+            Some("alternativeme".to_string()),
+            None,
+            now - 3_600_000,
+            now,
+            10,
+        ));
+        let mut chunk_count = 0;
+        let mut csv = String::new();
+        while let Some(chunk) = chunks.next().await {
+            chunk_count += 1;
+            csv.push_str(std::str::from_utf8(&chunk.unwrap()).unwrap());
+        }
+
+        // Header chunk + pages of 10, 10 and 5 rows.
+        assert_eq!(chunk_count, 4);
+        let lines: Vec<&str> = csv.lines().collect();
+        assert_eq!(lines.len(), 26);
+        assert_eq!(lines[0], CSV_HEADER.trim_end());
+        assert!(lines[1].contains("\"{\"\"classification\"\":\"\"Fear, Extreme\"\"}\""));
+    }
+
+    #[tokio::test]
+    async fn test_export_csv_response_is_streamed() {
+        let state = create_test_state().await;
+        let metric = Metric::new(DataSource::AlternativeMe, "fng", 40.0, MetricUnit::Index);
+        state.storage.store(&[metric]).await.unwrap();
+
+        let response = api_metrics_export_csv(State(state), Query(ExportQuery::default()))
+            .await
+            .into_response();
+
+        assert_eq!(response.status(), StatusCode::OK);
+        assert_eq!(
+            response.headers()[header::CONTENT_TYPE],
+            "text/csv; charset=utf-8"
+        );
+        // No Content-Length: the body is sent with chunked transfer encoding.
+        assert!(response.headers().get(header::CONTENT_LENGTH).is_none());
+        assert!(
+            axum::body::HttpBody::size_hint(response.body())
+                .exact()
+                .is_none()
+        );
+
+        let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        let csv = String::from_utf8(body.to_vec()).unwrap();
+        assert_eq!(csv.lines().count(), 2);
+        assert!(
+            csv.lines()
+                .nth(1)
+                .unwrap()
+                .contains(",alternativeme,fng,40,index,")
+        );
+    }
 }
@@ -117,12 +117,22 @@ pub struct StatusTemplate {
 
 /// View model for the latest metric value.
 pub struct LatestMetricView {
+    /// Series key, unique per row.
+    pub key: String,
     pub source: String,
     pub name: String,
+    /// Labels as sorted `key=value` pairs, empty if unlabelled.
+    pub labels: String,
     pub value: String,
     pub unit: String,
     pub timestamp: String,
     pub age_seconds: i64,
+    /// Human-readable age, e.g. "5m".
+    pub age: String,
+    /// Older than the staleness threshold for its source.
+    pub stale: bool,
+    /// Recent values as a Unicode sparkline, empty if too few points.
+    pub sparkline: String,
 }
 
 // =============================================================================
@@ -169,6 +179,8 @@ pub struct EventsTemplate {
     pub instance_id: String,
     pub available_instances: Vec<String>,
     pub filter_instance: Option<String>,
+    /// Jobs with the most `TaskFailed` events in the last 24 hours.
+    pub top_failing_jobs: Vec<(String, usize)>,
 }
 
 /// View model for a single event.
@@ -274,8 +286,16 @@ impl JobView {
         let updated_at = format_utc_time_seconds(record.updated_at, "%Y-%m-%d %H:%M:%S");
 
         let (schedule, schedule_type) = match &record.job.schedule {
-            crate::config::Schedule::Interval { interval_secs } => {
-                (format!("{}s", interval_secs), "interval".to_string())
+            crate::config::Schedule::Interval {
+                interval_secs,
+                aligned,
+            } => {
+                let schedule = if *aligned {
+                    format!("{}s (aligned)", interval_secs)
+                } else {
+                    format!("{}s", interval_secs)
+                };
+                (schedule, "interval".to_string())
             }
             crate::config::Schedule::Cron { cron } => (cron.clone(), "cron".to_string()),
         };
@@ -436,4 +456,5 @@ pub struct JobFormDataView {
     pub params: Option<String>,
     pub retention_days: u32,
     pub enabled: Option<String>, // "true" or None (checkbox)
+    pub only_on_change: Option<String>,
 }